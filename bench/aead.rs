@@ -23,6 +23,7 @@ use ring::{
 static ALGORITHMS: &[(&str, &aead::Algorithm)] = &[
     ("aes128_gcm", &aead::AES_128_GCM),
     ("aes256_gcm", &aead::AES_256_GCM),
+    #[cfg(not(feature = "fips"))]
     ("chacha20_poly1305", &aead::CHACHA20_POLY1305),
 ];
 
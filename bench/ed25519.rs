@@ -0,0 +1,71 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+#![allow(missing_docs)]
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use ring::{
+    rand,
+    signature::{self, Ed25519KeyPair, KeyPair},
+};
+
+const BATCH_SIZES: [usize; 2] = [64, 1024];
+
+fn verify(c: &mut Criterion) {
+    let rng = rand::SystemRandom::new();
+    let key_pairs: Vec<Ed25519KeyPair> = (0..BATCH_SIZES[BATCH_SIZES.len() - 1])
+        .map(|_| {
+            let pkcs8_bytes = Ed25519KeyPair::generate_pkcs8(&rng).unwrap();
+            Ed25519KeyPair::from_pkcs8(pkcs8_bytes.as_ref()).unwrap()
+        })
+        .collect();
+    let messages: Vec<[u8; 32]> = (0..key_pairs.len())
+        .map(|i| [u8::try_from(i % 256).unwrap(); 32])
+        .collect();
+    let signatures: Vec<signature::Signature> = key_pairs
+        .iter()
+        .zip(&messages)
+        .map(|(key_pair, msg)| key_pair.sign(msg))
+        .collect();
+    let entries: Vec<(&[u8], &[u8], &[u8])> = key_pairs
+        .iter()
+        .zip(&messages)
+        .zip(&signatures)
+        .map(|((key_pair, msg), sig)| (key_pair.public_key().as_ref(), &msg[..], sig.as_ref()))
+        .collect();
+
+    let mut group = c.benchmark_group("ed25519_verify");
+    for batch_size in BATCH_SIZES {
+        let entries = &entries[..batch_size];
+        let _ = group.throughput(Throughput::Elements(u64::try_from(batch_size).unwrap()));
+
+        let _ = group.bench_function(format!("serial_{batch_size}"), |b| {
+            b.iter(|| {
+                for (public_key, msg, sig) in entries {
+                    signature::UnparsedPublicKey::new(&signature::ED25519, public_key)
+                        .verify(msg, black_box(sig))
+                        .unwrap();
+                }
+            })
+        });
+
+        let _ = group.bench_function(format!("batch_{batch_size}"), |b| {
+            b.iter(|| signature::ed25519_verify_batch(black_box(entries), &rng).unwrap())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(ed25519, verify);
+criterion_main!(ed25519);
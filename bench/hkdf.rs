@@ -0,0 +1,54 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+#![allow(missing_docs)]
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use ring::hkdf;
+
+struct Len(usize);
+
+impl hkdf::KeyType for Len {
+    fn len(&self) -> usize {
+        self.0
+    }
+}
+
+// The traffic keys and IVs of a TLS 1.3-style key schedule.
+const OUTPUTS: [(&[&[u8]], usize); 4] = [
+    (&[b"client", b" key"], 32),
+    (&[b"client", b" iv"], 12),
+    (&[b"server", b" key"], 32),
+    (&[b"server", b" iv"], 12),
+];
+
+fn key_schedule(c: &mut Criterion) {
+    let prk = hkdf::Salt::new(hkdf::HKDF_SHA256, b"salt").extract(&[0; 32]);
+
+    c.bench_function("hkdf::Prk::expand", |b| {
+        b.iter(|| {
+            let mut out = [0u8; 32];
+            for (info, len) in OUTPUTS {
+                prk.expand(info, Len(len))
+                    .unwrap()
+                    .fill(&mut out[..len])
+                    .unwrap();
+                black_box(&out);
+            }
+        })
+    });
+}
+
+criterion_group!(hkdf, key_schedule);
+criterion_main!(hkdf);
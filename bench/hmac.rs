@@ -0,0 +1,60 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+#![allow(missing_docs)]
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use ring::hmac;
+
+const NUM_KEYS: &[usize] = &[1, 4, 16];
+
+const INPUT_LENGTHS: &[usize] = &[64, 16 * 1024, 1024 * 1024];
+
+fn multi_key(c: &mut Criterion) {
+    for &num_keys in NUM_KEYS {
+        let keys: Vec<hmac::Key> = (0..num_keys)
+            .map(|i| hmac::Key::new(hmac::HMAC_SHA256, &[i as u8; 32]))
+            .collect();
+        let keys: Vec<&hmac::Key> = keys.iter().collect();
+
+        for &input_len in INPUT_LENGTHS {
+            let input = vec![0u8; input_len];
+
+            c.bench_with_input(
+                BenchmarkId::new(format!("hmac::sign_multi_key::{num_keys}"), input_len),
+                &input,
+                |b, input| {
+                    b.iter(|| -> usize {
+                        let tags = hmac::sign_multi_key(&keys, input);
+                        black_box(tags.len())
+                    })
+                },
+            );
+
+            c.bench_with_input(
+                BenchmarkId::new(format!("hmac::sign::{num_keys}"), input_len),
+                &input,
+                |b, input| {
+                    b.iter(|| -> usize {
+                        let tags: Vec<hmac::Tag> =
+                            keys.iter().map(|key| hmac::sign(key, input)).collect();
+                        black_box(tags.len())
+                    })
+                },
+            );
+        }
+    }
+}
+
+criterion_group!(hmac, multi_key);
+criterion_main!(hmac);
@@ -869,6 +869,7 @@ fn prefix_all_symbols(pp: char, prefix_prefix: &str, prefix: &str) -> String {
         "aes_hw_ctr32_encrypt_blocks",
         "aes_hw_set_encrypt_key",
         "aes_nohw_ctr32_encrypt_blocks",
+        "aes_nohw_decrypt",
         "aes_nohw_encrypt",
         "aes_nohw_set_encrypt_key",
         "aesni_gcm_decrypt",
@@ -938,8 +939,10 @@ fn prefix_all_symbols(pp: char, prefix_prefix: &str, prefix: &str) -> String {
         "x25519_fe_mul_ttt",
         "x25519_fe_neg",
         "x25519_fe_tobytes",
+        "x25519_ge_cofactor_multi_scalarmult_vartime",
         "x25519_ge_double_scalarmult_vartime",
         "x25519_ge_frombytes_vartime",
+        "x25519_ge_p3_to_montgomery_u",
         "x25519_ge_scalarmult_base",
         "x25519_ge_scalarmult_base_adx",
         "x25519_public_from_private_generic_masked",
@@ -874,6 +874,23 @@ static void slide(signed char *r, const uint8_t *a) {
   }
 }
 
+// Ai = A,3A,5A,7A,9A,11A,13A,15A
+static void ge_odd_multiples(ge_cached Ai[8], const ge_p3 *A) {
+  ge_p1p1 t;
+  ge_p3 u;
+  ge_p3 A2;
+  int i;
+
+  x25519_ge_p3_to_cached(&Ai[0], A);
+  ge_p3_dbl(&t, A);
+  x25519_ge_p1p1_to_p3(&A2, &t);
+  for (i = 1; i < 8; ++i) {
+    x25519_ge_add(&t, &A2, &Ai[i - 1]);
+    x25519_ge_p1p1_to_p3(&u, &t);
+    x25519_ge_p3_to_cached(&Ai[i], &u);
+  }
+}
+
 // r = a * A + b * B
 // where a = a[0]+256*a[1]+...+256^31 a[31].
 // and b = b[0]+256*b[1]+...+256^31 b[31].
@@ -885,36 +902,12 @@ static void ge_double_scalarmult_vartime(ge_p2 *r, const uint8_t *a,
   ge_cached Ai[8];  // A,3A,5A,7A,9A,11A,13A,15A
   ge_p1p1 t;
   ge_p3 u;
-  ge_p3 A2;
   int i;
 
   slide(aslide, a);
   slide(bslide, b);
 
-  x25519_ge_p3_to_cached(&Ai[0], A);
-  ge_p3_dbl(&t, A);
-  x25519_ge_p1p1_to_p3(&A2, &t);
-  x25519_ge_add(&t, &A2, &Ai[0]);
-  x25519_ge_p1p1_to_p3(&u, &t);
-  x25519_ge_p3_to_cached(&Ai[1], &u);
-  x25519_ge_add(&t, &A2, &Ai[1]);
-  x25519_ge_p1p1_to_p3(&u, &t);
-  x25519_ge_p3_to_cached(&Ai[2], &u);
-  x25519_ge_add(&t, &A2, &Ai[2]);
-  x25519_ge_p1p1_to_p3(&u, &t);
-  x25519_ge_p3_to_cached(&Ai[3], &u);
-  x25519_ge_add(&t, &A2, &Ai[3]);
-  x25519_ge_p1p1_to_p3(&u, &t);
-  x25519_ge_p3_to_cached(&Ai[4], &u);
-  x25519_ge_add(&t, &A2, &Ai[4]);
-  x25519_ge_p1p1_to_p3(&u, &t);
-  x25519_ge_p3_to_cached(&Ai[5], &u);
-  x25519_ge_add(&t, &A2, &Ai[5]);
-  x25519_ge_p1p1_to_p3(&u, &t);
-  x25519_ge_p3_to_cached(&Ai[6], &u);
-  x25519_ge_add(&t, &A2, &Ai[6]);
-  x25519_ge_p1p1_to_p3(&u, &t);
-  x25519_ge_p3_to_cached(&Ai[7], &u);
+  ge_odd_multiples(Ai, A);
 
   ge_p2_0(r);
 
@@ -947,6 +940,58 @@ static void ge_double_scalarmult_vartime(ge_p2 *r, const uint8_t *a,
   }
 }
 
+// r = 8 * (b * B + a_0 * A_0 + ... + a_{n-1} * A_{n-1})
+// where each a_i is 32 bytes of |a| in the same form as |b|, and B is the
+// Ed25519 base point. |Ai| and |aslide| are scratch space for 8 * n and
+// 256 * n entries, respectively.
+static void ge_cofactor_multi_scalarmult_vartime(
+    ge_p2 *r, const uint8_t *b, const uint8_t *a, const ge_p3 *A, size_t n,
+    ge_cached *Ai, signed char *aslide) {
+  signed char bslide[256];
+  ge_p1p1 t;
+  ge_p3 u;
+  size_t j;
+  int i;
+
+  slide(bslide, b);
+  for (j = 0; j < n; ++j) {
+    slide(&aslide[256 * j], &a[32 * j]);
+    ge_odd_multiples(&Ai[8 * j], &A[j]);
+  }
+
+  ge_p2_0(r);
+
+  for (i = 255; i >= 0; --i) {
+    ge_p2_dbl(&t, r);
+
+    for (j = 0; j < n; ++j) {
+      signed char digit = aslide[256 * j + (size_t)i];
+      if (digit > 0) {
+        x25519_ge_p1p1_to_p3(&u, &t);
+        x25519_ge_add(&t, &u, &Ai[8 * j + (size_t)(digit / 2)]);
+      } else if (digit < 0) {
+        x25519_ge_p1p1_to_p3(&u, &t);
+        x25519_ge_sub(&t, &u, &Ai[8 * j + (size_t)(-digit / 2)]);
+      }
+    }
+
+    if (bslide[i] > 0) {
+      x25519_ge_p1p1_to_p3(&u, &t);
+      ge_madd(&t, &u, &Bi[bslide[i] / 2]);
+    } else if (bslide[i] < 0) {
+      x25519_ge_p1p1_to_p3(&u, &t);
+      ge_msub(&t, &u, &Bi[(-bslide[i]) / 2]);
+    }
+
+    x25519_ge_p1p1_to_p2(r, &t);
+  }
+
+  for (i = 0; i < 3; ++i) {
+    ge_p2_dbl(&t, r);
+    x25519_ge_p1p1_to_p2(r, &t);
+  }
+}
+
 // int64_lshift21 returns |a << 21| but is defined when shifting bits into the
 // sign bit. This works around a language flaw in C.
 static inline int64_t int64_lshift21(int64_t a) {
@@ -1864,6 +1909,20 @@ void x25519_scalar_mult_generic_masked(uint8_t out[32],
   fe_tobytes(out, &x2);
 }
 
+// Maps the Edwards point |A| to the u-coordinate of the corresponding
+// curve25519 point. The identity maps to zero.
+void x25519_ge_p3_to_montgomery_u(uint8_t out[32], const ge_p3 *A) {
+  // We only need the u-coordinate of the curve25519 point. The map is
+  // u=(y+1)/(1-y). Since y=Y/Z, this gives u=(Z+Y)/(Z-Y).
+  fe_loose zplusy, zminusy;
+  fe zminusy_inv;
+  fe_add(&zplusy, &A->Z, &A->Y);
+  fe_sub(&zminusy, &A->Z, &A->Y);
+  fe_loose_invert(&zminusy_inv, &zminusy);
+  fe_mul_tlt(&zminusy_inv, &zplusy, &zminusy_inv);
+  fe_tobytes(out, &zminusy_inv);
+}
+
 void x25519_public_from_private_generic_masked(uint8_t out_public_value[32],
                                                const uint8_t private_key_masked[32],
                                                int use_adx) {
@@ -1873,15 +1932,7 @@ void x25519_public_from_private_generic_masked(uint8_t out_public_value[32],
   ge_p3 A;
   x25519_ge_scalarmult_base(&A, e, use_adx);
 
-  // We only need the u-coordinate of the curve25519 point. The map is
-  // u=(y+1)/(1-y). Since y=Y/Z, this gives u=(Z+Y)/(Z-Y).
-  fe_loose zplusy, zminusy;
-  fe zminusy_inv;
-  fe_add(&zplusy, &A.Z, &A.Y);
-  fe_sub(&zminusy, &A.Z, &A.Y);
-  fe_loose_invert(&zminusy_inv, &zminusy);
-  fe_mul_tlt(&zminusy_inv, &zplusy, &zminusy_inv);
-  fe_tobytes(out_public_value, &zminusy_inv);
+  x25519_ge_p3_to_montgomery_u(out_public_value, &A);
   CONSTTIME_DECLASSIFY(out_public_value, 32);
 }
 
@@ -1912,6 +1963,12 @@ void x25519_ge_double_scalarmult_vartime(ge_p2 *r, const uint8_t *a,
   ge_double_scalarmult_vartime(r, a, A, b);
 }
 
+void x25519_ge_cofactor_multi_scalarmult_vartime(
+    ge_p2 *r, const uint8_t *b, const uint8_t *a, const ge_p3 *A, size_t n,
+    ge_cached *Ai, signed char *aslide) {
+  ge_cofactor_multi_scalarmult_vartime(r, b, a, A, n, Ai, aslide);
+}
+
 void x25519_sc_mask(uint8_t a[32]) {
   a[0] &= 248;
   a[31] &= 127;
@@ -123,6 +123,7 @@ typedef struct {
   fe_loose xy2d;
 } ge_precomp;
 
+// Keep in sync with `CachedPoint` in curve25519/ops.rs.
 typedef struct {
   fe_loose YplusX;
   fe_loose YminusX;
@@ -583,6 +583,56 @@ static void aes_nohw_sub_bytes(AES_NOHW_BATCH *batch) {
   batch->w[7] = s0;
 }
 
+// aes_nohw_sub_bytes_inv_affine inverts the affine transform portion of the AES
+// S-box, defined in FIPS PUB 197, section 5.1.1, step 2.
+static void aes_nohw_sub_bytes_inv_affine(AES_NOHW_BATCH *batch) {
+  aes_word_t a0 = batch->w[0];
+  aes_word_t a1 = batch->w[1];
+  aes_word_t a2 = batch->w[2];
+  aes_word_t a3 = batch->w[3];
+  aes_word_t a4 = batch->w[4];
+  aes_word_t a5 = batch->w[5];
+  aes_word_t a6 = batch->w[6];
+  aes_word_t a7 = batch->w[7];
+
+  // Apply the inverse of the linear transform:
+  //
+  //   b_i = a_{(i + 2) % 8} ^ a_{(i + 5) % 8} ^ a_{(i + 7) % 8}
+  //
+  // Then XOR with 0x05, which flips bits 0 and 2.
+  aes_word_t b0 = aes_nohw_xor(a2, aes_nohw_xor(a5, a7));
+  aes_word_t b1 = aes_nohw_xor(a3, aes_nohw_xor(a6, a0));
+  aes_word_t b2 = aes_nohw_xor(a4, aes_nohw_xor(a7, a1));
+  aes_word_t b3 = aes_nohw_xor(a5, aes_nohw_xor(a0, a2));
+  aes_word_t b4 = aes_nohw_xor(a6, aes_nohw_xor(a1, a3));
+  aes_word_t b5 = aes_nohw_xor(a7, aes_nohw_xor(a2, a4));
+  aes_word_t b6 = aes_nohw_xor(a0, aes_nohw_xor(a3, a5));
+  aes_word_t b7 = aes_nohw_xor(a1, aes_nohw_xor(a4, a6));
+
+  batch->w[0] = aes_nohw_not(b0);
+  batch->w[1] = b1;
+  batch->w[2] = aes_nohw_not(b2);
+  batch->w[3] = b3;
+  batch->w[4] = b4;
+  batch->w[5] = b5;
+  batch->w[6] = b6;
+  batch->w[7] = b7;
+}
+
+static void aes_nohw_inv_sub_bytes(AES_NOHW_BATCH *batch) {
+  // We implement the inverse S-box using the forwards implementation with the
+  // technique described in https://www.bearssl.org/constanttime.html#aes.
+  //
+  // The forwards S-box inverts its input and applies an affine transformation:
+  // S(x) = A(Inv(x)). Thus Inv(x) = InvA(S(x)). The inverse S-box is then:
+  //
+  //   InvS(x) = Inv(InvA(x))
+  //           = InvA(S(InvA(x)))
+  aes_nohw_sub_bytes_inv_affine(batch);
+  aes_nohw_sub_bytes(batch);
+  aes_nohw_sub_bytes_inv_affine(batch);
+}
+
 // aes_nohw_rotate_cols_right returns |v| with the columns in each row rotated
 // to the right by |n|. This is a macro because |aes_nohw_shift_*| require
 // constant shift counts in the SSE2 implementation.
@@ -603,6 +653,19 @@ static void aes_nohw_shift_rows(AES_NOHW_BATCH *batch) {
   }
 }
 
+static void aes_nohw_inv_shift_rows(AES_NOHW_BATCH *batch) {
+  for (size_t i = 0; i < 8; i++) {
+    aes_word_t row0 = aes_nohw_and(batch->w[i], AES_NOHW_ROW0_MASK);
+    aes_word_t row1 = aes_nohw_and(batch->w[i], AES_NOHW_ROW1_MASK);
+    aes_word_t row2 = aes_nohw_and(batch->w[i], AES_NOHW_ROW2_MASK);
+    aes_word_t row3 = aes_nohw_and(batch->w[i], AES_NOHW_ROW3_MASK);
+    row1 = aes_nohw_rotate_cols_right(row1, 3);
+    row2 = aes_nohw_rotate_cols_right(row2, 2);
+    row3 = aes_nohw_rotate_cols_right(row3, 1);
+    batch->w[i] = aes_nohw_or(aes_nohw_or(row0, row1), aes_nohw_or(row2, row3));
+  }
+}
+
 // aes_nohw_rotate_rows_down returns |v| with the rows in each column rotated
 // down by one.
 static inline aes_word_t aes_nohw_rotate_rows_down(aes_word_t v) {
@@ -674,6 +737,65 @@ static void aes_nohw_mix_columns(AES_NOHW_BATCH *batch) {
       aes_nohw_xor(aes_nohw_xor(a6_r6, r7), aes_nohw_rotate_rows_twice(a7_r7));
 }
 
+static void aes_nohw_inv_mix_columns(AES_NOHW_BATCH *batch) {
+  aes_word_t a0 = batch->w[0];
+  aes_word_t a1 = batch->w[1];
+  aes_word_t a2 = batch->w[2];
+  aes_word_t a3 = batch->w[3];
+  aes_word_t a4 = batch->w[4];
+  aes_word_t a5 = batch->w[5];
+  aes_word_t a6 = batch->w[6];
+  aes_word_t a7 = batch->w[7];
+
+  // bsaes-x86_64.pl describes the following decomposition of the inverse
+  // MixColumns matrix, credited to Jussi Kivilinna. This gives a much simpler
+  // multiplication.
+  //
+  // | 0e 0b 0d 09 |   | 02 03 01 01 |   | 05 00 04 00 |
+  // | 09 0e 0b 0d | = | 01 02 03 01 | x | 00 05 00 04 |
+  // | 0d 09 0e 0b |   | 01 01 02 03 |   | 04 00 05 00 |
+  // | 0b 0d 09 0e |   | 03 01 01 02 |   | 00 04 00 05 |
+  //
+  // First, apply the [5 0 4 0] matrix. Multiplying by 4 in F(2^8) is described
+  // by the following bit equations:
+  //
+  //   b0 = a6
+  //   b1 = a6 ^ a7
+  //   b2 = a0 ^ a7
+  //   b3 = a1 ^ a6
+  //   b4 = a2 ^ a6 ^ a7
+  //   b5 = a3 ^ a7
+  //   b6 = a4
+  //   b7 = a5
+  //
+  // Each coefficient is given by:
+  //
+  //   b_ij = 05·a_ij ⊕ 04·a_i(j+2) = 04·(a_ij ⊕ a_i(j+2)) ⊕ a_ij
+  //
+  // We combine the two equations below. Note a_i(j+2) is a row rotation.
+  aes_word_t a0_r0 = aes_nohw_xor(a0, aes_nohw_rotate_rows_twice(a0));
+  aes_word_t a1_r1 = aes_nohw_xor(a1, aes_nohw_rotate_rows_twice(a1));
+  aes_word_t a2_r2 = aes_nohw_xor(a2, aes_nohw_rotate_rows_twice(a2));
+  aes_word_t a3_r3 = aes_nohw_xor(a3, aes_nohw_rotate_rows_twice(a3));
+  aes_word_t a4_r4 = aes_nohw_xor(a4, aes_nohw_rotate_rows_twice(a4));
+  aes_word_t a5_r5 = aes_nohw_xor(a5, aes_nohw_rotate_rows_twice(a5));
+  aes_word_t a6_r6 = aes_nohw_xor(a6, aes_nohw_rotate_rows_twice(a6));
+  aes_word_t a7_r7 = aes_nohw_xor(a7, aes_nohw_rotate_rows_twice(a7));
+
+  batch->w[0] = aes_nohw_xor(a0, a6_r6);
+  batch->w[1] = aes_nohw_xor(a1, aes_nohw_xor(a6_r6, a7_r7));
+  batch->w[2] = aes_nohw_xor(a2, aes_nohw_xor(a0_r0, a7_r7));
+  batch->w[3] = aes_nohw_xor(a3, aes_nohw_xor(a1_r1, a6_r6));
+  batch->w[4] =
+      aes_nohw_xor(aes_nohw_xor(a4, a2_r2), aes_nohw_xor(a6_r6, a7_r7));
+  batch->w[5] = aes_nohw_xor(a5, aes_nohw_xor(a3_r3, a7_r7));
+  batch->w[6] = aes_nohw_xor(a6, a4_r4);
+  batch->w[7] = aes_nohw_xor(a7, a5_r5);
+
+  // Apply the [02 03 01 01] matrix, which is just MixColumns.
+  aes_nohw_mix_columns(batch);
+}
+
 static void aes_nohw_encrypt_batch(const AES_NOHW_SCHEDULE *key,
                                    size_t num_rounds, AES_NOHW_BATCH *batch) {
   aes_nohw_add_round_key(batch, &key->keys[0]);
@@ -688,6 +810,23 @@ static void aes_nohw_encrypt_batch(const AES_NOHW_SCHEDULE *key,
   aes_nohw_add_round_key(batch, &key->keys[num_rounds]);
 }
 
+// aes_nohw_decrypt_batch applies FIPS 197's InvCipher to |batch|. It uses the
+// same round keys as |aes_nohw_encrypt_batch|, in reverse order, so no separate
+// decryption key schedule is needed.
+static void aes_nohw_decrypt_batch(const AES_NOHW_SCHEDULE *key,
+                                   size_t num_rounds, AES_NOHW_BATCH *batch) {
+  aes_nohw_add_round_key(batch, &key->keys[num_rounds]);
+  aes_nohw_inv_sub_bytes(batch);
+  aes_nohw_inv_shift_rows(batch);
+  for (size_t i = num_rounds - 1; i > 0; i--) {
+    aes_nohw_add_round_key(batch, &key->keys[i]);
+    aes_nohw_inv_mix_columns(batch);
+    aes_nohw_inv_sub_bytes(batch);
+    aes_nohw_inv_shift_rows(batch);
+  }
+  aes_nohw_add_round_key(batch, &key->keys[0]);
+}
+
 // Key schedule.
 
 static void aes_nohw_expand_round_keys(AES_NOHW_SCHEDULE *out,
@@ -752,6 +891,40 @@ static void aes_nohw_setup_key_128(AES_KEY *key, const uint8_t in[16]) {
   }
 }
 
+static void aes_nohw_setup_key_192(AES_KEY *key, const uint8_t in[24]) {
+  key->rounds = 12;
+
+  // Each AES-192 key schedule iteration produces six words, which don't line
+  // up with the blocks that the other key schedules work on. Instead, expand
+  // the key one word at a time, as in FIPS 197, using |aes_nohw_sub_block| for
+  // SubWord, and then compact each round key.
+  uint8_t w[4 * 4 * 13];
+  OPENSSL_memcpy(w, in, 24);
+  for (size_t i = 6; i < 4 * 13; i++) {
+    uint8_t temp[4];
+    OPENSSL_memcpy(temp, w + 4 * (i - 1), 4);
+    if (i % 6 == 0) {
+      // RotWord, then SubWord.
+      uint8_t block[16] = {temp[1], temp[2], temp[3], temp[0]};
+      aes_word_t compact[AES_NOHW_BLOCK_WORDS], sub[AES_NOHW_BLOCK_WORDS];
+      aes_nohw_compact_block(compact, block);
+      aes_nohw_sub_block(sub, compact);
+      aes_nohw_uncompact_block(block, sub);
+      OPENSSL_memcpy(temp, block, 4);
+      temp[0] = (uint8_t)(temp[0] ^ aes_nohw_rcon[i / 6 - 1]);
+    }
+    for (size_t j = 0; j < 4; j++) {
+      w[4 * i + j] = (uint8_t)(w[4 * (i - 6) + j] ^ temp[j]);
+    }
+  }
+
+  for (size_t i = 0; i <= 12; i++) {
+    aes_word_t block[AES_NOHW_BLOCK_WORDS];
+    aes_nohw_compact_block(block, w + 16 * i);
+    OPENSSL_memcpy(key->rd_key + 4 * i, block, 16);
+  }
+}
+
 static void aes_nohw_setup_key_256(AES_KEY *key, const uint8_t in[32]) {
   key->rounds = 14;
 
@@ -808,6 +981,9 @@ int aes_nohw_set_encrypt_key(const uint8_t *key, unsigned bits,
     case 128:
       aes_nohw_setup_key_128(aeskey, key);
       return 0;
+    case 192:
+      aes_nohw_setup_key_192(aeskey, key);
+      return 0;
     case 256:
       aes_nohw_setup_key_256(aeskey, key);
       return 0;
@@ -824,6 +1000,15 @@ void aes_nohw_encrypt(const uint8_t *in, uint8_t *out, const AES_KEY *key) {
   aes_nohw_from_batch(out, /*num_blocks=*/1, &batch);
 }
 
+void aes_nohw_decrypt(const uint8_t *in, uint8_t *out, const AES_KEY *key) {
+  AES_NOHW_SCHEDULE sched;
+  aes_nohw_expand_round_keys(&sched, key);
+  AES_NOHW_BATCH batch;
+  aes_nohw_to_batch(&batch, in, /*num_blocks=*/1);
+  aes_nohw_decrypt_batch(&sched, key->rounds, &batch);
+  aes_nohw_from_batch(out, /*num_blocks=*/1, &batch);
+}
+
 static inline void aes_nohw_xor_block(uint8_t out[16], const uint8_t a[16],
                                       const uint8_t b[16]) {
   for (size_t i = 0; i < 16; i += sizeof(aes_word_t)) {
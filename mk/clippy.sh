@@ -18,10 +18,13 @@ set -eux -o pipefail
 IFS=$'\n\t'
 
 export NULL=""
-cargo clippy \
-  --all-features --all-targets \
-  -- \
-  --deny missing_docs \
-  --deny unused_qualifications \
-  --deny warnings \
-  $NULL
+# `fips` removes algorithms, so check the build without it too.
+for features in --all-features --features=std,debug-internals,slow_tests,test_logging; do
+  cargo clippy \
+    $features --all-targets \
+    -- \
+    --deny missing_docs \
+    --deny unused_qualifications \
+    --deny warnings \
+    $NULL
+done
@@ -22,19 +22,45 @@
 //! [`crypto.cipher.AEAD`]: https://golang.org/pkg/crypto/cipher/#AEAD
 
 use crate::{
-    cpu, error,
+    error,
     polyfill::{u64_from_usize, usize_from_u64_saturated},
 };
 
 pub use self::{
-    algorithm::{Algorithm, AES_128_GCM, AES_256_GCM, CHACHA20_POLY1305},
+    algorithm::{algorithm_by_id, Algorithm, AlgorithmParams, AES_128_GCM, AES_256_GCM},
+    ciphertext_shape::{analyze_ciphertext, CiphertextShape},
+    commitment::{compute_commitment, COMMITMENT_LEN},
     less_safe_key::LessSafeKey,
-    nonce::{Nonce, NONCE_LEN},
+    multi_recipient::verify_recipient_tag,
+    nonce::{deterministic_nonce, Nonce, NONCE_LEN},
     opening_key::OpeningKey,
+    ratcheting_key::{RatchetingOpeningKey, RatchetingSealingKey},
+    rekeyable_key::RekeyableKey,
+    seal_builder::SealBuilder,
     sealing_key::SealingKey,
+    streaming_key::{StreamingOpeningKey, StreamingSealingKey},
+    transcript::{Transcript, TRANSCRIPT_LEN},
     unbound_key::UnboundKey,
 };
 
+#[cfg(not(feature = "fips"))]
+pub use self::algorithm::{
+    AEGIS_128L, AES_128_GCM_SIV, AES_256_GCM_SIV, CHACHA20_POLY1305, XCHACHA20_POLY1305,
+};
+
+#[cfg(all(feature = "alloc", not(feature = "fips")))]
+pub use self::password::chacha20poly1305_from_password;
+
+#[cfg(feature = "alloc")]
+pub use self::{
+    multi_recipient::seal_multi_recipient,
+    oblivious::open_oblivious,
+    protocol_bound_key::{with_protocol_id, ProtocolBoundKey},
+    replay_guard::ReplayGuard,
+};
+
+pub(crate) use self::self_test::self_test;
+
 /// A sequences of unique nonces.
 ///
 /// A given `NonceSequence` must never return the same `Nonce` twice from
@@ -42,9 +68,14 @@ pub use self::{
 ///
 /// A simple counter is a reasonable (but probably not ideal) `NonceSequence`.
 ///
+/// `L` is the length of the nonces, `Algorithm::nonce_len()`. It is
+/// `NONCE_LEN` for every algorithm except `AEGIS_128L` and
+/// `XCHACHA20_POLY1305`; see `xchacha20_poly1305::PrefixedNonceSequence`
+/// for the latter.
+///
 /// Intentionally not `Clone` or `Copy` since cloning would allow duplication
 /// of the sequence.
-pub trait NonceSequence {
+pub trait NonceSequence<const L: usize = NONCE_LEN> {
     /// Returns the next nonce in the sequence.
     ///
     /// This may fail if "too many" nonces have been requested, where how many
@@ -52,13 +83,13 @@ pub trait NonceSequence {
     /// implementation may that enforce a maximum number of records are
     /// sent/received under a key this way. Once `advance()` fails, it must
     /// fail for all subsequent calls.
-    fn advance(&mut self) -> Result<Nonce, error::Unspecified>;
+    fn advance(&mut self) -> Result<Nonce<L>, error::Unspecified>;
 }
 
 /// An AEAD key bound to a nonce sequence.
-pub trait BoundKey<N: NonceSequence>: core::fmt::Debug {
+pub trait BoundKey<N: NonceSequence<L>, const L: usize = NONCE_LEN>: core::fmt::Debug {
     /// Constructs a new key from the given `UnboundKey` and `NonceSequence`.
-    fn new(key: UnboundKey, nonce_sequence: N) -> Self;
+    fn new(key: UnboundKey<L>, nonce_sequence: N) -> Self;
 
     /// The key's AEAD algorithm.
     fn algorithm(&self) -> &'static Algorithm;
@@ -121,7 +152,15 @@ impl<A> Eq for Aad<A> where A: Eq {}
 #[derive(Clone)]
 enum KeyInner {
     AesGcm(aes_gcm::Key),
+
+    #[cfg(not(feature = "fips"))]
+    AesGcmSiv(aes_gcm_siv::Key),
+
+    #[cfg(not(feature = "fips"))]
     ChaCha20Poly1305(chacha20_poly1305::Key),
+
+    #[cfg(not(feature = "fips"))]
+    Aegis128L(aegis128l::Key),
 }
 
 const fn max_input_len(block_len: usize, overhead_blocks_per_nonce: usize) -> usize {
@@ -160,6 +199,11 @@ impl From<[u8; TAG_LEN]> for Tag {
     }
 }
 
+#[cfg(feature = "std")]
+pub(crate) fn benchmark_select(cpu_features: crate::cpu::Features) {
+    aes_gcm::benchmark::select(cpu_features)
+}
+
 const MAX_KEY_LEN: usize = 32;
 
 // All the AEADs we support use 128-bit tags.
@@ -168,18 +212,70 @@ const TAG_LEN: usize = 16;
 /// The maximum length of a tag for the algorithms in this module.
 pub const MAX_TAG_LEN: usize = TAG_LEN;
 
+#[cfg(not(feature = "fips"))]
+pub mod aegis128l;
+
 mod aes;
+pub(crate) mod aes_cmac;
+pub(crate) mod aes_ctr;
 mod aes_gcm;
+
+#[cfg(not(feature = "fips"))]
+mod aes_gcm_siv;
+
+pub(crate) mod aes_xts;
 mod algorithm;
-mod chacha;
+
+// Only HChaCha20 is used outside ChaCha20-Poly1305 and its variants.
+#[cfg_attr(feature = "fips", allow(dead_code))]
+pub(crate) mod chacha;
+
+#[cfg(not(feature = "fips"))]
 mod chacha20_poly1305;
+
+#[cfg(not(feature = "fips"))]
 pub mod chacha20_poly1305_openssh;
+
+mod ciphertext_shape;
+mod commitment;
 mod gcm;
+pub mod gmac;
 mod less_safe_key;
+mod multi_recipient;
 mod nonce;
+
+#[cfg(feature = "alloc")]
+mod oblivious;
+
 mod opening_key;
+
+#[cfg(all(feature = "alloc", not(feature = "fips")))]
+mod password;
+
+#[cfg(not(feature = "fips"))]
 mod poly1305;
+
+#[cfg(not(feature = "fips"))]
+pub mod polyval;
+
+#[cfg(feature = "alloc")]
+mod protocol_bound_key;
+
 pub mod quic;
+mod ratcheting_key;
+mod rekeyable_key;
+
+#[cfg(feature = "alloc")]
+mod replay_guard;
+
+mod seal_builder;
 mod sealing_key;
+mod self_test;
 mod shift;
+mod streaming_key;
+pub mod test;
+mod transcript;
 mod unbound_key;
+
+#[cfg(not(feature = "fips"))]
+pub mod xchacha20_poly1305;
@@ -0,0 +1,417 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! AEGIS-128L, as specified in [draft-irtf-cfrg-aegis-aead].
+//!
+//! See [`super::AEGIS_128L`]. Its nonces are 128 bits long, so keys for it
+//! take `Nonce<NONCE_LEN>`s and are bound to `NonceSequence<NONCE_LEN>`s.
+//!
+//! [draft-irtf-cfrg-aegis-aead]: https://datatracker.ietf.org/doc/draft-irtf-cfrg-aegis-aead/
+
+use super::{
+    aes::{
+        self,
+        round::{AesRound, Fallback},
+        Block, ZERO_BLOCK,
+    },
+    Aad, Nonce, Tag, TAG_LEN,
+};
+use crate::{
+    constant_time, cpu, error,
+    polyfill::{u64_from_usize, usize_from_u64_saturated},
+};
+use core::ops::RangeFrom;
+
+#[cfg(any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64"))]
+use cpu::GetFeature as _;
+
+/// The length of an AEGIS-128L key.
+pub const KEY_LEN: usize = 128 / 8;
+
+/// The length of an AEGIS-128L nonce.
+pub const NONCE_LEN: usize = 128 / 8;
+
+// The specification limits the plaintext and the AAD to 2^61 - 1 bytes so
+// that their lengths in bits fit in 64 bits.
+pub(super) const MAX_IN_OUT_LEN: usize = usize_from_u64_saturated((1 << 61) - 1);
+
+#[derive(Clone)]
+pub(super) struct Key {
+    key: KeyBytes,
+    round: Round,
+}
+
+/// The raw key, which AEGIS uses directly instead of expanding it.
+#[derive(Clone)]
+struct KeyBytes(Block);
+
+impl Drop for KeyBytes {
+    fn drop(&mut self) {
+        constant_time::secure_zero(&mut self.0);
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Round {
+    #[cfg(any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64"))]
+    Hw(aes::hw::Round),
+
+    Fallback(Fallback),
+}
+
+impl Key {
+    pub(super) fn new(key: [u8; KEY_LEN], cpu_features: cpu::Features) -> Self {
+        #[cfg(any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64"))]
+        if let Some(aes) = cpu_features.get_feature() {
+            return Self {
+                key: KeyBytes(key),
+                round: Round::Hw(aes::hw::Round::new(aes)),
+            };
+        }
+
+        let _ = cpu_features;
+
+        Self {
+            key: KeyBytes(key),
+            round: Round::Fallback(Fallback::new()),
+        }
+    }
+}
+
+pub(super) fn seal(
+    key: &Key,
+    nonce: Nonce<NONCE_LEN>,
+    aad: Aad<&[u8]>,
+    in_out: &mut [u8],
+) -> Result<Tag, error::Unspecified> {
+    process(key, nonce, aad.as_ref(), in_out, Direction::Seal)
+}
+
+pub(super) fn open(
+    key: &Key,
+    nonce: Nonce<NONCE_LEN>,
+    aad: Aad<&[u8]>,
+    in_out: &mut [u8],
+    src: RangeFrom<usize>,
+) -> Result<Tag, error::Unspecified> {
+    let ciphertext_len = in_out.get(src.clone()).ok_or(error::Unspecified)?.len();
+    in_out.copy_within(src, 0);
+    process(
+        key,
+        nonce,
+        aad.as_ref(),
+        &mut in_out[..ciphertext_len],
+        Direction::Open,
+    )
+}
+
+fn process(
+    key: &Key,
+    nonce: Nonce<NONCE_LEN>,
+    aad: &[u8],
+    in_out: &mut [u8],
+    direction: Direction,
+) -> Result<Tag, error::Unspecified> {
+    if aad.len() > MAX_IN_OUT_LEN || in_out.len() > MAX_IN_OUT_LEN {
+        return Err(error::Unspecified);
+    }
+    let lens = Lens {
+        aad_bits: u64_from_usize(aad.len()) * 8,
+        in_out_bits: u64_from_usize(in_out.len()) * 8,
+    };
+
+    let nonce = nonce.as_ref();
+    let tag = match key.round {
+        #[cfg(any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64"))]
+        Round::Hw(round) => {
+            // SAFETY: `round` proves the AES instructions are available.
+            unsafe { process_hw(round, &key.key.0, nonce, aad, in_out, direction, lens) }
+        }
+        Round::Fallback(round) => {
+            process_with(round, &key.key.0, nonce, aad, in_out, direction, lens)
+        }
+    };
+    Ok(Tag(tag))
+}
+
+#[derive(Clone, Copy)]
+enum Direction {
+    Seal,
+    Open,
+}
+
+#[derive(Clone, Copy)]
+struct Lens {
+    aad_bits: u64,
+    in_out_bits: u64,
+}
+
+#[cfg(any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64"))]
+#[target_feature(enable = "aes")]
+unsafe fn process_hw(
+    round: aes::hw::Round,
+    key: &Block,
+    nonce: &Block,
+    aad: &[u8],
+    in_out: &mut [u8],
+    direction: Direction,
+    lens: Lens,
+) -> [u8; TAG_LEN] {
+    // Compile `process_with` with the AES instructions enabled so the AES
+    // rounds are inlined.
+    process_with(round, key, nonce, aad, in_out, direction, lens)
+}
+
+#[inline(always)]
+fn process_with<R: AesRound>(
+    round: R,
+    key: &Block,
+    nonce: &Block,
+    aad: &[u8],
+    in_out: &mut [u8],
+    direction: Direction,
+    lens: Lens,
+) -> [u8; TAG_LEN] {
+    let mut state = State::new(round, key, nonce);
+
+    let (whole, partial) = aad.split_at(aad.len() - (aad.len() % RATE));
+    for block in whole.chunks_exact(RATE) {
+        state.absorb(block.try_into().unwrap());
+    }
+    if !partial.is_empty() {
+        let mut block = [0u8; RATE];
+        block[..partial.len()].copy_from_slice(partial);
+        state.absorb(&block);
+    }
+
+    let partial_len = in_out.len() % RATE;
+    let (whole, partial) = in_out.split_at_mut(in_out.len() - partial_len);
+    for block in whole.chunks_exact_mut(RATE) {
+        let block: &mut [u8; RATE] = block.try_into().unwrap();
+        match direction {
+            Direction::Seal => state.encrypt(block),
+            Direction::Open => state.decrypt(block),
+        }
+    }
+    if !partial.is_empty() {
+        let mut block = [0u8; RATE];
+        block[..partial.len()].copy_from_slice(partial);
+        match direction {
+            Direction::Seal => state.encrypt(&mut block),
+            Direction::Open => state.decrypt_partial(&mut block, partial.len()),
+        }
+        partial.copy_from_slice(&block[..partial.len()]);
+    }
+
+    state.finalize(lens)
+}
+
+/// The number of bytes absorbed or encrypted per state update.
+const RATE: usize = 32;
+
+struct State<R> {
+    s: [Block; 8],
+    round: R,
+}
+
+impl<R: AesRound> State<R> {
+    #[inline(always)]
+    fn new(round: R, key: &Block, nonce: &Block) -> Self {
+        const C0: Block = [
+            0x00, 0x01, 0x01, 0x02, 0x03, 0x05, 0x08, 0x0d, 0x15, 0x22, 0x37, 0x59, 0x90, 0xe9,
+            0x79, 0x62,
+        ];
+        const C1: Block = [
+            0xdb, 0x3d, 0x18, 0x55, 0x6d, 0xc2, 0x2f, 0xf1, 0x20, 0x11, 0x31, 0x42, 0x73, 0xb5,
+            0x28, 0xdd,
+        ];
+
+        let key_xor_nonce = xor(*key, *nonce);
+        let mut state = Self {
+            s: [
+                key_xor_nonce,
+                C1,
+                C0,
+                C1,
+                key_xor_nonce,
+                xor(*key, C0),
+                xor(*key, C1),
+                xor(*key, C0),
+            ],
+            round,
+        };
+        for _ in 0..10 {
+            state.update(*nonce, *key);
+        }
+        state
+    }
+
+    #[inline(always)]
+    fn update(&mut self, m0: Block, m1: Block) {
+        let s = &self.s;
+        let r = self.round;
+        self.s = [
+            r.round(s[7], xor(s[0], m0)),
+            r.round(s[0], s[1]),
+            r.round(s[1], s[2]),
+            r.round(s[2], s[3]),
+            r.round(s[3], xor(s[4], m1)),
+            r.round(s[4], s[5]),
+            r.round(s[5], s[6]),
+            r.round(s[6], s[7]),
+        ];
+    }
+
+    #[inline(always)]
+    fn keystream(&self) -> [u8; RATE] {
+        let s = &self.s;
+        let z0 = xor(xor(s[6], s[1]), and(s[2], s[3]));
+        let z1 = xor(xor(s[2], s[5]), and(s[6], s[7]));
+        join(z0, z1)
+    }
+
+    #[inline(always)]
+    fn absorb(&mut self, block: &[u8; RATE]) {
+        let (m0, m1) = split(block);
+        self.update(m0, m1);
+    }
+
+    #[inline(always)]
+    fn encrypt(&mut self, block: &mut [u8; RATE]) {
+        let (m0, m1) = split(block);
+        let (z0, z1) = split(&self.keystream());
+        *block = join(xor(m0, z0), xor(m1, z1));
+        self.update(m0, m1);
+    }
+
+    #[inline(always)]
+    fn decrypt(&mut self, block: &mut [u8; RATE]) {
+        let (c0, c1) = split(block);
+        let (z0, z1) = split(&self.keystream());
+        let (m0, m1) = (xor(c0, z0), xor(c1, z1));
+        *block = join(m0, m1);
+        self.update(m0, m1);
+    }
+
+    /// Decrypts the first `len` bytes of `block`, which is the final,
+    /// zero-padded, partial block of ciphertext.
+    #[inline(always)]
+    fn decrypt_partial(&mut self, block: &mut [u8; RATE], len: usize) {
+        let (c0, c1) = split(block);
+        let (z0, z1) = split(&self.keystream());
+        *block = join(xor(c0, z0), xor(c1, z1));
+        block[len..].fill(0);
+        self.absorb(block);
+    }
+
+    #[inline(always)]
+    fn finalize(mut self, lens: Lens) -> [u8; TAG_LEN] {
+        let mut lens_block = ZERO_BLOCK;
+        let (aad_bits, in_out_bits) = lens_block.split_at_mut(8);
+        aad_bits.copy_from_slice(&lens.aad_bits.to_le_bytes());
+        in_out_bits.copy_from_slice(&lens.in_out_bits.to_le_bytes());
+        let t = xor(self.s[2], lens_block);
+        for _ in 0..7 {
+            self.update(t, t);
+        }
+
+        let s = &self.s;
+        [s[1], s[2], s[3], s[4], s[5], s[6]]
+            .iter()
+            .fold(s[0], |acc, b| xor(acc, *b))
+    }
+}
+
+#[inline(always)]
+fn xor(a: Block, b: Block) -> Block {
+    constant_time::xor_16(a, b)
+}
+
+#[inline(always)]
+fn and(a: Block, b: Block) -> Block {
+    (u128::from_ne_bytes(a) & u128::from_ne_bytes(b)).to_ne_bytes()
+}
+
+#[inline(always)]
+fn split(block: &[u8; RATE]) -> (Block, Block) {
+    let (a, b) = block.split_at(16);
+    (a.try_into().unwrap(), b.try_into().unwrap())
+}
+
+#[inline(always)]
+fn join(a: Block, b: Block) -> [u8; RATE] {
+    let mut r = [0u8; RATE];
+    r[..16].copy_from_slice(&a);
+    r[16..].copy_from_slice(&b);
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn test_aegis128l() {
+        test::run(test_file!("aegis128l_tests.txt"), |section, test_case| {
+            assert_eq!(section, "");
+            let key_bytes = test_case.consume_bytes("KEY");
+            let nonce = test_case.consume_bytes("NONCE");
+            let ad = test_case.consume_bytes("AD");
+            let plaintext = test_case.consume_bytes("IN");
+            let ct = test_case.consume_bytes("CT");
+            let tag = test_case.consume_bytes("TAG");
+
+            for key in all_implementations(key_bytes.as_slice().try_into()?) {
+                let nonce = || Nonce::<NONCE_LEN>::try_assume_unique_for_key_of_len(&nonce);
+
+                let mut in_out = plaintext.clone();
+                let Tag(actual_tag) = seal(&key, nonce()?, Aad::from(&ad), &mut in_out)?;
+                assert_eq!(in_out, ct);
+                assert_eq!(&actual_tag[..], &tag[..]);
+
+                // Opening moves the plaintext to the start of `in_out`.
+                let mut in_out = [&[0xff; 3][..], &ct[..]].concat();
+                let Tag(actual_tag) = open(&key, nonce()?, Aad::from(&ad), &mut in_out, 3..)?;
+                assert_eq!(&in_out[..plaintext.len()], &plaintext[..]);
+                assert_eq!(&actual_tag[..], &tag[..]);
+            }
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_aegis128l_key_zeroed_on_drop() {
+        let key = KeyBytes([0xa5; KEY_LEN]);
+        assert!(constant_time::is_zeroed_after_drop(key));
+    }
+
+    // Every implementation that is available on this CPU, including the
+    // fallback implementation.
+    fn all_implementations(key: [u8; KEY_LEN]) -> Vec<Key> {
+        let mut keys = Vec::new();
+        let key = Key::new(key, cpu::features());
+        #[cfg(any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64"))]
+        if let Round::Hw(_) = key.round {
+            keys.push(key.clone());
+        }
+        keys.push(Key {
+            key: key.key.clone(),
+            round: Round::Fallback(Fallback::new()),
+        });
+        keys
+    }
+}
@@ -29,6 +29,11 @@ mod ffi;
 mod bs;
 pub(super) mod fallback;
 pub(super) mod hw;
+
+// Only used by AEGIS.
+#[cfg(not(feature = "fips"))]
+pub(super) mod round;
+
 pub(super) mod vp;
 
 cfg_if! {
@@ -61,6 +66,11 @@ impl Key {
         bytes: KeyBytes<'_>,
         cpu_features: cpu::Features,
     ) -> Result<Self, error::Unspecified> {
+        #[cfg(test)]
+        if let KeyBytes::AES_192(_) = bytes {
+            return Ok(Self::Fallback(fallback::Key::new(bytes)?));
+        }
+
         #[cfg(any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64"))]
         if let Some(hw_features) = cpu_features.get_feature() {
             return Ok(Self::Hw(hw::Key::new(bytes, hw_features)?));
@@ -81,8 +91,42 @@ impl Key {
         Ok(Self::Fallback(fallback::Key::new(bytes)?))
     }
 
+    /// Calls `f` with a key for every implementation that is available on
+    /// this CPU, including the fallback implementation.
+    pub(super) fn for_each_implementation(
+        bytes: KeyBytes<'_>,
+        cpu_features: cpu::Features,
+        mut f: impl FnMut(Self),
+    ) -> Result<(), error::Unspecified> {
+        #[cfg(test)]
+        if let KeyBytes::AES_192(_) = bytes {
+            f(Self::Fallback(fallback::Key::new(bytes)?));
+            return Ok(());
+        }
+
+        #[cfg(any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64"))]
+        if let Some(hw_features) = cpu_features.get_feature() {
+            f(Self::Hw(hw::Key::new(bytes, hw_features)?));
+        }
+
+        #[cfg(any(
+            target_arch = "aarch64",
+            target_arch = "arm",
+            target_arch = "x86_64",
+            target_arch = "x86"
+        ))]
+        if let Some(vp_features) = cpu_features.get_feature() {
+            f(Self::Vp(vp::Key::new(bytes, vp_features)?));
+        }
+
+        let _ = cpu_features;
+
+        f(Self::Fallback(fallback::Key::new(bytes)?));
+        Ok(())
+    }
+
     #[inline]
-    fn encrypt_block(&self, a: Block) -> Block {
+    pub(super) fn encrypt_block(&self, a: Block) -> Block {
         match self {
             #[cfg(any(target_arch = "aarch64", target_arch = "x86_64", target_arch = "x86"))]
             Key::Hw(inner) => inner.encrypt_block(a),
@@ -99,6 +143,29 @@ impl Key {
         }
     }
 
+    #[inline]
+    pub(super) fn ctr32_encrypt_within(
+        &self,
+        in_out: &mut [u8],
+        src: RangeFrom<usize>,
+        ctr: &mut Counter,
+    ) {
+        match self {
+            #[cfg(any(target_arch = "aarch64", target_arch = "x86_64", target_arch = "x86"))]
+            Key::Hw(inner) => inner.ctr32_encrypt_within(in_out, src, ctr),
+
+            #[cfg(any(
+                target_arch = "aarch64",
+                target_arch = "arm",
+                target_arch = "x86",
+                target_arch = "x86_64"
+            ))]
+            Key::Vp(inner) => inner.ctr32_encrypt_within(in_out, src, ctr),
+
+            Key::Fallback(inner) => inner.ctr32_encrypt_within(in_out, src, ctr),
+        }
+    }
+
     pub fn new_mask(&self, sample: Sample) -> [u8; 5] {
         let [b0, b1, b2, b3, b4, ..] = self.encrypt_block(sample);
         [b0, b1, b2, b3, b4]
@@ -106,10 +173,17 @@ impl Key {
 }
 
 pub const AES_128_KEY_LEN: usize = 128 / 8;
+#[cfg(test)]
+pub const AES_192_KEY_LEN: usize = 192 / 8;
 pub const AES_256_KEY_LEN: usize = 256 / 8;
 
+#[derive(Clone, Copy)]
 pub enum KeyBytes<'a> {
     AES_128(&'a [u8; AES_128_KEY_LEN]),
+    // Only the fallback implementation supports AES-192, and only its tests
+    // use it.
+    #[cfg(test)]
+    AES_192(&'a [u8; AES_192_KEY_LEN]),
     AES_256(&'a [u8; AES_256_KEY_LEN]),
 }
 
@@ -123,6 +197,14 @@ impl Counter {
         Self(value)
     }
 
+    /// The counter block `nonce || value`, with `value` big-endian.
+    pub(super) fn new(nonce: &[u8; NONCE_LEN], value: u32) -> Self {
+        let mut block = [0u8; BLOCK_LEN];
+        block[..NONCE_LEN].copy_from_slice(nonce);
+        block[NONCE_LEN..].copy_from_slice(&value.to_be_bytes());
+        Self(block)
+    }
+
     pub fn increment(&mut self) -> Iv {
         let iv = Iv(self.0);
         self.increment_by_less_safe(1);
@@ -187,6 +269,12 @@ mod tests {
     use super::*;
     use crate::test;
 
+    #[test]
+    fn test_aes_key_zeroed_on_drop() {
+        let key = fallback::Key::new(KeyBytes::AES_256(&[0xa5; 32])).unwrap();
+        assert!(constant_time::is_zeroed_after_drop(key));
+    }
+
     #[test]
     pub fn test_aes() {
         test::run(test_file!("aes_tests.txt"), |section, test_case| {
@@ -208,6 +296,7 @@ mod tests {
         let key = &key[..];
         let key = match key.len() {
             16 => KeyBytes::AES_128(key.try_into().unwrap()),
+            24 => KeyBytes::AES_192(key.try_into().unwrap()),
             32 => KeyBytes::AES_256(key.try_into().unwrap()),
             _ => unreachable!(),
         };
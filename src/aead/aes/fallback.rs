@@ -26,6 +26,12 @@ impl Key {
         let inner = unsafe { set_encrypt_key!(aes_nohw_set_encrypt_key, bytes) }?;
         Ok(Self { inner })
     }
+
+    /// FIPS 197 Section 5.3, `InvCipher`. This is constant-time, like the
+    /// forward cipher.
+    pub(in super::super) fn decrypt_block(&self, block: Block) -> Block {
+        unsafe { decrypt_block!(aes_nohw_decrypt, block, &self.inner) }
+    }
 }
 
 impl EncryptBlock for Key {
@@ -45,3 +51,49 @@ impl EncryptCtr32 for Key {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{super::KeyBytes, *};
+    use crate::test;
+
+    #[test]
+    fn test_decrypt_block() {
+        // FIPS 197 Appendix C.1, C.2, and C.3.
+        let plaintext = test::from_hex("00112233445566778899aabbccddeeff").unwrap();
+        let plaintext: Block = plaintext.as_slice().try_into().unwrap();
+        let key_128: [u8; 16] = core::array::from_fn(|i| u8::try_from(i).unwrap());
+        let key_192: [u8; 24] = core::array::from_fn(|i| u8::try_from(i).unwrap());
+        let key_256: [u8; 32] = core::array::from_fn(|i| u8::try_from(i).unwrap());
+
+        for (bytes, ciphertext) in [
+            (
+                KeyBytes::AES_128(&key_128),
+                "69c4e0d86a7b0430d8cdb78070b4c55a",
+            ),
+            (
+                KeyBytes::AES_192(&key_192),
+                "dda97ca4864cdfe06eaf70a0ec0d7191",
+            ),
+            (
+                KeyBytes::AES_256(&key_256),
+                "8ea2b7ca516745bfeafc49904b496089",
+            ),
+        ] {
+            let ciphertext = test::from_hex(ciphertext).unwrap();
+            let ciphertext: Block = ciphertext.as_slice().try_into().unwrap();
+            let key = Key::new(bytes).unwrap();
+            assert_eq!(key.encrypt_block(plaintext), ciphertext);
+            assert_eq!(key.decrypt_block(ciphertext), plaintext);
+
+            // Round trips, starting from a block with every byte value in
+            // play over the iterations.
+            let mut block = plaintext;
+            for _ in 0..64 {
+                let encrypted = key.encrypt_block(block);
+                assert_eq!(key.decrypt_block(encrypted), block);
+                block = encrypted;
+            }
+        }
+    }
+}
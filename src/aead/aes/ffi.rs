@@ -13,7 +13,7 @@
 // CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
 
 use super::{Block, KeyBytes, BLOCK_LEN};
-use crate::{bits::BitLength, c, error, polyfill::slice};
+use crate::{bits::BitLength, c, constant_time, error, polyfill::slice};
 use core::{num::NonZeroUsize, ops::RangeFrom};
 
 /// nonce || big-endian counter.
@@ -31,6 +31,13 @@ pub(in super::super) struct AES_KEY {
 // Keep this in sync with `AES_MAXNR` in aes.h.
 const MAX_ROUNDS: usize = 14;
 
+impl Drop for AES_KEY {
+    fn drop(&mut self) {
+        constant_time::secure_overwrite(&mut self.rd_key, 0);
+        constant_time::secure_overwrite(core::slice::from_mut(&mut self.rounds), 0);
+    }
+}
+
 impl AES_KEY {
     #[inline]
     pub(super) unsafe fn new(
@@ -44,6 +51,8 @@ impl AES_KEY {
 
         let (bytes, key_bits) = match bytes {
             KeyBytes::AES_128(bytes) => (&bytes[..], BitLength::from_bits(128)),
+            #[cfg(test)]
+            KeyBytes::AES_192(bytes) => (&bytes[..], BitLength::from_bits(192)),
             KeyBytes::AES_256(bytes) => (&bytes[..], BitLength::from_bits(256)),
         };
 
@@ -106,6 +115,17 @@ macro_rules! encrypt_block {
     }};
 }
 
+macro_rules! decrypt_block {
+    ($name:ident, $block:expr, $key:expr) => {{
+        use crate::aead::aes::{ffi::AES_KEY, Block};
+        prefixed_extern! {
+            fn $name(a: &Block, r: *mut Block, key: &AES_KEY);
+        }
+        // `AES_KEY::encrypt_block` just applies `$name` to the block.
+        $key.encrypt_block($name, $block)
+    }};
+}
+
 impl AES_KEY {
     #[inline]
     pub(super) unsafe fn encrypt_block(
@@ -18,6 +18,9 @@ use super::{Block, Counter, EncryptBlock, EncryptCtr32, Iv, KeyBytes, AES_KEY};
 use crate::{cpu, error};
 use core::ops::RangeFrom;
 
+#[cfg(not(feature = "fips"))]
+use super::{round::AesRound, ZERO_BLOCK};
+
 #[cfg(target_arch = "aarch64")]
 pub(in super::super) type RequiredCpuFeatures = cpu::arm::Aes;
 
@@ -62,3 +65,84 @@ impl EncryptCtr32 for Key {
         unsafe { ctr32_encrypt_blocks!(aes_hw_ctr32_encrypt_blocks, in_out, src, &self.inner, ctr) }
     }
 }
+
+/// The AES round function computed with the AES instructions.
+///
+/// A `Round` can only be constructed with proof that the AES instructions
+/// are available.
+#[cfg(not(feature = "fips"))]
+#[derive(Clone, Copy)]
+pub(in super::super) struct Round(());
+
+#[cfg(not(feature = "fips"))]
+impl Round {
+    pub(in super::super) fn new(_cpu: RequiredCpuFeatures) -> Self {
+        Self(())
+    }
+}
+
+#[cfg(not(feature = "fips"))]
+impl AesRound for Round {
+    #[inline(always)]
+    fn round(self, input: Block, round_key: Block) -> Block {
+        // SAFETY: The existence of `self` proves the AES instructions are
+        // available.
+        unsafe { aes_round(input, round_key) }
+    }
+}
+
+#[cfg(all(
+    not(feature = "fips"),
+    any(target_arch = "x86", target_arch = "x86_64")
+))]
+#[target_feature(enable = "aes")]
+#[inline]
+unsafe fn aes_round(input: Block, round_key: Block) -> Block {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::{__m128i, _mm_aesenc_si128, _mm_loadu_si128, _mm_storeu_si128};
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::{__m128i, _mm_aesenc_si128, _mm_loadu_si128, _mm_storeu_si128};
+
+    let mut out = ZERO_BLOCK;
+    // SAFETY: AES-NI implies SSE2. The unaligned loads and stores are within
+    // the arrays.
+    unsafe {
+        let input = _mm_loadu_si128(input.as_ptr().cast::<__m128i>());
+        let round_key = _mm_loadu_si128(round_key.as_ptr().cast::<__m128i>());
+        let r = _mm_aesenc_si128(input, round_key);
+        _mm_storeu_si128(out.as_mut_ptr().cast::<__m128i>(), r);
+    }
+    out
+}
+
+// `AESE` with an all-zero round key is `ShiftRows(SubBytes(input))`, so
+// `AESE` followed by `AESMC` and an XOR with `round_key` is `AESENC`.
+#[cfg(all(not(feature = "fips"), target_arch = "aarch64"))]
+#[target_feature(enable = "aes")]
+#[inline]
+unsafe fn aes_round(input: Block, round_key: Block) -> Block {
+    use core::arch::{
+        aarch64::{vdupq_n_u8, veorq_u8, vld1q_u8, vst1q_u8},
+        asm,
+    };
+
+    let mut out = ZERO_BLOCK;
+    // TODO(MSRV): Use `vaeseq_u8` and `vaesmcq_u8` instead of `asm!` when
+    // the MSRV is 1.72 or later.
+    //
+    // SAFETY: The AES instructions imply NEON. The loads and stores are
+    // within the arrays.
+    unsafe {
+        let mut state = vld1q_u8(input.as_ptr());
+        asm!(
+            "aese {state:v}.16b, {zero:v}.16b",
+            "aesmc {state:v}.16b, {state:v}.16b",
+            state = inout(vreg) state,
+            zero = in(vreg) vdupq_n_u8(0),
+            options(pure, nomem, nostack, preserves_flags),
+        );
+        let state = veorq_u8(state, vld1q_u8(round_key.as_ptr()));
+        vst1q_u8(out.as_mut_ptr(), state);
+    }
+    out
+}
@@ -0,0 +1,153 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! A single AES encryption round, for constructions such as AEGIS that use
+//! the AES round function without the AES key schedule.
+//!
+//! `hw::Round` computes it with the AES instructions; `Fallback` is used
+//! when they aren't available.
+
+use super::Block;
+
+pub(in super::super) trait AesRound: Copy {
+    /// Returns `MixColumns(ShiftRows(SubBytes(input))) ^ round_key`, i.e.
+    /// the x86 `AESENC` instruction.
+    fn round(self, input: Block, round_key: Block) -> Block;
+}
+
+/// A constant-time software implementation.
+///
+/// `SubBytes` computes the multiplicative inverse in GF(2^8) as `x^254` for
+/// all 16 bytes at once, using only shifts, masks, and XORs, so there are no
+/// secret-dependent table lookups or branches. This is much slower than a
+/// hardware implementation.
+#[derive(Clone, Copy)]
+pub(in super::super) struct Fallback(());
+
+impl Fallback {
+    pub(in super::super) fn new() -> Self {
+        Self(())
+    }
+}
+
+impl AesRound for Fallback {
+    fn round(self, input: Block, round_key: Block) -> Block {
+        let s = sub_bytes(u128::from_le_bytes(input)).to_le_bytes();
+
+        // ShiftRows and MixColumns. The state is column-major: byte
+        // `4 * c + r` is row `r` of column `c`.
+        let mut out = [0u8; 16];
+        for (c, out) in out.chunks_exact_mut(4).enumerate() {
+            let a: [u8; 4] = core::array::from_fn(|r| s[4 * ((c + r) % 4) + r]);
+            for (r, out) in out.iter_mut().enumerate() {
+                let (a0, a1, a2, a3) = (a[r], a[(r + 1) % 4], a[(r + 2) % 4], a[(r + 3) % 4]);
+                *out = xtime_u8(a0) ^ xtime_u8(a1) ^ a1 ^ a2 ^ a3 ^ round_key[4 * c + r];
+            }
+        }
+        out
+    }
+}
+
+/// The least significant bit of every byte.
+const LSBS: u128 = u128::from_le_bytes([1; 16]);
+
+/// Applies the AES S-box to every byte of `x`.
+fn sub_bytes(x: u128) -> u128 {
+    // x^254 = x^-1 for nonzero x, and 0^254 = 0.
+    let x2 = gf_mul(x, x);
+    let x3 = gf_mul(x2, x);
+    let x6 = gf_mul(x3, x3);
+    let x12 = gf_mul(x6, x6);
+    let x14 = gf_mul(x12, x2);
+    let x15 = gf_mul(x12, x3);
+    let x30 = gf_mul(x15, x15);
+    let x60 = gf_mul(x30, x30);
+    let x120 = gf_mul(x60, x60);
+    let x240 = gf_mul(x120, x120);
+    let inverse = gf_mul(x240, x14);
+
+    // The affine transformation.
+    inverse
+        ^ rotl_bytes(inverse, 1)
+        ^ rotl_bytes(inverse, 2)
+        ^ rotl_bytes(inverse, 3)
+        ^ rotl_bytes(inverse, 4)
+        ^ (LSBS * 0x63)
+}
+
+/// Multiplies each byte of `a` by the corresponding byte of `b` in GF(2^8).
+fn gf_mul(mut a: u128, b: u128) -> u128 {
+    let mut r = 0;
+    for i in 0..8 {
+        let bits = (b >> i) & LSBS;
+        // 0xff in each byte where the bit is set. The subtraction never
+        // borrows across bytes; the top byte wraps as intended.
+        let mask = (bits << 8).wrapping_sub(bits);
+        r ^= a & mask;
+        a = xtime(a);
+    }
+    r
+}
+
+/// Multiplies each byte of `a` by `x` in GF(2^8).
+fn xtime(a: u128) -> u128 {
+    let carries = (a >> 7) & LSBS;
+    // 0x1b = x^4 + x^3 + x + 1.
+    ((a << 1) & (LSBS * 0xfe)) ^ (carries << 4) ^ (carries << 3) ^ (carries << 1) ^ carries
+}
+
+fn xtime_u8(a: u8) -> u8 {
+    (a << 1) ^ (0u8.wrapping_sub(a >> 7) & 0x1b)
+}
+
+/// Rotates each byte of `a` left by `n` bits.
+fn rotl_bytes(a: u128, n: u32) -> u128 {
+    let high = LSBS * u128::from(0xffu8 << n);
+    ((a << n) & high) | ((a >> (8 - n)) & !high)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    #[cfg(any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64"))]
+    use crate::cpu::{self, GetFeature as _};
+
+    #[test]
+    fn test_aes_round() {
+        // From the AESRound test vector in the AEGIS specification.
+        let input = test::from_hex("000102030405060708090a0b0c0d0e0f").unwrap();
+        let round_key = test::from_hex("101112131415161718191a1b1c1d1e1f").unwrap();
+        let expected = test::from_hex("7a7b4e5638782546a8c0477a3b813f43").unwrap();
+        let input: Block = input.as_slice().try_into().unwrap();
+        let round_key: Block = round_key.as_slice().try_into().unwrap();
+
+        assert_eq!(&Fallback::new().round(input, round_key)[..], &expected[..]);
+
+        #[cfg(any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64"))]
+        if let Some(aes) = cpu::features().get_feature() {
+            let hw = super::super::hw::Round::new(aes);
+            assert_eq!(&hw.round(input, round_key)[..], &expected[..]);
+        }
+    }
+
+    #[test]
+    fn test_sub_bytes() {
+        // The first row of the AES S-box table in FIPS 197.
+        let input = u128::from_le_bytes(core::array::from_fn(|i| u8::try_from(i).unwrap()));
+        let expected = test::from_hex("637c777bf26b6fc53001672bfed7ab76").unwrap();
+        assert_eq!(&sub_bytes(input).to_le_bytes()[..], &expected[..]);
+    }
+}
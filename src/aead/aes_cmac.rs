@@ -0,0 +1,124 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! AES-CMAC, as specified in [NIST SP 800-38B] and [RFC 4493].
+//!
+//! This isn't exposed publicly; it is the PRF for other constructions, such
+//! as the SP 800-108 KDF in `ring::kdf`.
+//!
+//! [NIST SP 800-38B]: https://doi.org/10.6028/NIST.SP.800-38B
+//! [RFC 4493]: https://tools.ietf.org/html/rfc4493
+
+use super::aes::{self, Block, BLOCK_LEN};
+use crate::{cpu, error};
+
+/// The length of a CMAC tag.
+pub(crate) const TAG_LEN: usize = BLOCK_LEN;
+
+/// An AES-CMAC key.
+#[derive(Clone)]
+pub(crate) struct Key {
+    aes: aes::Key,
+    k1: Block,
+    k2: Block,
+}
+
+impl Key {
+    /// Constructs an AES-128 key if `key` is 16 bytes long, or an AES-256
+    /// key if it is 32 bytes long; otherwise fails.
+    pub(crate) fn new(key: &[u8]) -> Result<Self, error::Unspecified> {
+        let key = match key.len() {
+            aes::AES_128_KEY_LEN => aes::KeyBytes::AES_128(key.try_into()?),
+            aes::AES_256_KEY_LEN => aes::KeyBytes::AES_256(key.try_into()?),
+            _ => return Err(error::Unspecified),
+        };
+        let aes = aes::Key::new(key, cpu::features())?;
+
+        // SP 800-38B Section 6.1: subkey generation.
+        let l = aes.encrypt_block(aes::ZERO_BLOCK);
+        let k1 = dbl(l);
+        let k2 = dbl(k1);
+        Ok(Self { aes, k1, k2 })
+    }
+
+    /// Computes the CMAC of the concatenation of `parts`.
+    pub(crate) fn sign(&self, parts: &[&[u8]]) -> [u8; TAG_LEN] {
+        let mut x = aes::ZERO_BLOCK;
+
+        // The last block is treated specially, so a full block is only
+        // processed once it is known that more input follows it.
+        let mut pending = aes::ZERO_BLOCK;
+        let mut pending_len = 0;
+        for mut part in parts.iter().copied() {
+            while !part.is_empty() {
+                if pending_len == BLOCK_LEN {
+                    x = self.aes.encrypt_block(xor(x, pending));
+                    pending_len = 0;
+                }
+                let n = core::cmp::min(BLOCK_LEN - pending_len, part.len());
+                let (taken, rest) = part.split_at(n);
+                pending[pending_len..][..n].copy_from_slice(taken);
+                pending_len += n;
+                part = rest;
+            }
+        }
+
+        let last = if pending_len == BLOCK_LEN {
+            xor(pending, self.k1)
+        } else {
+            pending[pending_len] = 0x80;
+            pending[(pending_len + 1)..].fill(0);
+            xor(pending, self.k2)
+        };
+        self.aes.encrypt_block(xor(x, last))
+    }
+}
+
+/// Multiplication by `x` in GF(2^128), in SP 800-38B's bit order.
+fn dbl(b: Block) -> Block {
+    let v = u128::from_be_bytes(b);
+    let carry = 0u128.wrapping_sub(v >> 127);
+    ((v << 1) ^ (carry & 0x87)).to_be_bytes()
+}
+
+fn xor(a: Block, b: Block) -> Block {
+    (u128::from_ne_bytes(a) ^ u128::from_ne_bytes(b)).to_ne_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    #[test]
+    fn test_aes_cmac() {
+        test::run(test_file!("aes_cmac_tests.txt"), |section, test_case| {
+            assert_eq!(section, "");
+            let key = test_case.consume_bytes("Key");
+            let msg = test_case.consume_bytes("Msg");
+            let expected = test_case.consume_bytes("Tag");
+
+            let key = Key::new(&key)?;
+            assert_eq!(&key.sign(&[&msg])[..], &expected[..]);
+
+            // Splitting the input at every position gives the same result.
+            for i in 0..=msg.len() {
+                let (a, b) = msg.split_at(i);
+                assert_eq!(&key.sign(&[a, &[], b])[..], &expected[..]);
+            }
+
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,298 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! AES in counter (CTR) mode, with a configurable counter layout.
+
+use super::aes;
+use crate::{cpu, error, polyfill::u64_from_usize};
+
+/// An AES key for encrypting and decrypting in counter (CTR) mode.
+///
+/// CTR mode provides no integrity protection; use it only as a building block
+/// for protocols that authenticate the ciphertext some other way.
+#[derive(Clone)]
+pub struct CtrKey {
+    key: aes::Key,
+    counter: CounterConfig,
+}
+
+impl CtrKey {
+    /// Constructs an AES-CTR key whose counter blocks are laid out as
+    /// described by `counter`.
+    ///
+    /// The key is an AES-128 key if `key_bytes` is 16 bytes long, and an
+    /// AES-256 key if it is 32 bytes long; otherwise this fails.
+    pub fn new(key_bytes: &[u8], counter: CounterConfig) -> Result<Self, error::Unspecified> {
+        Ok(Self {
+            key: new_aes_key(key_bytes)?,
+            counter,
+        })
+    }
+
+    /// XORs `in_out` with the keystream whose first counter block is
+    /// `initial_counter_block`, which encrypts plaintext and decrypts
+    /// ciphertext.
+    ///
+    /// For each block, only the counter field of the counter block is
+    /// incremented. Fails, without modifying `in_out`, if the counter field
+    /// would wrap around before the end of `in_out`, since that would reuse
+    /// the keystream.
+    pub fn apply_keystream(
+        &self,
+        initial_counter_block: [u8; AES_CTR_BLOCK_LEN],
+        in_out: &mut [u8],
+    ) -> Result<(), error::Unspecified> {
+        let mut counter_block = initial_counter_block;
+        let first = self.counter.get(&counter_block);
+        let blocks = in_out.chunks(AES_CTR_BLOCK_LEN).len();
+        // The counter block for the last chunk has a counter value of
+        // `first + blocks - 1`, which must fit in the counter field.
+        if let Some(last_offset) = blocks.checked_sub(1) {
+            let last = first
+                .checked_add(u128::from(u64_from_usize(last_offset)))
+                .ok_or(error::Unspecified)?;
+            if last > self.counter.max() {
+                return Err(error::Unspecified);
+            }
+        }
+
+        let mut value = first;
+        for chunk in in_out.chunks_mut(AES_CTR_BLOCK_LEN) {
+            let keystream = self.key.encrypt_block(counter_block);
+            chunk
+                .iter_mut()
+                .zip(keystream.iter())
+                .for_each(|(b, k)| *b ^= k);
+            value = value.wrapping_add(1);
+            self.counter.set(&mut counter_block, value);
+        }
+        Ok(())
+    }
+
+    /// The layout of the counter blocks.
+    #[inline]
+    pub fn counter_config(&self) -> CounterConfig {
+        self.counter
+    }
+}
+
+impl core::fmt::Debug for CtrKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("CtrKey")
+            .field("counter", &self.counter)
+            .finish()
+    }
+}
+
+/// The length of a `Ctr` nonce.
+pub const CTR_NONCE_LEN: usize = AES_CTR_BLOCK_LEN - 4;
+
+/// An AES-CTR keystream, positioned at a particular byte, for encrypting or
+/// decrypting a stream of data across multiple calls.
+///
+/// The counter blocks are the 96-bit nonce followed by a 32-bit big-endian
+/// block counter, as in NIST SP 800-38D (AES-GCM). The keystream ends before
+/// the block counter reaches 2^32 - 1, after which `apply_keystream()` fails;
+/// the block counter never wraps around, so the keystream is never reused.
+///
+/// Like `CtrKey`, this provides no integrity protection. Intentionally not
+/// `Clone` since cloning would allow the keystream to be reused.
+pub struct Ctr {
+    key: aes::Key,
+    nonce: [u8; CTR_NONCE_LEN],
+
+    /// The counter value of the next keystream block to generate, where
+    /// `u32::MAX` means the keystream is exhausted.
+    next_counter: u32,
+
+    /// The last keystream block generated, of which the bytes from
+    /// `partial_used` on haven't been used yet.
+    partial: [u8; AES_CTR_BLOCK_LEN],
+    partial_used: usize,
+}
+
+impl Ctr {
+    /// Constructs an AES-CTR keystream whose block counter starts at 0.
+    ///
+    /// The key is an AES-128 key if `key_bytes` is 16 bytes long, and an
+    /// AES-256 key if it is 32 bytes long; otherwise this fails. `nonce`
+    /// must be unique for the key.
+    pub fn new(key_bytes: &[u8], nonce: [u8; CTR_NONCE_LEN]) -> Result<Self, error::Unspecified> {
+        Self::with_initial_counter(key_bytes, nonce, 0)
+    }
+
+    /// Like `new()`, except the block counter starts at `initial_counter`,
+    /// e.g. 2 to match the ciphertext blocks of AES-GCM.
+    pub fn with_initial_counter(
+        key_bytes: &[u8],
+        nonce: [u8; CTR_NONCE_LEN],
+        initial_counter: u32,
+    ) -> Result<Self, error::Unspecified> {
+        Ok(Self {
+            key: new_aes_key(key_bytes)?,
+            nonce,
+            next_counter: initial_counter,
+            partial: [0; AES_CTR_BLOCK_LEN],
+            partial_used: AES_CTR_BLOCK_LEN,
+        })
+    }
+
+    /// XORs `in_out` with the next `in_out.len()` bytes of the keystream,
+    /// which encrypts plaintext and decrypts ciphertext.
+    ///
+    /// Splitting the data across multiple calls gives the same result as a
+    /// single call, regardless of where it is split. Fails, without modifying
+    /// `in_out` or advancing the keystream, if the keystream would run out.
+    pub fn apply_keystream(&mut self, in_out: &mut [u8]) -> Result<(), error::Unspecified> {
+        let from_partial = core::cmp::min(AES_CTR_BLOCK_LEN - self.partial_used, in_out.len());
+        let (head, rest) = in_out.split_at_mut(from_partial);
+
+        let blocks = u64_from_usize(rest.chunks(AES_CTR_BLOCK_LEN).len());
+        if blocks > u64::from(u32::MAX - self.next_counter) {
+            return Err(error::Unspecified);
+        }
+
+        xor_assign(head, &self.partial[self.partial_used..][..from_partial]);
+        self.partial_used += from_partial;
+
+        // Since there are at most `u32::MAX - self.next_counter` blocks,
+        // `ctr` never overflows.
+        let mut ctr = aes::Counter::new(&self.nonce, self.next_counter);
+
+        let whole_len = rest.len() - (rest.len() % AES_CTR_BLOCK_LEN);
+        let (whole, tail) = rest.split_at_mut(whole_len);
+        self.key.ctr32_encrypt_within(whole, 0.., &mut ctr);
+
+        if !tail.is_empty() {
+            let mut keystream = [0; AES_CTR_BLOCK_LEN];
+            self.key.ctr32_encrypt_within(&mut keystream, 0.., &mut ctr);
+            xor_assign(tail, &keystream[..tail.len()]);
+            self.partial = keystream;
+            self.partial_used = tail.len();
+        }
+
+        // `blocks <= u32::MAX - self.next_counter`, so this doesn't overflow.
+        self.next_counter += u32::try_from(blocks).unwrap();
+
+        Ok(())
+    }
+}
+
+impl core::fmt::Debug for Ctr {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("Ctr").finish()
+    }
+}
+
+fn new_aes_key(key_bytes: &[u8]) -> Result<aes::Key, error::Unspecified> {
+    let key_bytes = match key_bytes.len() {
+        aes::AES_128_KEY_LEN => aes::KeyBytes::AES_128(key_bytes.try_into()?),
+        aes::AES_256_KEY_LEN => aes::KeyBytes::AES_256(key_bytes.try_into()?),
+        _ => return Err(error::Unspecified),
+    };
+    aes::Key::new(key_bytes, cpu::features())
+}
+
+fn xor_assign(in_out: &mut [u8], keystream: &[u8]) {
+    in_out.iter_mut().zip(keystream).for_each(|(b, k)| *b ^= k);
+}
+
+/// The length of an AES-CTR counter block.
+pub const AES_CTR_BLOCK_LEN: usize = aes::BLOCK_LEN;
+
+/// Which bytes of an AES-CTR counter block form the counter, and their byte
+/// order.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CounterConfig {
+    start: usize,
+    len: usize,
+    byte_order: ByteOrder,
+}
+
+/// The byte order of an AES-CTR counter.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ByteOrder {
+    /// The most significant byte comes first.
+    BigEndian,
+
+    /// The least significant byte comes first.
+    LittleEndian,
+}
+
+impl CounterConfig {
+    /// The last 4 bytes of the block, big-endian, as in NIST SP 800-38D
+    /// (AES-GCM).
+    pub const BIG_ENDIAN_32: Self = Self {
+        start: AES_CTR_BLOCK_LEN - 4,
+        len: 4,
+        byte_order: ByteOrder::BigEndian,
+    };
+
+    /// The whole block as a 128-bit big-endian integer, as in NIST SP
+    /// 800-38A Appendix B.1.
+    pub const BIG_ENDIAN_128: Self = Self {
+        start: 0,
+        len: AES_CTR_BLOCK_LEN,
+        byte_order: ByteOrder::BigEndian,
+    };
+
+    /// A counter occupying the `len` bytes starting at `start`, in the byte
+    /// order `byte_order`. The other bytes of the counter block are never
+    /// modified.
+    ///
+    /// Fails if `len` is zero or if the counter doesn't fit in the block.
+    pub fn new(
+        start: usize,
+        len: usize,
+        byte_order: ByteOrder,
+    ) -> Result<Self, error::Unspecified> {
+        match start.checked_add(len) {
+            Some(end) if len > 0 && end <= AES_CTR_BLOCK_LEN => Ok(Self {
+                start,
+                len,
+                byte_order,
+            }),
+            _ => Err(error::Unspecified),
+        }
+    }
+
+    fn max(&self) -> u128 {
+        u128::MAX >> (128 - (8 * self.len))
+    }
+
+    fn get(&self, block: &[u8; AES_CTR_BLOCK_LEN]) -> u128 {
+        let field = &block[self.start..][..self.len];
+        let mut value = [0u8; AES_CTR_BLOCK_LEN];
+        match self.byte_order {
+            ByteOrder::BigEndian => {
+                value[(AES_CTR_BLOCK_LEN - self.len)..].copy_from_slice(field);
+                u128::from_be_bytes(value)
+            }
+            ByteOrder::LittleEndian => {
+                value[..self.len].copy_from_slice(field);
+                u128::from_le_bytes(value)
+            }
+        }
+    }
+
+    fn set(&self, block: &mut [u8; AES_CTR_BLOCK_LEN], value: u128) {
+        let field = &mut block[self.start..][..self.len];
+        match self.byte_order {
+            ByteOrder::BigEndian => {
+                field.copy_from_slice(&value.to_be_bytes()[(AES_CTR_BLOCK_LEN - self.len)..])
+            }
+            ByteOrder::LittleEndian => field.copy_from_slice(&value.to_le_bytes()[..self.len]),
+        }
+    }
+}
@@ -62,46 +62,177 @@ enum DynKey {
     Fallback(Combo<aes::fallback::Key, gcm::fallback::Key>),
 }
 
+/// The AES-GCM implementations, in the order in which they are preferred
+/// when they are available.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+enum Implementation {
+    AesHwClMulAvxMovbe = 1,
+    AesHwClMul = 2,
+    Simd = 3,
+    Fallback = 4,
+}
+
+const PREFERENCE_ORDER: [Implementation; 4] = [
+    Implementation::AesHwClMulAvxMovbe,
+    Implementation::AesHwClMul,
+    Implementation::Simd,
+    Implementation::Fallback,
+];
+
 impl DynKey {
     fn new(key: aes::KeyBytes, cpu_features: cpu::Features) -> Result<Self, error::Unspecified> {
-        #[cfg(target_arch = "x86_64")]
-        if let (Some(aes), Some(gcm)) = (cpu_features.get_feature(), cpu_features.get_feature()) {
-            let aes_key = aes::hw::Key::new(key, aes)?;
-            let gcm_key_value = derive_gcm_key_value(&aes_key);
-            let gcm_key = gcm::clmulavxmovbe::Key::new(gcm_key_value, gcm);
-            return Ok(Self::AesHwClMulAvxMovbe(Combo { aes_key, gcm_key }));
+        #[cfg(feature = "std")]
+        if let Some(implementation) = benchmark::selected() {
+            if let Some(key) = Self::try_new(key, cpu_features, implementation)? {
+                return Ok(key);
+            }
         }
 
-        #[cfg(any(target_arch = "aarch64", target_arch = "x86_64", target_arch = "x86"))]
-        if let (Some(aes), Some(gcm)) = (cpu_features.get_feature(), cpu_features.get_feature()) {
-            let aes_key = aes::hw::Key::new(key, aes)?;
-            let gcm_key_value = derive_gcm_key_value(&aes_key);
-            let gcm_key = gcm::clmul::Key::new(gcm_key_value, gcm);
-            return Ok(Self::AesHwClMul(Combo { aes_key, gcm_key }));
+        for implementation in PREFERENCE_ORDER {
+            if let Some(key) = Self::try_new(key, cpu_features, implementation)? {
+                return Ok(key);
+            }
         }
 
-        #[cfg(any(target_arch = "aarch64", target_arch = "arm"))]
-        if let (Some(aes), Some(gcm)) = (cpu_features.get_feature(), cpu_features.get_feature()) {
-            let aes_key = aes::vp::Key::new(key, aes)?;
-            let gcm_key_value = derive_gcm_key_value(&aes_key);
-            let gcm_key = gcm::neon::Key::new(gcm_key_value, gcm);
-            return Ok(Self::Simd(Combo { aes_key, gcm_key }));
-        }
+        unreachable!() // `Implementation::Fallback` is always available.
+    }
+
+    /// Returns `Ok(None)` if `implementation` isn't available.
+    fn try_new(
+        key: aes::KeyBytes,
+        cpu_features: cpu::Features,
+        implementation: Implementation,
+    ) -> Result<Option<Self>, error::Unspecified> {
+        match implementation {
+            Implementation::AesHwClMulAvxMovbe => {
+                #[cfg(target_arch = "x86_64")]
+                if let (Some(aes), Some(gcm)) =
+                    (cpu_features.get_feature(), cpu_features.get_feature())
+                {
+                    let aes_key = aes::hw::Key::new(key, aes)?;
+                    let gcm_key_value = derive_gcm_key_value(&aes_key);
+                    let gcm_key = gcm::clmulavxmovbe::Key::new(gcm_key_value, gcm);
+                    return Ok(Some(Self::AesHwClMulAvxMovbe(Combo { aes_key, gcm_key })));
+                }
+            }
+
+            Implementation::AesHwClMul => {
+                #[cfg(any(target_arch = "aarch64", target_arch = "x86_64", target_arch = "x86"))]
+                if let (Some(aes), Some(gcm)) =
+                    (cpu_features.get_feature(), cpu_features.get_feature())
+                {
+                    let aes_key = aes::hw::Key::new(key, aes)?;
+                    let gcm_key_value = derive_gcm_key_value(&aes_key);
+                    let gcm_key = gcm::clmul::Key::new(gcm_key_value, gcm);
+                    return Ok(Some(Self::AesHwClMul(Combo { aes_key, gcm_key })));
+                }
+            }
+
+            Implementation::Simd => {
+                #[cfg(any(target_arch = "aarch64", target_arch = "arm"))]
+                if let (Some(aes), Some(gcm)) =
+                    (cpu_features.get_feature(), cpu_features.get_feature())
+                {
+                    let aes_key = aes::vp::Key::new(key, aes)?;
+                    let gcm_key_value = derive_gcm_key_value(&aes_key);
+                    let gcm_key = gcm::neon::Key::new(gcm_key_value, gcm);
+                    return Ok(Some(Self::Simd(Combo { aes_key, gcm_key })));
+                }
 
-        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-        if let Some(aes) = cpu_features.get_feature() {
-            let aes_key = aes::vp::Key::new(key, aes)?;
-            let gcm_key_value = derive_gcm_key_value(&aes_key);
-            let gcm_key = gcm::fallback::Key::new(gcm_key_value);
-            return Ok(Self::Simd(Combo { aes_key, gcm_key }));
+                #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                if let Some(aes) = cpu_features.get_feature() {
+                    let aes_key = aes::vp::Key::new(key, aes)?;
+                    let gcm_key_value = derive_gcm_key_value(&aes_key);
+                    let gcm_key = gcm::fallback::Key::new(gcm_key_value);
+                    return Ok(Some(Self::Simd(Combo { aes_key, gcm_key })));
+                }
+            }
+
+            Implementation::Fallback => {
+                let aes_key = aes::fallback::Key::new(key)?;
+                let gcm_key_value = derive_gcm_key_value(&aes_key);
+                let gcm_key = gcm::fallback::Key::new(gcm_key_value);
+                return Ok(Some(Self::Fallback(Combo { aes_key, gcm_key })));
+            }
         }
 
-        let _ = cpu_features;
+        let _ = (key, cpu_features);
 
-        let aes_key = aes::fallback::Key::new(key)?;
-        let gcm_key_value = derive_gcm_key_value(&aes_key);
-        let gcm_key = gcm::fallback::Key::new(gcm_key_value);
-        Ok(Self::Fallback(Combo { aes_key, gcm_key }))
+        Ok(None)
+    }
+}
+
+/// Benchmark-based selection of the implementation; see
+/// `cpu::benchmark_select()`.
+#[cfg(feature = "std")]
+pub(super) mod benchmark {
+    extern crate std;
+
+    use super::{DynKey, Implementation, Key, PREFERENCE_ORDER};
+    use crate::{
+        aead::{aes, Aad, Nonce, NONCE_LEN},
+        cpu,
+    };
+    use core::sync::atomic::{AtomicU8, Ordering};
+    use std::{sync::Once, time::Instant};
+
+    // Zero means no implementation has been selected.
+    static SELECTED: AtomicU8 = AtomicU8::new(0);
+
+    pub(super) fn selected() -> Option<Implementation> {
+        let selected = SELECTED.load(Ordering::Relaxed);
+        PREFERENCE_ORDER
+            .into_iter()
+            .find(|&implementation| implementation as u8 == selected)
+    }
+
+    /// Benchmarks every available implementation, once per process, and
+    /// selects the fastest. Ties go to the implementation that would be
+    /// preferred anyway.
+    pub(in super::super) fn select(cpu_features: cpu::Features) {
+        static ONCE: Once = Once::new();
+        ONCE.call_once(|| {
+            let fastest = PREFERENCE_ORDER
+                .into_iter()
+                .filter_map(|implementation| {
+                    let elapsed = measure(cpu_features, implementation)?;
+                    Some((elapsed, implementation))
+                })
+                .min_by_key(|&(elapsed, _)| elapsed);
+            if let Some((_, implementation)) = fastest {
+                SELECTED.store(implementation as u8, Ordering::Relaxed);
+            }
+        });
+    }
+
+    fn measure(
+        cpu_features: cpu::Features,
+        implementation: Implementation,
+    ) -> Option<std::time::Duration> {
+        const RUNS: usize = 5;
+        const RECORDS_PER_RUN: usize = 4;
+
+        let key_bytes = [0u8; aes::AES_128_KEY_LEN];
+        let key = DynKey::try_new(
+            aes::KeyBytes::AES_128(&key_bytes),
+            cpu_features,
+            implementation,
+        )
+        .ok()??;
+        let key = Key(key);
+
+        let mut in_out = [0u8; 16 * 1024];
+        (0..RUNS)
+            .map(|_| {
+                let start = Instant::now();
+                for _ in 0..RECORDS_PER_RUN {
+                    let nonce = Nonce::assume_unique_for_key([0; NONCE_LEN]);
+                    let _: Result<_, _> = super::seal(&key, nonce, Aad::from(&[]), &mut in_out);
+                }
+                start.elapsed()
+            })
+            .min()
     }
 }
 
@@ -490,6 +621,181 @@ fn finish<A: aes::EncryptBlock, G: gcm::Gmult>(
     gcm_ctx.pre_finish(|pre_tag| Tag(aes_key.encrypt_iv_xor_block(tag_iv, pre_tag)))
 }
 
+/// Seals the plaintext consisting of the whole blocks produced by `blocks`
+/// followed by `remainder`, passing each ciphertext block to `out` as soon as
+/// it is computed. `remainder` is encrypted in place.
+pub(super) fn seal_blocks(
+    Key(key): &Key,
+    nonce: Nonce,
+    aad: Aad<&[u8]>,
+    blocks: impl Iterator<Item = aes::Block>,
+    remainder: &mut [u8],
+    out: impl FnMut(aes::Block),
+) -> Result<Tag, error::Unspecified> {
+    if remainder.len() >= BLOCK_LEN {
+        return Err(error::Unspecified);
+    }
+
+    let ctr = Counter::one(nonce);
+    match key {
+        #[cfg(target_arch = "x86_64")]
+        DynKey::AesHwClMulAvxMovbe(c) => seal_blocks_(c, aad, blocks, remainder, out, ctr),
+
+        #[cfg(any(target_arch = "aarch64", target_arch = "x86_64", target_arch = "x86"))]
+        DynKey::AesHwClMul(c) => seal_blocks_(c, aad, blocks, remainder, out, ctr),
+
+        #[cfg(any(
+            target_arch = "aarch64",
+            target_arch = "arm",
+            target_arch = "x86_64",
+            target_arch = "x86"
+        ))]
+        DynKey::Simd(c) => seal_blocks_(c, aad, blocks, remainder, out, ctr),
+
+        DynKey::Fallback(c) => seal_blocks_(c, aad, blocks, remainder, out, ctr),
+    }
+}
+
+fn seal_blocks_<A: aes::EncryptBlock, G: gcm::Gmult>(
+    Combo { aes_key, gcm_key }: &Combo<A, G>,
+    aad: Aad<&[u8]>,
+    blocks: impl Iterator<Item = aes::Block>,
+    remainder: &mut [u8],
+    mut out: impl FnMut(aes::Block),
+    mut ctr: Counter,
+) -> Result<Tag, error::Unspecified> {
+    let tag_iv = ctr.increment();
+
+    // The length isn't known until `blocks` is exhausted.
+    let mut auth = gcm::Context::new(gcm_key, aad, 0)?;
+    let mut in_out_len = 0;
+    for block in blocks {
+        // Check the length before encrypting so that the counter can't wrap.
+        in_out_len += BLOCK_LEN;
+        if in_out_len > MAX_IN_OUT_LEN {
+            return Err(error::Unspecified);
+        }
+        let block = aes_key.encrypt_iv_xor_block(ctr.increment(), block);
+        auth.update_block(block);
+        out(block);
+    }
+    auth.set_in_out_len(in_out_len + remainder.len())?;
+
+    seal_finish(aes_key, auth, remainder, ctr, tag_iv)
+}
+
+/// Extends the GHASH of the message whose standard AES-GCM tag is `tag` by
+/// one more block, `total_len` as a 128-bit big-endian integer, and returns
+/// the resulting tag.
+///
+/// This is non-standard; see `LessSafeKey::seal_in_place_append_tag_with_total_length`.
+pub(super) fn bind_total_len(Key(key): &Key, nonce: Nonce, tag: Tag, total_len: u64) -> Tag {
+    let nonce = *nonce.as_ref();
+    let tag_iv = || Counter::one(Nonce::assume_unique_for_key(nonce)).into();
+    match key {
+        #[cfg(target_arch = "x86_64")]
+        DynKey::AesHwClMulAvxMovbe(c) => bind_total_len_(c, tag_iv, tag, total_len),
+
+        #[cfg(any(target_arch = "aarch64", target_arch = "x86_64", target_arch = "x86"))]
+        DynKey::AesHwClMul(c) => bind_total_len_(c, tag_iv, tag, total_len),
+
+        #[cfg(any(
+            target_arch = "aarch64",
+            target_arch = "arm",
+            target_arch = "x86_64",
+            target_arch = "x86"
+        ))]
+        DynKey::Simd(c) => bind_total_len_(c, tag_iv, tag, total_len),
+
+        DynKey::Fallback(c) => bind_total_len_(c, tag_iv, tag, total_len),
+    }
+}
+
+fn bind_total_len_<A: aes::EncryptBlock, G: gcm::Gmult>(
+    Combo { aes_key, gcm_key }: &Combo<A, G>,
+    tag_iv: impl Fn() -> aes::Iv,
+    Tag(tag): Tag,
+    total_len: u64,
+) -> Tag {
+    // `tag` is E(K, J0) ^ S, where S is the GHASH state after the lengths
+    // block, so S can be recovered and GHASH continued from it.
+    let mut xi = gcm::Xi::new();
+    xi.bitxor_assign(aes_key.encrypt_iv_xor_block(tag_iv(), tag));
+    xi.bitxor_assign(u128::from(total_len).to_be_bytes());
+    gcm_key.gmult(&mut xi);
+    Tag(aes_key.encrypt_iv_xor_block(tag_iv(), xi.into_block()))
+}
+
+/// Returns the tag that AES-GCM with `key` would produce for a message
+/// whose AAD is `aad` and whose ciphertext is `ciphertext`, without
+/// decrypting anything.
+pub(super) fn tag_ciphertext(
+    Key(key): &Key,
+    nonce: Nonce,
+    aad: Aad<&[u8]>,
+    ciphertext: &[u8],
+) -> Result<Tag, error::Unspecified> {
+    let tag_iv = Counter::one(nonce).into();
+    match key {
+        #[cfg(target_arch = "x86_64")]
+        DynKey::AesHwClMulAvxMovbe(c) => tag_ciphertext_(c, aad, ciphertext, tag_iv),
+
+        #[cfg(any(target_arch = "aarch64", target_arch = "x86_64", target_arch = "x86"))]
+        DynKey::AesHwClMul(c) => tag_ciphertext_(c, aad, ciphertext, tag_iv),
+
+        #[cfg(any(
+            target_arch = "aarch64",
+            target_arch = "arm",
+            target_arch = "x86_64",
+            target_arch = "x86"
+        ))]
+        DynKey::Simd(c) => tag_ciphertext_(c, aad, ciphertext, tag_iv),
+
+        DynKey::Fallback(c) => tag_ciphertext_(c, aad, ciphertext, tag_iv),
+    }
+}
+
+fn tag_ciphertext_<A: aes::EncryptBlock, G: gcm::Gmult>(
+    Combo { aes_key, gcm_key }: &Combo<A, G>,
+    aad: Aad<&[u8]>,
+    ciphertext: &[u8],
+    tag_iv: aes::Iv,
+) -> Result<Tag, error::Unspecified> {
+    let mut auth = gcm::Context::new(gcm_key, aad, ciphertext.len())?;
+    for chunk in ciphertext.chunks(BLOCK_LEN) {
+        let mut block = ZERO_BLOCK;
+        overwrite_at_start(&mut block, chunk);
+        auth.update_block(block);
+    }
+    Ok(finish(aes_key, auth, tag_iv))
+}
+
+/// Returns the GHASH key H = E(K, 0^128), computed by the AES
+/// implementation that `key` uses.
+pub(super) fn hash_subkey(Key(key): &Key) -> aes::Block {
+    match key {
+        #[cfg(target_arch = "x86_64")]
+        DynKey::AesHwClMulAvxMovbe(c) => hash_subkey_(c),
+
+        #[cfg(any(target_arch = "aarch64", target_arch = "x86_64", target_arch = "x86"))]
+        DynKey::AesHwClMul(c) => hash_subkey_(c),
+
+        #[cfg(any(
+            target_arch = "aarch64",
+            target_arch = "arm",
+            target_arch = "x86_64",
+            target_arch = "x86"
+        ))]
+        DynKey::Simd(c) => hash_subkey_(c),
+
+        DynKey::Fallback(c) => hash_subkey_(c),
+    }
+}
+
+fn hash_subkey_<A: aes::EncryptBlock, G>(Combo { aes_key, .. }: &Combo<A, G>) -> aes::Block {
+    aes_key.encrypt_block(ZERO_BLOCK)
+}
+
 pub(super) const MAX_IN_OUT_LEN: usize = super::max_input_len(BLOCK_LEN, 2);
 
 // [NIST SP800-38D] Section 5.2.1.1. Note that [RFC 5116 Section 5.1] and
@@ -507,3 +813,105 @@ pub(super) struct Combo<Aes, Gcm> {
     pub(super) aes_key: Aes,
     pub(super) gcm_key: Gcm,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    // Every available implementation gives the same results, so whichever one
+    // `cpu::benchmark_select()` selects is correct.
+    #[test]
+    fn test_every_implementation() {
+        // Test Case 4 from the original GCM specification.
+        let key = test::from_hex("feffe9928665731c6d6a8f9467308308").unwrap();
+        let nonce = test::from_hex("cafebabefacedbaddecaf888").unwrap();
+        let aad = test::from_hex("feedfacedeadbeeffeedfacedeadbeefabaddad2").unwrap();
+        let plaintext = test::from_hex(
+            "d9313225f88406e5a55909c5aff5269a86a7a9531534f7da2e4c303d8a318a72\
+             1c3c0c95956809532fcf0e2449a6b525b16aedf5aa0de657ba637b39",
+        )
+        .unwrap();
+        let ciphertext = test::from_hex(
+            "42831ec2217774244b7221b784d0d49ce3aa212f2c02a4e035c17e2329aca12e\
+             21d514b25466931c7d8f6a5aac84aa051ba30b396a0aac973d58e091",
+        )
+        .unwrap();
+        let tag = test::from_hex("5bc94fbc3221a5db94fae95ae7121a47").unwrap();
+
+        let key = aes::KeyBytes::AES_128(key.as_slice().try_into().unwrap());
+        let nonce = || Nonce::try_assume_unique_for_key(&nonce).unwrap();
+
+        for implementation in PREFERENCE_ORDER {
+            let key = match DynKey::try_new(key, cpu::features(), implementation).unwrap() {
+                Some(key) => Key(key),
+                None => continue,
+            };
+
+            let mut in_out = plaintext.clone();
+            let Tag(actual_tag) = seal(&key, nonce(), Aad::from(&aad), &mut in_out).unwrap();
+            assert_eq!(in_out, ciphertext);
+            assert_eq!(&actual_tag[..], &tag[..]);
+
+            let Tag(actual_tag) = open(&key, nonce(), Aad::from(&aad), &mut in_out, 0..).unwrap();
+            assert_eq!(in_out, plaintext);
+            assert_eq!(&actual_tag[..], &tag[..]);
+        }
+    }
+
+    // With no AAD and no plaintext, GHASH only processes the length block,
+    // which is all zeros, and GHASH(H, 0^128) = 0, so the tag is E_K(J0).
+    #[test]
+    fn test_empty_every_implementation() {
+        const TEST_CASES: &[(&str, &str, &str)] = &[
+            // Test Case 1 from the original GCM specification.
+            (
+                "00000000000000000000000000000000",
+                "000000000000000000000000",
+                "58e2fccefa7e3061367f1d57a4e7455a",
+            ),
+            (
+                "757d90e5cb88bba677c755c16a0a07cf",
+                "da5c0724b077d31e3d7dacda",
+                "270cf47dc1bf5212a31b35170f078676",
+            ),
+            // Test Case 13 from the original GCM specification.
+            (
+                "0000000000000000000000000000000000000000000000000000000000000000",
+                "000000000000000000000000",
+                "530f8afbc74536b9a963b4f1c4cb738b",
+            ),
+            (
+                "befd747e039ca1380b7a65b1c99730643e76230aaf4db829619f02673ae8f16d",
+                "875452cefbbff255b7a6e3a7",
+                "df2260cb1ec810ecaa047750c25a35b9",
+            ),
+        ];
+
+        for &(key, nonce, tag) in TEST_CASES {
+            let key = test::from_hex(key).unwrap();
+            let nonce = test::from_hex(nonce).unwrap();
+            let tag = test::from_hex(tag).unwrap();
+
+            let key = match key.len() {
+                16 => aes::KeyBytes::AES_128(key.as_slice().try_into().unwrap()),
+                32 => aes::KeyBytes::AES_256(key.as_slice().try_into().unwrap()),
+                _ => unreachable!(),
+            };
+            let nonce = || Nonce::try_assume_unique_for_key(&nonce).unwrap();
+
+            for implementation in PREFERENCE_ORDER {
+                let key = match DynKey::try_new(key, cpu::features(), implementation).unwrap() {
+                    Some(key) => Key(key),
+                    None => continue,
+                };
+
+                let Tag(actual_tag) = seal(&key, nonce(), Aad::from(&[]), &mut []).unwrap();
+                assert_eq!(&actual_tag[..], &tag[..]);
+
+                let Tag(actual_tag) = open(&key, nonce(), Aad::from(&[]), &mut [], 0..).unwrap();
+                assert_eq!(&actual_tag[..], &tag[..]);
+            }
+        }
+    }
+}
@@ -0,0 +1,185 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! AES-GCM-SIV, as specified in [RFC 8452].
+//!
+//! [RFC 8452]: https://tools.ietf.org/html/rfc8452
+
+use super::{
+    aes::{self, Block, BLOCK_LEN, ZERO_BLOCK},
+    polyval::{self, Polyval},
+    Aad, Nonce, Tag, NONCE_LEN,
+};
+use crate::{
+    cpu, error,
+    polyfill::{u64_from_usize, usize_from_u64_saturated},
+};
+use core::ops::RangeFrom;
+
+// RFC 8452 Section 6: P_MAX = A_MAX = 2^36 bytes. The counter wraps around
+// instead of overflowing, so this isn't a limit of the counter.
+pub(super) const MAX_IN_OUT_LEN: usize = usize_from_u64_saturated(MAX_LEN);
+const MAX_LEN: u64 = 1 << 36;
+
+#[derive(Clone)]
+pub(super) struct Key {
+    key_generating_key: aes::Key,
+    key_len: usize,
+}
+
+impl Key {
+    pub(super) fn new(
+        key: aes::KeyBytes,
+        cpu_features: cpu::Features,
+    ) -> Result<Self, error::Unspecified> {
+        let key_len = match key {
+            aes::KeyBytes::AES_128(_) => aes::AES_128_KEY_LEN,
+            aes::KeyBytes::AES_256(_) => aes::AES_256_KEY_LEN,
+            #[cfg(test)]
+            aes::KeyBytes::AES_192(_) => return Err(error::Unspecified),
+        };
+        Ok(Self {
+            key_generating_key: aes::Key::new(key, cpu_features)?,
+            key_len,
+        })
+    }
+
+    /// Derives the per-nonce message-authentication key and
+    /// message-encryption key, as described in RFC 8452 Section 4.
+    fn derive_keys(
+        &self,
+        nonce: &[u8; NONCE_LEN],
+        cpu_features: cpu::Features,
+    ) -> Result<([u8; polyval::KEY_LEN], aes::Key), error::Unspecified> {
+        let mut derived = [0u8; polyval::KEY_LEN + aes::AES_256_KEY_LEN];
+        let derived = &mut derived[..(polyval::KEY_LEN + self.key_len)];
+        // Only the first half of each encrypted block is used.
+        for (i, half) in (0u32..).zip(derived.chunks_mut(BLOCK_LEN / 2)) {
+            let mut block = ZERO_BLOCK;
+            block[..4].copy_from_slice(&i.to_le_bytes());
+            block[4..].copy_from_slice(nonce);
+            let encrypted = self.key_generating_key.encrypt_block(block);
+            half.copy_from_slice(&encrypted[..half.len()]);
+        }
+
+        let (auth_key, enc_key) = derived.split_at(polyval::KEY_LEN);
+        let enc_key = match enc_key.len() {
+            aes::AES_128_KEY_LEN => aes::KeyBytes::AES_128(enc_key.try_into()?),
+            _ => aes::KeyBytes::AES_256(enc_key.try_into()?),
+        };
+        Ok((auth_key.try_into()?, aes::Key::new(enc_key, cpu_features)?))
+    }
+}
+
+pub(super) fn seal(
+    key: &Key,
+    nonce: Nonce,
+    aad: Aad<&[u8]>,
+    in_out: &mut [u8],
+    cpu_features: cpu::Features,
+) -> Result<Tag, error::Unspecified> {
+    check_lens(aad, in_out.len())?;
+    let (auth_key, enc_key) = key.derive_keys(nonce.as_ref(), cpu_features)?;
+    let tag = compute_tag(&auth_key, &enc_key, nonce.as_ref(), aad, in_out);
+    apply_keystream(&enc_key, &tag, in_out);
+    Ok(tag)
+}
+
+/// Unlike the other AEADs, the keystream depends on the tag, so this fails
+/// if `received_tag` is `None`. The returned tag must still be compared with
+/// `received_tag`.
+pub(super) fn open(
+    key: &Key,
+    nonce: Nonce,
+    aad: Aad<&[u8]>,
+    in_out: &mut [u8],
+    src: RangeFrom<usize>,
+    received_tag: Option<&Tag>,
+    cpu_features: cpu::Features,
+) -> Result<Tag, error::Unspecified> {
+    let received_tag = received_tag.ok_or(error::Unspecified)?;
+    let ciphertext_len = in_out
+        .len()
+        .checked_sub(src.start)
+        .ok_or(error::Unspecified)?;
+    check_lens(aad, ciphertext_len)?;
+    let (auth_key, enc_key) = key.derive_keys(nonce.as_ref(), cpu_features)?;
+
+    in_out.copy_within(src, 0);
+    let in_out = &mut in_out[..ciphertext_len];
+    apply_keystream(&enc_key, received_tag, in_out);
+    Ok(compute_tag(
+        &auth_key,
+        &enc_key,
+        nonce.as_ref(),
+        aad,
+        in_out,
+    ))
+}
+
+fn check_lens(aad: Aad<&[u8]>, in_out_len: usize) -> Result<(), error::Unspecified> {
+    if in_out_len > MAX_IN_OUT_LEN || u64_from_usize(aad.as_ref().len()) > MAX_LEN {
+        return Err(error::Unspecified);
+    }
+    Ok(())
+}
+
+/// Computes the tag over the plaintext `in_out`, as described in RFC 8452
+/// Section 4.
+fn compute_tag(
+    auth_key: &[u8; polyval::KEY_LEN],
+    enc_key: &aes::Key,
+    nonce: &[u8; NONCE_LEN],
+    aad: Aad<&[u8]>,
+    in_out: &[u8],
+) -> Tag {
+    let aad = aad.as_ref();
+
+    let mut ctx = Polyval::new(auth_key);
+    ctx.update(aad);
+    ctx.update(in_out);
+    let mut lengths = ZERO_BLOCK;
+    let (aad_bits, in_out_bits) = lengths.split_at_mut(BLOCK_LEN / 2);
+    // The lengths are at most 2^36 bytes, so the bit lengths can't overflow.
+    aad_bits.copy_from_slice(&(u64_from_usize(aad.len()) * 8).to_le_bytes());
+    in_out_bits.copy_from_slice(&(u64_from_usize(in_out.len()) * 8).to_le_bytes());
+    ctx.update(&lengths);
+
+    let mut s = ctx.finish();
+    s.iter_mut().zip(nonce.iter()).for_each(|(s, n)| *s ^= n);
+    s[BLOCK_LEN - 1] &= 0x7f;
+    Tag(enc_key.encrypt_block(s))
+}
+
+/// Encrypts or decrypts `in_out` in counter mode, with the counter blocks
+/// derived from `tag`.
+///
+/// The counter is the first 32 bits of the block, little-endian, so this
+/// can't use the `ctr32` functions, which use a big-endian counter at the
+/// end of the block. The counter wraps around modulo 2^32.
+fn apply_keystream(enc_key: &aes::Key, tag: &Tag, in_out: &mut [u8]) {
+    let mut counter_block: Block = tag.0;
+    counter_block[BLOCK_LEN - 1] |= 0x80;
+    let [c0, c1, c2, c3, ..] = counter_block;
+    let mut counter = u32::from_le_bytes([c0, c1, c2, c3]);
+    for chunk in in_out.chunks_mut(BLOCK_LEN) {
+        counter_block[..4].copy_from_slice(&counter.to_le_bytes());
+        let keystream = enc_key.encrypt_block(counter_block);
+        chunk
+            .iter_mut()
+            .zip(keystream.iter())
+            .for_each(|(b, k)| *b ^= k);
+        counter = counter.wrapping_add(1);
+    }
+}
@@ -0,0 +1,188 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! AES in XTS mode, as specified in IEEE 1619.
+
+use super::aes::{self, fallback, Block, BLOCK_LEN};
+use crate::{constant_time, cpu, error};
+
+/// An AES-XTS key for encrypting and decrypting the sectors (data units) of
+/// a storage device, as specified in IEEE 1619.
+///
+/// XTS is length-preserving, so it provides no integrity protection; an
+/// attacker who can modify the ciphertext can, for example, replace a block
+/// with an earlier version of that block, or corrupt it in a way that
+/// decrypts to garbage without being detected. It is meant for disk
+/// encryption, where there is no room to store a tag. Other applications
+/// should use `ring::aead`.
+///
+/// Decryption uses a constant-time software implementation of the AES inverse
+/// cipher, so it is much slower than encryption.
+#[derive(Clone)]
+pub struct XtsKey {
+    key1: aes::Key,
+    key1_decrypt: fallback::Key,
+    key2: aes::Key,
+}
+
+impl XtsKey {
+    /// Constructs an AES-XTS key from the data encryption key `key1` and the
+    /// tweak encryption key `key2`.
+    ///
+    /// The keys are AES-128 keys if they are 16 bytes long, and AES-256 keys
+    /// if they are 32 bytes long; otherwise, or if they have different
+    /// lengths, this fails. `key1` and `key2` should be generated
+    /// independently; IEEE 1619-2018 Section 5.1 requires them to differ, so
+    /// this fails if they are equal.
+    pub fn new(key1: &[u8], key2: &[u8]) -> Result<Self, error::Unspecified> {
+        if key1.len() != key2.len() {
+            return Err(error::Unspecified);
+        }
+        if constant_time::verify_slices_are_equal(key1, key2).is_ok() {
+            return Err(error::Unspecified);
+        }
+        let key1 = key_bytes(key1)?;
+        let cpu_features = cpu::features();
+        Ok(Self {
+            key1: aes::Key::new(key1, cpu_features)?,
+            key1_decrypt: fallback::Key::new(key1)?,
+            key2: aes::Key::new(key_bytes(key2)?, cpu_features)?,
+        })
+    }
+
+    /// Encrypts `data`, the contents of the sector with index
+    /// `sector_index`, in place.
+    ///
+    /// If `data` isn't a multiple of `XTS_BLOCK_LEN` bytes long, then
+    /// ciphertext stealing is used for the last, partial, block. Fails,
+    /// without modifying `data`, if `data` is shorter than `XTS_BLOCK_LEN`
+    /// bytes or longer than `XTS_MAX_SECTOR_LEN` bytes.
+    pub fn encrypt_sector(
+        &self,
+        sector_index: u128,
+        data: &mut [u8],
+    ) -> Result<(), error::Unspecified> {
+        let encrypt = |block| self.key1.encrypt_block(block);
+        let (mut tweak, last, stolen) = self.start_sector(sector_index, data, encrypt)?;
+
+        // IEEE 1619 Section 5.3.1: the last full block is encrypted with the
+        // previous tweak, and its ciphertext is split between the partial
+        // block and the last full block.
+        if let Some(last) = last {
+            let cc = xex(encrypt, tweak, block(last));
+            tweak = mul_alpha(tweak);
+            let mut pp = cc;
+            pp[..stolen.len()].copy_from_slice(stolen);
+            stolen.copy_from_slice(&cc[..stolen.len()]);
+            last.copy_from_slice(&xex(encrypt, tweak, pp));
+        }
+        Ok(())
+    }
+
+    /// Decrypts `data`, the contents of the sector with index
+    /// `sector_index`, in place.
+    ///
+    /// Fails, without modifying `data`, if `data` is shorter than
+    /// `XTS_BLOCK_LEN` bytes or longer than `XTS_MAX_SECTOR_LEN` bytes.
+    pub fn decrypt_sector(
+        &self,
+        sector_index: u128,
+        data: &mut [u8],
+    ) -> Result<(), error::Unspecified> {
+        let decrypt = |block| self.key1_decrypt.decrypt_block(block);
+        let (tweak, last, stolen) = self.start_sector(sector_index, data, decrypt)?;
+
+        // IEEE 1619 Section 5.4.1: the reverse of the ciphertext stealing in
+        // `encrypt_sector`, so the tweaks are used in the opposite order.
+        if let Some(last) = last {
+            let pp = xex(decrypt, mul_alpha(tweak), block(last));
+            let mut cc = pp;
+            cc[..stolen.len()].copy_from_slice(stolen);
+            stolen.copy_from_slice(&pp[..stolen.len()]);
+            last.copy_from_slice(&xex(decrypt, tweak, cc));
+        }
+        Ok(())
+    }
+
+    /// Processes every full block of `data` with `f`, except the last full
+    /// block when ciphertext stealing is needed. Returns the tweak for the
+    /// next block, that last full block, and the partial block after it.
+    fn start_sector<'d>(
+        &self,
+        sector_index: u128,
+        data: &'d mut [u8],
+        f: impl Fn(Block) -> Block + Copy,
+    ) -> Result<(Block, Option<&'d mut [u8]>, &'d mut [u8]), error::Unspecified> {
+        if data.len() < XTS_BLOCK_LEN || data.len() > XTS_MAX_SECTOR_LEN {
+            return Err(error::Unspecified);
+        }
+
+        let mut tweak = self.key2.encrypt_block(sector_index.to_le_bytes());
+
+        let (blocks, stolen) = data.split_at_mut(data.len() - (data.len() % BLOCK_LEN));
+        let mut blocks = blocks.chunks_exact_mut(BLOCK_LEN);
+        let last = if stolen.is_empty() {
+            None
+        } else {
+            blocks.next_back()
+        };
+        for b in blocks {
+            b.copy_from_slice(&xex(f, tweak, block(b)));
+            tweak = mul_alpha(tweak);
+        }
+        Ok((tweak, last, stolen))
+    }
+}
+
+impl core::fmt::Debug for XtsKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("XtsKey").finish()
+    }
+}
+
+/// The length of an AES-XTS block. Sectors must be at least this long.
+pub const XTS_BLOCK_LEN: usize = BLOCK_LEN;
+
+/// The maximum length of a sector: IEEE 1619 limits a data unit to 2^20
+/// blocks.
+pub const XTS_MAX_SECTOR_LEN: usize = (1 << 20) * XTS_BLOCK_LEN;
+
+fn key_bytes(key: &[u8]) -> Result<aes::KeyBytes<'_>, error::Unspecified> {
+    match key.len() {
+        aes::AES_128_KEY_LEN => Ok(aes::KeyBytes::AES_128(key.try_into()?)),
+        aes::AES_256_KEY_LEN => Ok(aes::KeyBytes::AES_256(key.try_into()?)),
+        _ => Err(error::Unspecified),
+    }
+}
+
+fn block(b: &[u8]) -> Block {
+    b.try_into().unwrap()
+}
+
+/// `f(block ^ tweak) ^ tweak`.
+fn xex(f: impl Fn(Block) -> Block, tweak: Block, block: Block) -> Block {
+    let x = f(core::array::from_fn(|i| block[i] ^ tweak[i]));
+    core::array::from_fn(|i| x[i] ^ tweak[i])
+}
+
+/// Multiplies `tweak` by the primitive element α in GF(2^128), with the
+/// bytes in little-endian order as IEEE 1619 Section 5.2 specifies.
+fn mul_alpha(tweak: Block) -> Block {
+    // The reduction by x^128 + x^7 + x^2 + x + 1 is applied when a bit
+    // shifts out, like `mulX_GHASH` in `polyval`, but in the opposite bit
+    // order.
+    let v = u128::from_le_bytes(tweak);
+    let carry = 0u128.wrapping_sub(v >> 127);
+    ((v << 1) ^ (carry & 0x87)).to_le_bytes()
+}
@@ -16,11 +16,14 @@ use crate::{constant_time, cpu, error, hkdf};
 use core::ops::RangeFrom;
 
 use super::{
-    aes, aes_gcm, chacha20_poly1305,
+    aes, aes_gcm,
     nonce::{Nonce, NONCE_LEN},
     Aad, KeyInner, Tag, TAG_LEN,
 };
 
+#[cfg(not(feature = "fips"))]
+use super::{aegis128l, aes_gcm_siv, chacha20_poly1305, xchacha20_poly1305};
+
 impl hkdf::KeyType for &'static Algorithm {
     #[inline]
     fn len(&self) -> usize {
@@ -34,21 +37,23 @@ pub struct Algorithm {
 
     seal: fn(
         key: &KeyInner,
-        nonce: Nonce,
+        nonce: &[u8],
         aad: Aad<&[u8]>,
         in_out: &mut [u8],
         cpu_features: cpu::Features,
     ) -> Result<Tag, error::Unspecified>,
     open: fn(
         key: &KeyInner,
-        nonce: Nonce,
+        nonce: &[u8],
         aad: Aad<&[u8]>,
         in_out: &mut [u8],
         src: RangeFrom<usize>,
+        received_tag: Option<&Tag>,
         cpu_features: cpu::Features,
     ) -> Result<Tag, error::Unspecified>,
 
     key_len: usize,
+    nonce_len: usize,
     id: AlgorithmID,
 }
 
@@ -67,10 +72,51 @@ impl Algorithm {
         TAG_LEN
     }
 
+    /// The number of bytes that sealing adds to the plaintext.
+    ///
+    /// This is the length of the tag, as the ciphertext itself is the same
+    /// length as the plaintext. It doesn't include the nonce or any other
+    /// framing that a protocol transmits along with the ciphertext.
+    #[inline(always)]
+    pub fn ciphertext_overhead(&self) -> usize {
+        self.tag_len()
+    }
+
     /// The length of the nonces.
+    ///
+    /// This is `NONCE_LEN` for every algorithm except `AEGIS_128L` and
+    /// `XCHACHA20_POLY1305`. Keys for an algorithm whose nonces are `N` bytes
+    /// long take `Nonce<N>`s; see `UnboundKey::new_of_len()`.
     #[inline(always)]
     pub fn nonce_len(&self) -> usize {
-        NONCE_LEN
+        self.nonce_len
+    }
+
+    /// The algorithm's parameters, for self-describing serialization formats.
+    pub fn params(&self) -> AlgorithmParams {
+        AlgorithmParams {
+            key_len: self.key_len(),
+            nonce_len: self.nonce_len(),
+            tag_len: self.tag_len(),
+            id: self.id.stable_id(),
+        }
+    }
+
+    /// The maximum length of the plaintext (ciphertext, excluding the tag).
+    pub(super) fn max_input_len(&self) -> usize {
+        match self.id {
+            AlgorithmID::AES_128_GCM | AlgorithmID::AES_256_GCM => aes_gcm::MAX_IN_OUT_LEN,
+            #[cfg(not(feature = "fips"))]
+            AlgorithmID::AES_128_GCM_SIV | AlgorithmID::AES_256_GCM_SIV => {
+                aes_gcm_siv::MAX_IN_OUT_LEN
+            }
+            #[cfg(not(feature = "fips"))]
+            AlgorithmID::CHACHA20_POLY1305 | AlgorithmID::XCHACHA20_POLY1305 => {
+                chacha20_poly1305::MAX_IN_OUT_LEN
+            }
+            #[cfg(not(feature = "fips"))]
+            AlgorithmID::AEGIS_128L => aegis128l::MAX_IN_OUT_LEN,
+        }
     }
 
     pub(super) fn new_key(
@@ -81,10 +127,10 @@ impl Algorithm {
         (self.init)(key_bytes, cpu_features)
     }
 
-    pub(super) fn open_within<'io>(
+    pub(super) fn open_within<'io, const L: usize>(
         &self,
         key: &KeyInner,
-        nonce: Nonce,
+        nonce: Nonce<L>,
         aad: Aad<&[u8]>,
         received_tag: Tag,
         in_out: &'io mut [u8],
@@ -93,7 +139,15 @@ impl Algorithm {
     ) -> Result<&'io mut [u8], error::Unspecified> {
         let ciphertext_len = in_out.get(src.clone()).ok_or(error::Unspecified)?.len();
 
-        let Tag(calculated_tag) = (self.open)(key, nonce, aad, in_out, src, cpu_features)?;
+        let Tag(calculated_tag) = (self.open)(
+            key,
+            nonce.as_ref(),
+            aad,
+            in_out,
+            src,
+            Some(&received_tag),
+            cpu_features,
+        )?;
 
         if constant_time::verify_slices_are_equal(calculated_tag.as_ref(), received_tag.as_ref())
             .is_err()
@@ -113,29 +167,38 @@ impl Algorithm {
     }
 
     #[inline]
-    pub(super) fn open(
+    pub(super) fn open<const L: usize>(
         &self,
         key: &KeyInner,
-        nonce: Nonce,
+        nonce: Nonce<L>,
         aad: Aad<&[u8]>,
         in_out: &mut [u8],
         src: RangeFrom<usize>,
+        received_tag: Option<&Tag>,
         cpu_features: cpu::Features,
     ) -> Result<Tag, error::Unspecified> {
-        let calculated_tag = (self.open)(key, nonce, aad, in_out, src, cpu_features)?;
+        let calculated_tag = (self.open)(
+            key,
+            nonce.as_ref(),
+            aad,
+            in_out,
+            src,
+            received_tag,
+            cpu_features,
+        )?;
         Ok(calculated_tag)
     }
 
     #[inline]
-    pub(super) fn seal(
+    pub(super) fn seal<const L: usize>(
         &self,
         key: &KeyInner,
-        nonce: Nonce,
+        nonce: Nonce<L>,
         aad: Aad<&[u8]>,
         in_out: &mut [u8],
         cpu_features: cpu::Features,
     ) -> Result<Tag, error::Unspecified> {
-        (self.seal)(key, nonce, aad, in_out, cpu_features)
+        (self.seal)(key, nonce.as_ref(), aad, in_out, cpu_features)
     }
 }
 
@@ -145,7 +208,111 @@ derive_debug_via_id!(Algorithm);
 pub(super) enum AlgorithmID {
     AES_128_GCM,
     AES_256_GCM,
+    #[cfg(not(feature = "fips"))]
+    AES_128_GCM_SIV,
+    #[cfg(not(feature = "fips"))]
+    AES_256_GCM_SIV,
+    #[cfg(not(feature = "fips"))]
     CHACHA20_POLY1305,
+    #[cfg(not(feature = "fips"))]
+    XCHACHA20_POLY1305,
+    #[cfg(not(feature = "fips"))]
+    AEGIS_128L,
+}
+
+impl AlgorithmID {
+    /// The algorithm's numeric identifier in the IANA "AEAD Algorithms"
+    /// registry established by [RFC 5116 Section 6].
+    ///
+    /// XChaCha20-Poly1305 isn't registered, so it uses an identifier from the
+    /// registry's Private Use range, 32768-65535.
+    ///
+    /// [RFC 5116 Section 6]: https://tools.ietf.org/html/rfc5116#section-6
+    fn stable_id(&self) -> u16 {
+        match self {
+            AlgorithmID::AES_128_GCM => 1,
+            AlgorithmID::AES_256_GCM => 2,
+            #[cfg(not(feature = "fips"))]
+            AlgorithmID::AES_128_GCM_SIV => 30,
+            #[cfg(not(feature = "fips"))]
+            AlgorithmID::AES_256_GCM_SIV => 31,
+            #[cfg(not(feature = "fips"))]
+            AlgorithmID::CHACHA20_POLY1305 => 29,
+            #[cfg(not(feature = "fips"))]
+            AlgorithmID::XCHACHA20_POLY1305 => 32768,
+            #[cfg(not(feature = "fips"))]
+            AlgorithmID::AEGIS_128L => 32,
+        }
+    }
+}
+
+/// The parameters of an AEAD algorithm, as returned by `Algorithm::params()`.
+///
+/// This is meant for self-describing formats, such as encrypted file
+/// headers, that record which algorithm was used so that readers can find it
+/// again with `algorithm_by_id()`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct AlgorithmParams {
+    key_len: usize,
+    nonce_len: usize,
+    tag_len: usize,
+    id: u16,
+}
+
+impl AlgorithmParams {
+    /// The length of the key.
+    #[inline]
+    pub fn key_len(&self) -> usize {
+        self.key_len
+    }
+
+    /// The length of the nonces.
+    #[inline]
+    pub fn nonce_len(&self) -> usize {
+        self.nonce_len
+    }
+
+    /// The length of a tag.
+    #[inline]
+    pub fn tag_len(&self) -> usize {
+        self.tag_len
+    }
+
+    /// The algorithm's numeric identifier.
+    ///
+    /// The identifier is the algorithm's number in the IANA "AEAD Algorithms"
+    /// registry of [RFC 5116 Section 6], so it never changes and can be
+    /// stored.
+    ///
+    /// [RFC 5116 Section 6]: https://tools.ietf.org/html/rfc5116#section-6
+    #[inline]
+    pub fn id(&self) -> u16 {
+        self.id
+    }
+}
+
+/// Finds the algorithm whose `params().id()` is `id`.
+///
+/// Returns `None` if `id` doesn't identify an algorithm that *ring*
+/// supports. When the `fips` feature is enabled, the algorithms that aren't
+/// approved aren't supported.
+pub fn algorithm_by_id(id: u16) -> Option<&'static Algorithm> {
+    [
+        &AES_128_GCM,
+        &AES_256_GCM,
+        #[cfg(not(feature = "fips"))]
+        &AES_128_GCM_SIV,
+        #[cfg(not(feature = "fips"))]
+        &AES_256_GCM_SIV,
+        #[cfg(not(feature = "fips"))]
+        &CHACHA20_POLY1305,
+        #[cfg(not(feature = "fips"))]
+        &XCHACHA20_POLY1305,
+        #[cfg(not(feature = "fips"))]
+        &AEGIS_128L,
+    ]
+    .into_iter()
+    .find(|algorithm| algorithm.id.stable_id() == id)
 }
 
 impl PartialEq for Algorithm {
@@ -159,6 +326,7 @@ impl Eq for Algorithm {}
 /// AES-128 in GCM mode with 128-bit tags and 96 bit nonces.
 pub static AES_128_GCM: Algorithm = Algorithm {
     key_len: aes::AES_128_KEY_LEN,
+    nonce_len: NONCE_LEN,
     init: aes_gcm_init_128,
     seal: aes_gcm_seal,
     open: aes_gcm_open,
@@ -168,6 +336,7 @@ pub static AES_128_GCM: Algorithm = Algorithm {
 /// AES-256 in GCM mode with 128-bit tags and 96 bit nonces.
 pub static AES_256_GCM: Algorithm = Algorithm {
     key_len: aes::AES_256_KEY_LEN,
+    nonce_len: NONCE_LEN,
     init: aes_gcm_init_256,
     seal: aes_gcm_seal,
     open: aes_gcm_open,
@@ -196,42 +365,144 @@ fn aes_gcm_init_256(
     )?))
 }
 
+#[cfg_attr(feature = "fips", allow(clippy::infallible_destructuring_match))]
 fn aes_gcm_seal(
     key: &KeyInner,
-    nonce: Nonce,
+    nonce: &[u8],
     aad: Aad<&[u8]>,
     in_out: &mut [u8],
     _cpu_features: cpu::Features,
 ) -> Result<Tag, error::Unspecified> {
     let key = match key {
         KeyInner::AesGcm(key) => key,
+        #[cfg(not(feature = "fips"))]
         _ => unreachable!(),
     };
+    let nonce = Nonce::try_assume_unique_for_key(nonce)?;
     aes_gcm::seal(key, nonce, aad, in_out)
 }
 
+#[cfg_attr(feature = "fips", allow(clippy::infallible_destructuring_match))]
 pub(super) fn aes_gcm_open(
     key: &KeyInner,
-    nonce: Nonce,
+    nonce: &[u8],
     aad: Aad<&[u8]>,
     in_out: &mut [u8],
     src: RangeFrom<usize>,
+    _received_tag: Option<&Tag>,
     _cpu_features: cpu::Features,
 ) -> Result<Tag, error::Unspecified> {
     let key = match key {
         KeyInner::AesGcm(key) => key,
+        #[cfg(not(feature = "fips"))]
         _ => unreachable!(),
     };
+    let nonce = Nonce::try_assume_unique_for_key(nonce)?;
     aes_gcm::open(key, nonce, aad, in_out, src)
 }
 
+/// AES-128 in GCM-SIV mode with 128-bit tags and 96 bit nonces, as described
+/// in [RFC 8452].
+///
+/// Unlike AES-GCM, reusing a nonce only reveals whether the same plaintext
+/// was sealed twice with the same nonce and AAD; it doesn't reveal anything
+/// else about the plaintexts or allow forgeries. Nonces should still be
+/// unique whenever possible.
+///
+/// [RFC 8452]: https://tools.ietf.org/html/rfc8452
+#[cfg(not(feature = "fips"))]
+pub static AES_128_GCM_SIV: Algorithm = Algorithm {
+    key_len: aes::AES_128_KEY_LEN,
+    nonce_len: NONCE_LEN,
+    init: aes_gcm_siv_init_128,
+    seal: aes_gcm_siv_seal,
+    open: aes_gcm_siv_open,
+    id: AlgorithmID::AES_128_GCM_SIV,
+};
+
+/// AES-256 in GCM-SIV mode with 128-bit tags and 96 bit nonces, as described
+/// in [RFC 8452].
+///
+/// See `AES_128_GCM_SIV` regarding nonce reuse.
+///
+/// [RFC 8452]: https://tools.ietf.org/html/rfc8452
+#[cfg(not(feature = "fips"))]
+pub static AES_256_GCM_SIV: Algorithm = Algorithm {
+    key_len: aes::AES_256_KEY_LEN,
+    nonce_len: NONCE_LEN,
+    init: aes_gcm_siv_init_256,
+    seal: aes_gcm_siv_seal,
+    open: aes_gcm_siv_open,
+    id: AlgorithmID::AES_256_GCM_SIV,
+};
+
+#[cfg(not(feature = "fips"))]
+fn aes_gcm_siv_init_128(
+    key: &[u8],
+    cpu_features: cpu::Features,
+) -> Result<KeyInner, error::Unspecified> {
+    let key = key.try_into().map_err(|_| error::Unspecified)?;
+    Ok(KeyInner::AesGcmSiv(aes_gcm_siv::Key::new(
+        aes::KeyBytes::AES_128(key),
+        cpu_features,
+    )?))
+}
+
+#[cfg(not(feature = "fips"))]
+fn aes_gcm_siv_init_256(
+    key: &[u8],
+    cpu_features: cpu::Features,
+) -> Result<KeyInner, error::Unspecified> {
+    let key = key.try_into().map_err(|_| error::Unspecified)?;
+    Ok(KeyInner::AesGcmSiv(aes_gcm_siv::Key::new(
+        aes::KeyBytes::AES_256(key),
+        cpu_features,
+    )?))
+}
+
+#[cfg(not(feature = "fips"))]
+fn aes_gcm_siv_seal(
+    key: &KeyInner,
+    nonce: &[u8],
+    aad: Aad<&[u8]>,
+    in_out: &mut [u8],
+    cpu_features: cpu::Features,
+) -> Result<Tag, error::Unspecified> {
+    let key = match key {
+        KeyInner::AesGcmSiv(key) => key,
+        _ => unreachable!(),
+    };
+    let nonce = Nonce::try_assume_unique_for_key(nonce)?;
+    aes_gcm_siv::seal(key, nonce, aad, in_out, cpu_features)
+}
+
+#[cfg(not(feature = "fips"))]
+fn aes_gcm_siv_open(
+    key: &KeyInner,
+    nonce: &[u8],
+    aad: Aad<&[u8]>,
+    in_out: &mut [u8],
+    src: RangeFrom<usize>,
+    received_tag: Option<&Tag>,
+    cpu_features: cpu::Features,
+) -> Result<Tag, error::Unspecified> {
+    let key = match key {
+        KeyInner::AesGcmSiv(key) => key,
+        _ => unreachable!(),
+    };
+    let nonce = Nonce::try_assume_unique_for_key(nonce)?;
+    aes_gcm_siv::open(key, nonce, aad, in_out, src, received_tag, cpu_features)
+}
+
 /// ChaCha20-Poly1305 as described in [RFC 8439].
 ///
 /// The keys are 256 bits long and the nonces are 96 bits long.
 ///
 /// [RFC 8439]: https://tools.ietf.org/html/rfc8439
+#[cfg(not(feature = "fips"))]
 pub static CHACHA20_POLY1305: Algorithm = Algorithm {
     key_len: chacha20_poly1305::KEY_LEN,
+    nonce_len: NONCE_LEN,
     init: chacha20_poly1305_init,
     seal: chacha20_poly1305_seal,
     open: chacha20_poly1305_open,
@@ -239,6 +510,7 @@ pub static CHACHA20_POLY1305: Algorithm = Algorithm {
 };
 
 /// Copies |key| into |ctx_buf|.
+#[cfg(not(feature = "fips"))]
 fn chacha20_poly1305_init(
     key: &[u8],
     _cpu_features: cpu::Features,
@@ -247,9 +519,10 @@ fn chacha20_poly1305_init(
     Ok(KeyInner::ChaCha20Poly1305(chacha20_poly1305::Key::new(key)))
 }
 
+#[cfg(not(feature = "fips"))]
 fn chacha20_poly1305_seal(
     key: &KeyInner,
-    nonce: Nonce,
+    nonce: &[u8],
     aad: Aad<&[u8]>,
     in_out: &mut [u8],
     cpu_features: cpu::Features,
@@ -258,20 +531,140 @@ fn chacha20_poly1305_seal(
         KeyInner::ChaCha20Poly1305(key) => key,
         _ => unreachable!(),
     };
+    let nonce = Nonce::try_assume_unique_for_key(nonce)?;
     chacha20_poly1305::seal(key, nonce, aad, in_out, cpu_features)
 }
 
+#[cfg(not(feature = "fips"))]
 fn chacha20_poly1305_open(
     key: &KeyInner,
-    nonce: Nonce,
+    nonce: &[u8],
     aad: Aad<&[u8]>,
     in_out: &mut [u8],
     src: RangeFrom<usize>,
+    _received_tag: Option<&Tag>,
     cpu_features: cpu::Features,
 ) -> Result<Tag, error::Unspecified> {
     let key = match key {
         KeyInner::ChaCha20Poly1305(key) => key,
         _ => unreachable!(),
     };
+    let nonce = Nonce::try_assume_unique_for_key(nonce)?;
     chacha20_poly1305::open(key, nonce, aad, in_out, src, cpu_features)
 }
+
+/// XChaCha20-Poly1305 as described in [draft-irtf-cfrg-xchacha].
+///
+/// The keys are 256 bits long and the nonces are 192 bits long, which is
+/// long enough that nonces can be chosen at random for every message without
+/// a meaningful risk of reuse. Keys are constructed with
+/// `UnboundKey::<{ xchacha20_poly1305::NONCE_LEN }>::new_of_len()`, and take
+/// `Nonce<{ xchacha20_poly1305::NONCE_LEN }>`s.
+///
+/// [draft-irtf-cfrg-xchacha]: https://tools.ietf.org/html/draft-irtf-cfrg-xchacha-03
+#[cfg(not(feature = "fips"))]
+pub static XCHACHA20_POLY1305: Algorithm = Algorithm {
+    key_len: chacha20_poly1305::KEY_LEN,
+    nonce_len: xchacha20_poly1305::NONCE_LEN,
+    init: chacha20_poly1305_init,
+    seal: xchacha20_poly1305_seal,
+    open: xchacha20_poly1305_open,
+    id: AlgorithmID::XCHACHA20_POLY1305,
+};
+
+#[cfg(not(feature = "fips"))]
+fn xchacha20_poly1305_seal(
+    key: &KeyInner,
+    nonce: &[u8],
+    aad: Aad<&[u8]>,
+    in_out: &mut [u8],
+    cpu_features: cpu::Features,
+) -> Result<Tag, error::Unspecified> {
+    let key = match key {
+        KeyInner::ChaCha20Poly1305(key) => key,
+        _ => unreachable!(),
+    };
+    let nonce = Nonce::try_assume_unique_for_key_of_len(nonce)?;
+    xchacha20_poly1305::seal(key, nonce, aad, in_out, cpu_features)
+}
+
+#[cfg(not(feature = "fips"))]
+fn xchacha20_poly1305_open(
+    key: &KeyInner,
+    nonce: &[u8],
+    aad: Aad<&[u8]>,
+    in_out: &mut [u8],
+    src: RangeFrom<usize>,
+    _received_tag: Option<&Tag>,
+    cpu_features: cpu::Features,
+) -> Result<Tag, error::Unspecified> {
+    let key = match key {
+        KeyInner::ChaCha20Poly1305(key) => key,
+        _ => unreachable!(),
+    };
+    let nonce = Nonce::try_assume_unique_for_key_of_len(nonce)?;
+    xchacha20_poly1305::open(key, nonce, aad, in_out, src, cpu_features)
+}
+
+/// AEGIS-128L with 128-bit tags, as described in
+/// [draft-irtf-cfrg-aegis-aead].
+///
+/// AEGIS-128L is built on the AES round function, and is much faster than
+/// AES-GCM on processors with AES instructions. Without them it uses a
+/// constant-time software implementation of the AES round, which is much
+/// slower.
+///
+/// The keys and the nonces are 128 bits long. Keys are constructed with
+/// `UnboundKey::<{ aegis128l::NONCE_LEN }>::new_of_len()`, and take
+/// `Nonce<{ aegis128l::NONCE_LEN }>`s.
+///
+/// [draft-irtf-cfrg-aegis-aead]: https://datatracker.ietf.org/doc/draft-irtf-cfrg-aegis-aead/
+#[cfg(not(feature = "fips"))]
+pub static AEGIS_128L: Algorithm = Algorithm {
+    key_len: aegis128l::KEY_LEN,
+    nonce_len: aegis128l::NONCE_LEN,
+    init: aegis128l_init,
+    seal: aegis128l_seal,
+    open: aegis128l_open,
+    id: AlgorithmID::AEGIS_128L,
+};
+
+#[cfg(not(feature = "fips"))]
+fn aegis128l_init(key: &[u8], cpu_features: cpu::Features) -> Result<KeyInner, error::Unspecified> {
+    let key: [u8; aegis128l::KEY_LEN] = key.try_into()?;
+    Ok(KeyInner::Aegis128L(aegis128l::Key::new(key, cpu_features)))
+}
+
+#[cfg(not(feature = "fips"))]
+fn aegis128l_seal(
+    key: &KeyInner,
+    nonce: &[u8],
+    aad: Aad<&[u8]>,
+    in_out: &mut [u8],
+    _cpu_features: cpu::Features,
+) -> Result<Tag, error::Unspecified> {
+    let key = match key {
+        KeyInner::Aegis128L(key) => key,
+        _ => unreachable!(),
+    };
+    let nonce = Nonce::try_assume_unique_for_key_of_len(nonce)?;
+    aegis128l::seal(key, nonce, aad, in_out)
+}
+
+#[cfg(not(feature = "fips"))]
+fn aegis128l_open(
+    key: &KeyInner,
+    nonce: &[u8],
+    aad: Aad<&[u8]>,
+    in_out: &mut [u8],
+    src: RangeFrom<usize>,
+    _received_tag: Option<&Tag>,
+    _cpu_features: cpu::Features,
+) -> Result<Tag, error::Unspecified> {
+    let key = match key {
+        KeyInner::Aegis128L(key) => key,
+        _ => unreachable!(),
+    };
+    let nonce = Nonce::try_assume_unique_for_key_of_len(nonce)?;
+    aegis128l::open(key, nonce, aad, in_out, src)
+}
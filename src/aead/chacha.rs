@@ -15,18 +15,9 @@
 
 use super::{quic::Sample, Nonce};
 
-#[cfg(any(
-    test,
-    not(any(
-        target_arch = "aarch64",
-        target_arch = "arm",
-        target_arch = "x86",
-        target_arch = "x86_64"
-    ))
-))]
 mod fallback;
 
-use crate::polyfill::ArraySplitMap;
+use crate::{constant_time, polyfill::ArraySplitMap};
 use core::ops::RangeFrom;
 
 #[derive(Clone)]
@@ -42,6 +33,12 @@ impl Key {
     }
 }
 
+impl Drop for Key {
+    fn drop(&mut self) {
+        constant_time::secure_overwrite(&mut self.words, 0);
+    }
+}
+
 impl Key {
     #[inline]
     pub fn encrypt_in_place(&self, counter: Counter, in_out: &mut [u8]) {
@@ -183,6 +180,20 @@ impl Iv {
 
 pub const KEY_LEN: usize = 32;
 
+pub(crate) const HCHACHA20_NONCE_LEN: usize = 16;
+
+/// HChaCha20, as specified in draft-irtf-cfrg-xchacha Section 2.2.
+pub(crate) fn hchacha20(key: [u8; KEY_LEN], nonce: [u8; HCHACHA20_NONCE_LEN]) -> [u8; KEY_LEN] {
+    Key::new(key).hchacha20(nonce)
+}
+
+impl Key {
+    /// HChaCha20 with this key.
+    pub(super) fn hchacha20(&self, nonce: [u8; HCHACHA20_NONCE_LEN]) -> [u8; KEY_LEN] {
+        fallback::hchacha20(self, nonce)
+    }
+}
+
 const BLOCK_LEN: usize = 64;
 
 #[cfg(test)]
@@ -201,6 +212,12 @@ mod tests {
             (0, 0)
         };
 
+    #[test]
+    fn chacha20_key_zeroed_on_drop() {
+        let key = Key::new([0xa5; KEY_LEN]);
+        assert!(constant_time::is_zeroed_after_drop(key));
+    }
+
     #[test]
     fn chacha20_test_default() {
         // Always use `MAX_OFFSET` if we hav assembly code.
@@ -15,22 +15,42 @@
 // Adapted from the public domain, estream code by D. Bernstein.
 // Adapted from the BoringSSL crypto/chacha/chacha.c.
 
-use super::{Counter, Key, BLOCK_LEN};
-use core::ops::RangeFrom;
+use super::{Key, BLOCK_LEN, HCHACHA20_NONCE_LEN, KEY_LEN};
+use crate::polyfill::ArraySplitMap;
 
+#[cfg(any(
+    test,
+    not(any(
+        target_arch = "aarch64",
+        target_arch = "arm",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))
+))]
+use {super::Counter, core::ops::RangeFrom};
+
+const SIGMA: [u32; 4] = [
+    u32::from_le_bytes(*b"expa"),
+    u32::from_le_bytes(*b"nd 3"),
+    u32::from_le_bytes(*b"2-by"),
+    u32::from_le_bytes(*b"te k"),
+];
+
+#[cfg(any(
+    test,
+    not(any(
+        target_arch = "aarch64",
+        target_arch = "arm",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))
+))]
 pub(super) fn ChaCha20_ctr32(
     key: &Key,
     counter: Counter,
     in_out: &mut [u8],
     src: RangeFrom<usize>,
 ) {
-    const SIGMA: [u32; 4] = [
-        u32::from_le_bytes(*b"expa"),
-        u32::from_le_bytes(*b"nd 3"),
-        u32::from_le_bytes(*b"2-by"),
-        u32::from_le_bytes(*b"te k"),
-    ];
-
     let key = key.words_less_safe();
     let counter = counter.into_words_less_safe();
 
@@ -61,21 +81,41 @@ pub(super) fn ChaCha20_ctr32(
     }
 }
 
+// HChaCha20 is only used for key derivation, so there is no assembly
+// implementation of it.
+pub(super) fn hchacha20(key: &Key, nonce: [u8; HCHACHA20_NONCE_LEN]) -> [u8; KEY_LEN] {
+    let key = key.words_less_safe();
+    let nonce: [u32; 4] = nonce.array_split_map(u32::from_le_bytes);
+
+    let mut x = [
+        SIGMA[0], SIGMA[1], SIGMA[2], SIGMA[3], key[0], key[1], key[2], key[3], key[4], key[5],
+        key[6], key[7], nonce[0], nonce[1], nonce[2], nonce[3],
+    ];
+    rounds(&mut x);
+
+    // Unlike the ChaCha20 block function, the input isn't added back in.
+    let mut output = [0u8; KEY_LEN];
+    output
+        .chunks_exact_mut(core::mem::size_of::<u32>())
+        .zip(x[..4].iter().chain(x[12..].iter()))
+        .for_each(|(output, &x)| output.copy_from_slice(&x.to_le_bytes()));
+    output
+}
+
 // Performs 20 rounds of ChaCha on `input`, storing the result in `output`.
+#[cfg(any(
+    test,
+    not(any(
+        target_arch = "aarch64",
+        target_arch = "arm",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))
+))]
 #[inline(always)]
 fn chacha_core(output: &mut [u8; BLOCK_LEN], input: &State) {
     let mut x = *input;
-
-    for _ in (0..20).step_by(2) {
-        quarterround(&mut x, 0, 4, 8, 12);
-        quarterround(&mut x, 1, 5, 9, 13);
-        quarterround(&mut x, 2, 6, 10, 14);
-        quarterround(&mut x, 3, 7, 11, 15);
-        quarterround(&mut x, 0, 5, 10, 15);
-        quarterround(&mut x, 1, 6, 11, 12);
-        quarterround(&mut x, 2, 7, 8, 13);
-        quarterround(&mut x, 3, 4, 9, 14);
-    }
+    rounds(&mut x);
 
     for (x, input) in x.iter_mut().zip(input.iter()) {
         *x = x.wrapping_add(*input);
@@ -87,6 +127,20 @@ fn chacha_core(output: &mut [u8; BLOCK_LEN], input: &State) {
         .for_each(|(output, &x)| output.copy_from_slice(&x.to_le_bytes()));
 }
 
+#[inline(always)]
+fn rounds(x: &mut State) {
+    for _ in (0..20).step_by(2) {
+        quarterround(x, 0, 4, 8, 12);
+        quarterround(x, 1, 5, 9, 13);
+        quarterround(x, 2, 6, 10, 14);
+        quarterround(x, 3, 7, 11, 15);
+        quarterround(x, 0, 5, 10, 15);
+        quarterround(x, 1, 6, 11, 12);
+        quarterround(x, 2, 7, 8, 13);
+        quarterround(x, 3, 4, 9, 14);
+    }
+}
+
 #[inline(always)]
 fn quarterround(x: &mut State, a: usize, b: usize, c: usize, d: usize) {
     #[inline(always)]
@@ -17,14 +17,14 @@ use super::{
     poly1305, Aad, Nonce, Tag,
 };
 use crate::{
-    cpu, error,
+    constant_time, cpu, error,
     polyfill::{u64_from_usize, usize_from_u64_saturated},
 };
 use core::ops::RangeFrom;
 
 pub(super) const KEY_LEN: usize = chacha::KEY_LEN;
 
-const MAX_IN_OUT_LEN: usize = super::max_input_len(64, 1);
+pub(super) const MAX_IN_OUT_LEN: usize = super::max_input_len(64, 1);
 // https://tools.ietf.org/html/rfc8439#section-2.8
 const _MAX_IN_OUT_LEN_BOUNDED_BY_RFC: () =
     assert!(MAX_IN_OUT_LEN == usize_from_u64_saturated(274_877_906_880u64));
@@ -36,6 +36,15 @@ impl Key {
     pub(super) fn new(value: [u8; KEY_LEN]) -> Self {
         Self(chacha::Key::new(value))
     }
+
+    /// The key HChaCha20(self, `nonce`), as used by XChaCha20-Poly1305.
+    pub(super) fn hchacha20(&self, nonce: [u8; chacha::HCHACHA20_NONCE_LEN]) -> Self {
+        let Self(key) = self;
+        let mut subkey = key.hchacha20(nonce);
+        let r = Self::new(subkey);
+        constant_time::secure_zero(&mut subkey);
+        r
+    }
 }
 
 pub(super) fn seal(
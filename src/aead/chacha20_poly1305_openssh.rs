@@ -32,9 +32,9 @@
 use super::{
     chacha::{self, *},
     chacha20_poly1305::derive_poly1305_key,
-    cpu, poly1305, Nonce, Tag,
+    poly1305, Nonce, Tag,
 };
-use crate::{constant_time, error};
+use crate::{constant_time, cpu, error};
 
 /// A key for sealing packets.
 pub struct SealingKey {
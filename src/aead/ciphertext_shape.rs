@@ -0,0 +1,67 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+use super::Algorithm;
+
+/// What the length of a sealed message says about how its tag is stored.
+///
+/// This is a debugging aid for "opening fails" problems that are caused by
+/// the sender and recipient disagreeing about the format of sealed messages.
+/// A sealed message's length is the only thing that can be checked without
+/// the key, so this can only tell whether the message is too short to contain
+/// a tag. In particular, whether a tag is appended to the ciphertext or
+/// prepended to it can't be determined.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CiphertextShape {
+    tagged_plaintext_len: Option<usize>,
+    detached: bool,
+}
+
+impl CiphertextShape {
+    /// Whether the message is long enough to contain the ciphertext and the
+    /// tag, as produced by `seal_in_place_append_tag`.
+    #[inline]
+    pub fn is_long_enough_for_tag(&self) -> bool {
+        self.tagged_plaintext_len.is_some()
+    }
+
+    /// Whether the message could be just the ciphertext, with the tag stored
+    /// separately, as produced by `seal_in_place_separate_tag`.
+    #[inline]
+    pub fn is_consistent_with_detached_tag(&self) -> bool {
+        self.detached
+    }
+
+    /// The length of the plaintext if the message contains the tag.
+    #[inline]
+    pub fn tagged_plaintext_len(&self) -> Option<usize> {
+        self.tagged_plaintext_len
+    }
+}
+
+/// Reports whether the length of `sealed`, a message sealed with `algorithm`,
+/// is consistent with it containing a tag, or only a detached ciphertext.
+///
+/// This doesn't do any cryptographic checks, so a message whose length is
+/// consistent with a format may still fail to open.
+pub fn analyze_ciphertext(algorithm: &'static Algorithm, sealed: &[u8]) -> CiphertextShape {
+    let max_input_len = algorithm.max_input_len();
+    CiphertextShape {
+        tagged_plaintext_len: sealed
+            .len()
+            .checked_sub(algorithm.tag_len())
+            .filter(|&len| len <= max_input_len),
+        detached: sealed.len() <= max_input_len,
+    }
+}
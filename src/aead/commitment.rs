@@ -0,0 +1,51 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+use super::Nonce;
+use crate::hmac;
+
+/// The length of a key commitment.
+pub const COMMITMENT_LEN: usize = 32;
+
+/// Computes a commitment to the AEAD key `key_bytes` for use with `nonce`.
+///
+/// None of the AEADs in this module are key-committing: a ciphertext can be
+/// constructed that opens successfully under more than one key. A recipient
+/// that stores the commitment alongside the ciphertext, and checks that it
+/// matches the commitment it computes with its own key before (or after)
+/// opening, will reject such ciphertexts. The commitment doesn't change the
+/// format of the ciphertext.
+///
+/// The commitment is HMAC-SHA256 keyed with `key_bytes` over a fixed label,
+/// the length of `key_bytes`, and `nonce`. The comparison of commitments
+/// should be done with `constant_time::verify_slices_are_equal`.
+pub fn compute_commitment(key_bytes: &[u8], nonce: &Nonce) -> [u8; COMMITMENT_LEN] {
+    // HMAC pads short keys with zeros, so the key length is included in the
+    // input to distinguish e.g. a 16-byte key from the same key followed by
+    // 16 zero bytes.
+    let key_len = u8::try_from(key_bytes.len()).unwrap_or(u8::MAX);
+
+    let key = hmac::Key::new(hmac::HMAC_SHA256, key_bytes);
+    let mut ctx = hmac::Context::with_key(&key);
+    ctx.update(COMMITMENT_LABEL);
+    ctx.update(&[key_len]);
+    ctx.update(nonce.as_ref());
+    let tag = ctx.sign();
+
+    let mut commitment = [0u8; COMMITMENT_LEN];
+    commitment.copy_from_slice(tag.as_ref());
+    commitment
+}
+
+const COMMITMENT_LABEL: &[u8] = b"ring aead key commitment";
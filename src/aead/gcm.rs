@@ -16,18 +16,26 @@ use self::ffi::{Block, BLOCK_LEN, ZERO_BLOCK};
 use super::{aes_gcm, Aad};
 use crate::{
     bits::{BitLength, FromByteLen as _},
-    error,
+    cpu, error,
     polyfill::{sliceutil::overwrite_at_start, NotSend},
 };
 use cfg_if::cfg_if;
 
-pub(super) use ffi::KeyValue;
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "arm",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+use cpu::GetFeature as _;
+
+pub(super) use ffi::{KeyValue, Xi};
 
 cfg_if! {
     if #[cfg(any(target_arch = "aarch64", target_arch = "x86_64"))] {
-        pub(super) use self::ffi::{HTable, Xi};
+        pub(super) use self::ffi::HTable;
     } else {
-        use self::ffi::{HTable, Xi};
+        use self::ffi::HTable;
     }
 }
 
@@ -44,6 +52,12 @@ pub(super) struct Context<'key, K> {
     key: &'key K,
     aad_len: BitLength<u64>,
     in_out_len: BitLength<u64>,
+
+    // The AAD that doesn't fill a whole block yet; see `update_aad`.
+    aad_partial: Block,
+    aad_partial_len: usize,
+    aad_finished: bool,
+
     _not_send: NotSend,
 }
 
@@ -58,27 +72,125 @@ impl<'key, K: Gmult> Context<'key, K> {
             return Err(error::Unspecified);
         }
         let in_out_len = BitLength::from_byte_len(in_out_len)?;
-        let aad_len = BitLength::from_byte_len(aad.as_ref().len())?;
-
-        // NIST SP800-38D Section 5.2.1.1 says that the maximum AAD length is
-        // 2**64 - 1 bits, i.e. BitLength<u64>::MAX, so we don't need to do an
-        // explicit check here.
 
         let mut ctx = Self {
             Xi: Xi(ZERO_BLOCK),
             key,
-            aad_len,
+            aad_len: BitLength::from_bits(0),
             in_out_len,
+            aad_partial: ZERO_BLOCK,
+            aad_partial_len: 0,
+            aad_finished: false,
             _not_send: NotSend::VALUE,
         };
+        ctx.update_aad(aad.0)?;
 
-        for ad in aad.0.chunks(BLOCK_LEN) {
-            let mut block = ZERO_BLOCK;
-            overwrite_at_start(&mut block, ad);
-            ctx.update_block(block);
+        Ok(ctx)
+    }
+
+    /// Appends `aad` to the AAD.
+    ///
+    /// The AAD may be split across calls arbitrarily; only the end of the
+    /// whole AAD is padded to a block boundary. Fails once any of the input
+    /// has been processed, or if the total AAD length would overflow.
+    pub(super) fn update_aad(&mut self, mut aad: &[u8]) -> Result<(), error::Unspecified> {
+        if self.aad_finished {
+            return Err(error::Unspecified);
         }
 
-        Ok(ctx)
+        // NIST SP800-38D Section 5.2.1.1 says that the maximum AAD length is
+        // 2**64 - 1 bits, i.e. BitLength<u64>::MAX.
+        let len = BitLength::<u64>::from_byte_len(aad.len())?;
+        self.aad_len = BitLength::from_bits(
+            self.aad_len
+                .as_bits()
+                .checked_add(len.as_bits())
+                .ok_or(error::Unspecified)?,
+        );
+
+        if self.aad_partial_len > 0 {
+            let (head, rest) =
+                aad.split_at(core::cmp::min(BLOCK_LEN - self.aad_partial_len, aad.len()));
+            self.aad_partial[self.aad_partial_len..][..head.len()].copy_from_slice(head);
+            self.aad_partial_len += head.len();
+            aad = rest;
+            if self.aad_partial_len < BLOCK_LEN {
+                return Ok(());
+            }
+            self.ghash_block(self.aad_partial);
+            self.aad_partial_len = 0;
+        }
+
+        let mut blocks = aad.chunks_exact(BLOCK_LEN);
+        for block in &mut blocks {
+            self.ghash_block(block.try_into()?);
+        }
+        let remainder = blocks.remainder();
+        self.aad_partial = ZERO_BLOCK;
+        overwrite_at_start(&mut self.aad_partial, remainder);
+        self.aad_partial_len = remainder.len();
+
+        Ok(())
+    }
+
+    /// Pads the end of the AAD, if necessary. After this, no more AAD can be
+    /// added.
+    #[inline(always)]
+    fn finish_aad(&mut self) {
+        if !self.aad_finished {
+            if self.aad_partial_len > 0 {
+                self.ghash_block(self.aad_partial);
+            }
+            self.aad_finished = true;
+        }
+    }
+
+    #[inline(always)]
+    fn ghash_block(&mut self, a: Block) {
+        self.Xi.bitxor_assign(a);
+        self.key.gmult(&mut self.Xi);
+    }
+}
+
+impl<K: Gmult> Context<'_, K> {
+    /// The GHASH state `Xi` after the blocks processed so far, for comparing
+    /// the intermediate state against a reference implementation while
+    /// debugging. AAD that doesn't fill a whole block is included as though
+    /// it were the end of the AAD.
+    #[cfg(test)]
+    fn current_xi(&self) -> [u8; BLOCK_LEN] {
+        let partial = if self.aad_finished {
+            &[][..]
+        } else {
+            &self.aad_partial[..self.aad_partial_len]
+        };
+        xi_with_partial_block(self.key, &self.Xi, partial)
+    }
+}
+
+/// Returns `xi` after processing `partial`, padded with zeros, as the final
+/// block, without modifying `xi`. `partial` may be empty.
+#[cfg(any(test, feature = "debug-internals"))]
+pub(super) fn xi_with_partial_block(key: &impl Gmult, xi: &Xi, partial: &[u8]) -> [u8; BLOCK_LEN] {
+    let mut xi = Xi(xi.0);
+    if !partial.is_empty() {
+        let mut block = ZERO_BLOCK;
+        overwrite_at_start(&mut block, partial);
+        xi.bitxor_assign(block);
+        key.gmult(&mut xi);
+    }
+    xi.0
+}
+
+impl<K> Context<'_, K> {
+    /// Replaces the length of the input given to `new`. This is for callers
+    /// that don't know the length until all the input has been processed.
+    pub(super) fn set_in_out_len(&mut self, in_out_len: usize) -> Result<(), error::Unspecified> {
+        if in_out_len > aes_gcm::MAX_IN_OUT_LEN {
+            return Err(error::Unspecified);
+        }
+        self.in_out_len = BitLength::from_byte_len(in_out_len)?;
+        Ok(())
     }
 }
 
@@ -99,6 +211,7 @@ impl<K> Context<'_, K> {
 impl Context<'_, clmul::Key> {
     #[inline]
     pub(super) fn inner(&mut self) -> (&HTable, &mut Xi) {
+        self.finish_aad();
         (&self.key.inner(), &mut self.Xi)
     }
 }
@@ -108,21 +221,23 @@ impl Context<'_, clmulavxmovbe::Key> {
     /// Access to `inner` for the integrated AES-GCM implementations only.
     #[inline]
     pub(super) fn inner(&mut self) -> (&HTable, &mut Xi) {
+        self.finish_aad();
         (self.key.inner(), &mut self.Xi)
     }
 }
 
-impl<K: UpdateBlocks> Context<'_, K> {
+impl<K: UpdateBlocks + Gmult> Context<'_, K> {
     #[inline(always)]
     pub fn update_blocks(&mut self, input: &[[u8; BLOCK_LEN]]) {
+        self.finish_aad();
         self.key.update_blocks(&mut self.Xi, input);
     }
 }
 
 impl<K: Gmult> Context<'_, K> {
     pub fn update_block(&mut self, a: Block) {
-        self.Xi.bitxor_assign(a);
-        self.key.gmult(&mut self.Xi);
+        self.finish_aad();
+        self.ghash_block(a);
     }
 
     #[inline(always)]
@@ -130,15 +245,114 @@ impl<K: Gmult> Context<'_, K> {
     where
         F: FnOnce(Block) -> super::Tag,
     {
+        self.finish_aad();
         let mut block = [0u8; BLOCK_LEN];
         let (alen, clen) = block.split_at_mut(BLOCK_LEN / 2);
         alen.copy_from_slice(&BitLength::<u64>::to_be_bytes(self.aad_len));
         clen.copy_from_slice(&BitLength::<u64>::to_be_bytes(self.in_out_len));
-        self.update_block(block);
+        self.ghash_block(block);
         f(self.Xi.0)
     }
 }
 
+/// A GHASH key using the best implementation available on this CPU, for
+/// constructions that use GHASH without AES-GCM's integrated
+/// implementations.
+#[allow(clippy::large_enum_variant, variant_size_differences)]
+pub(super) enum DynKey {
+    #[cfg(any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64"))]
+    ClMul(clmul::Key),
+
+    #[cfg(any(target_arch = "aarch64", target_arch = "arm"))]
+    Neon(neon::Key),
+
+    Fallback(fallback::Key),
+}
+
+impl DynKey {
+    pub(super) fn new(value: KeyValue, cpu_features: cpu::Features) -> Self {
+        #[cfg(any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64"))]
+        if let Some(cpu) = cpu_features.get_feature() {
+            return Self::ClMul(clmul::Key::new(value, cpu));
+        }
+
+        #[cfg(any(target_arch = "aarch64", target_arch = "arm"))]
+        if let Some(cpu) = cpu_features.get_feature() {
+            return Self::Neon(neon::Key::new(value, cpu));
+        }
+
+        let _ = cpu_features;
+
+        Self::Fallback(fallback::Key::new(value))
+    }
+}
+
+impl DynKey {
+    /// Calls `f` with a key for every implementation that is available on
+    /// this CPU, including the fallback implementation.
+    pub(super) fn for_each_implementation(value: Block, mut f: impl FnMut(Self)) {
+        let cpu_features = cpu::features();
+
+        #[cfg(any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64"))]
+        if let Some(cpu) = cpu_features.get_feature() {
+            f(Self::ClMul(clmul::Key::new(KeyValue::new(value), cpu)));
+        }
+
+        #[cfg(any(target_arch = "aarch64", target_arch = "arm"))]
+        if let Some(cpu) = cpu_features.get_feature() {
+            f(Self::Neon(neon::Key::new(KeyValue::new(value), cpu)));
+        }
+
+        let _ = cpu_features;
+
+        f(Self::Fallback(fallback::Key::new(KeyValue::new(value))));
+    }
+
+    /// Every implementation that is available on this CPU, including the
+    /// fallback implementation.
+    #[cfg(test)]
+    pub(super) fn all(value: Block) -> alloc::vec::Vec<Self> {
+        let mut keys = alloc::vec::Vec::new();
+        Self::for_each_implementation(value, |key| keys.push(key));
+        keys
+    }
+}
+
+impl Gmult for DynKey {
+    fn gmult(&self, xi: &mut Xi) {
+        match self {
+            #[cfg(any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64"))]
+            Self::ClMul(key) => key.gmult(xi),
+
+            #[cfg(any(target_arch = "aarch64", target_arch = "arm"))]
+            Self::Neon(key) => key.gmult(xi),
+
+            Self::Fallback(key) => key.gmult(xi),
+        }
+    }
+}
+
+impl UpdateBlocks for DynKey {
+    fn update_blocks(&self, xi: &mut Xi, input: &[[u8; BLOCK_LEN]]) {
+        match self {
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            Self::ClMul(key) => key.update_blocks(xi, input),
+
+            // There is no non-integrated bulk GHASH for AArch64 PMULL.
+            #[cfg(target_arch = "aarch64")]
+            Self::ClMul(key) => input.iter().for_each(|block| {
+                xi.bitxor_assign(*block);
+                key.gmult(xi);
+            }),
+
+            #[cfg(any(target_arch = "aarch64", target_arch = "arm"))]
+            Self::Neon(key) => key.update_blocks(xi, input),
+
+            Self::Fallback(key) => key.update_blocks(xi, input),
+        }
+    }
+}
+
 pub(super) trait Gmult {
     fn gmult(&self, xi: &mut Xi);
 }
@@ -146,3 +360,110 @@ pub(super) trait Gmult {
 pub(super) trait UpdateBlocks {
     fn update_blocks(&self, xi: &mut Xi, input: &[[u8; BLOCK_LEN]]);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_zeroed_on_drop() {
+        let h: Block = hex("b83b533708bf535d0aa6e52980d53b78");
+        assert!(crate::constant_time::is_zeroed_after_drop(KeyValue::new(h)));
+        let key = fallback::Key::new(KeyValue::new(h));
+        assert!(crate::constant_time::is_zeroed_after_drop(key));
+    }
+
+    #[test]
+    fn test_current_xi() {
+        // The hash key and AAD of test case 4 of "The Galois/Counter Mode of
+        // Operation (GCM)", with the intermediate values X_1 and X_2.
+        let h: Block = hex("b83b533708bf535d0aa6e52980d53b78");
+        let key = fallback::Key::new(KeyValue::new(h));
+        let aad: &[u8] = &[
+            0xfe, 0xed, 0xfa, 0xce, 0xde, 0xad, 0xbe, 0xef, 0xfe, 0xed, 0xfa, 0xce, 0xde, 0xad,
+            0xbe, 0xef, 0xab, 0xad, 0xda, 0xd2,
+        ];
+        let expected = [
+            hex("ed56aaf8a72d67049fdb9228edba1322"),
+            hex("cd47221ccef0554ee4bb044c88150352"),
+        ];
+
+        let mut ctx = Context::new(&key, Aad::from(&[][..]), 0).unwrap();
+        assert_eq!(ctx.current_xi(), ZERO_BLOCK);
+        for (ad, expected) in aad.chunks(BLOCK_LEN).zip(expected.iter()) {
+            let mut block = ZERO_BLOCK;
+            overwrite_at_start(&mut block, ad);
+            ctx.update_block(block);
+            assert_eq!(&ctx.current_xi(), expected);
+        }
+
+        // Processing the AAD all at once reaches the same state.
+        let ctx = Context::new(&key, Aad::from(aad), 0).unwrap();
+        assert_eq!(ctx.current_xi(), expected[1]);
+    }
+
+    #[test]
+    fn test_update_aad() {
+        // Test case 4 of "The Galois/Counter Mode of Operation (GCM)", with
+        // GHASH(H, A, C).
+        let h: Block = hex("b83b533708bf535d0aa6e52980d53b78");
+        let aad = crate::test::from_hex("feedfacedeadbeeffeedfacedeadbeefabaddad2").unwrap();
+        let ciphertext = crate::test::from_hex(
+            "42831ec2217774244b7221b784d0d49ce3aa212f2c02a4e035c17e2329aca12e\
+             21d514b25466931c7d8f6a5aac84aa051ba30b396a0aac973d58e091",
+        )
+        .unwrap();
+        let expected = hex("698e57f70e6ecc7fd9463b7260a9ae5f");
+
+        let ghash = |key: &DynKey, aad_chunks: &[&[u8]]| {
+            let mut ctx = Context::new(key, Aad::from(&[][..]), ciphertext.len()).unwrap();
+            for chunk in aad_chunks {
+                ctx.update_aad(chunk).unwrap();
+            }
+            let (whole, remainder) = crate::polyfill::slice::as_chunks(&ciphertext);
+            ctx.update_blocks(whole);
+            let mut block = ZERO_BLOCK;
+            overwrite_at_start(&mut block, remainder);
+            ctx.update_block(block);
+            ctx.pre_finish(super::super::Tag)
+        };
+
+        for key in DynKey::all(h) {
+            // The whole AAD at once, in `new`.
+            let mut ctx = Context::new(&key, Aad::from(&aad[..]), ciphertext.len()).unwrap();
+            let (whole, remainder) = crate::polyfill::slice::as_chunks(&ciphertext);
+            ctx.update_blocks(whole);
+            let mut block = ZERO_BLOCK;
+            overwrite_at_start(&mut block, remainder);
+            ctx.update_block(block);
+            assert_eq!(ctx.pre_finish(super::super::Tag).as_ref(), &expected[..]);
+
+            // Split at odd boundaries, including ones that cross and end on a
+            // block boundary, and with empty chunks.
+            for chunks in [
+                &[&aad[..3], &aad[3..]][..],
+                &[&aad[..7], &aad[7..16], &aad[16..]],
+                &[&aad[..1], &aad[1..17], &aad[17..19], &aad[19..]],
+                &[&[], &aad[..5], &[], &aad[5..], &[]],
+            ] {
+                assert_eq!(ghash(&key, chunks).as_ref(), &expected[..]);
+            }
+            for split in 0..=aad.len() {
+                let (a, b) = aad.split_at(split);
+                assert_eq!(ghash(&key, &[a, b]).as_ref(), &expected[..]);
+            }
+        }
+
+        // No more AAD is accepted once the input has been processed.
+        let key = fallback::Key::new(KeyValue::new(h));
+        let mut ctx = Context::new(&key, Aad::from(&aad[..3]), 16).unwrap();
+        ctx.update_aad(&aad[3..]).unwrap();
+        ctx.update_block(ZERO_BLOCK);
+        assert!(ctx.update_aad(&[]).is_err());
+        assert!(ctx.update_aad(&aad).is_err());
+    }
+
+    fn hex(s: &str) -> Block {
+        crate::test::from_hex(s).unwrap().try_into().unwrap()
+    }
+}
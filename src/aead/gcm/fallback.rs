@@ -23,7 +23,7 @@
 // Unlike the BearSSL notes, we use u128 in the 64-bit implementation.
 
 use super::{ffi::U128, Gmult, KeyValue, UpdateBlocks, Xi, BLOCK_LEN};
-use crate::polyfill::ArraySplitMap as _;
+use crate::{constant_time, polyfill::ArraySplitMap as _};
 
 #[derive(Clone)]
 pub struct Key {
@@ -36,6 +36,12 @@ impl Key {
     }
 }
 
+impl Drop for Key {
+    fn drop(&mut self) {
+        constant_time::secure_overwrite(core::slice::from_mut(&mut self.h), U128 { hi: 0, lo: 0 });
+    }
+}
+
 impl Gmult for Key {
     fn gmult(&self, xi: &mut Xi) {
         gmult(xi, self.h);
@@ -86,6 +86,12 @@ impl KeyValue {
     }
 }
 
+impl Drop for KeyValue {
+    fn drop(&mut self) {
+        constant_time::secure_overwrite(&mut self.0, 0);
+    }
+}
+
 /// SAFETY:
 ///   * `f` must read `len` bytes from `inp`; it may assume
 ///     that `len` is a (non-zero) multiple of `BLOCK_LEN`.
@@ -164,12 +170,41 @@ pub(super) struct U128 {
 
 const HTABLE_LEN: usize = 16;
 
+impl Drop for HTable {
+    fn drop(&mut self) {
+        constant_time::secure_overwrite(&mut self.Htable, U128 { hi: 0, lo: 0 });
+    }
+}
+
 #[repr(transparent)]
 pub(in super::super) struct Xi(pub(super) Block);
 
 impl Xi {
     #[inline]
-    pub(super) fn bitxor_assign(&mut self, a: Block) {
+    pub(in super::super) fn new() -> Self {
+        Self(ZERO_BLOCK)
+    }
+
+    #[inline]
+    pub(in super::super) fn bitxor_assign(&mut self, a: Block) {
         self.0 = constant_time::xor_16(self.0, a)
     }
+
+    #[inline]
+    pub(in super::super) fn into_block(self) -> Block {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_htable_zeroed_on_drop() {
+        let h_table = HTable {
+            Htable: [U128 { hi: !0, lo: 1 }; HTABLE_LEN],
+        };
+        assert!(constant_time::is_zeroed_after_drop(h_table));
+    }
 }
@@ -0,0 +1,190 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! GMAC, the authentication-only mode of AES-GCM.
+//!
+//! See [NIST SP 800-38D]. The GMAC tag of a message is the AES-GCM tag of the
+//! message as the AAD with an empty plaintext. Like AES-GCM, GMAC requires a
+//! unique nonce for every message authenticated with a given key.
+//!
+//! [NIST SP 800-38D]: https://csrc.nist.gov/publications/detail/sp/800-38d/final
+
+use super::{
+    aes,
+    gcm::{DynKey, Gmult as _, KeyValue, UpdateBlocks as _, Xi},
+    Nonce, Tag, NONCE_LEN,
+};
+use crate::{
+    constant_time, cpu, error,
+    polyfill::{slice, sliceutil::overwrite_at_start, u64_from_usize},
+};
+
+/// The length of a GMAC key.
+pub const KEY_LEN: usize = aes::AES_128_KEY_LEN;
+
+const BLOCK_LEN: usize = aes::BLOCK_LEN;
+
+/// A GMAC computation in progress.
+pub struct Gmac {
+    aes_key: aes::Key,
+    ghash_key: DynKey,
+    xi: Xi,
+    pending: [u8; BLOCK_LEN],
+    pending_len: usize,
+    len: u64,
+}
+
+impl Gmac {
+    /// Constructs a new GMAC context with the AES-128 key `key`.
+    pub fn new(key: &[u8; KEY_LEN]) -> Result<Self, error::Unspecified> {
+        let cpu_features = cpu::features();
+        let aes_key = aes::Key::new(aes::KeyBytes::AES_128(key), cpu_features)?;
+        let h = aes_key.encrypt_block(aes::ZERO_BLOCK);
+        Ok(Self {
+            aes_key,
+            ghash_key: DynKey::new(KeyValue::new(h), cpu_features),
+            xi: Xi::new(),
+            pending: [0; BLOCK_LEN],
+            pending_len: 0,
+            len: 0,
+        })
+    }
+
+    /// Updates the GMAC state with `data`.
+    ///
+    /// Unlike POLYVAL, the input may be split across calls arbitrarily; the
+    /// end of the input is padded with zeros only when the tag is computed.
+    pub fn update(&mut self, mut data: &[u8]) {
+        self.len = self.len.saturating_add(u64_from_usize(data.len()));
+
+        if self.pending_len > 0 {
+            let to_copy = core::cmp::min(BLOCK_LEN - self.pending_len, data.len());
+            let (head, rest) = data.split_at(to_copy);
+            self.pending[self.pending_len..][..to_copy].copy_from_slice(head);
+            self.pending_len += to_copy;
+            data = rest;
+            if self.pending_len < BLOCK_LEN {
+                return;
+            }
+            self.ghash_key.update_blocks(&mut self.xi, &[self.pending]);
+            self.pending_len = 0;
+        }
+
+        let (whole, remainder) = slice::as_chunks(data);
+        self.ghash_key.update_blocks(&mut self.xi, whole);
+        overwrite_at_start(&mut self.pending, remainder);
+        self.pending_len = remainder.len();
+    }
+
+    /// The GHASH state `Xi` after the input so far, for comparing the
+    /// intermediate state against a reference implementation while debugging.
+    ///
+    /// GMAC is AES-GCM with the input as the AAD, so this is also the state
+    /// of AES-GCM with the same key after the same AAD. Input that doesn't
+    /// fill a whole block is included as though it were the end of the input.
+    #[cfg(feature = "debug-internals")]
+    pub fn current_xi(&self) -> [u8; BLOCK_LEN] {
+        super::gcm::xi_with_partial_block(
+            &self.ghash_key,
+            &self.xi,
+            &self.pending[..self.pending_len],
+        )
+    }
+
+    /// Returns the GMAC tag of all the input, using `nonce`.
+    ///
+    /// `nonce` must be unique for every message authenticated with the key.
+    pub fn finish(mut self, nonce: Nonce) -> Tag {
+        if self.pending_len > 0 {
+            let mut block = [0; BLOCK_LEN];
+            overwrite_at_start(&mut block, &self.pending[..self.pending_len]);
+            self.xi.bitxor_assign(block);
+            self.ghash_key.gmult(&mut self.xi);
+        }
+
+        // The lengths block: the input is the AAD, and the ciphertext is
+        // empty. Inputs longer than 2^61 - 1 bytes aren't supported by GCM.
+        let mut lengths = [0; BLOCK_LEN];
+        lengths[..8].copy_from_slice(&self.len.saturating_mul(8).to_be_bytes());
+        self.xi.bitxor_assign(lengths);
+        self.ghash_key.gmult(&mut self.xi);
+
+        let mut j0 = [0; BLOCK_LEN];
+        j0[..NONCE_LEN].copy_from_slice(nonce.as_ref());
+        j0[BLOCK_LEN - 1] = 1;
+        let mask = self.aes_key.encrypt_block(j0);
+        Tag(constant_time::xor_16(self.xi.into_block(), mask))
+    }
+
+    /// Verifies, in constant time, that `tag` is the GMAC tag of all the
+    /// input, using `nonce`.
+    pub fn verify(self, nonce: Nonce, tag: &[u8]) -> Result<(), error::Unspecified> {
+        constant_time::verify_slices_are_equal(self.finish(nonce).as_ref(), tag)
+    }
+}
+
+impl core::fmt::Debug for Gmac {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("Gmac").finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    #[test]
+    fn test_gmac() {
+        test::run(test_file!("gmac_tests.txt"), |section, test_case| {
+            assert_eq!(section, "");
+            let key: [u8; KEY_LEN] = test_case.consume_bytes("KEY").as_slice().try_into()?;
+            let nonce: [u8; NONCE_LEN] = test_case.consume_bytes("NONCE").as_slice().try_into()?;
+            let input = test_case.consume_bytes("AAD");
+            let expected = test_case.consume_bytes("TAG");
+            let nonce = || Nonce::assume_unique_for_key(nonce);
+
+            // Every GHASH implementation gives the same result.
+            let h = aes::Key::new(aes::KeyBytes::AES_128(&key), cpu::features())?
+                .encrypt_block(aes::ZERO_BLOCK);
+            for ghash_key in DynKey::all(h) {
+                let mut ctx = Gmac::new(&key)?;
+                ctx.ghash_key = ghash_key;
+                ctx.update(&input);
+                assert_eq!(ctx.finish(nonce()).as_ref(), &expected[..]);
+            }
+
+            // Splitting the input at any point gives the same result.
+            for split in 0..=input.len() {
+                let (a, b) = input.split_at(split);
+                let mut ctx = Gmac::new(&key)?;
+                ctx.update(a);
+                ctx.update(b);
+                assert_eq!(ctx.finish(nonce()).as_ref(), &expected[..]);
+            }
+
+            let mut ctx = Gmac::new(&key)?;
+            ctx.update(&input);
+            assert!(ctx.verify(nonce(), &expected).is_ok());
+
+            let mut wrong = expected.clone();
+            wrong[0] ^= 1;
+            let mut ctx = Gmac::new(&key)?;
+            ctx.update(&input);
+            assert!(ctx.verify(nonce(), &wrong).is_err());
+
+            Ok(())
+        })
+    }
+}
@@ -12,32 +12,39 @@
 // OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
 // CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
 
-use super::{Aad, Algorithm, KeyInner, Nonce, Tag, UnboundKey, TAG_LEN};
-use crate::{cpu, error};
+use super::{aes_gcm, Aad, Algorithm, KeyInner, Nonce, Tag, UnboundKey, NONCE_LEN, TAG_LEN};
+use crate::{constant_time, cpu, digest, error};
 use core::ops::RangeFrom;
 
 /// Immutable keys for use in situations where `OpeningKey`/`SealingKey` and
 /// `NonceSequence` cannot reasonably be used.
 ///
 /// Prefer to use `OpeningKey`/`SealingKey` and `NonceSequence` when practical.
+///
+/// `L` is the length of the key's nonces; see `Algorithm::nonce_len()`.
 #[derive(Clone)]
-pub struct LessSafeKey {
+pub struct LessSafeKey<const L: usize = NONCE_LEN> {
     inner: KeyInner,
     algorithm: &'static Algorithm,
 }
 
-impl LessSafeKey {
+impl<const L: usize> LessSafeKey<L> {
     /// Constructs a `LessSafeKey`.
     #[inline]
-    pub fn new(key: UnboundKey) -> Self {
+    pub fn new(key: UnboundKey<L>) -> Self {
         key.into_inner()
     }
 
+    /// Fails if `algorithm`'s nonces aren't `L` bytes long, or if
+    /// `key_bytes.len() != algorithm.key_len()`.
     pub(super) fn new_(
         algorithm: &'static Algorithm,
         key_bytes: &[u8],
         cpu_features: cpu::Features,
     ) -> Result<Self, error::Unspecified> {
+        if algorithm.nonce_len() != L {
+            return Err(error::Unspecified);
+        }
         Ok(Self {
             inner: algorithm.new_key(key_bytes, cpu_features)?,
             algorithm,
@@ -49,7 +56,7 @@ impl LessSafeKey {
     #[inline]
     pub fn open_in_place_separate_tag<'in_out, A>(
         &self,
-        nonce: Nonce,
+        nonce: Nonce<L>,
         aad: Aad<A>,
         tag: Tag,
         in_out: &'in_out mut [u8],
@@ -76,10 +83,13 @@ impl LessSafeKey {
     /// # Safety
     ///
     /// The caller is responsible for checking the integrity of the message.
+    ///
+    /// Fails for AES-GCM-SIV keys, since AES-GCM-SIV can't decrypt without
+    /// the tag.
     #[inline]
     pub unsafe fn open_in_place_unchecked<A>(
         &self,
-        nonce: Nonce,
+        nonce: Nonce<L>,
         aad: Aad<A>,
         in_out: &mut [u8],
         ciphertext: RangeFrom<usize>,
@@ -88,8 +98,15 @@ impl LessSafeKey {
         A: AsRef<[u8]>,
     {
         let aad = Aad::from(aad.as_ref());
-        self.algorithm
-            .open(&self.inner, nonce, aad, in_out, ciphertext, cpu::features())
+        self.algorithm.open(
+            &self.inner,
+            nonce,
+            aad,
+            in_out,
+            ciphertext,
+            None,
+            cpu::features(),
+        )
     }
 
     /// Like [`super::OpeningKey::open_in_place()`], except it accepts an
@@ -99,7 +116,7 @@ impl LessSafeKey {
     #[inline]
     pub fn open_in_place<'in_out, A>(
         &self,
-        nonce: Nonce,
+        nonce: Nonce<L>,
         aad: Aad<A>,
         in_out: &'in_out mut [u8],
     ) -> Result<&'in_out mut [u8], error::Unspecified>
@@ -109,6 +126,50 @@ impl LessSafeKey {
         self.open_within(nonce, aad, in_out, 0..)
     }
 
+    /// Like [`super::OpeningKey::open_in_place_bounded()`], except it accepts
+    /// an arbitrary nonce.
+    ///
+    /// `nonce` must be unique for every use of the key to open data.
+    #[inline]
+    pub fn open_in_place_bounded<'in_out, A>(
+        &self,
+        nonce: Nonce<L>,
+        aad: Aad<A>,
+        in_out: &'in_out mut [u8],
+        max_plaintext_len: usize,
+    ) -> Result<&'in_out mut [u8], error::Unspecified>
+    where
+        A: AsRef<[u8]>,
+    {
+        // If `in_out` is shorter than a tag then `open_in_place` rejects it.
+        if in_out.len().saturating_sub(TAG_LEN) > max_plaintext_len {
+            return Err(error::Unspecified);
+        }
+        self.open_in_place(nonce, aad, in_out)
+    }
+
+    /// Like [`open_in_place`](Self::open_in_place), except it also returns
+    /// the `digest_alg` digest of the plaintext.
+    ///
+    /// This is a convenience wrapper: the whole message is opened first, and
+    /// then the digest is computed over the plaintext in a second pass, so it
+    /// is never computed over unauthenticated plaintext. The result is the
+    /// same as calling [`digest::digest`] on the returned plaintext.
+    pub fn open_in_place_with_digest<'in_out, A>(
+        &self,
+        nonce: Nonce<L>,
+        aad: Aad<A>,
+        in_out: &'in_out mut [u8],
+        digest_alg: &'static digest::Algorithm,
+    ) -> Result<(&'in_out mut [u8], digest::Digest), error::Unspecified>
+    where
+        A: AsRef<[u8]>,
+    {
+        let plaintext = self.open_in_place(nonce, aad, in_out)?;
+        let digest = digest::digest(digest_alg, plaintext);
+        Ok((plaintext, digest))
+    }
+
     /// Like [`super::OpeningKey::open_within()`], except it accepts an
     /// arbitrary nonce.
     ///
@@ -116,7 +177,7 @@ impl LessSafeKey {
     #[inline]
     pub fn open_within<'in_out, A>(
         &self,
-        nonce: Nonce,
+        nonce: Nonce<L>,
         aad: Aad<A>,
         in_out: &'in_out mut [u8],
         ciphertext_and_tag: RangeFrom<usize>,
@@ -144,7 +205,7 @@ impl LessSafeKey {
     #[inline]
     pub fn seal_in_place_append_tag<A, InOut>(
         &self,
-        nonce: Nonce,
+        nonce: Nonce<L>,
         aad: Aad<A>,
         in_out: &mut InOut,
     ) -> Result<(), error::Unspecified>
@@ -163,7 +224,7 @@ impl LessSafeKey {
     #[inline]
     pub fn seal_in_place_separate_tag<A>(
         &self,
-        nonce: Nonce,
+        nonce: Nonce<L>,
         aad: Aad<A>,
         in_out: &mut [u8],
     ) -> Result<Tag, error::Unspecified>
@@ -179,6 +240,176 @@ impl LessSafeKey {
         )
     }
 
+    /// Like [`super::SealingKey::seal_in_place_into()`], except it accepts
+    /// an arbitrary nonce.
+    ///
+    /// `nonce` must be unique for every use of the key to seal data.
+    #[inline]
+    pub fn seal_in_place_into<A, Out>(
+        &self,
+        nonce: Nonce<L>,
+        aad: Aad<A>,
+        in_out: &mut [u8],
+        out: &mut Out,
+    ) -> Result<(), error::Unspecified>
+    where
+        A: AsRef<[u8]>,
+        Out: for<'in_out> Extend<&'in_out u8>,
+    {
+        let tag = self.seal_in_place_separate_tag(nonce, aad, in_out)?;
+        out.extend(in_out.iter());
+        out.extend(tag.as_ref());
+        Ok(())
+    }
+}
+
+impl LessSafeKey {
+    /// Like [`seal_in_place_separate_tag`](Self::seal_in_place_separate_tag),
+    /// except the plaintext is produced incrementally by `blocks`, so it
+    /// never needs to be in memory all at once.
+    ///
+    /// The plaintext is the concatenation of the 16-byte blocks produced by
+    /// `blocks`, followed by `remainder`, its final 0 to 15 bytes. Each block
+    /// is encrypted and passed to `out` before the next block is requested
+    /// from `blocks`. Then `remainder` is encrypted in place. The ciphertext
+    /// and tag are the same as if the whole plaintext had been sealed with
+    /// `seal_in_place_separate_tag`.
+    ///
+    /// Fails if `remainder` is 16 bytes or longer, if the plaintext is too
+    /// long, or if the key isn't an AES-GCM key. If it fails after some
+    /// ciphertext has been passed to `out` then that ciphertext must be
+    /// discarded.
+    pub fn seal_blocks<A, I, F>(
+        &self,
+        nonce: Nonce,
+        aad: Aad<A>,
+        blocks: I,
+        remainder: &mut [u8],
+        out: F,
+    ) -> Result<Tag, error::Unspecified>
+    where
+        A: AsRef<[u8]>,
+        I: IntoIterator<Item = [u8; 16]>,
+        F: FnMut([u8; 16]),
+    {
+        let key = self.aes_gcm_key()?;
+        aes_gcm::seal_blocks(
+            key,
+            nonce,
+            Aad::from(aad.as_ref()),
+            blocks.into_iter(),
+            remainder,
+            out,
+        )
+    }
+
+    /// Like [`seal_in_place_append_tag`](Self::seal_in_place_append_tag),
+    /// except `total_len` is also authenticated.
+    ///
+    /// This is for the final record of a stream of records, where
+    /// `total_len` is the total length of the plaintext of all the records.
+    /// The final record must be opened with
+    /// [`open_in_place_with_total_length`](Self::open_in_place_with_total_length)
+    /// and the recipient's count of the plaintext it received, so that a
+    /// stream that was truncated at a record boundary is detected.
+    ///
+    /// This is a non-standard extension of AES-GCM: the GHASH input is
+    /// extended by one block after the lengths block, `total_len` as a
+    /// 128-bit big-endian integer. The result is not compatible with any
+    /// other implementation. Fails if the key isn't an AES-GCM key.
+    pub fn seal_in_place_append_tag_with_total_length<A, InOut>(
+        &self,
+        nonce: Nonce,
+        aad: Aad<A>,
+        in_out: &mut InOut,
+        total_len: u64,
+    ) -> Result<(), error::Unspecified>
+    where
+        A: AsRef<[u8]>,
+        InOut: AsMut<[u8]> + for<'in_out> Extend<&'in_out u8>,
+    {
+        let key = self.aes_gcm_key()?;
+        let tag_nonce = Nonce::assume_unique_for_key(*nonce.as_ref());
+        let tag = self.seal_in_place_separate_tag(nonce, aad, in_out.as_mut())?;
+        let tag = aes_gcm::bind_total_len(key, tag_nonce, tag, total_len);
+        in_out.extend(tag.as_ref());
+        Ok(())
+    }
+
+    /// Like [`open_in_place`](Self::open_in_place), except for records
+    /// sealed with
+    /// [`seal_in_place_append_tag_with_total_length`](Self::seal_in_place_append_tag_with_total_length).
+    ///
+    /// Fails if `total_len` isn't the value the record was sealed with, or if
+    /// the key isn't an AES-GCM key.
+    pub fn open_in_place_with_total_length<'in_out, A>(
+        &self,
+        nonce: Nonce,
+        aad: Aad<A>,
+        in_out: &'in_out mut [u8],
+        total_len: u64,
+    ) -> Result<&'in_out mut [u8], error::Unspecified>
+    where
+        A: AsRef<[u8]>,
+    {
+        let key = self.aes_gcm_key()?;
+
+        let tag_offset = in_out
+            .len()
+            .checked_sub(TAG_LEN)
+            .ok_or(error::Unspecified)?;
+        let (in_out, received_tag) = in_out.split_at_mut(tag_offset);
+
+        let tag_nonce = Nonce::assume_unique_for_key(*nonce.as_ref());
+        let tag = self.algorithm.open(
+            &self.inner,
+            nonce,
+            Aad::from(aad.as_ref()),
+            in_out,
+            0..,
+            None,
+            cpu::features(),
+        )?;
+        let tag = aes_gcm::bind_total_len(key, tag_nonce, tag, total_len);
+
+        if constant_time::verify_slices_are_equal(tag.as_ref(), received_tag).is_err() {
+            // Zero out the plaintext, like `Algorithm::open_within` does.
+            in_out.fill(0);
+            return Err(error::Unspecified);
+        }
+        Ok(in_out)
+    }
+
+    /// Decrypts `in_out` in place and returns the calculated tag, without
+    /// comparing it to `received_tag`.
+    pub(super) fn open_unverified(
+        &self,
+        nonce: Nonce,
+        aad: Aad<&[u8]>,
+        received_tag: &Tag,
+        in_out: &mut [u8],
+    ) -> Result<Tag, error::Unspecified> {
+        self.algorithm.open(
+            &self.inner,
+            nonce,
+            aad,
+            in_out,
+            0..,
+            Some(received_tag),
+            cpu::features(),
+        )
+    }
+
+    pub(super) fn aes_gcm_key(&self) -> Result<&aes_gcm::Key, error::Unspecified> {
+        match &self.inner {
+            KeyInner::AesGcm(key) => Ok(key),
+            #[cfg(not(feature = "fips"))]
+            _ => Err(error::Unspecified),
+        }
+    }
+}
+
+impl<const L: usize> LessSafeKey<L> {
     /// The key's AEAD algorithm.
     #[inline]
     pub fn algorithm(&self) -> &'static Algorithm {
@@ -196,7 +427,7 @@ impl LessSafeKey {
     }
 }
 
-impl core::fmt::Debug for LessSafeKey {
+impl<const L: usize> core::fmt::Debug for LessSafeKey<L> {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
         self.fmt_debug("LessSafeKey", f)
     }
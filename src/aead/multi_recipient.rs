@@ -0,0 +1,92 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Authenticating one ciphertext for multiple recipients.
+
+use super::{aes_gcm, Aad, LessSafeKey, Nonce, Tag};
+use crate::{constant_time, error};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Seals `in_out` once with `content_key`, and then computes a tag over the
+/// resulting ciphertext for each of `recipient_keys`.
+///
+/// Returns a tuple `(content_tag, recipient_tags)`, where `content_tag` is the
+/// tag for `content_key` and `recipient_tags` holds the tag for each
+/// recipient, in the same order as `recipient_keys`.
+///
+/// The recipient tag for a recipient key is the AES-GCM tag that the key
+/// would have produced for the same nonce, AAD, and ciphertext, i.e. GHASH
+/// of the AAD and the ciphertext under the recipient key's hash key, masked
+/// with the encryption of the initial counter block under the recipient
+/// key. Only the sender and that recipient can compute it, so a recipient
+/// that checks its tag with [`verify_recipient_tag()`] knows the ciphertext
+/// wasn't produced by another recipient, even though every recipient has
+/// `content_key`. The recipient then opens the ciphertext with `content_key`
+/// and the content tag as usual.
+///
+/// Every recipient key must be an AES-GCM key. `nonce` is used with every
+/// key, so it must be unique for every use of `content_key` and of each
+/// recipient key, across sealing and computing recipient tags.
+#[cfg(feature = "alloc")]
+pub fn seal_multi_recipient<A>(
+    content_key: &LessSafeKey,
+    recipient_keys: &[&LessSafeKey],
+    nonce: Nonce,
+    aad: Aad<A>,
+    in_out: &mut [u8],
+) -> Result<(Tag, Vec<Tag>), error::Unspecified>
+where
+    A: AsRef<[u8]>,
+{
+    let recipient_keys = recipient_keys
+        .iter()
+        .map(|key| key.aes_gcm_key())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let nonce_bytes = *nonce.as_ref();
+    let aad = Aad::from(aad.as_ref());
+    let content_tag = content_key.seal_in_place_separate_tag(nonce, aad, in_out)?;
+
+    let recipient_tags = recipient_keys
+        .into_iter()
+        .map(|key| {
+            let nonce = Nonce::assume_unique_for_key(nonce_bytes);
+            aes_gcm::tag_ciphertext(key, nonce, aad, in_out)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((content_tag, recipient_tags))
+}
+
+/// Verifies a recipient tag computed by [`seal_multi_recipient()`].
+///
+/// `ciphertext` is the ciphertext, still encrypted. Fails if `recipient_key`
+/// isn't an AES-GCM key or if `tag` isn't the recipient tag for
+/// `recipient_key`.
+pub fn verify_recipient_tag<A>(
+    recipient_key: &LessSafeKey,
+    nonce: Nonce,
+    aad: Aad<A>,
+    ciphertext: &[u8],
+    tag: &Tag,
+) -> Result<(), error::Unspecified>
+where
+    A: AsRef<[u8]>,
+{
+    let key = recipient_key.aes_gcm_key()?;
+    let expected = aes_gcm::tag_ciphertext(key, nonce, Aad::from(aad.as_ref()), ciphertext)?;
+    constant_time::verify_slices_are_equal(expected.as_ref(), tag.as_ref())
+}
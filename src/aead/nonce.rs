@@ -12,7 +12,7 @@
 // OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
 // CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
 
-use crate::error;
+use crate::{error, hmac};
 
 /// A nonce for a single AEAD opening or sealing operation.
 ///
@@ -20,7 +20,23 @@ use crate::error;
 ///
 /// `Nonce` intentionally doesn't implement `Clone` to ensure that each one is
 /// consumed at most once.
-pub struct Nonce([u8; NONCE_LEN]);
+///
+/// The length of the nonce is part of its type. `Nonce` without a length is
+/// `Nonce<NONCE_LEN>`, which is what every algorithm in this module except
+/// `AEGIS_128L` and `XCHACHA20_POLY1305` takes. Keys also have the length of their nonces as
+/// part of their type, so using a nonce of the wrong length is a compile-time
+/// error:
+///
+/// ```compile_fail
+/// use ring::aead;
+///
+/// let key = aead::UnboundKey::new(&aead::AES_256_GCM, &[0; 32]).unwrap();
+/// let key = aead::LessSafeKey::new(key);
+/// let nonce = aead::Nonce::<24>::assume_unique_for_key([0; 24]);
+/// let mut in_out = Vec::new();
+/// key.seal_in_place_append_tag(nonce, aead::Aad::empty(), &mut in_out);
+/// ```
+pub struct Nonce<const N: usize = NONCE_LEN>([u8; N]);
 
 impl Nonce {
     /// Constructs a `Nonce` with the given value, assuming that the value is
@@ -29,23 +45,59 @@ impl Nonce {
     /// Fails if `value` isn't `NONCE_LEN` bytes long.
     #[inline]
     pub fn try_assume_unique_for_key(value: &[u8]) -> Result<Self, error::Unspecified> {
-        let value: &[u8; NONCE_LEN] = value.try_into()?;
-        Ok(Self::assume_unique_for_key(*value))
+        Self::try_assume_unique_for_key_of_len(value)
     }
+}
 
+impl<const N: usize> Nonce<N> {
     /// Constructs a `Nonce` with the given value, assuming that the value is
     /// unique for the lifetime of the key it is being used with.
     #[inline]
-    pub fn assume_unique_for_key(value: [u8; NONCE_LEN]) -> Self {
+    pub fn assume_unique_for_key(value: [u8; N]) -> Self {
         Self(value)
     }
+
+    /// Like `Nonce::try_assume_unique_for_key()`, but for nonces of any
+    /// length, e.g. `Nonce::<24>::try_assume_unique_for_key_of_len(value)`.
+    ///
+    /// Fails if `value` isn't `N` bytes long.
+    #[inline]
+    pub fn try_assume_unique_for_key_of_len(value: &[u8]) -> Result<Self, error::Unspecified> {
+        let value: &[u8; N] = value.try_into()?;
+        Ok(Self::assume_unique_for_key(*value))
+    }
 }
 
-impl AsRef<[u8; NONCE_LEN]> for Nonce {
-    fn as_ref(&self) -> &[u8; NONCE_LEN] {
+impl<const N: usize> AsRef<[u8; N]> for Nonce<N> {
+    fn as_ref(&self) -> &[u8; N] {
         &self.0
     }
 }
 
-/// All the AEADs we support use 96-bit nonces.
+/// All the AEADs we support, except AEGIS-128L and XChaCha20-Poly1305, use
+/// 96-bit nonces.
 pub const NONCE_LEN: usize = 96 / 8;
+
+/// Derives a nonce from `message` and `counter`, as the first `NONCE_LEN`
+/// bytes of `HMAC(key, counter || message)`, where `counter` is encoded as
+/// a big-endian 64-bit integer.
+///
+/// This is a mitigation for bugs in the caller's nonce management, not a
+/// nonce-misuse-resistant AEAD like AES-GCM-SIV: if the caller accidentally
+/// reuses a counter value, the nonce is still different for different
+/// messages. But sealing the same message twice with the same counter gives
+/// the same nonce, and thus the same ciphertext, which reveals that the
+/// messages are equal. Nonces are 96 bits, so the usual birthday bound on
+/// random nonces also applies.
+///
+/// `key` must be independent of the AEAD key. The message is processed
+/// twice, once here and once when it is sealed.
+pub fn deterministic_nonce(key: &hmac::Key, message: &[u8], counter: u64) -> Nonce {
+    let mut ctx = hmac::Context::with_key(key);
+    ctx.update(&counter.to_be_bytes());
+    ctx.update(message);
+    let tag = ctx.sign();
+    let mut value = [0u8; NONCE_LEN];
+    value.copy_from_slice(&tag.as_ref()[..NONCE_LEN]);
+    Nonce::assume_unique_for_key(value)
+}
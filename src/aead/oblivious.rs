@@ -0,0 +1,110 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Opening with one of several keys without revealing which one.
+
+use super::{Aad, LessSafeKey, Nonce, Tag, TAG_LEN};
+use crate::{
+    constant_time, error,
+    limb::{self, Limb, LimbMask},
+};
+use alloc::{vec, vec::Vec};
+
+/// Opens `in_out` with `keys[selector]`, in a way that doesn't reveal
+/// `selector` through timing or memory access patterns.
+///
+/// This is for applications that support multiple AEAD algorithms or keys
+/// where the choice between them depends on secret data. Choosing the key
+/// with a branch or an index can leak the choice; instead, `open_oblivious`
+/// decrypts and authenticates the ciphertext with *every* key in `keys`, and
+/// then selects the result for `keys[selector]` in constant time.
+///
+/// `in_out` is the ciphertext followed by the tag, like
+/// [`LessSafeKey::open_in_place()`]. On success, the plaintext is returned.
+/// On failure, the ciphertext is overwritten with zeros. Fails if `selector`
+/// isn't less than `keys.len()`, or if the ciphertext isn't authentic for
+/// `keys[selector]`; it doesn't matter whether the ciphertext is authentic
+/// for any other key.
+///
+/// The work done is proportional to `keys.len()` times the length of
+/// `in_out`. `selector` is protected, but the number of keys, the length of
+/// `in_out`, and whether the operation succeeds are not. The algorithm of
+/// each key is not protected either, since different algorithms take
+/// different amounts of time; to hide which algorithm is selected, every
+/// algorithm the application might select needs to be in `keys` every time.
+///
+/// `nonce` is used with every key, so it must be unique for every use of
+/// each of the keys to open data.
+pub fn open_oblivious<'in_out, A>(
+    keys: &[&LessSafeKey],
+    selector: usize,
+    nonce: Nonce,
+    aad: Aad<A>,
+    in_out: &'in_out mut [u8],
+) -> Result<&'in_out mut [u8], error::Unspecified>
+where
+    A: AsRef<[u8]>,
+{
+    let ciphertext_len = in_out
+        .len()
+        .checked_sub(TAG_LEN)
+        .ok_or(error::Unspecified)?;
+    let (ciphertext, received_tag) = in_out.split_at_mut(ciphertext_len);
+    let received_tag = Tag::try_from(&*received_tag)?;
+
+    let nonce = *nonce.as_ref();
+    let aad = Aad::from(aad.as_ref());
+
+    let selector = Limb::try_from(selector).map_err(|_| error::Unspecified)?;
+
+    let mut selected = vec![0u8; ciphertext_len];
+    let mut selected_ok: Limb = 0;
+    let mut scratch = Vec::with_capacity(ciphertext_len);
+
+    for (i, key) in keys.iter().enumerate() {
+        scratch.clear();
+        scratch.extend_from_slice(ciphertext);
+        // A failure here only depends on the lengths of the inputs, and so
+        // it doesn't leak `selector`. Treat it like a tag mismatch so that
+        // every key is still tried.
+        let tag_ok = key
+            .open_unverified(
+                Nonce::assume_unique_for_key(nonce),
+                aad,
+                &received_tag,
+                &mut scratch,
+            )
+            .map(|calculated_tag| {
+                constant_time::slices_are_equal_mask(calculated_tag.as_ref(), received_tag.as_ref())
+            })
+            .unwrap_or(LimbMask::False);
+        let i = Limb::try_from(i).map_err(|_| error::Unspecified)?;
+        let is_selected = limb::limbs_equal_limb_constant_time(&[i], selector);
+        let mask = (tag_ok as Limb) & (is_selected as Limb);
+        selected_ok |= mask;
+        let mask = mask.to_le_bytes()[0];
+        selected
+            .iter_mut()
+            .zip(scratch.iter())
+            .for_each(|(s, p)| *s |= p & mask);
+    }
+
+    // Only the result of the whole operation is revealed.
+    if selected_ok == 0 {
+        ciphertext.fill(0);
+        return Err(error::Unspecified);
+    }
+    ciphertext.copy_from_slice(&selected);
+    Ok(ciphertext)
+}
@@ -21,7 +21,7 @@
 //! [AEAD]: https://eprint.iacr.org/2000/025.pdf
 //! [`crypto.cipher.AEAD`]: https://golang.org/pkg/crypto/cipher/#AEAD
 
-use super::{Aad, Algorithm, BoundKey, LessSafeKey, NonceSequence, UnboundKey};
+use super::{Aad, Algorithm, BoundKey, LessSafeKey, NonceSequence, Tag, UnboundKey, NONCE_LEN};
 use crate::error;
 use core::ops::RangeFrom;
 
@@ -30,13 +30,13 @@ use core::ops::RangeFrom;
 ///
 /// Intentionally not `Clone` or `Copy` since cloning would allow duplication
 /// of the nonce sequence.
-pub struct OpeningKey<N: NonceSequence> {
-    key: LessSafeKey,
+pub struct OpeningKey<N: NonceSequence<L>, const L: usize = NONCE_LEN> {
+    key: LessSafeKey<L>,
     nonce_sequence: N,
 }
 
-impl<N: NonceSequence> BoundKey<N> for OpeningKey<N> {
-    fn new(key: UnboundKey, nonce_sequence: N) -> Self {
+impl<N: NonceSequence<L>, const L: usize> BoundKey<N, L> for OpeningKey<N, L> {
+    fn new(key: UnboundKey<L>, nonce_sequence: N) -> Self {
         Self {
             key: key.into_inner(),
             nonce_sequence,
@@ -49,13 +49,13 @@ impl<N: NonceSequence> BoundKey<N> for OpeningKey<N> {
     }
 }
 
-impl<N: NonceSequence> core::fmt::Debug for OpeningKey<N> {
+impl<N: NonceSequence<L>, const L: usize> core::fmt::Debug for OpeningKey<N, L> {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
         self.key.fmt_debug("OpeningKey", f)
     }
 }
 
-impl<N: NonceSequence> OpeningKey<N> {
+impl<N: NonceSequence<L>, const L: usize> OpeningKey<N, L> {
     /// Authenticates and decrypts (“opens”) data in place.
     ///
     /// `aad` is the additional authenticated data (AAD), if any.
@@ -80,6 +80,50 @@ impl<N: NonceSequence> OpeningKey<N> {
             .open_in_place(self.nonce_sequence.advance()?, aad, in_out)
     }
 
+    /// Like [`open_in_place()`](Self::open_in_place), except it fails without
+    /// decrypting anything if the plaintext would be longer than
+    /// `max_plaintext_len` bytes.
+    ///
+    /// The nonce sequence is advanced even when the length check fails, just
+    /// like when authentication fails.
+    #[inline]
+    pub fn open_in_place_bounded<'in_out, A>(
+        &mut self,
+        aad: Aad<A>,
+        in_out: &'in_out mut [u8],
+        max_plaintext_len: usize,
+    ) -> Result<&'in_out mut [u8], error::Unspecified>
+    where
+        A: AsRef<[u8]>,
+    {
+        self.key.open_in_place_bounded(
+            self.nonce_sequence.advance()?,
+            aad,
+            in_out,
+            max_plaintext_len,
+        )
+    }
+
+    /// Like [`open_in_place()`](Self::open_in_place), except the tag is
+    /// passed separately.
+    ///
+    /// On input, `in_out` must be just the ciphertext. This is the
+    /// counterpart of `SealingKey::seal_in_place_separate_tag()`, for formats
+    /// that store the tag apart from the ciphertext, e.g. in a header.
+    #[inline]
+    pub fn open_in_place_separate_tag<'in_out, A>(
+        &mut self,
+        aad: Aad<A>,
+        tag: Tag,
+        in_out: &'in_out mut [u8],
+    ) -> Result<&'in_out mut [u8], error::Unspecified>
+    where
+        A: AsRef<[u8]>,
+    {
+        self.key
+            .open_in_place_separate_tag(self.nonce_sequence.advance()?, aad, tag, in_out, 0..)
+    }
+
     /// Authenticates and decrypts (“opens”) data in place, with a shift.
     ///
     /// `aad` is the additional authenticated data (AAD), if any.
@@ -0,0 +1,38 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+use super::{chacha20_poly1305, UnboundKey, CHACHA20_POLY1305};
+use crate::{argon2, constant_time, error};
+
+/// Derives a ChaCha20-Poly1305 key from `password` using Argon2id.
+///
+/// The 32-byte key is `argon2::derive(params, salt, password, ..)`, so the
+/// same `params`, `salt`, and `password` always give the same key. The caller
+/// must store `salt` and `params` (which needn't be kept secret) alongside
+/// the ciphertext, since the key can't be derived again without them. Use a
+/// new random salt of at least `argon2::MIN_SALT_LEN` bytes for every key;
+/// 16 bytes is recommended.
+///
+/// Fails if `argon2::derive` fails, e.g. if `salt` is too short.
+pub fn chacha20poly1305_from_password(
+    params: &argon2::Params,
+    salt: &[u8],
+    password: &[u8],
+) -> Result<UnboundKey, error::Unspecified> {
+    let mut key_bytes = [0u8; chacha20_poly1305::KEY_LEN];
+    let result = argon2::derive(params, salt, password, &mut key_bytes)
+        .and_then(|()| UnboundKey::new(&CHACHA20_POLY1305, &key_bytes));
+    constant_time::secure_zero(&mut key_bytes);
+    result
+}
@@ -0,0 +1,130 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! The POLYVAL universal hash function.
+//!
+//! See [RFC 8452 Section 3]. POLYVAL is the universal hash used by
+//! AES-GCM-SIV. It is provided separately for building other constructions;
+//! it is **not** a MAC by itself.
+//!
+//! [RFC 8452 Section 3]: https://tools.ietf.org/html/rfc8452#section-3
+
+use super::gcm::{DynKey, Gmult, KeyValue, Xi};
+use crate::{cpu, polyfill::sliceutil::overwrite_at_start};
+
+/// The length of a POLYVAL key.
+pub const KEY_LEN: usize = 16;
+
+/// The length of a POLYVAL block, and of its output.
+pub const BLOCK_LEN: usize = 16;
+
+type Block = [u8; BLOCK_LEN];
+
+/// A POLYVAL computation in progress.
+pub struct Polyval {
+    key: DynKey,
+    xi: Xi,
+}
+
+impl Polyval {
+    /// Constructs a new POLYVAL context with the given key `H`.
+    pub fn new(key: &[u8; KEY_LEN]) -> Self {
+        Self::with_dyn_key(DynKey::new(KeyValue::new(ghash_key(key)), cpu::features()))
+    }
+
+    fn with_dyn_key(key: DynKey) -> Self {
+        Self { key, xi: Xi::new() }
+    }
+
+    /// Updates the POLYVAL state with the blocks of `input`.
+    ///
+    /// If `input` isn't a multiple of `BLOCK_LEN` bytes long then its last
+    /// block is padded with zeros, the same way AES-GCM-SIV pads the AAD and
+    /// the plaintext. Thus, splitting one input across multiple calls to
+    /// `update` only gives the same result when every piece except the last
+    /// is a whole number of blocks.
+    pub fn update(&mut self, input: &[u8]) {
+        for chunk in input.chunks(BLOCK_LEN) {
+            let mut block = [0u8; BLOCK_LEN];
+            overwrite_at_start(&mut block, chunk);
+            self.update_block(block);
+        }
+    }
+
+    fn update_block(&mut self, block: Block) {
+        // RFC 8452 Appendix A: POLYVAL(H, X_1, ..., X_n) =
+        //   ByteReverse(GHASH(mulX_GHASH(ByteReverse(H)),
+        //                     ByteReverse(X_1), ..., ByteReverse(X_n))).
+        self.xi.bitxor_assign(byte_reverse(block));
+        self.key.gmult(&mut self.xi);
+    }
+
+    /// Returns the POLYVAL of all the input.
+    pub fn finish(self) -> [u8; BLOCK_LEN] {
+        byte_reverse(self.xi.into_block())
+    }
+}
+
+impl core::fmt::Debug for Polyval {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("Polyval").finish()
+    }
+}
+
+/// Computes the GHASH key `mulX_GHASH(ByteReverse(H))`.
+fn ghash_key(h: &[u8; KEY_LEN]) -> Block {
+    // In GHASH's bit order, multiplying by `x` is a right shift, with the
+    // reduction by x^128 + x^7 + x^2 + x + 1 applied when a bit shifts out.
+    let v = u128::from_be_bytes(byte_reverse(*h));
+    let carry = 0u128.wrapping_sub(v & 1);
+    let v = (v >> 1) ^ (carry & (0xe1 << 120));
+    v.to_be_bytes()
+}
+
+#[inline]
+fn byte_reverse(mut block: Block) -> Block {
+    block.reverse();
+    block
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    #[test]
+    fn test_polyval() {
+        test::run(test_file!("polyval_tests.txt"), |section, test_case| {
+            assert_eq!(section, "");
+            let key: [u8; KEY_LEN] = test_case.consume_bytes("H").as_slice().try_into()?;
+            let input = test_case.consume_bytes("X");
+            let expected = test_case.consume_bytes("POLYVAL");
+
+            for key in DynKey::all(ghash_key(&key)) {
+                let mut ctx = Polyval::with_dyn_key(key);
+                ctx.update(&input);
+                assert_eq!(&ctx.finish()[..], &expected[..]);
+            }
+
+            // Feeding the input one block at a time gives the same result.
+            let mut ctx = Polyval::new(&key);
+            for block in input.chunks(BLOCK_LEN) {
+                ctx.update(block);
+            }
+            assert_eq!(&ctx.finish()[..], &expected[..]);
+
+            Ok(())
+        })
+    }
+}
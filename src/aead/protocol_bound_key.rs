@@ -0,0 +1,137 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+use super::{Aad, Algorithm, LessSafeKey, Nonce, Tag, UnboundKey};
+use crate::error;
+use alloc::vec::Vec;
+use core::ops::RangeFrom;
+
+/// Binds `key` to the protocol identified by `protocol_id`.
+///
+/// See [`ProtocolBoundKey`].
+#[inline]
+pub fn with_protocol_id(key: UnboundKey, protocol_id: u16) -> ProtocolBoundKey {
+    ProtocolBoundKey {
+        key: key.into_inner(),
+        protocol_id,
+    }
+}
+
+/// An AEAD key that is bound to a protocol identifier.
+///
+/// Every seal and open operation prepends the big-endian encoding of the
+/// protocol identifier to the AAD, so a ciphertext sealed for one protocol
+/// fails to open as a message of another protocol that uses the same key.
+/// Otherwise this works exactly like `LessSafeKey`.
+#[derive(Clone)]
+pub struct ProtocolBoundKey {
+    key: LessSafeKey,
+    protocol_id: u16,
+}
+
+impl ProtocolBoundKey {
+    /// Like [`LessSafeKey::open_in_place()`], except the protocol identifier
+    /// is prepended to `aad`.
+    #[inline]
+    pub fn open_in_place<'in_out, A>(
+        &self,
+        nonce: Nonce,
+        aad: Aad<A>,
+        in_out: &'in_out mut [u8],
+    ) -> Result<&'in_out mut [u8], error::Unspecified>
+    where
+        A: AsRef<[u8]>,
+    {
+        self.open_within(nonce, aad, in_out, 0..)
+    }
+
+    /// Like [`LessSafeKey::open_within()`], except the protocol identifier
+    /// is prepended to `aad`.
+    #[inline]
+    pub fn open_within<'in_out, A>(
+        &self,
+        nonce: Nonce,
+        aad: Aad<A>,
+        in_out: &'in_out mut [u8],
+        ciphertext_and_tag: RangeFrom<usize>,
+    ) -> Result<&'in_out mut [u8], error::Unspecified>
+    where
+        A: AsRef<[u8]>,
+    {
+        self.key
+            .open_within(nonce, self.bound_aad(aad), in_out, ciphertext_and_tag)
+    }
+
+    /// Like [`LessSafeKey::seal_in_place_append_tag()`], except the protocol
+    /// identifier is prepended to `aad`.
+    #[inline]
+    pub fn seal_in_place_append_tag<A, InOut>(
+        &self,
+        nonce: Nonce,
+        aad: Aad<A>,
+        in_out: &mut InOut,
+    ) -> Result<(), error::Unspecified>
+    where
+        A: AsRef<[u8]>,
+        InOut: AsMut<[u8]> + for<'in_out> Extend<&'in_out u8>,
+    {
+        self.key
+            .seal_in_place_append_tag(nonce, self.bound_aad(aad), in_out)
+    }
+
+    /// Like [`LessSafeKey::seal_in_place_separate_tag()`], except the
+    /// protocol identifier is prepended to `aad`.
+    #[inline]
+    pub fn seal_in_place_separate_tag<A>(
+        &self,
+        nonce: Nonce,
+        aad: Aad<A>,
+        in_out: &mut [u8],
+    ) -> Result<Tag, error::Unspecified>
+    where
+        A: AsRef<[u8]>,
+    {
+        self.key
+            .seal_in_place_separate_tag(nonce, self.bound_aad(aad), in_out)
+    }
+
+    /// The protocol identifier the key is bound to.
+    #[inline]
+    pub fn protocol_id(&self) -> u16 {
+        self.protocol_id
+    }
+
+    /// The key's AEAD algorithm.
+    #[inline]
+    pub fn algorithm(&self) -> &'static Algorithm {
+        self.key.algorithm()
+    }
+
+    fn bound_aad<A: AsRef<[u8]>>(&self, aad: Aad<A>) -> Aad<Vec<u8>> {
+        let aad = aad.as_ref();
+        let mut bound = Vec::with_capacity(2 + aad.len());
+        bound.extend_from_slice(&self.protocol_id.to_be_bytes());
+        bound.extend_from_slice(aad);
+        Aad::from(bound)
+    }
+}
+
+impl core::fmt::Debug for ProtocolBoundKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("ProtocolBoundKey")
+            .field("algorithm", &self.algorithm())
+            .field("protocol_id", &self.protocol_id)
+            .finish()
+    }
+}
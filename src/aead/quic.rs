@@ -16,10 +16,10 @@
 //!
 //! See draft-ietf-quic-tls.
 
-use crate::{
-    aead::{aes, chacha},
-    cpu, error, hkdf,
-};
+use crate::{aead::aes, cpu, error, hkdf};
+
+#[cfg(not(feature = "fips"))]
+use crate::aead::chacha;
 
 /// A key for generating QUIC Header Protection masks.
 pub struct HeaderProtectionKey {
@@ -30,6 +30,7 @@ pub struct HeaderProtectionKey {
 #[allow(clippy::large_enum_variant, variant_size_differences)]
 enum KeyInner {
     Aes(aes::Key),
+    #[cfg(not(feature = "fips"))]
     ChaCha20(chacha::Key),
 }
 
@@ -116,6 +117,7 @@ derive_debug_via_id!(Algorithm);
 enum AlgorithmID {
     AES_128,
     AES_256,
+    #[cfg(not(feature = "fips"))]
     CHACHA20,
 }
 
@@ -155,9 +157,11 @@ fn aes_init_256(key: &[u8], cpu_features: cpu::Features) -> Result<KeyInner, err
     Ok(KeyInner::Aes(aes_key))
 }
 
+#[cfg_attr(feature = "fips", allow(clippy::infallible_destructuring_match))]
 fn aes_new_mask(key: &KeyInner, sample: Sample) -> [u8; 5] {
     let aes_key = match key {
         KeyInner::Aes(key) => key,
+        #[cfg(not(feature = "fips"))]
         _ => unreachable!(),
     };
 
@@ -165,6 +169,7 @@ fn aes_new_mask(key: &KeyInner, sample: Sample) -> [u8; 5] {
 }
 
 /// ChaCha20.
+#[cfg(not(feature = "fips"))]
 pub static CHACHA20: Algorithm = Algorithm {
     key_len: chacha::KEY_LEN,
     init: chacha20_init,
@@ -172,11 +177,13 @@ pub static CHACHA20: Algorithm = Algorithm {
     id: AlgorithmID::CHACHA20,
 };
 
+#[cfg(not(feature = "fips"))]
 fn chacha20_init(key: &[u8], _cpu_features: cpu::Features) -> Result<KeyInner, error::Unspecified> {
     let chacha20_key: [u8; chacha::KEY_LEN] = key.try_into()?;
     Ok(KeyInner::ChaCha20(chacha::Key::new(chacha20_key)))
 }
 
+#[cfg(not(feature = "fips"))]
 fn chacha20_new_mask(key: &KeyInner, sample: Sample) -> [u8; 5] {
     let chacha20_key = match key {
         KeyInner::ChaCha20(key) => key,
@@ -0,0 +1,186 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+use super::{Aad, Algorithm, LessSafeKey, Nonce, Tag, MAX_KEY_LEN, NONCE_LEN};
+use crate::{cpu, error, hkdf};
+
+/// An AEAD key for sealing a sequence of records, where the key is ratcheted
+/// forward after every record.
+///
+/// After each record is sealed, the key is replaced with a one-way function
+/// of itself, and the old key is overwritten. Thus, if the current key is
+/// compromised, it cannot be used to decrypt or forge records that were
+/// sealed before it.
+///
+/// Each key seals exactly one record, so no nonce sequence is needed.
+///
+/// The `RatchetingOpeningKey` for the sequence must open the records in the
+/// same order they were sealed. If a record is lost or reordered, then every
+/// following record will fail to open, and there is no way to recover
+/// synchronization.
+///
+/// Intentionally not `Clone` or `Copy` since cloning would allow a key to be
+/// used for more than one record.
+pub struct RatchetingSealingKey {
+    ratchet: Ratchet,
+}
+
+impl RatchetingSealingKey {
+    /// Constructs a new `RatchetingSealingKey` with `key_bytes` as the key
+    /// for the first record.
+    ///
+    /// Fails if `key_bytes.len() != algorithm.key_len()`.
+    pub fn new(
+        algorithm: &'static Algorithm,
+        key_bytes: &[u8],
+    ) -> Result<Self, error::Unspecified> {
+        Ok(Self {
+            ratchet: Ratchet::new(algorithm, key_bytes)?,
+        })
+    }
+
+    /// Like [`super::SealingKey::seal_in_place_append_tag()`], except the
+    /// key is ratcheted forward afterwards.
+    pub fn seal_in_place_append_tag<A, InOut>(
+        &mut self,
+        aad: Aad<A>,
+        in_out: &mut InOut,
+    ) -> Result<(), error::Unspecified>
+    where
+        A: AsRef<[u8]>,
+        InOut: AsMut<[u8]> + for<'in_out> Extend<&'in_out u8>,
+    {
+        self.seal_in_place_separate_tag(aad, in_out.as_mut())
+            .map(|tag| in_out.extend(tag.as_ref()))
+    }
+
+    /// Like [`super::SealingKey::seal_in_place_separate_tag()`], except the
+    /// key is ratcheted forward afterwards.
+    pub fn seal_in_place_separate_tag<A>(
+        &mut self,
+        aad: Aad<A>,
+        in_out: &mut [u8],
+    ) -> Result<Tag, error::Unspecified>
+    where
+        A: AsRef<[u8]>,
+    {
+        let tag = self
+            .ratchet
+            .key
+            .seal_in_place_separate_tag(record_nonce(), aad, in_out)?;
+        self.ratchet.advance();
+        Ok(tag)
+    }
+
+    /// The key's AEAD algorithm.
+    #[inline]
+    pub fn algorithm(&self) -> &'static Algorithm {
+        self.ratchet.key.algorithm()
+    }
+}
+
+impl core::fmt::Debug for RatchetingSealingKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        self.ratchet.key.fmt_debug("RatchetingSealingKey", f)
+    }
+}
+
+/// An AEAD key for opening a sequence of records sealed with a
+/// `RatchetingSealingKey`.
+///
+/// The key is ratcheted forward after every record that is opened
+/// successfully; a record that fails to open leaves the key unchanged. See
+/// `RatchetingSealingKey` for details.
+pub struct RatchetingOpeningKey {
+    ratchet: Ratchet,
+}
+
+impl RatchetingOpeningKey {
+    /// Constructs a new `RatchetingOpeningKey` with `key_bytes` as the key
+    /// for the first record.
+    ///
+    /// Fails if `key_bytes.len() != algorithm.key_len()`.
+    pub fn new(
+        algorithm: &'static Algorithm,
+        key_bytes: &[u8],
+    ) -> Result<Self, error::Unspecified> {
+        Ok(Self {
+            ratchet: Ratchet::new(algorithm, key_bytes)?,
+        })
+    }
+
+    /// Like [`super::OpeningKey::open_in_place()`], except the key is
+    /// ratcheted forward afterwards if the record was opened successfully.
+    pub fn open_in_place<'in_out, A>(
+        &mut self,
+        aad: Aad<A>,
+        in_out: &'in_out mut [u8],
+    ) -> Result<&'in_out mut [u8], error::Unspecified>
+    where
+        A: AsRef<[u8]>,
+    {
+        let plaintext = self
+            .ratchet
+            .key
+            .open_in_place(record_nonce(), aad, in_out)?;
+        self.ratchet.advance();
+        Ok(plaintext)
+    }
+
+    /// The key's AEAD algorithm.
+    #[inline]
+    pub fn algorithm(&self) -> &'static Algorithm {
+        self.ratchet.key.algorithm()
+    }
+}
+
+impl core::fmt::Debug for RatchetingOpeningKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        self.ratchet.key.fmt_debug("RatchetingOpeningKey", f)
+    }
+}
+
+struct Ratchet {
+    key: LessSafeKey,
+    key_bytes: [u8; MAX_KEY_LEN],
+}
+
+impl Ratchet {
+    fn new(algorithm: &'static Algorithm, key_bytes: &[u8]) -> Result<Self, error::Unspecified> {
+        let key = LessSafeKey::new_(algorithm, key_bytes, cpu::features())?;
+        let mut r = Self {
+            key,
+            key_bytes: [0; MAX_KEY_LEN],
+        };
+        r.key_bytes[..key_bytes.len()].copy_from_slice(key_bytes);
+        Ok(r)
+    }
+
+    /// Replaces the key with HKDF-Expand(key, RATCHET_LABEL).
+    fn advance(&mut self) {
+        let algorithm = self.key.algorithm();
+        let key_bytes = &mut self.key_bytes[..algorithm.key_len()];
+        let prk = hkdf::Prk::new_less_safe(hkdf::HKDF_SHA256, key_bytes);
+        let okm = prk.expand(&[RATCHET_LABEL], algorithm).unwrap();
+        okm.fill(key_bytes).unwrap();
+        self.key = LessSafeKey::new_(algorithm, key_bytes, cpu::features()).unwrap();
+    }
+}
+
+const RATCHET_LABEL: &[u8] = b"ring aead ratchet";
+
+// Every key is used for exactly one record so a fixed nonce is fine.
+fn record_nonce() -> Nonce {
+    Nonce::assume_unique_for_key([0; NONCE_LEN])
+}
@@ -0,0 +1,114 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+use super::{Algorithm, UnboundKey, MAX_KEY_LEN};
+use crate::{constant_time, error, hkdf};
+
+/// An AEAD key from which new keys can be derived with
+/// [`rekey()`](Self::rekey).
+///
+/// Rekeying needs the raw key, so a `RekeyableKey` keeps a copy of it, which
+/// is erased when the `RekeyableKey` is dropped. An `UnboundKey` keeps only
+/// the expanded key, so prefer it when rekeying isn't needed.
+pub struct RekeyableKey {
+    algorithm: &'static Algorithm,
+    key_bytes: KeyBytes,
+}
+
+impl RekeyableKey {
+    /// Constructs a `RekeyableKey`.
+    ///
+    /// Fails if `key_bytes.len() != algorithm.key_len()`.
+    pub fn new(
+        algorithm: &'static Algorithm,
+        key_bytes: &[u8],
+    ) -> Result<Self, error::Unspecified> {
+        if key_bytes.len() != algorithm.key_len() {
+            return Err(error::Unspecified);
+        }
+        let mut r = Self {
+            algorithm,
+            key_bytes: KeyBytes([0; MAX_KEY_LEN]),
+        };
+        r.key_bytes.0[..key_bytes.len()].copy_from_slice(key_bytes);
+        Ok(r)
+    }
+
+    /// Derives a new key for the same algorithm from this key.
+    ///
+    /// The new key is HKDF-Expand(PRK, info, L) using HKDF-SHA256, where PRK
+    /// is this key, `info` is a fixed label followed by `context`, and `L`
+    /// is the algorithm's key length. The same key and `context` always give
+    /// the same new key, so both peers can rekey independently.
+    ///
+    /// The new key is a one-way function of this key. To get forward secrecy,
+    /// drop this key, and every `UnboundKey` made from it, once the new one
+    /// has been derived.
+    pub fn rekey(&self, context: &[u8]) -> Self {
+        let prk = hkdf::Prk::new_less_safe(hkdf::HKDF_SHA256, self.key_bytes());
+        let info = [REKEY_LABEL, context];
+        let okm = prk.expand(&info, self.algorithm).unwrap();
+        let mut r = Self {
+            algorithm: self.algorithm,
+            key_bytes: KeyBytes([0; MAX_KEY_LEN]),
+        };
+        okm.fill(&mut r.key_bytes.0[..self.algorithm.key_len()])
+            .unwrap();
+        r
+    }
+
+    /// Constructs an `UnboundKey` for the current key.
+    pub fn unbound_key(&self) -> UnboundKey {
+        UnboundKey::new(self.algorithm, self.key_bytes()).unwrap()
+    }
+
+    /// The key's AEAD algorithm.
+    #[inline]
+    pub fn algorithm(&self) -> &'static Algorithm {
+        self.algorithm
+    }
+
+    fn key_bytes(&self) -> &[u8] {
+        &self.key_bytes.0[..self.algorithm.key_len()]
+    }
+}
+
+impl core::fmt::Debug for RekeyableKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("RekeyableKey")
+            .field("algorithm", &self.algorithm)
+            .finish()
+    }
+}
+
+const REKEY_LABEL: &[u8] = b"ring aead rekey";
+
+struct KeyBytes([u8; MAX_KEY_LEN]);
+
+impl Drop for KeyBytes {
+    fn drop(&mut self) {
+        constant_time::secure_zero(&mut self.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_bytes_zeroed_on_drop() {
+        let key_bytes = KeyBytes([0xa5; MAX_KEY_LEN]);
+        assert!(constant_time::is_zeroed_after_drop(key_bytes));
+    }
+}
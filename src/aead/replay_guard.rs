@@ -0,0 +1,108 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+use super::{Aad, LessSafeKey, Nonce, NONCE_LEN, TAG_LEN};
+use crate::error;
+use alloc::collections::VecDeque;
+
+/// Rejects ciphertexts that have already been opened.
+///
+/// A `ReplayGuard` remembers the (nonce, tag) pairs of the most recently
+/// opened ciphertexts, up to a fixed capacity. `open_in_place` refuses to
+/// open a ciphertext whose (nonce, tag) pair is in the cache, so an attacker
+/// who captures a ciphertext can't get it accepted a second time.
+///
+/// Only ciphertexts that open successfully are recorded, so forgeries can't
+/// be used to evict legitimate entries. When the cache is full, the least
+/// recently used entry is evicted; a ciphertext whose entry has been evicted
+/// will be accepted again. Thus the capacity must be chosen to cover the
+/// window in which replays are a concern. Lookups are a linear search, so
+/// very large capacities are slow.
+pub struct ReplayGuard {
+    seen: VecDeque<[u8; NONCE_LEN + TAG_LEN]>,
+    capacity: usize,
+}
+
+impl ReplayGuard {
+    /// Constructs a new `ReplayGuard` that remembers up to `capacity`
+    /// ciphertexts.
+    ///
+    /// Fails if `capacity` is zero.
+    pub fn new(capacity: usize) -> Result<Self, error::Unspecified> {
+        if capacity == 0 {
+            return Err(error::Unspecified);
+        }
+        Ok(Self {
+            seen: VecDeque::new(),
+            capacity,
+        })
+    }
+
+    /// Like [`LessSafeKey::open_in_place()`], except it fails if a
+    /// ciphertext with the same nonce and tag has already been opened.
+    ///
+    /// When the ciphertext is rejected as a replay, `in_out` is left
+    /// unmodified.
+    pub fn open_in_place<'in_out, A>(
+        &mut self,
+        key: &LessSafeKey,
+        nonce: Nonce,
+        aad: Aad<A>,
+        in_out: &'in_out mut [u8],
+    ) -> Result<&'in_out mut [u8], error::Unspecified>
+    where
+        A: AsRef<[u8]>,
+    {
+        let tag_start = in_out
+            .len()
+            .checked_sub(TAG_LEN)
+            .ok_or(error::Unspecified)?;
+        let mut entry = [0u8; NONCE_LEN + TAG_LEN];
+        let (entry_nonce, entry_tag) = entry.split_at_mut(NONCE_LEN);
+        entry_nonce.copy_from_slice(nonce.as_ref());
+        entry_tag.copy_from_slice(&in_out[tag_start..]);
+
+        if let Some(i) = self.seen.iter().position(|seen| *seen == entry) {
+            // Mark the entry as recently used so that repeated replays keep
+            // it from being evicted.
+            if let Some(seen) = self.seen.remove(i) {
+                self.seen.push_back(seen);
+            }
+            return Err(error::Unspecified);
+        }
+
+        let plaintext = key.open_in_place(nonce, aad, in_out)?;
+
+        if self.seen.len() == self.capacity {
+            let _ = self.seen.pop_front();
+        }
+        self.seen.push_back(entry);
+
+        Ok(plaintext)
+    }
+
+    /// The maximum number of ciphertexts that are remembered.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+impl core::fmt::Debug for ReplayGuard {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("ReplayGuard")
+            .field("capacity", &self.capacity)
+            .finish()
+    }
+}
@@ -0,0 +1,73 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+use super::{Aad, LessSafeKey, Nonce, Tag};
+use crate::error;
+
+/// Collects the inputs of a seal operation in any order, then seals.
+///
+/// The AAD is always authenticated before the ciphertext, as the AEAD
+/// requires, regardless of whether `aad()` is called before or after
+/// `plaintext()`. Thus the tag is exactly the tag that
+/// `LessSafeKey::seal_in_place_separate_tag()` computes for the same inputs.
+/// This is convenient for callers that only know the AAD after they've
+/// built the plaintext.
+///
+/// If `aad()` isn't called then the AAD is empty; if `plaintext()` isn't
+/// called then the plaintext is empty.
+#[must_use]
+pub struct SealBuilder<'a> {
+    key: &'a LessSafeKey,
+    nonce: Nonce,
+    aad: &'a [u8],
+    in_out: &'a mut [u8],
+}
+
+impl<'a> SealBuilder<'a> {
+    /// Starts building a seal operation using `key` and `nonce`.
+    ///
+    /// `nonce` must be unique for every use of the key to seal data.
+    pub fn new(key: &'a LessSafeKey, nonce: Nonce) -> Self {
+        Self {
+            key,
+            nonce,
+            aad: &[],
+            in_out: &mut [],
+        }
+    }
+
+    /// Sets the plaintext, which `seal()` encrypts in place.
+    pub fn plaintext(self, in_out: &'a mut [u8]) -> Self {
+        Self { in_out, ..self }
+    }
+
+    /// Sets the additional authenticated data.
+    pub fn aad(self, aad: &'a [u8]) -> Self {
+        Self { aad, ..self }
+    }
+
+    /// Encrypts the plaintext in place and returns the tag.
+    pub fn seal(self) -> Result<Tag, error::Unspecified> {
+        self.key
+            .seal_in_place_separate_tag(self.nonce, Aad::from(self.aad), self.in_out)
+    }
+}
+
+impl core::fmt::Debug for SealBuilder<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("SealBuilder")
+            .field("algorithm", self.key.algorithm())
+            .finish()
+    }
+}
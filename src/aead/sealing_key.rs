@@ -21,7 +21,9 @@
 //! [AEAD]: https://eprint.iacr.org/2000/025.pdf
 //! [`crypto.cipher.AEAD`]: https://golang.org/pkg/crypto/cipher/#AEAD
 
-use super::{Aad, Algorithm, BoundKey, LessSafeKey, NonceSequence, Tag, UnboundKey};
+use super::{
+    Aad, Algorithm, BoundKey, LessSafeKey, Nonce, NonceSequence, Tag, UnboundKey, NONCE_LEN,
+};
 use crate::error;
 
 /// An AEAD key for encrypting and signing ("sealing"), bound to a nonce
@@ -29,16 +31,18 @@ use crate::error;
 ///
 /// Intentionally not `Clone` or `Copy` since cloning would allow duplication
 /// of the nonce sequence.
-pub struct SealingKey<N: NonceSequence> {
-    key: LessSafeKey,
+pub struct SealingKey<N: NonceSequence<L>, const L: usize = NONCE_LEN> {
+    key: LessSafeKey<L>,
     nonce_sequence: N,
+    reject_trivial_nonces: bool,
 }
 
-impl<N: NonceSequence> BoundKey<N> for SealingKey<N> {
-    fn new(key: UnboundKey, nonce_sequence: N) -> Self {
+impl<N: NonceSequence<L>, const L: usize> BoundKey<N, L> for SealingKey<N, L> {
+    fn new(key: UnboundKey<L>, nonce_sequence: N) -> Self {
         Self {
             key: key.into_inner(),
             nonce_sequence,
+            reject_trivial_nonces: false,
         }
     }
 
@@ -48,13 +52,55 @@ impl<N: NonceSequence> BoundKey<N> for SealingKey<N> {
     }
 }
 
-impl<N: NonceSequence> core::fmt::Debug for SealingKey<N> {
+impl<N: NonceSequence<L>, const L: usize> core::fmt::Debug for SealingKey<N, L> {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
         self.key.fmt_debug("SealingKey", f)
     }
 }
 
-impl<N: NonceSequence> SealingKey<N> {
+impl<N: NonceSequence<L>, const L: usize> SealingKey<N, L> {
+    /// Makes every sealing operation fail if the nonce sequence produces an
+    /// all-zero nonce.
+    ///
+    /// An all-zero nonce is a valid nonce, but it is also what a buggy
+    /// `NonceSequence` is likely to produce, e.g. one that never got
+    /// initialized or that always returns the same default value. This is a
+    /// debugging aid that catches such mistakes early; it doesn't detect nonce
+    /// reuse in general. Don't enable it for a nonce sequence that
+    /// legitimately starts at zero, such as a counter.
+    ///
+    /// ```
+    /// use ring::aead::{self, BoundKey};
+    ///
+    /// struct ZeroNonce;
+    /// impl aead::NonceSequence for ZeroNonce {
+    ///     fn advance(&mut self) -> Result<aead::Nonce, ring::error::Unspecified> {
+    ///         Ok(aead::Nonce::assume_unique_for_key([0; aead::NONCE_LEN]))
+    ///     }
+    /// }
+    ///
+    /// let key = aead::UnboundKey::new(&aead::AES_128_GCM, &[1; 16]).unwrap();
+    /// let mut key = aead::SealingKey::new(key, ZeroNonce).reject_trivial_nonces();
+    /// let mut in_out = [0u8; 4];
+    /// assert!(key
+    ///     .seal_in_place_separate_tag(aead::Aad::empty(), &mut in_out)
+    ///     .is_err());
+    /// ```
+    pub fn reject_trivial_nonces(self) -> Self {
+        Self {
+            reject_trivial_nonces: true,
+            ..self
+        }
+    }
+
+    fn advance(&mut self) -> Result<Nonce<L>, error::Unspecified> {
+        let nonce = self.nonce_sequence.advance()?;
+        if self.reject_trivial_nonces && nonce.as_ref().iter().all(|&b| b == 0) {
+            return Err(error::Unspecified);
+        }
+        Ok(nonce)
+    }
+
     /// Encrypts and signs (“seals”) data in place, appending the tag to the
     /// resulting ciphertext.
     ///
@@ -64,6 +110,11 @@ impl<N: NonceSequence> SealingKey<N> {
     /// key.seal_in_place_separate_tag(aad, in_out.as_mut())
     ///     .map(|tag| in_out.extend(tag.as_ref()))
     /// ```
+    ///
+    /// When `in_out` is a `Vec<u8>`, appending the tag reallocates it at
+    /// most once, and not at all if it has enough spare capacity for the
+    /// tag. To avoid the allocation, reserve the space beforehand, e.g. with
+    /// `in_out.reserve(key.algorithm().tag_len())`.
     #[inline]
     pub fn seal_in_place_append_tag<A, InOut>(
         &mut self,
@@ -74,8 +125,8 @@ impl<N: NonceSequence> SealingKey<N> {
         A: AsRef<[u8]>,
         InOut: AsMut<[u8]> + for<'in_out> Extend<&'in_out u8>,
     {
-        self.key
-            .seal_in_place_append_tag(self.nonce_sequence.advance()?, aad, in_out)
+        let nonce = self.advance()?;
+        self.key.seal_in_place_append_tag(nonce, aad, in_out)
     }
 
     /// Encrypts and signs (“seals”) data in place.
@@ -98,7 +149,38 @@ impl<N: NonceSequence> SealingKey<N> {
     where
         A: AsRef<[u8]>,
     {
-        self.key
-            .seal_in_place_separate_tag(self.nonce_sequence.advance()?, aad, in_out)
+        let nonce = self.advance()?;
+        self.key.seal_in_place_separate_tag(nonce, aad, in_out)
+    }
+
+    /// Encrypts and signs (“seals”) data in place, and then appends the
+    /// ciphertext followed by the tag to `out`.
+    ///
+    /// `out` can be any sink for bytes, such as the transmit buffer of a
+    /// network connection; e.g. a `VecDeque<u8>` used as a ring buffer. This
+    /// avoids assembling the ciphertext and tag in an intermediate buffer.
+    /// Nothing is written to `out` if sealing fails.
+    ///
+    /// `key.seal_in_place_into(aad, in_out, out)` is equivalent to:
+    ///
+    /// ```skip
+    /// key.seal_in_place_separate_tag(aad, in_out).map(|tag| {
+    ///     out.extend(in_out.iter());
+    ///     out.extend(tag.as_ref());
+    /// })
+    /// ```
+    #[inline]
+    pub fn seal_in_place_into<A, Out>(
+        &mut self,
+        aad: Aad<A>,
+        in_out: &mut [u8],
+        out: &mut Out,
+    ) -> Result<(), error::Unspecified>
+    where
+        A: AsRef<[u8]>,
+        Out: for<'in_out> Extend<&'in_out u8>,
+    {
+        let nonce = self.advance()?;
+        self.key.seal_in_place_into(nonce, aad, in_out, out)
     }
 }
@@ -0,0 +1,171 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Known-answer tests of the AEAD building blocks, for `integrity`.
+
+use super::{
+    aes::{self, KeyBytes},
+    gcm::{DynKey, UpdateBlocks, Xi},
+    Aad, LessSafeKey, Nonce, UnboundKey, AES_128_GCM,
+};
+use crate::{cpu, error};
+
+/// Checks every AES and GHASH implementation that is available on this CPU,
+/// and then AES-128-GCM and ChaCha20-Poly1305, against known answers.
+///
+/// ChaCha20-Poly1305 isn't checked when the `fips` feature is enabled, since
+/// it isn't available then.
+pub(crate) fn self_test() -> Result<(), error::Unspecified> {
+    let cpu_features = cpu::features();
+
+    // FIPS-197 Appendix C.1 and C.3.
+    const PLAINTEXT: aes::Block = [
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee,
+        0xff,
+    ];
+    let mut key = [0u8; aes::AES_256_KEY_LEN];
+    key.iter_mut()
+        .enumerate()
+        .for_each(|(i, b)| *b = u8::try_from(i).unwrap());
+    let aes_128_key: &[u8; aes::AES_128_KEY_LEN] = key[..aes::AES_128_KEY_LEN].try_into().unwrap();
+    let vectors: [(KeyBytes, aes::Block); 2] = [
+        (
+            KeyBytes::AES_128(aes_128_key),
+            [
+                0x69, 0xc4, 0xe0, 0xd8, 0x6a, 0x7b, 0x04, 0x30, 0xd8, 0xcd, 0xb7, 0x80, 0x70, 0xb4,
+                0xc5, 0x5a,
+            ],
+        ),
+        (
+            KeyBytes::AES_256(&key),
+            [
+                0x8e, 0xa2, 0xb7, 0xca, 0x51, 0x67, 0x45, 0xbf, 0xea, 0xfc, 0x49, 0x90, 0x4b, 0x49,
+                0x60, 0x89,
+            ],
+        ),
+    ];
+    for (key_bytes, expected) in vectors {
+        let mut ok = true;
+        aes::Key::for_each_implementation(key_bytes, cpu_features, |key| {
+            ok &= key.encrypt_block(PLAINTEXT) == expected;
+        })?;
+        if !ok {
+            return Err(error::Unspecified);
+        }
+    }
+
+    // GHASH(H, {}, C) from Test Case 2 of "The Galois/Counter Mode of
+    // Operation (GCM)", whose H is the encryption of the zero block under the
+    // zero key.
+    const H: aes::Block = [
+        0x66, 0xe9, 0x4b, 0xd4, 0xef, 0x8a, 0x2c, 0x3b, 0x88, 0x4c, 0xfa, 0x59, 0xca, 0x34, 0x2b,
+        0x2e,
+    ];
+    const GHASH_INPUT: [aes::Block; 2] = [
+        [
+            0x03, 0x88, 0xda, 0xce, 0x60, 0xb6, 0xa3, 0x92, 0xf3, 0x28, 0xc2, 0xb9, 0x71, 0xb2,
+            0xfe, 0x78,
+        ],
+        // len(A) || len(C), in bits.
+        [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x80],
+    ];
+    const GHASH_OUTPUT: aes::Block = [
+        0xf3, 0x8c, 0xbb, 0x1a, 0xd6, 0x92, 0x23, 0xdc, 0xc3, 0x45, 0x7a, 0xe5, 0xb6, 0xb0, 0xf8,
+        0x85,
+    ];
+    let mut ok = true;
+    DynKey::for_each_implementation(H, |key| {
+        let mut xi = Xi::new();
+        key.update_blocks(&mut xi, &GHASH_INPUT);
+        ok &= xi.into_block() == GHASH_OUTPUT;
+    });
+    if !ok {
+        return Err(error::Unspecified);
+    }
+
+    // Test Case 2 of "The Galois/Counter Mode of Operation (GCM)".
+    check_aead(
+        UnboundKey::new(&AES_128_GCM, &[0; 16])?,
+        [0; 12],
+        &[],
+        &[0; 16],
+        &[
+            0x03, 0x88, 0xda, 0xce, 0x60, 0xb6, 0xa3, 0x92, 0xf3, 0x28, 0xc2, 0xb9, 0x71, 0xb2,
+            0xfe, 0x78, 0xab, 0x6e, 0x47, 0xd4, 0x2c, 0xec, 0x13, 0xbd, 0xf5, 0x3a, 0x67, 0xb2,
+            0x12, 0x57, 0xbd, 0xdf,
+        ],
+    )?;
+
+    #[cfg(not(feature = "fips"))]
+    {
+        use super::CHACHA20_POLY1305;
+
+        // The first 32 bytes of the plaintext of RFC 8439 Section 2.8.2, with
+        // the key, nonce, and AAD of that example.
+        let mut key = [0u8; 32];
+        key.iter_mut()
+            .enumerate()
+            .for_each(|(i, b)| *b = 0x80 + u8::try_from(i).unwrap());
+        check_aead(
+            UnboundKey::new(&CHACHA20_POLY1305, &key)?,
+            [
+                0x07, 0x00, 0x00, 0x00, 0x40, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47,
+            ],
+            &[
+                0x50, 0x51, 0x52, 0x53, 0xc0, 0xc1, 0xc2, 0xc3, 0xc4, 0xc5, 0xc6, 0xc7,
+            ],
+            b"Ladies and Gentlemen of the clas",
+            &[
+                0xd3, 0x1a, 0x8d, 0x34, 0x64, 0x8e, 0x60, 0xdb, 0x7b, 0x86, 0xaf, 0xbc, 0x53, 0xef,
+                0x7e, 0xc2, 0xa4, 0xad, 0xed, 0x51, 0x29, 0x6e, 0x08, 0xfe, 0xa9, 0xe2, 0xb5, 0xa7,
+                0x36, 0xee, 0x62, 0xd6, 0xe3, 0xd0, 0x49, 0x61, 0x73, 0x93, 0x7e, 0x24, 0x0f, 0xd6,
+                0xa6, 0x79, 0x6b, 0xc6, 0x0b, 0x16,
+            ],
+        )?;
+    }
+
+    Ok(())
+}
+
+// `expected` is the ciphertext followed by the tag. `plaintext` must be at
+// most 32 bytes long.
+fn check_aead(
+    key: UnboundKey,
+    nonce: [u8; 12],
+    aad: &[u8],
+    plaintext: &[u8],
+    expected: &[u8],
+) -> Result<(), error::Unspecified> {
+    let key = LessSafeKey::new(key);
+    let mut buf = [0u8; 32 + super::MAX_TAG_LEN];
+    let (in_out, tag_out) = buf.split_at_mut(plaintext.len());
+    in_out.copy_from_slice(plaintext);
+    let tag = key.seal_in_place_separate_tag(
+        Nonce::assume_unique_for_key(nonce),
+        Aad::from(aad),
+        in_out,
+    )?;
+    let tag = tag.as_ref();
+    tag_out[..tag.len()].copy_from_slice(tag);
+    let sealed = &mut buf[..(plaintext.len() + tag.len())];
+    if sealed != expected {
+        return Err(error::Unspecified);
+    }
+
+    let opened = key.open_in_place(Nonce::assume_unique_for_key(nonce), Aad::from(aad), sealed)?;
+    if opened != plaintext {
+        return Err(error::Unspecified);
+    }
+    Ok(())
+}
@@ -0,0 +1,231 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+use super::{Aad, Algorithm, LessSafeKey, Nonce, NONCE_LEN, TAG_LEN};
+use crate::{cpu, error};
+
+/// An AEAD key for sealing a long message as a sequence of fixed-size
+/// segments, without holding the whole message in memory.
+///
+/// Each segment is sealed with its own nonce, derived from a base nonce and
+/// the segment's index, and the last segment is marked as such, so that
+/// segments can't be reordered, dropped, or truncated without opening
+/// failing.
+///
+/// # Framing
+///
+/// The sealed stream is the concatenation of the sealed segments, with no
+/// other framing. Segment `i` (counting from 0) is
+/// `ciphertext_i || tag_i`, where:
+///
+/// * `ciphertext_i` is exactly `segment_len` bytes long for every segment
+///   but the last, which may be anywhere from 0 to `segment_len` bytes long.
+///   There is always a last segment, even if the message is empty.
+/// * The nonce is `base_nonce XOR (0x00000000 || [i]_64)`, where `[i]_64` is
+///   `i` as a 64-bit big-endian integer.
+/// * The associated data is the single byte `0x00` for every segment but
+///   the last, and `0x01` for the last segment.
+/// * `tag_i` is `algorithm.tag_len()` bytes long.
+///
+/// A decoder reads `segment_len + tag_len` bytes at a time; the segment that
+/// is followed by the end of the stream is the last one, even if it is full
+/// length.
+///
+/// The base nonce must be unique for the key, as for any other nonce; e.g. a
+/// random nonce for every stream, stored in the stream's header.
+pub struct StreamingSealingKey {
+    stream: Stream,
+}
+
+impl StreamingSealingKey {
+    /// Constructs a new `StreamingSealingKey`.
+    ///
+    /// Fails if `key_bytes.len() != algorithm.key_len()`, or if
+    /// `segment_len` is zero or longer than the algorithm can seal at once.
+    pub fn new(
+        algorithm: &'static Algorithm,
+        key_bytes: &[u8],
+        base_nonce: Nonce,
+        segment_len: usize,
+    ) -> Result<Self, error::Unspecified> {
+        Ok(Self {
+            stream: Stream::new(algorithm, key_bytes, base_nonce, segment_len)?,
+        })
+    }
+
+    /// Seals a segment other than the last, appending its tag.
+    ///
+    /// `in_out` must be exactly `segment_len` bytes long on input; otherwise
+    /// this fails without modifying it.
+    pub fn seal_chunk<InOut>(&mut self, in_out: &mut InOut) -> Result<(), error::Unspecified>
+    where
+        InOut: AsMut<[u8]> + for<'in_out> Extend<&'in_out u8>,
+    {
+        if in_out.as_mut().len() != self.stream.segment_len {
+            return Err(error::Unspecified);
+        }
+        let nonce = self.stream.next_nonce()?;
+        self.stream
+            .key
+            .seal_in_place_append_tag(nonce, Aad::from(NOT_LAST), in_out)
+    }
+
+    /// Seals the last segment, appending its tag.
+    ///
+    /// `in_out` must be at most `segment_len` bytes long on input; otherwise
+    /// this fails without modifying it.
+    pub fn seal_last_chunk<InOut>(mut self, in_out: &mut InOut) -> Result<(), error::Unspecified>
+    where
+        InOut: AsMut<[u8]> + for<'in_out> Extend<&'in_out u8>,
+    {
+        if in_out.as_mut().len() > self.stream.segment_len {
+            return Err(error::Unspecified);
+        }
+        let nonce = self.stream.next_nonce()?;
+        self.stream
+            .key
+            .seal_in_place_append_tag(nonce, Aad::from(LAST), in_out)
+    }
+
+    /// The key's AEAD algorithm.
+    #[inline]
+    pub fn algorithm(&self) -> &'static Algorithm {
+        self.stream.key.algorithm()
+    }
+}
+
+impl core::fmt::Debug for StreamingSealingKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        self.stream.key.fmt_debug("StreamingSealingKey", f)
+    }
+}
+
+/// An AEAD key for opening a stream sealed with a `StreamingSealingKey`.
+///
+/// See `StreamingSealingKey` for the framing. A segment that fails to open
+/// leaves the key unchanged.
+pub struct StreamingOpeningKey {
+    stream: Stream,
+}
+
+impl StreamingOpeningKey {
+    /// Constructs a new `StreamingOpeningKey`.
+    ///
+    /// The parameters must match those of the `StreamingSealingKey` that
+    /// sealed the stream. Fails under the same conditions as
+    /// `StreamingSealingKey::new`.
+    pub fn new(
+        algorithm: &'static Algorithm,
+        key_bytes: &[u8],
+        base_nonce: Nonce,
+        segment_len: usize,
+    ) -> Result<Self, error::Unspecified> {
+        Ok(Self {
+            stream: Stream::new(algorithm, key_bytes, base_nonce, segment_len)?,
+        })
+    }
+
+    /// Opens a sealed segment other than the last, returning the plaintext.
+    ///
+    /// `in_out` must be exactly `segment_len + tag_len` bytes long.
+    pub fn open_chunk<'in_out>(
+        &mut self,
+        in_out: &'in_out mut [u8],
+    ) -> Result<&'in_out mut [u8], error::Unspecified> {
+        if in_out.len() != self.stream.segment_len + TAG_LEN {
+            return Err(error::Unspecified);
+        }
+        self.stream.open(NOT_LAST, in_out)
+    }
+
+    /// Opens the last sealed segment, returning the plaintext.
+    ///
+    /// `in_out` must be at most `segment_len + tag_len` bytes long.
+    pub fn open_last_chunk(mut self, in_out: &mut [u8]) -> Result<&mut [u8], error::Unspecified> {
+        if in_out.len() > self.stream.segment_len + TAG_LEN {
+            return Err(error::Unspecified);
+        }
+        self.stream.open(LAST, in_out)
+    }
+
+    /// The key's AEAD algorithm.
+    #[inline]
+    pub fn algorithm(&self) -> &'static Algorithm {
+        self.stream.key.algorithm()
+    }
+}
+
+impl core::fmt::Debug for StreamingOpeningKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        self.stream.key.fmt_debug("StreamingOpeningKey", f)
+    }
+}
+
+const NOT_LAST: [u8; 1] = [0x00];
+const LAST: [u8; 1] = [0x01];
+
+struct Stream {
+    key: LessSafeKey,
+    base_nonce: [u8; NONCE_LEN],
+    segment_len: usize,
+    index: u64,
+}
+
+impl Stream {
+    fn new(
+        algorithm: &'static Algorithm,
+        key_bytes: &[u8],
+        base_nonce: Nonce,
+        segment_len: usize,
+    ) -> Result<Self, error::Unspecified> {
+        if segment_len == 0 || segment_len > algorithm.max_input_len() {
+            return Err(error::Unspecified);
+        }
+        Ok(Self {
+            key: LessSafeKey::new_(algorithm, key_bytes, cpu::features())?,
+            base_nonce: *base_nonce.as_ref(),
+            segment_len,
+            index: 0,
+        })
+    }
+
+    /// The nonce for the current segment. The index is advanced, so it is
+    /// never reused even if sealing fails.
+    fn next_nonce(&mut self) -> Result<Nonce, error::Unspecified> {
+        let nonce = self.nonce();
+        self.index = self.index.checked_add(1).ok_or(error::Unspecified)?;
+        Ok(nonce)
+    }
+
+    fn nonce(&self) -> Nonce {
+        let mut nonce = self.base_nonce;
+        nonce[(NONCE_LEN - 8)..]
+            .iter_mut()
+            .zip(self.index.to_be_bytes())
+            .for_each(|(n, i)| *n ^= i);
+        Nonce::assume_unique_for_key(nonce)
+    }
+
+    fn open<'in_out>(
+        &mut self,
+        aad: [u8; 1],
+        in_out: &'in_out mut [u8],
+    ) -> Result<&'in_out mut [u8], error::Unspecified> {
+        let plaintext = self
+            .key
+            .open_in_place(self.nonce(), Aad::from(aad), in_out)?;
+        self.index = self.index.checked_add(1).ok_or(error::Unspecified)?;
+        Ok(plaintext)
+    }
+}
@@ -0,0 +1,148 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Diagnostics for validating a build of *ring* on a particular target.
+
+use super::{aes_gcm, Aad, LessSafeKey, Nonce, AES_128_GCM};
+use crate::cpu;
+
+/// Checks that AES-GCM, as implemented for the current CPU, gives the
+/// expected results for a known-answer test.
+///
+/// This is a lightweight power-on self-test for integrators who are
+/// validating a build on unusual hardware. It runs Test Case 4 from the
+/// original GCM specification through whichever AES and GHASH
+/// implementations would be used for a new key. On failure, the error names
+/// the step that produced the wrong result.
+pub fn self_check_gcm() -> Result<(), &'static str> {
+    check(&GCM_TEST_CASE_4)
+}
+
+struct KnownAnswer {
+    key: [u8; 16],
+    h: [u8; 16],
+    nonce: [u8; 12],
+    aad: &'static [u8],
+    plaintext: &'static [u8],
+    ciphertext: &'static [u8],
+    tag: [u8; 16],
+}
+
+static GCM_TEST_CASE_4: KnownAnswer = KnownAnswer {
+    key: [
+        0xfe, 0xff, 0xe9, 0x92, 0x86, 0x65, 0x73, 0x1c, 0x6d, 0x6a, 0x8f, 0x94, 0x67, 0x30, 0x83,
+        0x08,
+    ],
+    h: [
+        0xb8, 0x3b, 0x53, 0x37, 0x08, 0xbf, 0x53, 0x5d, 0x0a, 0xa6, 0xe5, 0x29, 0x80, 0xd5, 0x3b,
+        0x78,
+    ],
+    nonce: [
+        0xca, 0xfe, 0xba, 0xbe, 0xfa, 0xce, 0xdb, 0xad, 0xde, 0xca, 0xf8, 0x88,
+    ],
+    aad: &[
+        0xfe, 0xed, 0xfa, 0xce, 0xde, 0xad, 0xbe, 0xef, 0xfe, 0xed, 0xfa, 0xce, 0xde, 0xad, 0xbe,
+        0xef, 0xab, 0xad, 0xda, 0xd2,
+    ],
+    plaintext: &[
+        0xd9, 0x31, 0x32, 0x25, 0xf8, 0x84, 0x06, 0xe5, 0xa5, 0x59, 0x09, 0xc5, 0xaf, 0xf5, 0x26,
+        0x9a, 0x86, 0xa7, 0xa9, 0x53, 0x15, 0x34, 0xf7, 0xda, 0x2e, 0x4c, 0x30, 0x3d, 0x8a, 0x31,
+        0x8a, 0x72, 0x1c, 0x3c, 0x0c, 0x95, 0x95, 0x68, 0x09, 0x53, 0x2f, 0xcf, 0x0e, 0x24, 0x49,
+        0xa6, 0xb5, 0x25, 0xb1, 0x6a, 0xed, 0xf5, 0xaa, 0x0d, 0xe6, 0x57, 0xba, 0x63, 0x7b, 0x39,
+    ],
+    ciphertext: &[
+        0x42, 0x83, 0x1e, 0xc2, 0x21, 0x77, 0x74, 0x24, 0x4b, 0x72, 0x21, 0xb7, 0x84, 0xd0, 0xd4,
+        0x9c, 0xe3, 0xaa, 0x21, 0x2f, 0x2c, 0x02, 0xa4, 0xe0, 0x35, 0xc1, 0x7e, 0x23, 0x29, 0xac,
+        0xa1, 0x2e, 0x21, 0xd5, 0x14, 0xb2, 0x54, 0x66, 0x93, 0x1c, 0x7d, 0x8f, 0x6a, 0x5a, 0xac,
+        0x84, 0xaa, 0x05, 0x1b, 0xa3, 0x0b, 0x39, 0x6a, 0x0a, 0xac, 0x97, 0x3d, 0x58, 0xe0, 0x91,
+    ],
+    tag: [
+        0x5b, 0xc9, 0x4f, 0xbc, 0x32, 0x21, 0xa5, 0xdb, 0x94, 0xfa, 0xe9, 0x5a, 0xe7, 0x12, 0x1a,
+        0x47,
+    ],
+};
+
+const MAX_PLAINTEXT_LEN: usize = 64;
+
+fn check(ka: &KnownAnswer) -> Result<(), &'static str> {
+    let key =
+        LessSafeKey::new_(&AES_128_GCM, &ka.key, cpu::features()).map_err(|_| "key schedule")?;
+    let nonce = || Nonce::assume_unique_for_key(ka.nonce);
+
+    let gcm_key = key.aes_gcm_key().map_err(|_| "key schedule")?;
+    if aes_gcm::hash_subkey(gcm_key) != ka.h {
+        return Err("hash subkey");
+    }
+
+    let mut buffer = [0u8; MAX_PLAINTEXT_LEN];
+    let in_out = &mut buffer[..ka.plaintext.len()];
+    in_out.copy_from_slice(ka.plaintext);
+    let tag = key
+        .seal_in_place_separate_tag(nonce(), Aad::from(ka.aad), in_out)
+        .map_err(|_| "seal")?;
+    if in_out != ka.ciphertext {
+        return Err("seal ciphertext");
+    }
+    if tag.as_ref() != ka.tag {
+        return Err("seal tag");
+    }
+
+    let mut forged_tag = ka.tag;
+    forged_tag[0] ^= 1;
+    if key
+        .open_in_place_separate_tag(nonce(), Aad::from(ka.aad), forged_tag.into(), in_out, 0..)
+        .is_ok()
+    {
+        return Err("open forged tag");
+    }
+
+    in_out.copy_from_slice(ka.ciphertext);
+    let plaintext = key
+        .open_in_place_separate_tag(nonce(), Aad::from(ka.aad), ka.tag.into(), in_out, 0..)
+        .map_err(|_| "open tag")?;
+    if plaintext != ka.plaintext {
+        return Err("open plaintext");
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_self_check_gcm() {
+        assert_eq!(self_check_gcm(), Ok(()));
+    }
+
+    #[test]
+    fn test_self_check_gcm_detects_wrong_results() {
+        let mut h = GCM_TEST_CASE_4.h;
+        h[15] ^= 0x80;
+        let wrong_h = KnownAnswer {
+            h,
+            ..GCM_TEST_CASE_4
+        };
+        assert_eq!(check(&wrong_h), Err("hash subkey"));
+
+        let mut tag = GCM_TEST_CASE_4.tag;
+        tag[0] ^= 1;
+        let wrong_tag = KnownAnswer {
+            tag,
+            ..GCM_TEST_CASE_4
+        };
+        assert_eq!(check(&wrong_tag), Err("seal tag"));
+    }
+}
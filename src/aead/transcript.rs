@@ -0,0 +1,75 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+use super::Aad;
+use crate::digest;
+
+/// The length of a transcript hash.
+pub const TRANSCRIPT_LEN: usize = 32;
+
+/// A running hash of a sequence of AEAD messages, for binding each message to
+/// all the messages before it.
+///
+/// Both the sender and the recipient keep a `Transcript`. Each message is
+/// sealed (opened) with `transcript.aad()` as its additional data, and then
+/// the sealed message, i.e. the ciphertext followed by the tag, is added to
+/// the transcript with `update()`. A message then only opens if the
+/// recipient saw exactly the same messages, in the same order, as the sender
+/// did before sealing it, which prevents messages from being reordered,
+/// dropped, or spliced in from another session.
+///
+/// The hash is SHA-256, chained so that the new state is the hash of the
+/// previous state followed by the message.
+#[derive(Clone)]
+pub struct Transcript {
+    state: [u8; TRANSCRIPT_LEN],
+}
+
+impl Transcript {
+    /// Constructs a new, empty transcript.
+    pub fn new() -> Self {
+        let mut state = [0u8; TRANSCRIPT_LEN];
+        state.copy_from_slice(digest::digest(&digest::SHA256, TRANSCRIPT_LABEL).as_ref());
+        Self { state }
+    }
+
+    /// Adds a sealed message, i.e. its ciphertext followed by its tag, to the
+    /// transcript.
+    pub fn update(&mut self, ciphertext_and_tag: &[u8]) {
+        let mut ctx = digest::Context::new(&digest::SHA256);
+        ctx.update(&self.state);
+        ctx.update(ciphertext_and_tag);
+        self.state.copy_from_slice(ctx.finish().as_ref());
+    }
+
+    /// The additional data to use for the next message.
+    #[inline]
+    pub fn aad(&self) -> Aad<[u8; TRANSCRIPT_LEN]> {
+        Aad::from(self.state)
+    }
+}
+
+impl Default for Transcript {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl core::fmt::Debug for Transcript {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("Transcript").finish()
+    }
+}
+
+const TRANSCRIPT_LABEL: &[u8] = b"ring aead transcript";
@@ -21,22 +21,75 @@
 //! [AEAD]: https://eprint.iacr.org/2000/025.pdf
 //! [`crypto.cipher.AEAD`]: https://golang.org/pkg/crypto/cipher/#AEAD
 
-use super::{Algorithm, LessSafeKey, MAX_KEY_LEN};
-use crate::{cpu, error, hkdf};
+use super::{Algorithm, LessSafeKey, MAX_KEY_LEN, NONCE_LEN};
+use crate::{constant_time, cpu, error, hkdf};
 
 /// An AEAD key without a designated role or nonce sequence.
-pub struct UnboundKey {
-    inner: LessSafeKey,
+///
+/// `L` is the length of the key's nonces; see `Algorithm::nonce_len()`.
+pub struct UnboundKey<const L: usize = NONCE_LEN> {
+    inner: LessSafeKey<L>,
 }
 
 impl UnboundKey {
     /// Constructs a `UnboundKey`.
     ///
-    /// Fails if `key_bytes.len() != algorithm.key_len()`.
+    /// Fails if `key_bytes.len() != algorithm.key_len()`, or if `algorithm`'s
+    /// nonces aren't `NONCE_LEN` bytes long; use
+    /// [`new_of_len()`](Self::new_of_len) for those algorithms.
     #[inline]
     pub fn new(
         algorithm: &'static Algorithm,
         key_bytes: &[u8],
+    ) -> Result<Self, error::Unspecified> {
+        Self::new_of_len(algorithm, key_bytes)
+    }
+
+    /// Constructs a `UnboundKey` from the output of an HKDF expansion.
+    ///
+    /// The output is written to a temporary buffer that is only used to
+    /// compute the key schedule, and is then erased with
+    /// `constant_time::secure_zero`; the caller never handles the raw key
+    /// bytes.
+    ///
+    /// Fails if `okm.len().len() != algorithm.key_len()`.
+    pub fn from_derived<L: hkdf::KeyType>(
+        algorithm: &'static Algorithm,
+        okm: hkdf::Okm<L>,
+    ) -> Result<Self, error::Unspecified> {
+        let key_len = algorithm.key_len();
+        if okm.len().len() != key_len {
+            return Err(error::Unspecified);
+        }
+        let mut key_bytes = [0; MAX_KEY_LEN];
+        let key_bytes = &mut key_bytes[..key_len];
+        let result = okm
+            .fill(key_bytes)
+            .and_then(|()| Self::new(algorithm, key_bytes));
+        constant_time::secure_zero(key_bytes);
+        result
+    }
+}
+
+impl<const L: usize> UnboundKey<L> {
+    /// Constructs a `UnboundKey` for an algorithm with `L`-byte nonces.
+    ///
+    /// Fails if `key_bytes.len() != algorithm.key_len()` or if
+    /// `algorithm.nonce_len() != L`.
+    ///
+    /// ```
+    /// use ring::aead::{self, xchacha20_poly1305, UnboundKey};
+    ///
+    /// let key = UnboundKey::<{ xchacha20_poly1305::NONCE_LEN }>::new_of_len(
+    ///     &aead::XCHACHA20_POLY1305,
+    ///     &[0; xchacha20_poly1305::KEY_LEN],
+    /// )?;
+    /// # Ok::<(), ring::error::Unspecified>(())
+    /// ```
+    #[inline]
+    pub fn new_of_len(
+        algorithm: &'static Algorithm,
+        key_bytes: &[u8],
     ) -> Result<Self, error::Unspecified> {
         Ok(Self {
             inner: LessSafeKey::new_(algorithm, key_bytes, cpu::features())?,
@@ -50,12 +103,12 @@ impl UnboundKey {
     }
 
     #[inline]
-    pub(super) fn into_inner(self) -> LessSafeKey {
+    pub(super) fn into_inner(self) -> LessSafeKey<L> {
         self.inner
     }
 }
 
-impl core::fmt::Debug for UnboundKey {
+impl<const L: usize> core::fmt::Debug for UnboundKey<L> {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
         self.inner.fmt_debug("UnboundKey", f)
     }
@@ -63,12 +116,7 @@ impl core::fmt::Debug for UnboundKey {
 
 impl From<hkdf::Okm<'_, &'static Algorithm>> for UnboundKey {
     fn from(okm: hkdf::Okm<&'static Algorithm>) -> Self {
-        let mut key_bytes = [0; MAX_KEY_LEN];
-        let key_bytes = &mut key_bytes[..okm.len().key_len()];
         let algorithm = *okm.len();
-        okm.fill(key_bytes).unwrap();
-        Self {
-            inner: LessSafeKey::new_(algorithm, key_bytes, cpu::features()).unwrap(),
-        }
+        Self::from_derived(algorithm, okm).unwrap()
     }
 }
@@ -0,0 +1,109 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! XChaCha20-Poly1305, as specified in [draft-irtf-cfrg-xchacha].
+//!
+//! See [`super::XCHACHA20_POLY1305`]. Its nonces are 192 bits long, so keys
+//! for it take `Nonce<NONCE_LEN>`s and are bound to
+//! `NonceSequence<NONCE_LEN>`s, e.g. a [`PrefixedNonceSequence`].
+//!
+//! Each message is sealed with ChaCha20-Poly1305 using a subkey that is
+//! derived from the key and the first 128 bits of the nonce with HChaCha20,
+//! and a nonce of four zero bytes followed by the last 64 bits of the nonce.
+//!
+//! [draft-irtf-cfrg-xchacha]: https://tools.ietf.org/html/draft-irtf-cfrg-xchacha-03
+
+use super::{
+    chacha::{self, HCHACHA20_NONCE_LEN},
+    chacha20_poly1305, Aad, Nonce, NonceSequence, Tag,
+};
+use crate::{cpu, error};
+use core::ops::RangeFrom;
+
+/// The length of an XChaCha20-Poly1305 key.
+pub const KEY_LEN: usize = chacha::KEY_LEN;
+
+/// The length of an XChaCha20-Poly1305 nonce.
+pub const NONCE_LEN: usize = 192 / 8;
+
+/// The length of the prefix of a `PrefixedNonceSequence`.
+pub const PREFIX_LEN: usize = NONCE_LEN - super::NONCE_LEN;
+
+/// Adapts a `NonceSequence` of `aead::NONCE_LEN`-byte nonces to
+/// XChaCha20-Poly1305, by prefixing each of its nonces with a fixed
+/// `PREFIX_LEN`-byte prefix.
+///
+/// This allows a counter, or any other existing `NonceSequence`, to be used
+/// with `XCHACHA20_POLY1305`. Choosing the prefix at random for each sequence
+/// makes it very unlikely that two sequences that use the same key ever
+/// produce the same nonce, even if their inner sequences are the same.
+pub struct PrefixedNonceSequence<N: NonceSequence> {
+    prefix: [u8; PREFIX_LEN],
+    inner: N,
+}
+
+impl<N: NonceSequence> PrefixedNonceSequence<N> {
+    /// Constructs a `PrefixedNonceSequence` that prefixes each nonce of
+    /// `inner` with `prefix`.
+    pub fn new(prefix: [u8; PREFIX_LEN], inner: N) -> Self {
+        Self { prefix, inner }
+    }
+}
+
+impl<N: NonceSequence> NonceSequence<NONCE_LEN> for PrefixedNonceSequence<N> {
+    fn advance(&mut self) -> Result<Nonce<NONCE_LEN>, error::Unspecified> {
+        let inner = self.inner.advance()?;
+        let mut nonce = [0u8; NONCE_LEN];
+        let (prefix, rest) = nonce.split_at_mut(PREFIX_LEN);
+        prefix.copy_from_slice(&self.prefix);
+        rest.copy_from_slice(inner.as_ref());
+        Ok(Nonce::assume_unique_for_key(nonce))
+    }
+}
+
+pub(super) fn seal(
+    key: &chacha20_poly1305::Key,
+    nonce: Nonce<NONCE_LEN>,
+    aad: Aad<&[u8]>,
+    in_out: &mut [u8],
+    cpu_features: cpu::Features,
+) -> Result<Tag, error::Unspecified> {
+    let (subkey, nonce) = derive(key, nonce);
+    chacha20_poly1305::seal(&subkey, nonce, aad, in_out, cpu_features)
+}
+
+pub(super) fn open(
+    key: &chacha20_poly1305::Key,
+    nonce: Nonce<NONCE_LEN>,
+    aad: Aad<&[u8]>,
+    in_out: &mut [u8],
+    src: RangeFrom<usize>,
+    cpu_features: cpu::Features,
+) -> Result<Tag, error::Unspecified> {
+    let (subkey, nonce) = derive(key, nonce);
+    chacha20_poly1305::open(&subkey, nonce, aad, in_out, src, cpu_features)
+}
+
+/// Derives the ChaCha20-Poly1305 subkey and nonce for `nonce`.
+fn derive(
+    key: &chacha20_poly1305::Key,
+    nonce: Nonce<NONCE_LEN>,
+) -> (chacha20_poly1305::Key, Nonce) {
+    let (hchacha20_nonce, rest) = nonce.as_ref().split_at(HCHACHA20_NONCE_LEN);
+    let subkey = key.hchacha20(hchacha20_nonce.try_into().unwrap());
+
+    let mut chacha20_nonce = [0u8; super::NONCE_LEN];
+    chacha20_nonce[4..].copy_from_slice(rest);
+    (subkey, Nonce::assume_unique_for_key(chacha20_nonce))
+}
@@ -64,9 +64,12 @@ use crate::{cpu, debug, ec, error, rand};
 
 pub use crate::ec::{
     curve25519::x25519::X25519,
+    curve448::x448::X448,
     suite_b::ecdh::{ECDH_P256, ECDH_P384},
 };
 
+pub mod x25519;
+
 /// A key agreement algorithm.
 pub struct Algorithm {
     pub(crate) curve: &'static ec::Curve,
@@ -120,6 +123,20 @@ impl EphemeralPrivateKey {
         })
     }
 
+    #[cfg(feature = "alloc")]
+    pub(crate) fn from_bytes(
+        alg: &'static Algorithm,
+        bytes: &[u8],
+        cpu_features: cpu::Features,
+    ) -> Result<Self, error::Unspecified> {
+        let private_key =
+            ec::Seed::from_bytes(alg.curve, untrusted::Input::from(bytes), cpu_features)?;
+        Ok(Self {
+            private_key,
+            algorithm: alg,
+        })
+    }
+
     /// Computes the public key from the private key.
     #[inline(always)]
     pub fn compute_public_key(&self) -> Result<PublicKey, error::Unspecified> {
@@ -148,6 +165,68 @@ impl EphemeralPrivateKey {
     }
 }
 
+/// A static private key for use with `agree_static`.
+///
+/// Unlike an `EphemeralPrivateKey`, a `StaticPrivateKey` may be used for any
+/// number of key agreements, so key agreements using it do not have forward
+/// secrecy. Static private keys are never generated randomly by *ring*; they
+/// are constructed from existing key material, e.g. with
+/// [`StaticPrivateKey::from_private_key_bytes()`] or
+/// [`x25519::from_passphrase()`].
+pub struct StaticPrivateKey {
+    private_key: ec::Seed,
+    algorithm: &'static Algorithm,
+}
+
+derive_debug_via_field!(StaticPrivateKey, stringify!(StaticPrivateKey), algorithm);
+
+impl StaticPrivateKey {
+    /// Constructs a static private key from its encoding.
+    ///
+    /// For X25519, `bytes` is the 32-byte private key as in [RFC 7748]; it is
+    /// clamped when it is used. For ECDH P-256 and ECDH P-384, `bytes` is the
+    /// big-endian encoding of the private scalar, padded to the length of the
+    /// group order, which must be in the range [1, n).
+    ///
+    /// [RFC 7748]: https://tools.ietf.org/html/rfc7748
+    pub fn from_private_key_bytes(
+        alg: &'static Algorithm,
+        bytes: &[u8],
+    ) -> Result<Self, error::Unspecified> {
+        Self::from_bytes(alg, bytes, cpu::features())
+    }
+
+    pub(crate) fn from_bytes(
+        alg: &'static Algorithm,
+        bytes: &[u8],
+        cpu_features: cpu::Features,
+    ) -> Result<Self, error::Unspecified> {
+        let private_key =
+            ec::Seed::from_bytes(alg.curve, untrusted::Input::from(bytes), cpu_features)?;
+        Ok(Self {
+            private_key,
+            algorithm: alg,
+        })
+    }
+
+    /// Computes the public key from the private key.
+    #[inline]
+    pub fn compute_public_key(&self) -> Result<PublicKey, error::Unspecified> {
+        self.private_key
+            .compute_public_key(cpu::features())
+            .map(|public_key| PublicKey {
+                algorithm: self.algorithm,
+                bytes: public_key,
+            })
+    }
+
+    /// The algorithm for the private key.
+    #[inline]
+    pub fn algorithm(&self) -> &'static Algorithm {
+        self.algorithm
+    }
+}
+
 /// A public key for key agreement.
 #[derive(Clone)]
 pub struct PublicKey {
@@ -176,6 +255,21 @@ impl PublicKey {
     pub fn algorithm(&self) -> &'static Algorithm {
         self.algorithm
     }
+
+    /// Returns whether `other_bytes` is an encoding of this public key, e.g.
+    /// when checking a received key against a pinned one.
+    ///
+    /// For the NIST curves, `other_bytes` may be in either uncompressed or
+    /// compressed form; both encodings of the same point match. For X25519
+    /// and X448, `other_bytes` must equal the public key exactly.
+    pub fn matches(&self, other_bytes: &[u8]) -> bool {
+        match self.algorithm.curve.id {
+            ec::CurveID::Curve25519 | ec::CurveID::Curve448 => self.as_ref() == other_bytes,
+            ec::CurveID::P256 | ec::CurveID::P384 => {
+                ec::suite_b::public_key_matches(self.as_ref(), other_bytes)
+            }
+        }
+    }
 }
 
 /// An unparsed, possibly malformed, public key for key agreement.
@@ -252,11 +346,43 @@ pub fn agree_ephemeral<B: AsRef<[u8]>, R>(
         algorithm: peer_public_key.algorithm,
         bytes: peer_public_key.bytes.as_ref(),
     };
-    agree_ephemeral_(my_private_key, peer_public_key, kdf, cpu::features())
+    agree_(
+        my_private_key.algorithm,
+        &my_private_key.private_key,
+        peer_public_key,
+        kdf,
+        cpu::features(),
+    )
 }
 
-fn agree_ephemeral_<R>(
-    my_private_key: EphemeralPrivateKey,
+/// Performs a key agreement with a static private key and the given public
+/// key.
+///
+/// This is the same as `agree_ephemeral` except `my_private_key` is borrowed,
+/// so it can be used for more than one key agreement. Key agreements using a
+/// static private key do not have forward secrecy.
+#[inline]
+pub fn agree_static<B: AsRef<[u8]>, R>(
+    my_private_key: &StaticPrivateKey,
+    peer_public_key: &UnparsedPublicKey<B>,
+    kdf: impl FnOnce(&[u8]) -> R,
+) -> Result<R, error::Unspecified> {
+    let peer_public_key = UnparsedPublicKey {
+        algorithm: peer_public_key.algorithm,
+        bytes: peer_public_key.bytes.as_ref(),
+    };
+    agree_(
+        my_private_key.algorithm,
+        &my_private_key.private_key,
+        peer_public_key,
+        kdf,
+        cpu::features(),
+    )
+}
+
+fn agree_<R>(
+    alg: &'static Algorithm,
+    my_private_key: &ec::Seed,
     peer_public_key: UnparsedPublicKey<&[u8]>,
     kdf: impl FnOnce(&[u8]) -> R,
     cpu: cpu::Features,
@@ -266,12 +392,10 @@ fn agree_ephemeral_<R>(
     // The domain parameters are hard-coded. This check verifies that the
     // peer's public key's domain parameters match the domain parameters of
     // this private key.
-    if peer_public_key.algorithm != my_private_key.algorithm {
+    if peer_public_key.algorithm != alg {
         return Err(error::Unspecified);
     }
 
-    let alg = &my_private_key.algorithm;
-
     // NSA Guide Prerequisite 2, regarding which KDFs are allowed, is delegated
     // to the caller.
 
@@ -282,7 +406,7 @@ fn agree_ephemeral_<R>(
     // NSA Guide Step 1 is handled by `EphemeralPrivateKey::generate()` and
     // `EphemeralPrivateKey::compute_public_key()`.
 
-    let mut shared_key = [0u8; ec::ELEM_MAX_BYTES];
+    let mut shared_key = [0u8; ec::SEED_MAX_BYTES];
     let shared_key = &mut shared_key[..alg.curve.elem_scalar_seed_len];
 
     // NSA Guide Steps 2, 3, and 4.
@@ -291,7 +415,7 @@ fn agree_ephemeral_<R>(
     // that doesn't meet the NSA requirement to "zeroize."
     (alg.ecdh)(
         shared_key,
-        &my_private_key.private_key,
+        my_private_key,
         untrusted::Input::from(peer_public_key.bytes),
         cpu,
     )?;
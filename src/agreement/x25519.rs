@@ -0,0 +1,153 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! X25519-specific key agreement functionality.
+
+use super::{PublicKey, StaticPrivateKey, UnparsedPublicKey, X25519};
+use crate::{cpu, ec::curve25519::x25519, error, pbkdf2, signature};
+use core::num::NonZeroU32;
+#[cfg(feature = "alloc")]
+use {
+    super::EphemeralPrivateKey,
+    crate::{constant_time, rand},
+    alloc::{vec, vec::Vec},
+};
+
+const PRIVATE_KEY_LEN: usize = 32;
+const PUBLIC_KEY_LEN: usize = 32;
+
+/// Deterministically derives an X25519 key pair from a passphrase.
+///
+/// The 32-byte private key is `PBKDF2(pbkdf2_alg, iterations, salt,
+/// passphrase)`, clamped as described in [RFC 7748 Section 5]. The same
+/// inputs always give the same key pair, so the key pair can be recovered
+/// from a memorized or written-down passphrase.
+///
+/// The private key is only as strong as the passphrase: anybody who knows
+/// `salt` and the public key can search for the passphrase offline, and
+/// `iterations` only slows down each guess. Use a passphrase with at least
+/// as much entropy as the security level needed, e.g. a randomly-generated
+/// one of sufficient length, and a `salt` that is unique to the user and
+/// application. A human-chosen passphrase is not enough.
+///
+/// [RFC 7748 Section 5]: https://tools.ietf.org/html/rfc7748#section-5
+pub fn from_passphrase(
+    pbkdf2_alg: pbkdf2::Algorithm,
+    iterations: NonZeroU32,
+    salt: &[u8],
+    passphrase: &[u8],
+) -> Result<(StaticPrivateKey, PublicKey), error::Unspecified> {
+    let mut bytes = [0u8; PRIVATE_KEY_LEN];
+    pbkdf2::derive(pbkdf2_alg, iterations, salt, passphrase, &mut bytes);
+    bytes[0] &= 248;
+    bytes[31] &= 127;
+    bytes[31] |= 64;
+
+    let private_key = StaticPrivateKey::from_bytes(&X25519, &bytes, cpu::features())?;
+    let public_key = private_key.compute_public_key()?;
+    Ok((private_key, public_key))
+}
+
+/// The X25519 function of [RFC 7748 Section 5]: the scalar multiplication
+/// of the Curve25519 point with u-coordinate `u` by `scalar`, after
+/// clamping `scalar`.
+///
+/// This is the primitive underlying `X25519` key agreement, for building
+/// other protocols. Unlike key agreement, it doesn't reject an all-zero
+/// result, which occurs when `u` is a point of small order; protocols that
+/// require contributory behavior must check for that themselves, in
+/// constant time. Prefer `agree_ephemeral()` or `agree_static()` for
+/// Diffie-Hellman key agreement.
+///
+/// [RFC 7748 Section 5]: https://tools.ietf.org/html/rfc7748#section-5
+pub fn scalar_mult(
+    scalar: &[u8; PRIVATE_KEY_LEN],
+    u: &[u8; PUBLIC_KEY_LEN],
+) -> [u8; PUBLIC_KEY_LEN] {
+    x25519::x25519(scalar, u, cpu::features())
+}
+
+/// Generates `n` ephemeral X25519 private keys and their public keys.
+///
+/// This is equivalent to calling `EphemeralPrivateKey::generate()` and
+/// `compute_public_key()` `n` times, except that the randomness for all the
+/// keys is drawn from `rng` in one call. This is useful for pre-generating
+/// keys, e.g. for a pool of connections, when each call to `rng` is
+/// expensive.
+#[cfg(feature = "alloc")]
+pub fn generate_ephemeral_batch(
+    rng: &dyn rand::SecureRandom,
+    n: usize,
+) -> Result<Vec<(EphemeralPrivateKey, PublicKey)>, error::Unspecified> {
+    let cpu_features = cpu::features();
+
+    let mut bytes = vec![0u8; PRIVATE_KEY_LEN.checked_mul(n).ok_or(error::Unspecified)?];
+    let result = rng.fill(&mut bytes).and_then(|()| {
+        bytes
+            .chunks_exact(PRIVATE_KEY_LEN)
+            .map(|bytes| {
+                let private_key = EphemeralPrivateKey::from_bytes(&X25519, bytes, cpu_features)?;
+                let public_key = private_key.compute_public_key()?;
+                Ok((private_key, public_key))
+            })
+            .collect()
+    });
+    constant_time::secure_zero(&mut bytes);
+    result
+}
+
+/// Converts an Ed25519 key pair to the X25519 key pair with the same private
+/// scalar, for protocols that use one identity key for both signing and key
+/// agreement.
+///
+/// The public key is the one that `public_key_from_ed25519()` computes from
+/// the Ed25519 public key, so a peer that knows only the Ed25519 public key
+/// can agree on a key with this key pair. The private key is the clamped
+/// first half of the SHA-512 hash of the Ed25519 seed, which is also what
+/// libsodium's `crypto_sign_ed25519_sk_to_curve25519` computes.
+///
+/// Using one key for both signing and key agreement is only safe when the
+/// protocol has been analyzed for that reuse; otherwise, use independent
+/// keys. Either way, compromising the key compromises both uses: anybody
+/// who learns it can forge signatures and also decrypt all traffic
+/// protected by key agreements with it, past and future.
+pub fn from_ed25519_key_pair(
+    key_pair: &signature::Ed25519KeyPair,
+) -> Result<(StaticPrivateKey, PublicKey), error::Unspecified> {
+    let bytes = x25519::private_key_from_ed25519(key_pair);
+    let private_key = StaticPrivateKey::from_bytes(&X25519, &bytes, cpu::features())?;
+    let public_key = private_key.compute_public_key()?;
+    Ok((private_key, public_key))
+}
+
+/// Converts an Ed25519 public key to the X25519 public key of the same
+/// point, using the birational map `u = (1 + y) / (1 - y)` between the
+/// Edwards and Montgomery forms of Curve25519 in [RFC 7748 Section 4.1].
+///
+/// Fails if `ed25519_public_key` isn't the encoding of a point on the curve.
+/// Points of small order are not rejected; they convert to X25519 public
+/// keys of small order, with which key agreement always fails. The neutral
+/// element, for which the map is undefined, converts to zero.
+///
+/// See `from_ed25519_key_pair()` for the caveats of using one key for both
+/// signing and key agreement.
+///
+/// [RFC 7748 Section 4.1]: https://tools.ietf.org/html/rfc7748#section-4.1
+pub fn public_key_from_ed25519(
+    ed25519_public_key: &[u8],
+) -> Result<UnparsedPublicKey<[u8; PUBLIC_KEY_LEN]>, error::Unspecified> {
+    let ed25519_public_key = ed25519_public_key.try_into()?;
+    let public_key = x25519::public_key_from_ed25519(ed25519_public_key)?;
+    Ok(UnparsedPublicKey::new(&X25519, public_key))
+}
@@ -0,0 +1,134 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Rivest's package transform, an all-or-nothing transform (AONT).
+//!
+//! An all-or-nothing transform is an unkeyed, randomized, invertible
+//! transform of a message such that the message can't be recovered, even in
+//! part, without all of the transformed output. Applying it before
+//! encrypting means that an attacker who recovers some, but not all, of the
+//! ciphertext blocks (or who can only brute-force some of them) learns
+//! nothing about the plaintext. See [Rivest, "All-Or-Nothing Encryption and
+//! The Package Transform"].
+//!
+//! The transform provides no confidentiality or integrity by itself; anybody
+//! can invert it. It is a preprocessing step for use before encryption, e.g.
+//! with `ring::aead`.
+//!
+//! # Format
+//!
+//! The message `m` is split into `s` blocks `m_1, ..., m_s` of 16 bytes,
+//! where the last block may be partial. A random AES-128 key `K'` is
+//! generated, and the output is `m'_1 || ... || m'_s || m'_{s+1}`, where:
+//!
+//! ```text
+//! m'_i     = m_i XOR AES(K', [i]_128)
+//! h_i      = AES(K_0, pad(m'_i) XOR [i]_128)
+//! m'_{s+1} = K' XOR h_1 XOR ... XOR h_s
+//! ```
+//!
+//! `[i]_128` is the block number `i` as a 128-bit big-endian integer,
+//! `pad` zero-pads a partial last block to 16 bytes, and `K_0` is the fixed,
+//! public, AES-128 key `"ring aont K0 key"`. For a partial last block, `m'_s`
+//! is only as long as `m_s`.
+//!
+//! The output is thus exactly `OVERHEAD_LEN` (16) bytes longer than the
+//! message.
+//!
+//! [Rivest, "All-Or-Nothing Encryption and The Package Transform"]:
+//!     https://people.csail.mit.edu/rivest/pubs/Riv97d.prepub.pdf
+
+use crate::{
+    aead::aes_ctr::{CounterConfig, CtrKey, AES_CTR_BLOCK_LEN},
+    error, rand,
+};
+use alloc::vec::Vec;
+
+/// The number of bytes that `transform` adds to the message.
+pub const OVERHEAD_LEN: usize = KEY_LEN;
+
+const KEY_LEN: usize = 16;
+
+type Block = [u8; AES_CTR_BLOCK_LEN];
+
+/// The fixed, public key `K_0`.
+const PUBLIC_KEY: &[u8; KEY_LEN] = b"ring aont K0 key";
+
+/// Applies the package transform to `message`.
+///
+/// The result is `message.len() + OVERHEAD_LEN` bytes long. Fails only if
+/// `rng` fails.
+pub fn transform(
+    rng: &dyn rand::SecureRandom,
+    message: &[u8],
+) -> Result<Vec<u8>, error::Unspecified> {
+    let mut inner_key = [0u8; KEY_LEN];
+    rng.fill(&mut inner_key)?;
+
+    let mut out = Vec::with_capacity(message.len() + OVERHEAD_LEN);
+    out.extend_from_slice(message);
+    apply_keystream(&inner_key, &mut out)?;
+
+    let last = xor(inner_key, hash(&out)?);
+    out.extend_from_slice(&last);
+    Ok(out)
+}
+
+/// Inverts the package transform, returning the original message.
+///
+/// Fails if `package` is shorter than `OVERHEAD_LEN` bytes. Since the
+/// transform has no integrity protection, any other input is accepted, and
+/// a modified `package` results in a garbled message.
+pub fn inverse_transform(package: &[u8]) -> Result<Vec<u8>, error::Unspecified> {
+    let message_len = package
+        .len()
+        .checked_sub(OVERHEAD_LEN)
+        .ok_or(error::Unspecified)?;
+    let (transformed, last) = package.split_at(message_len);
+    let last: Block = last.try_into()?;
+
+    let inner_key = xor(last, hash(transformed)?);
+
+    let mut out = Vec::from(transformed);
+    apply_keystream(&inner_key, &mut out)?;
+    Ok(out)
+}
+
+/// `m_i XOR AES(key, [i]_128)` for every block, i.e. AES-CTR with the
+/// counter starting at 1.
+fn apply_keystream(key: &[u8; KEY_LEN], in_out: &mut [u8]) -> Result<(), error::Unspecified> {
+    let key = CtrKey::new(key, CounterConfig::BIG_ENDIAN_128)?;
+    key.apply_keystream(1u128.to_be_bytes(), in_out)
+}
+
+/// `h_1 XOR ... XOR h_s`.
+fn hash(transformed: &[u8]) -> Result<Block, error::Unspecified> {
+    let key = CtrKey::new(PUBLIC_KEY, CounterConfig::BIG_ENDIAN_128)?;
+    let mut acc = [0u8; AES_CTR_BLOCK_LEN];
+    for (i, chunk) in (1u128..).zip(transformed.chunks(AES_CTR_BLOCK_LEN)) {
+        let mut block = [0u8; AES_CTR_BLOCK_LEN];
+        block[..chunk.len()].copy_from_slice(chunk);
+        let block = xor(block, i.to_be_bytes());
+
+        // A single block of keystream is the encryption of the counter block.
+        let mut h = [0u8; AES_CTR_BLOCK_LEN];
+        key.apply_keystream(block, &mut h)?;
+        acc = xor(acc, h);
+    }
+    Ok(acc)
+}
+
+fn xor(a: Block, b: Block) -> Block {
+    core::array::from_fn(|i| a[i] ^ b[i])
+}
@@ -0,0 +1,518 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Argon2id password hashing.
+//!
+//! Use `derive` to derive Argon2id outputs. Use `verify` to verify a password
+//! against previously-derived outputs.
+//!
+//! Argon2 is specified in [RFC 9106]. Only the Argon2id variant, version
+//! 0x13, is implemented. Unlike PBKDF2, Argon2id fills a configurable amount
+//! of memory, which makes guessing passwords with GPUs and custom hardware
+//! much more expensive. [RFC 9106 Section 4] recommends parameters.
+//!
+//! The lanes are computed one after another, not in parallel, so the
+//! parallelism parameter affects the output but not the running time.
+//!
+//! [RFC 9106]: https://tools.ietf.org/html/rfc9106
+//! [RFC 9106 Section 4]: https://tools.ietf.org/html/rfc9106#section-4
+
+use crate::{constant_time, error, polyfill::usize_from_u32};
+use alloc::vec::Vec;
+
+/// Argon2id cost parameters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Params {
+    memory_kib: u32,
+    iterations: u32,
+    parallelism: u32,
+}
+
+impl Params {
+    /// Constructs new Argon2id parameters.
+    ///
+    /// | Parameter     | RFC 9106 Term
+    /// |---------------|--------------------------------------
+    /// | `memory_kib`  | m (memory size, in kibibytes)
+    /// | `iterations`  | t (number of passes)
+    /// | `parallelism` | p (degree of parallelism; lanes)
+    ///
+    /// Fails unless `1 <= parallelism < 2**24`, `memory_kib >= 8 *
+    /// parallelism`, and `iterations >= 1`.
+    pub fn new(
+        memory_kib: u32,
+        iterations: u32,
+        parallelism: u32,
+    ) -> Result<Self, error::Unspecified> {
+        if !(1..(1 << 24)).contains(&parallelism) || memory_kib < 8 * parallelism || iterations == 0
+        {
+            return Err(error::Unspecified);
+        }
+        Ok(Self {
+            memory_kib,
+            iterations,
+            parallelism,
+        })
+    }
+
+    /// The memory size, in kibibytes.
+    #[inline]
+    pub fn memory_kib(&self) -> u32 {
+        self.memory_kib
+    }
+
+    /// The number of passes over the memory.
+    #[inline]
+    pub fn iterations(&self) -> u32 {
+        self.iterations
+    }
+
+    /// The number of lanes.
+    #[inline]
+    pub fn parallelism(&self) -> u32 {
+        self.parallelism
+    }
+}
+
+/// The minimum length of a salt.
+pub const MIN_SALT_LEN: usize = 8;
+
+/// The minimum length of an output.
+pub const MIN_OUTPUT_LEN: usize = 4;
+
+/// Fills `out` with the Argon2id output for the given inputs.
+///
+/// Do not use `derive` as part of verifying a password; use `verify` instead,
+/// to minimize the effectiveness of timing attacks.
+///
+/// Fails if `salt.len() < MIN_SALT_LEN`, if `out.len() < MIN_OUTPUT_LEN`, if
+/// any input is longer than 2**32 - 1 bytes, or if the memory can't be
+/// allocated.
+pub fn derive(
+    params: &Params,
+    salt: &[u8],
+    password: &[u8],
+    out: &mut [u8],
+) -> Result<(), error::Unspecified> {
+    derive_with_secret(params, salt, password, &[], &[], out)
+}
+
+/// Like `derive`, but with a secret key and associated data.
+///
+/// | Parameter         | RFC 9106 Term
+/// |-------------------|-----------------------------------
+/// | `salt`            | S (nonce)
+/// | `password`        | P (message)
+/// | `secret`          | K (secret value)
+/// | `associated_data` | X (associated data)
+/// | `out`             | the tag
+/// | `out.len()`       | T (tag length)
+pub fn derive_with_secret(
+    params: &Params,
+    salt: &[u8],
+    password: &[u8],
+    secret: &[u8],
+    associated_data: &[u8],
+    out: &mut [u8],
+) -> Result<(), error::Unspecified> {
+    if salt.len() < MIN_SALT_LEN || out.len() < MIN_OUTPUT_LEN {
+        return Err(error::Unspecified);
+    }
+    let out_len = le32(out.len())?;
+
+    // RFC 9106 Section 3.2, steps 1 and 2.
+    let mut h0 = [0u8; blake2b::MAX_OUTPUT_LEN];
+    {
+        let mut ctx = blake2b::Context::new(h0.len());
+        ctx.update(&params.parallelism.to_le_bytes());
+        ctx.update(&out_len);
+        ctx.update(&params.memory_kib.to_le_bytes());
+        ctx.update(&params.iterations.to_le_bytes());
+        ctx.update(&VERSION.to_le_bytes());
+        ctx.update(&ARGON2ID.to_le_bytes());
+        for input in [password, salt, secret, associated_data] {
+            ctx.update(&le32(input.len())?);
+            ctx.update(input);
+        }
+        ctx.finish(&mut h0);
+    }
+
+    let mut memory = Memory::new(params)?;
+    memory.fill_first_blocks(&h0);
+    for pass in 0..params.iterations {
+        for slice in 0..SYNC_POINTS {
+            for lane in 0..params.parallelism {
+                memory.fill_segment(Position { pass, lane, slice });
+            }
+        }
+    }
+
+    // Step 7.
+    let mut c = memory.blocks[usize_from_u32(memory.lane_length - 1)].clone();
+    for lane in 1..params.parallelism {
+        let last = lane * memory.lane_length + memory.lane_length - 1;
+        c.xor_assign(&memory.blocks[usize_from_u32(last)]);
+    }
+    hash_variable(&[&c.to_le_bytes()], out);
+    Ok(())
+}
+
+/// Verifies that a previously-derived (e.g., using `derive`) Argon2id
+/// output matches the output derived from the other inputs.
+///
+/// The comparison is done in constant time to prevent timing attacks.
+pub fn verify(
+    params: &Params,
+    salt: &[u8],
+    password: &[u8],
+    previously_derived: &[u8],
+) -> Result<(), error::Unspecified> {
+    verify_with_secret(params, salt, password, &[], &[], previously_derived)
+}
+
+/// Like `verify`, but with a secret key and associated data; see
+/// `derive_with_secret`.
+pub fn verify_with_secret(
+    params: &Params,
+    salt: &[u8],
+    password: &[u8],
+    secret: &[u8],
+    associated_data: &[u8],
+    previously_derived: &[u8],
+) -> Result<(), error::Unspecified> {
+    let mut derived = Vec::new();
+    derived
+        .try_reserve_exact(previously_derived.len())
+        .map_err(|_| error::Unspecified)?;
+    derived.resize(previously_derived.len(), 0);
+    derive_with_secret(
+        params,
+        salt,
+        password,
+        secret,
+        associated_data,
+        &mut derived,
+    )?;
+    constant_time::verify_slices_are_equal(&derived, previously_derived)
+}
+
+const VERSION: u32 = 0x13;
+const ARGON2ID: u32 = 2;
+const SYNC_POINTS: u32 = 4;
+
+const BLOCK_LEN: usize = 1024;
+const BLOCK_WORDS: usize = BLOCK_LEN / 8;
+const ADDRESSES_PER_BLOCK: u32 = 128;
+
+fn le32(len: usize) -> Result<[u8; 4], error::Unspecified> {
+    u32::try_from(len)
+        .map(u32::to_le_bytes)
+        .map_err(|_| error::Unspecified)
+}
+
+/// H' of RFC 9106 Section 3.3.
+fn hash_variable(input: &[&[u8]], out: &mut [u8]) {
+    let out_len = le32(out.len()).unwrap();
+    if out.len() <= blake2b::MAX_OUTPUT_LEN {
+        let mut ctx = blake2b::Context::new(out.len());
+        ctx.update(&out_len);
+        input.iter().for_each(|input| ctx.update(input));
+        ctx.finish(out);
+        return;
+    }
+
+    let mut v = [0u8; blake2b::MAX_OUTPUT_LEN];
+    let mut ctx = blake2b::Context::new(v.len());
+    ctx.update(&out_len);
+    input.iter().for_each(|input| ctx.update(input));
+    ctx.finish(&mut v);
+
+    // Each V_i contributes its first half, except the last, which is whole.
+    const HALF: usize = blake2b::MAX_OUTPUT_LEN / 2;
+    let mut out = out;
+    while out.len() > blake2b::MAX_OUTPUT_LEN {
+        let (w, rest) = out.split_at_mut(HALF);
+        w.copy_from_slice(&v[..HALF]);
+        out = rest;
+        let mut ctx = blake2b::Context::new(core::cmp::min(out.len(), v.len()));
+        ctx.update(&v);
+        if out.len() < v.len() {
+            ctx.finish(out);
+            return;
+        }
+        ctx.finish(&mut v);
+    }
+    out.copy_from_slice(&v[..out.len()]);
+}
+
+#[derive(Clone)]
+struct Block([u64; BLOCK_WORDS]);
+
+impl Block {
+    const ZERO: Self = Self([0; BLOCK_WORDS]);
+
+    fn from_le_bytes(bytes: &[u8; BLOCK_LEN]) -> Self {
+        let mut r = Self::ZERO;
+        r.0.iter_mut()
+            .zip(bytes.chunks_exact(8))
+            .for_each(|(w, b)| *w = u64::from_le_bytes(b.try_into().unwrap()));
+        r
+    }
+
+    fn to_le_bytes(&self) -> [u8; BLOCK_LEN] {
+        let mut r = [0u8; BLOCK_LEN];
+        r.chunks_exact_mut(8)
+            .zip(self.0.iter())
+            .for_each(|(b, w)| b.copy_from_slice(&w.to_le_bytes()));
+        r
+    }
+
+    fn xor_assign(&mut self, other: &Self) {
+        self.0
+            .iter_mut()
+            .zip(other.0.iter())
+            .for_each(|(a, b)| *a ^= b);
+    }
+
+    /// Sets `next` to G(X, Y), or to G(X, Y) XOR `next` if `xor` is true,
+    /// where `self` is X XOR Y.
+    fn compress_into(mut self, next: &mut Self, xor: bool) {
+        let mut r = self.clone();
+        if xor {
+            r.xor_assign(next);
+        }
+
+        // Apply the permutation P to each row, then to each column.
+        for row in self.0.chunks_exact_mut(16) {
+            permute(row, |i| i);
+        }
+        for column in 0..8 {
+            permute(&mut self.0, |i| 2 * column + (i / 2) * 16 + (i % 2));
+        }
+
+        r.xor_assign(&self);
+        *next = r;
+    }
+}
+
+/// The permutation P of RFC 9106 Section 3.6, applied to the words
+/// `v[index(0)], ..., v[index(15)]`.
+#[inline(always)]
+fn permute(v: &mut [u64], index: impl Fn(usize) -> usize) {
+    let mut gb = |a: usize, b: usize, c: usize, d: usize| {
+        let (a, b, c, d) = (index(a), index(b), index(c), index(d));
+        v[a] = fbla_mka(v[a], v[b]);
+        v[d] = (v[d] ^ v[a]).rotate_right(32);
+        v[c] = fbla_mka(v[c], v[d]);
+        v[b] = (v[b] ^ v[c]).rotate_right(24);
+        v[a] = fbla_mka(v[a], v[b]);
+        v[d] = (v[d] ^ v[a]).rotate_right(16);
+        v[c] = fbla_mka(v[c], v[d]);
+        v[b] = (v[b] ^ v[c]).rotate_right(63);
+    };
+    gb(0, 4, 8, 12);
+    gb(1, 5, 9, 13);
+    gb(2, 6, 10, 14);
+    gb(3, 7, 11, 15);
+    gb(0, 5, 10, 15);
+    gb(1, 6, 11, 12);
+    gb(2, 7, 8, 13);
+    gb(3, 4, 9, 14);
+}
+
+#[inline(always)]
+fn fbla_mka(x: u64, y: u64) -> u64 {
+    let m = (x & 0xffff_ffff).wrapping_mul(y & 0xffff_ffff);
+    x.wrapping_add(y).wrapping_add(m.wrapping_mul(2))
+}
+
+#[derive(Clone, Copy)]
+struct Position {
+    pass: u32,
+    lane: u32,
+    slice: u32,
+}
+
+struct Memory {
+    blocks: Vec<Block>,
+    lanes: u32,
+    lane_length: u32,
+    segment_length: u32,
+    iterations: u32,
+}
+
+impl Memory {
+    fn new(params: &Params) -> Result<Self, error::Unspecified> {
+        // RFC 9106 Section 3.2, step 3: m' = 4 * p * floor(m / 4p).
+        let segment_length = params.memory_kib / (SYNC_POINTS * params.parallelism);
+        let lane_length = segment_length * SYNC_POINTS;
+        let num_blocks = usize_from_u32(lane_length * params.parallelism);
+
+        let mut blocks = Vec::new();
+        blocks
+            .try_reserve_exact(num_blocks)
+            .map_err(|_| error::Unspecified)?;
+        blocks.resize(num_blocks, Block::ZERO);
+        Ok(Self {
+            blocks,
+            lanes: params.parallelism,
+            lane_length,
+            segment_length,
+            iterations: params.iterations,
+        })
+    }
+
+    // Steps 3 and 4.
+    fn fill_first_blocks(&mut self, h0: &[u8; blake2b::MAX_OUTPUT_LEN]) {
+        for lane in 0..self.lanes {
+            for i in 0..2u32 {
+                let mut bytes = [0u8; BLOCK_LEN];
+                hash_variable(&[h0, &i.to_le_bytes(), &lane.to_le_bytes()], &mut bytes);
+                let index = lane * self.lane_length + i;
+                self.blocks[usize_from_u32(index)] = Block::from_le_bytes(&bytes);
+            }
+        }
+    }
+
+    // Steps 5 and 6, for one segment.
+    fn fill_segment(&mut self, position: Position) {
+        let Position { pass, lane, slice } = position;
+
+        // Argon2id uses data-independent addressing for the first half of
+        // the first pass, and data-dependent addressing afterwards.
+        let mut addresses = if pass == 0 && slice < SYNC_POINTS / 2 {
+            Some(Addresses::new(
+                position,
+                self.lanes * self.lane_length,
+                self.iterations,
+            ))
+        } else {
+            None
+        };
+
+        // The first two blocks of each lane were already filled.
+        let first = if pass == 0 && slice == 0 { 2 } else { 0 };
+        for index in first..self.segment_length {
+            let offset = lane * self.lane_length + slice * self.segment_length + index;
+            let prev = if offset % self.lane_length == 0 {
+                offset + self.lane_length - 1
+            } else {
+                offset - 1
+            };
+
+            let pseudo_rand = match &mut addresses {
+                Some(addresses) => addresses.next(index),
+                None => self.blocks[usize_from_u32(prev)].0[0],
+            };
+            let ref_lane = if pass == 0 && slice == 0 {
+                lane
+            } else {
+                #[allow(clippy::cast_possible_truncation)]
+                let ref_lane = ((pseudo_rand >> 32) % u64::from(self.lanes)) as u32;
+                ref_lane
+            };
+            #[allow(clippy::cast_possible_truncation)]
+            let j1 = pseudo_rand as u32;
+            let ref_index = self.ref_index(position, index, j1, ref_lane == lane);
+            let reference = ref_lane * self.lane_length + ref_index;
+
+            let mut x = self.blocks[usize_from_u32(prev)].clone();
+            x.xor_assign(&self.blocks[usize_from_u32(reference)]);
+            // Version 0x13 XORs the new block into the old one after the
+            // first pass.
+            x.compress_into(&mut self.blocks[usize_from_u32(offset)], pass > 0);
+        }
+    }
+
+    /// Maps `j1` to the index, within its lane, of the reference block for
+    /// the block at `index` within the segment at `position`; see RFC 9106
+    /// Section 3.4.2.
+    fn ref_index(&self, position: Position, index: u32, j1: u32, same_lane: bool) -> u32 {
+        let Position { pass, slice, .. } = position;
+
+        // The blocks that may be referenced are the ones in the previous
+        // `SYNC_POINTS - 1` segments (in the first pass, in all previous
+        // segments), plus the blocks already filled in the current segment
+        // when it is in the same lane, but never the previous block.
+        let finished_segments = if pass == 0 { slice } else { SYNC_POINTS - 1 };
+        let mut area_size = finished_segments * self.segment_length;
+        if same_lane {
+            area_size += index;
+        }
+        if same_lane || index == 0 {
+            area_size -= 1;
+        }
+
+        let x = u64::from(j1);
+        let y = (x * x) >> 32;
+        let area_size = u64::from(area_size);
+        let relative = area_size - 1 - ((area_size * y) >> 32);
+
+        let start = if pass == 0 || slice == SYNC_POINTS - 1 {
+            0
+        } else {
+            (slice + 1) * self.segment_length
+        };
+        let absolute = (u64::from(start) + relative) % u64::from(self.lane_length);
+        u32::try_from(absolute).unwrap()
+    }
+}
+
+/// The pseudo-random values for data-independent addressing; see RFC 9106
+/// Section 3.4.1.2.
+struct Addresses {
+    input: Block,
+    addresses: Block,
+}
+
+impl Addresses {
+    fn new(position: Position, num_blocks: u32, iterations: u32) -> Self {
+        let mut input = Block::ZERO;
+        input.0[..6].copy_from_slice(&[
+            u64::from(position.pass),
+            u64::from(position.lane),
+            u64::from(position.slice),
+            u64::from(num_blocks),
+            u64::from(iterations),
+            u64::from(ARGON2ID),
+        ]);
+        let mut addresses = Self {
+            input,
+            addresses: Block::ZERO,
+        };
+        // The first two blocks of each lane were already filled, so the
+        // first block of addresses wouldn't otherwise be computed.
+        if position.pass == 0 && position.slice == 0 {
+            addresses.refill();
+        }
+        addresses
+    }
+
+    fn next(&mut self, index: u32) -> u64 {
+        if index % ADDRESSES_PER_BLOCK == 0 {
+            self.refill();
+        }
+        self.addresses.0[usize_from_u32(index % ADDRESSES_PER_BLOCK)]
+    }
+
+    fn refill(&mut self) {
+        // The counter.
+        self.input.0[6] += 1;
+        let mut tmp = Block::ZERO;
+        self.input.clone().compress_into(&mut tmp, false);
+        tmp.clone().compress_into(&mut self.addresses, false);
+    }
+}
+
+mod blake2b;
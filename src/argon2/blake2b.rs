@@ -0,0 +1,199 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! BLAKE2b, as specified in [RFC 7693], without a key.
+//!
+//! [RFC 7693]: https://tools.ietf.org/html/rfc7693
+
+use crate::polyfill::u64_from_usize;
+
+pub(super) const MAX_OUTPUT_LEN: usize = 64;
+
+const BLOCK_LEN: usize = 128;
+
+const IV: [u64; 8] = [
+    0x6a09e667f3bcc908,
+    0xbb67ae8584caa73b,
+    0x3c6ef372fe94f82b,
+    0xa54ff53a5f1d36f1,
+    0x510e527fade682d1,
+    0x9b05688c2b3e6c1f,
+    0x1f83d9abfb41bd6b,
+    0x5be0cd19137e2179,
+];
+
+const SIGMA: [[usize; 16]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+    [11, 8, 12, 0, 5, 2, 15, 13, 10, 14, 3, 6, 7, 1, 9, 4],
+    [7, 9, 3, 1, 13, 12, 11, 14, 2, 6, 5, 10, 4, 0, 15, 8],
+    [9, 0, 5, 7, 2, 4, 10, 15, 14, 1, 11, 12, 6, 8, 3, 13],
+    [2, 12, 6, 10, 0, 11, 8, 3, 4, 13, 7, 5, 15, 14, 1, 9],
+    [12, 5, 1, 15, 14, 13, 4, 10, 0, 7, 6, 3, 9, 2, 8, 11],
+    [13, 11, 7, 14, 12, 1, 3, 9, 5, 0, 15, 4, 8, 6, 2, 10],
+    [6, 15, 14, 9, 11, 3, 0, 8, 12, 2, 13, 7, 1, 4, 10, 5],
+    [10, 2, 8, 4, 7, 6, 1, 5, 15, 11, 9, 14, 3, 12, 13, 0],
+];
+
+/// A BLAKE2b computation in progress.
+pub(super) struct Context {
+    h: [u64; 8],
+    // The number of bytes compressed so far.
+    t: u128,
+    // Always holds at least one byte, unless nothing has been input yet,
+    // because the last block must be compressed as the final block.
+    pending: [u8; BLOCK_LEN],
+    num_pending: usize,
+    output_len: usize,
+}
+
+impl Context {
+    /// Panics unless `1 <= output_len <= MAX_OUTPUT_LEN`.
+    pub(super) fn new(output_len: usize) -> Self {
+        assert!((1..=MAX_OUTPUT_LEN).contains(&output_len));
+        let mut h = IV;
+        h[0] ^= 0x01010000 ^ u64::try_from(output_len).unwrap();
+        Self {
+            h,
+            t: 0,
+            pending: [0; BLOCK_LEN],
+            num_pending: 0,
+            output_len,
+        }
+    }
+
+    pub(super) fn update(&mut self, mut input: &[u8]) {
+        while !input.is_empty() {
+            if self.num_pending == BLOCK_LEN {
+                self.t += u128::from(u64_from_usize(BLOCK_LEN));
+                compress(&mut self.h, &self.pending, self.t, false);
+                self.num_pending = 0;
+            }
+            let to_copy = core::cmp::min(BLOCK_LEN - self.num_pending, input.len());
+            let (head, rest) = input.split_at(to_copy);
+            self.pending[self.num_pending..][..to_copy].copy_from_slice(head);
+            self.num_pending += to_copy;
+            input = rest;
+        }
+    }
+
+    /// Fills `out` with the digest. Panics unless `out.len()` is the output
+    /// length given to `new`.
+    pub(super) fn finish(mut self, out: &mut [u8]) {
+        assert_eq!(out.len(), self.output_len);
+        self.t += u128::from(u64_from_usize(self.num_pending));
+        self.pending[self.num_pending..].fill(0);
+        compress(&mut self.h, &self.pending, self.t, true);
+        out.iter_mut()
+            .zip(self.h.iter().flat_map(|h| h.to_le_bytes()))
+            .for_each(|(o, h)| *o = h);
+    }
+}
+
+fn compress(h: &mut [u64; 8], block: &[u8; BLOCK_LEN], t: u128, last: bool) {
+    let mut m = [0u64; 16];
+    m.iter_mut()
+        .zip(block.chunks_exact(8))
+        .for_each(|(m, b)| *m = u64::from_le_bytes(b.try_into().unwrap()));
+
+    let mut v = [0u64; 16];
+    v[..8].copy_from_slice(h);
+    v[8..].copy_from_slice(&IV);
+    let t = t.to_le_bytes();
+    v[12] ^= u64::from_le_bytes(t[..8].try_into().unwrap());
+    v[13] ^= u64::from_le_bytes(t[8..].try_into().unwrap());
+    if last {
+        v[14] = !v[14];
+    }
+
+    for round in 0..12 {
+        let s = &SIGMA[round % 10];
+        g(&mut v, 0, 4, 8, 12, m[s[0]], m[s[1]]);
+        g(&mut v, 1, 5, 9, 13, m[s[2]], m[s[3]]);
+        g(&mut v, 2, 6, 10, 14, m[s[4]], m[s[5]]);
+        g(&mut v, 3, 7, 11, 15, m[s[6]], m[s[7]]);
+        g(&mut v, 0, 5, 10, 15, m[s[8]], m[s[9]]);
+        g(&mut v, 1, 6, 11, 12, m[s[10]], m[s[11]]);
+        g(&mut v, 2, 7, 8, 13, m[s[12]], m[s[13]]);
+        g(&mut v, 3, 4, 9, 14, m[s[14]], m[s[15]]);
+    }
+
+    h.iter_mut()
+        .zip(v[..8].iter().zip(v[8..].iter()))
+        .for_each(|(h, (a, b))| *h ^= a ^ b);
+}
+
+#[inline(always)]
+fn g(v: &mut [u64; 16], a: usize, b: usize, c: usize, d: usize, x: u64, y: u64) {
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(x);
+    v[d] = (v[d] ^ v[a]).rotate_right(32);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(24);
+    v[a] = v[a].wrapping_add(v[b]).wrapping_add(y);
+    v[d] = (v[d] ^ v[a]).rotate_right(16);
+    v[c] = v[c].wrapping_add(v[d]);
+    v[b] = (v[b] ^ v[c]).rotate_right(63);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blake2b() {
+        // RFC 7693 Appendix A, and the empty input.
+        const ABC_512: [u8; 64] = [
+            0xba, 0x80, 0xa5, 0x3f, 0x98, 0x1c, 0x4d, 0x0d, 0x6a, 0x27, 0x97, 0xb6, 0x9f, 0x12,
+            0xf6, 0xe9, 0x4c, 0x21, 0x2f, 0x14, 0x68, 0x5a, 0xc4, 0xb7, 0x4b, 0x12, 0xbb, 0x6f,
+            0xdb, 0xff, 0xa2, 0xd1, 0x7d, 0x87, 0xc5, 0x39, 0x2a, 0xab, 0x79, 0x2d, 0xc2, 0x52,
+            0xd5, 0xde, 0x45, 0x33, 0xcc, 0x95, 0x18, 0xd3, 0x8a, 0xa8, 0xdb, 0xf1, 0x92, 0x5a,
+            0xb9, 0x23, 0x86, 0xed, 0xd4, 0x00, 0x99, 0x23,
+        ];
+        const EMPTY_256: [u8; 32] = [
+            0x0e, 0x57, 0x51, 0xc0, 0x26, 0xe5, 0x43, 0xb2, 0xe8, 0xab, 0x2e, 0xb0, 0x60, 0x99,
+            0xda, 0xa1, 0xd1, 0xe5, 0xdf, 0x47, 0x77, 0x8f, 0x77, 0x87, 0xfa, 0xab, 0x45, 0xcd,
+            0xf1, 0x2f, 0xe3, 0xa8,
+        ];
+
+        let mut out = [0u8; 64];
+        let mut ctx = Context::new(64);
+        ctx.update(b"abc");
+        ctx.finish(&mut out);
+        assert_eq!(out, ABC_512);
+
+        let mut out = [0u8; 32];
+        Context::new(32).finish(&mut out);
+        assert_eq!(out, EMPTY_256);
+    }
+
+    #[test]
+    fn test_blake2b_split_input() {
+        let input: [u8; 3 * BLOCK_LEN + 1] =
+            core::array::from_fn(|i| u8::try_from(i % 251).unwrap());
+        let mut expected = [0u8; MAX_OUTPUT_LEN];
+        let mut ctx = Context::new(MAX_OUTPUT_LEN);
+        ctx.update(&input);
+        ctx.finish(&mut expected);
+
+        for split in [0, 1, BLOCK_LEN - 1, BLOCK_LEN, BLOCK_LEN + 1, 2 * BLOCK_LEN] {
+            let (a, b) = input.split_at(split);
+            let mut ctx = Context::new(MAX_OUTPUT_LEN);
+            ctx.update(a);
+            ctx.update(b);
+            let mut out = [0u8; MAX_OUTPUT_LEN];
+            ctx.finish(&mut out);
+            assert_eq!(out, expected);
+        }
+    }
+}
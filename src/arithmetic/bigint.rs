@@ -123,15 +123,42 @@ impl<M> Elem<M, Unencoded> {
         })
     }
 
+    /// Like `from_be_bytes_padded`, but reuses the storage of `self`, which
+    /// must be the same size as `m`. On failure, `self` is left with an
+    /// unspecified value.
+    pub fn set_from_be_bytes_padded(
+        &mut self,
+        input: untrusted::Input,
+        m: &Modulus<M>,
+    ) -> Result<(), error::Unspecified> {
+        debug_assert_eq!(self.limbs.len(), m.limbs().len());
+        limb::parse_big_endian_and_pad_consttime(input, &mut self.limbs)?;
+        if limb::limbs_less_than_limbs_consttime(&self.limbs, m.limbs()) != LimbMask::True {
+            return Err(error::Unspecified);
+        }
+        Ok(())
+    }
+
     #[inline]
     pub fn fill_be_bytes(&self, out: &mut [u8]) {
         // See Falko Strenzke, "Manger's Attack revisited", ICICS 2010.
         limb::big_endian_from_limbs(&self.limbs, out)
     }
 
-    fn is_one(&self) -> bool {
+    #[inline]
+    pub fn is_one(&self) -> bool {
         limb::limbs_equal_limb_constant_time(&self.limbs, 1) == LimbMask::True
     }
+
+    /// Returns whether `self` is `m - 1`, i.e. -1 (mod m).
+    pub fn is_minus_one(&self, m: &Modulus<M>) -> bool {
+        // `m` is odd so `m - 1` is `m` with the low bit cleared.
+        let mut minus_one = [0; MODULUS_MAX_LIMBS];
+        let minus_one = &mut minus_one[..m.limbs().len()];
+        minus_one.copy_from_slice(m.limbs());
+        minus_one[0] &= !1;
+        limb::limbs_equal_limbs_consttime(&self.limbs, minus_one) == LimbMask::True
+    }
 }
 
 pub fn elem_mul<M, AF, BF>(
@@ -149,6 +176,20 @@ where
     }
 }
 
+/// Like `elem_mul`, but writes the product into `r` instead of reusing the
+/// storage of `b`.
+pub fn elem_mul_into<M, AF, BF>(
+    r: &mut Elem<M, <(AF, BF) as ProductEncoding>::Output>,
+    a: &Elem<M, AF>,
+    b: &Elem<M, BF>,
+    m: &Modulus<M>,
+) where
+    (AF, BF): ProductEncoding,
+{
+    r.limbs.copy_from_slice(&b.limbs);
+    limbs_mont_mul(&mut r.limbs, &a.limbs, m.limbs(), m.n0(), m.cpu_features());
+}
+
 // r *= 2.
 fn elem_double<M, AF>(r: &mut Elem<M, AF>, m: &Modulus<M>) {
     limb::limbs_double_mod(&mut r.limbs, m.limbs())
@@ -385,18 +426,36 @@ pub(crate) fn elem_exp_vartime<M>(
     //
     // [Knuth]: The Art of Computer Programming, Volume 2: Seminumerical
     //          Algorithms (3rd Edition), Section 4.6.3.
-    let exponent = exponent.get();
     let mut acc = base.clone();
+    elem_exp_vartime_into(&mut acc, &base, exponent, m);
+    acc
+}
+
+/// Like `elem_exp_vartime`, but writes the result into `acc` instead of
+/// allocating.
+pub(crate) fn elem_exp_vartime_into<M>(
+    acc: &mut Elem<M, R>,
+    base: &Elem<M, R>,
+    exponent: NonZeroU64,
+    m: &Modulus<M>,
+) {
+    let exponent = exponent.get();
+    acc.limbs.copy_from_slice(&base.limbs);
     let mut bit = 1 << (64 - 1 - exponent.leading_zeros());
     debug_assert!((exponent & bit) != 0);
     while bit > 1 {
         bit >>= 1;
-        acc = elem_squared(acc, m);
+        limbs_mont_square(&mut acc.limbs, m.limbs(), m.n0(), m.cpu_features());
         if (exponent & bit) != 0 {
-            acc = elem_mul(&base, acc, m);
+            limbs_mont_mul(
+                &mut acc.limbs,
+                &base.limbs,
+                m.limbs(),
+                m.n0(),
+                m.cpu_features(),
+            );
         }
     }
-    acc
 }
 
 #[cfg(not(target_arch = "x86_64"))]
@@ -212,7 +212,7 @@ impl<M> Modulus<'_, M> {
     }
 
     // TODO: XXX Avoid duplication with `Modulus`.
-    pub(super) fn zero<E>(&self) -> Elem<M, E> {
+    pub(crate) fn zero<E>(&self) -> Elem<M, E> {
         Elem {
             limbs: BoxedLimbs::zero(self.limbs.len()),
             encoding: PhantomData,
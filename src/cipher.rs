@@ -0,0 +1,47 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Unauthenticated cipher primitives.
+//!
+//! These are building blocks for other constructions. Most applications
+//! should use `ring::aead` instead.
+
+use crate::aead::chacha;
+
+pub use crate::aead::{
+    aes_ctr::{ByteOrder, CounterConfig, Ctr, CtrKey, AES_CTR_BLOCK_LEN, CTR_NONCE_LEN},
+    aes_xts::{XtsKey, XTS_BLOCK_LEN, XTS_MAX_SECTOR_LEN},
+};
+
+/// The length of an HChaCha20 key.
+pub const HCHACHA20_KEY_LEN: usize = chacha::KEY_LEN;
+
+/// The length of an HChaCha20 nonce.
+pub const HCHACHA20_NONCE_LEN: usize = chacha::HCHACHA20_NONCE_LEN;
+
+/// HChaCha20, as specified in [draft-irtf-cfrg-xchacha Section 2.2].
+///
+/// HChaCha20 derives a 256-bit subkey from `key` and `nonce`. It is the
+/// subkey derivation step of XChaCha20, and is also useful by itself, e.g.
+/// for deriving per-session keys from a long-term key and a random nonce.
+/// The output is only pseudorandom if `key` is secret and uniformly random.
+///
+/// [draft-irtf-cfrg-xchacha Section 2.2]:
+///     https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-xchacha-03#section-2.2
+pub fn hchacha20(
+    key: [u8; HCHACHA20_KEY_LEN],
+    nonce: [u8; HCHACHA20_NONCE_LEN],
+) -> [u8; HCHACHA20_KEY_LEN] {
+    chacha::hchacha20(key, nonce)
+}
@@ -14,7 +14,10 @@
 
 //! Constant-time operations.
 
-use crate::{c, error};
+use crate::{
+    c, error,
+    limb::{self, Limb, LimbMask},
+};
 
 #[cfg(target_pointer_width = "64")]
 pub(crate) type Word = u64;
@@ -37,6 +40,253 @@ pub fn verify_slices_are_equal(a: &[u8], b: &[u8]) -> Result<(), error::Unspecif
     }
 }
 
+/// Returns `LimbMask::True` if `a == b` and `LimbMask::False` otherwise. Like
+/// `verify_slices_are_equal()`, except that the result is a mask instead of a
+/// `Result`, so that the caller can use it without branching.
+///
+/// Panics if `a` and `b` have different lengths.
+pub(crate) fn slices_are_equal_mask(a: &[u8], b: &[u8]) -> LimbMask {
+    assert_eq!(a.len(), b.len());
+    let result = unsafe { CRYPTO_memcmp(a.as_ptr(), b.as_ptr(), a.len()) };
+    limb::limbs_are_zero_constant_time(&[Limb::from(result.unsigned_abs())])
+}
+
+/// Sets every byte of `bytes` to zero, in a way that the compiler will not
+/// optimize away even if `bytes` is never read again.
+///
+/// This is intended for erasing secrets from memory before the memory is
+/// freed or reused. It only erases `bytes` itself; it can't erase copies of
+/// the secret that were made elsewhere, e.g. in registers, on the stack, or
+/// when a `Vec` was reallocated.
+pub fn secure_zero(bytes: &mut [u8]) {
+    secure_overwrite(bytes, 0)
+}
+
+/// Like `secure_zero()`, but for any type of element, overwriting each one
+/// with `zero`.
+pub(crate) fn secure_overwrite<T: Copy>(values: &mut [T], zero: T) {
+    for v in values.iter_mut() {
+        // SAFETY: `v` is a valid, aligned, exclusive reference.
+        unsafe { core::ptr::write_volatile(v, zero) };
+    }
+    // Prevent later accesses from being reordered before the zeroing.
+    core::sync::atomic::compiler_fence(core::sync::atomic::Ordering::SeqCst);
+}
+
+/// Drops `value` in place and returns whether the memory it occupied is all
+/// zeros afterwards. `T` must not have any padding.
+#[cfg(test)]
+pub(crate) fn is_zeroed_after_drop<T>(value: T) -> bool {
+    let mut value = core::mem::ManuallyDrop::new(value);
+    let p: *mut T = &mut *value;
+    // SAFETY: `value` is never used again, and its storage outlives the reads
+    // below, which read only initialized bytes since `T` has no padding.
+    unsafe {
+        core::ptr::drop_in_place(p);
+        let bytes = p.cast::<u8>();
+        (0..core::mem::size_of::<T>()).all(|i| core::ptr::read_volatile(bytes.add(i)) == 0)
+    }
+}
+
+/// Decodes the base64url ([RFC 4648 Section 5]) encoding `input` into `out`,
+/// returning the length of the decoded value.
+///
+/// The decoding is done in constant time with respect to the decoded value,
+/// so it is suitable for decoding secrets such as private keys. It is NOT
+/// done in constant time with respect to the length of `input`; in
+/// particular, the presence and amount of `=` padding at the end of `input`
+/// is not considered secret.
+///
+/// `input` may or may not be padded, but if it is padded then it must be
+/// padded correctly. Fails if `input` contains any other character outside
+/// the base64url alphabet, if `input` isn't the canonical encoding of its
+/// value (i.e. the unused bits of its last character aren't zero), or if
+/// `out` is too small. `out` must be at least `input.len() * 3 / 4` bytes long.
+///
+/// [RFC 4648 Section 5]: https://tools.ietf.org/html/rfc4648#section-5
+pub fn decode_base64url(input: &[u8], out: &mut [u8]) -> Result<usize, error::Unspecified> {
+    decode_base64_(input, out, b'-', b'_')
+}
+
+/// Like [`decode_base64url()`], except it decodes the standard base64
+/// ([RFC 4648 Section 4]) encoding, which uses `+` and `/` instead of `-` and
+/// `_`.
+///
+/// [RFC 4648 Section 4]: https://tools.ietf.org/html/rfc4648#section-4
+pub fn decode_base64(input: &[u8], out: &mut [u8]) -> Result<usize, error::Unspecified> {
+    decode_base64_(input, out, b'+', b'/')
+}
+
+// `c62` and `c63` are the characters for the values 62 and 63, which are
+// the only ones that differ between the alphabets.
+fn decode_base64_(
+    input: &[u8],
+    out: &mut [u8],
+    c62: u8,
+    c63: u8,
+) -> Result<usize, error::Unspecified> {
+    let input = match input {
+        [rest @ .., b'=', b'='] | [rest @ .., b'='] if input.len() % 4 == 0 => rest,
+        _ => input,
+    };
+
+    let out_len = match input.len() % 4 {
+        0 => input.len() / 4 * 3,
+        1 => return Err(error::Unspecified),
+        partial => input.len() / 4 * 3 + (partial - 1),
+    };
+    let out = out.get_mut(..out_len).ok_or(error::Unspecified)?;
+
+    // Accumulate the validity of every character, and then check it once at
+    // the end, so that the time taken doesn't depend on which character (if
+    // any) is invalid.
+    let mut invalid = 0u8;
+    let mut decode = |c: u8| {
+        let (value, valid) = decode_base64_char(c, c62, c63);
+        invalid |= !valid;
+        value
+    };
+
+    let (whole, partial) = input.split_at(input.len() - (input.len() % 4));
+    let (out_whole, out_partial) = out.split_at_mut(whole.len() / 4 * 3);
+    for (chars, out) in whole.chunks_exact(4).zip(out_whole.chunks_exact_mut(3)) {
+        let bits = (u32::from(decode(chars[0])) << 18)
+            | (u32::from(decode(chars[1])) << 12)
+            | (u32::from(decode(chars[2])) << 6)
+            | u32::from(decode(chars[3]));
+        let [_, b0, b1, b2] = bits.to_be_bytes();
+        out.copy_from_slice(&[b0, b1, b2]);
+    }
+    match (partial, out_partial) {
+        ([], []) => {}
+        ([c0, c1], [o0]) => {
+            let (c0, c1) = (decode(*c0), decode(*c1));
+            *o0 = (c0 << 2) | (c1 >> 4);
+            invalid |= 0u8.wrapping_sub((c1 & 0b1111 != 0).into());
+        }
+        ([c0, c1, c2], [o0, o1]) => {
+            let (c0, c1, c2) = (decode(*c0), decode(*c1), decode(*c2));
+            *o0 = (c0 << 2) | (c1 >> 4);
+            *o1 = (c1 << 4) | (c2 >> 2);
+            invalid |= 0u8.wrapping_sub((c2 & 0b11 != 0).into());
+        }
+        _ => unreachable!(),
+    }
+
+    if invalid != 0 {
+        out.fill(0);
+        return Err(error::Unspecified);
+    }
+    Ok(out_len)
+}
+
+/// Returns the 6-bit value of the base64 character `c` and `0xff` if `c`
+/// is valid, or `0` and `0x00` if it isn't, without branching on `c`.
+fn decode_base64_char(c: u8, c62: u8, c63: u8) -> (u8, u8) {
+    // Returns 0xff if `lo <= c <= hi` and 0x00 otherwise.
+    fn in_range(c: u8, lo: u8, hi: u8) -> u8 {
+        let (c, lo, hi) = (i16::from(c), i16::from(lo), i16::from(hi));
+        // Both differences are negative iff `c` is in range.
+        let [_, mask] = (((lo - 1 - c) & (c - hi - 1)) >> 8).to_be_bytes();
+        mask
+    }
+
+    let upper = in_range(c, b'A', b'Z');
+    let lower = in_range(c, b'a', b'z');
+    let digit = in_range(c, b'0', b'9');
+    let is_62 = in_range(c, c62, c62);
+    let is_63 = in_range(c, c63, c63);
+
+    let value = (upper & c.wrapping_sub(b'A'))
+        | (lower & c.wrapping_sub(b'a').wrapping_add(26))
+        | (digit & c.wrapping_sub(b'0').wrapping_add(52))
+        | (is_62 & 62)
+        | (is_63 & 63);
+    (value, upper | lower | digit | is_62 | is_63)
+}
+
+/// Checks that the constant-time comparison and XOR routines compute the
+/// right results on a fixed set of inputs.
+///
+/// This is a smoke test for integrators who are validating a build, e.g. on
+/// unusual hardware or with an unusual toolchain. It checks only functional
+/// correctness; whether the routines actually run in constant time can't be
+/// tested portably.
+pub fn self_test() -> Result<(), error::Unspecified> {
+    self_test_(&Routines {
+        verify_slices_are_equal,
+        xor_16,
+        xor_assign_at_start: |a, b| xor_assign_at_start(a, b.iter()),
+    })
+}
+
+// The routines under test, so that the tests can check that broken
+// implementations are detected.
+struct Routines {
+    verify_slices_are_equal: fn(&[u8], &[u8]) -> Result<(), error::Unspecified>,
+    xor_16: fn([u8; 16], [u8; 16]) -> [u8; 16],
+    xor_assign_at_start: fn(&mut [u8], &[u8]),
+}
+
+fn self_test_(routines: &Routines) -> Result<(), error::Unspecified> {
+    const LEN: usize = 33;
+    let mut a = [0u8; LEN];
+    a.iter_mut()
+        .enumerate()
+        .for_each(|(i, b)| *b = u8::try_from(i).unwrap().wrapping_mul(0x3b) ^ 0xa5);
+
+    // Equal inputs of every length compare equal, and inputs that differ in
+    // any single bit, or in length, don't.
+    let a_copy = a;
+    for len in 0..=LEN {
+        if (routines.verify_slices_are_equal)(&a[..len], &a_copy[..len]).is_err() {
+            return Err(error::Unspecified);
+        }
+    }
+    if (routines.verify_slices_are_equal)(&a, &a[..(LEN - 1)]).is_ok() {
+        return Err(error::Unspecified);
+    }
+    for i in 0..LEN {
+        for bit in 0..8 {
+            let mut b = a;
+            b[i] ^= 1 << bit;
+            if (routines.verify_slices_are_equal)(&a, &b).is_ok()
+                || (routines.verify_slices_are_equal)(&b, &a).is_ok()
+            {
+                return Err(error::Unspecified);
+            }
+        }
+    }
+
+    // XOR against a simple byte-at-a-time reference.
+    let mut b = [0u8; LEN];
+    b.iter_mut()
+        .enumerate()
+        .for_each(|(i, b)| *b = u8::try_from(i).unwrap().wrapping_mul(0x95).rotate_left(3));
+    let mut expected = [0u8; LEN];
+    expected
+        .iter_mut()
+        .zip(a.iter().zip(b.iter()))
+        .for_each(|(r, (a, b))| *r = *a ^ *b);
+
+    let a_16: [u8; 16] = a[..16].try_into().unwrap();
+    let b_16: [u8; 16] = b[..16].try_into().unwrap();
+    if (routines.xor_16)(a_16, b_16)[..] != expected[..16] {
+        return Err(error::Unspecified);
+    }
+
+    // Only the common prefix of the inputs is XORed.
+    for len in [0, 1, 15, 16, 17, LEN] {
+        let mut r = a;
+        (routines.xor_assign_at_start)(&mut r[..len], &b);
+        if r[..len] != expected[..len] || r[len..] != a[len..] {
+            return Err(error::Unspecified);
+        }
+    }
+
+    Ok(())
+}
+
 prefixed_extern! {
     fn CRYPTO_memcmp(a: *const u8, b: *const u8, len: c::size_t) -> c::int;
 }
@@ -62,6 +312,92 @@ pub(crate) fn xor_assign_at_start<'a>(
 mod tests {
     use crate::{bssl, constant_time::xor_assign_at_start, error, limb::LimbMask, rand};
 
+    #[test]
+    fn test_self_test() {
+        assert_eq!(super::self_test(), Ok(()));
+    }
+
+    #[test]
+    fn test_self_test_detects_wrong_results() {
+        use super::{self_test_, verify_slices_are_equal, xor_16, Routines};
+
+        let correct = Routines {
+            verify_slices_are_equal,
+            xor_16,
+            xor_assign_at_start: |a, b| xor_assign_at_start(a, b.iter()),
+        };
+        assert_eq!(self_test_(&correct), Ok(()));
+
+        // Ignores the last byte.
+        let broken = Routines {
+            verify_slices_are_equal: |a, b| {
+                verify_slices_are_equal(
+                    &a[..a.len().saturating_sub(1)],
+                    &b[..b.len().saturating_sub(1)],
+                )
+            },
+            ..correct
+        };
+        assert_eq!(self_test_(&broken), Err(error::Unspecified));
+
+        // Ignores the lengths.
+        let broken = Routines {
+            verify_slices_are_equal: |a, b| {
+                let len = core::cmp::min(a.len(), b.len());
+                verify_slices_are_equal(&a[..len], &b[..len])
+            },
+            ..correct
+        };
+        assert_eq!(self_test_(&broken), Err(error::Unspecified));
+
+        // Always fails.
+        let broken = Routines {
+            verify_slices_are_equal: |_, _| Err(error::Unspecified),
+            ..correct
+        };
+        assert_eq!(self_test_(&broken), Err(error::Unspecified));
+
+        // Drops the last byte.
+        let broken = Routines {
+            xor_16: |a, b| {
+                let mut r = xor_16(a, b);
+                r[15] = a[15];
+                r
+            },
+            ..correct
+        };
+        assert_eq!(self_test_(&broken), Err(error::Unspecified));
+
+        // Writes past the common prefix.
+        let broken = Routines {
+            xor_assign_at_start: |a, b| {
+                xor_assign_at_start(a.iter_mut(), b.iter());
+                if let Some(last) = a.last_mut() {
+                    *last ^= 1;
+                }
+            },
+            ..correct
+        };
+        assert_eq!(self_test_(&broken), Err(error::Unspecified));
+    }
+
+    #[test]
+    fn test_slices_are_equal_mask() {
+        use super::slices_are_equal_mask;
+        assert_eq!(slices_are_equal_mask(&[], &[]), LimbMask::True);
+        assert_eq!(
+            slices_are_equal_mask(&[1, 2, 3], &[1, 2, 3]),
+            LimbMask::True
+        );
+        for i in 0..3 {
+            for bit in 0..8 {
+                let mut b = [1, 2, 3];
+                b[i] ^= 1 << bit;
+                assert_eq!(slices_are_equal_mask(&[1, 2, 3], &b), LimbMask::False);
+            }
+        }
+    }
+
     #[test]
     fn test_constant_time() -> Result<(), error::Unspecified> {
         prefixed_extern! {
@@ -12,6 +12,12 @@
 // OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
 // CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
 
+//! CPU feature detection and implementation selection.
+//!
+//! *ring* detects the features of the CPU automatically and chooses the
+//! implementation of each algorithm accordingly; nothing here needs to be
+//! called for that to happen.
+
 pub(crate) use self::features::Features;
 
 macro_rules! impl_get_feature {
@@ -67,6 +73,21 @@ pub(crate) fn features() -> Features {
     get_or_init_feature_flags()
 }
 
+/// Benchmarks the implementations of AES-GCM that are available on this CPU
+/// and selects the fastest one for keys that are constructed afterwards.
+///
+/// By default, the implementation is chosen based only on which CPU features
+/// are available, which isn't always optimal for every microarchitecture.
+/// The benchmark takes a few milliseconds; it only runs the first time this
+/// is called, and the result is used for the rest of the life of the process.
+/// Keys constructed before the first call are not affected.
+///
+/// Nothing is benchmarked unless this is called.
+#[cfg(feature = "std")]
+pub fn benchmark_select() {
+    crate::aead::benchmark_select(features())
+}
+
 mod features {
     use crate::polyfill::NotSend;
 
@@ -100,10 +121,10 @@ const _: () = assert!(core::mem::size_of::<Features>() == 0);
 
 cfg_if::cfg_if! {
     if #[cfg(any(target_arch = "aarch64", target_arch = "arm"))] {
-        pub mod arm;
+        pub(crate) mod arm;
         use arm::featureflags::get_or_init as get_or_init_feature_flags;
     } else if #[cfg(any(target_arch = "x86", target_arch = "x86_64"))] {
-        pub mod intel;
+        pub(crate) mod intel;
         use intel::featureflags::get_or_init as get_or_init_feature_flags;
     } else {
         pub(super) fn get_or_init_feature_flags() -> Features {
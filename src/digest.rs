@@ -24,12 +24,13 @@ use self::{
 };
 use crate::{
     bits::{BitLength, FromByteLen as _},
-    cpu, debug,
+    constant_time, cpu, debug, error,
     polyfill::{self, slice, sliceutil},
 };
 use core::num::Wrapping;
 
 mod dynstate;
+pub(crate) mod keccak;
 mod sha1;
 mod sha2;
 
@@ -139,6 +140,26 @@ impl BlockContext {
 ///
 /// assert_eq!(&one_shot.as_ref(), &multi_part.as_ref());
 /// ```
+///
+/// A `Context` can be cloned to snapshot the state after a common prefix,
+/// so that many messages with that prefix can be digested without
+/// reprocessing it:
+///
+/// ```
+/// use ring::digest;
+///
+/// let mut prefix = digest::Context::new(&digest::SHA256);
+/// prefix.update(b"common prefix, ");
+///
+/// for suffix in [&b"first"[..], b"second"] {
+///     let mut ctx = prefix.clone();
+///     ctx.update(suffix);
+///     assert_eq!(
+///         ctx.finish(),
+///         digest::digest(&digest::SHA256, &[&b"common prefix, "[..], suffix].concat())
+///     );
+/// }
+/// ```
 #[derive(Clone)]
 pub struct Context {
     block: BlockContext,
@@ -252,6 +273,29 @@ pub fn digest(algorithm: &'static Algorithm, data: &[u8]) -> Digest {
     ctx.finish()
 }
 
+/// Calculates the digest of `data` using the given digest algorithm, and
+/// verifies whether it equals `expected`, in one step.
+///
+/// The comparison is done in constant time with respect to the digest value,
+/// so this is suitable for checking digests that are secret, e.g. hashed
+/// capability tokens. Comparing the result of [`digest()`] to `expected`
+/// with `==` on byte slices is not. Fails if `expected` has the wrong length.
+///
+/// ```
+/// use ring::digest;
+///
+/// let expected = digest::digest(&digest::SHA256, b"hello, world");
+/// assert!(digest::verify(&digest::SHA256, b"hello, world", expected.as_ref()).is_ok());
+/// assert!(digest::verify(&digest::SHA256, b"hello, world!", expected.as_ref()).is_err());
+/// ```
+pub fn verify(
+    algorithm: &'static Algorithm,
+    data: &[u8],
+    expected: &[u8],
+) -> Result<(), error::Unspecified> {
+    constant_time::verify_slices_are_equal(digest(algorithm, data).as_ref(), expected)
+}
+
 /// A calculated digest value.
 ///
 /// Use [`Self::as_ref`] to get the value as a `&[u8]`.
@@ -267,8 +311,29 @@ impl Digest {
     pub fn algorithm(&self) -> &'static Algorithm {
         self.algorithm
     }
+
+    /// Returns whether `self` and `other` are the same digest value,
+    /// calculated with the same algorithm.
+    ///
+    /// The comparison is done in constant time with respect to the digest
+    /// values, so it is suitable for comparing digests that are secret, e.g.
+    /// hashed capability tokens. It is not constant-time with respect to the
+    /// algorithms. `==` on `Digest`s does the same comparison.
+    pub fn ct_eq(&self, other: &Self) -> bool {
+        self.algorithm == other.algorithm
+            && constant_time::verify_slices_are_equal(self.as_ref(), other.as_ref()).is_ok()
+    }
 }
 
+impl PartialEq for Digest {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other)
+    }
+}
+
+impl Eq for Digest {}
+
 impl AsRef<[u8]> for Digest {
     #[inline(always)]
     fn as_ref(&self) -> &[u8] {
@@ -314,6 +379,7 @@ enum AlgorithmID {
     SHA256,
     SHA384,
     SHA512,
+    SHA512_224,
     SHA512_256,
 }
 
@@ -442,6 +508,33 @@ pub static SHA512: Algorithm = Algorithm {
     id: AlgorithmID::SHA512,
 };
 
+/// SHA-512/224 as specified in [FIPS 180-4].
+///
+/// This is *not* the same as just truncating the output of SHA-512, as
+/// SHA-512/224 has its own initial state distinct from SHA-512's initial
+/// state.
+///
+/// [FIPS 180-4]: http://nvlpubs.nist.gov/nistpubs/FIPS/NIST.FIPS.180-4.pdf
+pub static SHA512_224: Algorithm = Algorithm {
+    output_len: OutputLen::_224,
+    chaining_len: SHA512_OUTPUT_LEN,
+    block_len: SHA512_BLOCK_LEN,
+    len_len: SHA512_LEN_LEN,
+    block_data_order: dynstate::sha512_block_data_order,
+    format_output: dynstate::sha512_format_output,
+    initial_state: DynState::new64([
+        Wrapping(0x8c3d37c819544da2),
+        Wrapping(0x73e1996689dcd4d6),
+        Wrapping(0x1dfab7ae32ff9c82),
+        Wrapping(0x679dd514582f9fcf),
+        Wrapping(0x0f6d2b697bd44da8),
+        Wrapping(0x77e36f7304c48942),
+        Wrapping(0x3f9d85a86a1d36c8),
+        Wrapping(0x1112e6ad91d692a1),
+    ]),
+    id: AlgorithmID::SHA512_224,
+};
+
 /// SHA-512/256 as specified in [FIPS 180-4].
 ///
 /// This is *not* the same as just truncating the output of SHA-512, as
@@ -513,6 +606,9 @@ pub const SHA384_OUTPUT_LEN: usize = OutputLen::_384.into();
 /// The length of the output of SHA-512, in bytes.
 pub const SHA512_OUTPUT_LEN: usize = OutputLen::_512.into();
 
+/// The length of the output of SHA-512/224, in bytes.
+pub const SHA512_224_OUTPUT_LEN: usize = OutputLen::_224.into();
+
 /// The length of the output of SHA-512/256, in bytes.
 pub const SHA512_256_OUTPUT_LEN: usize = OutputLen::_256.into();
 
@@ -536,6 +632,7 @@ impl BlockLen {
 #[derive(Clone, Copy)]
 enum OutputLen {
     _160 = 160 / 8,
+    _224 = 224 / 8,
     _256 = 256 / 8,
     _384 = 384 / 8,
     _512 = 512 / 8, // MAX
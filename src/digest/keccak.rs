@@ -0,0 +1,197 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! The Keccak-p[1600, 24] permutation and the sponge construction, as
+//! specified in [FIPS 202].
+//!
+//! [FIPS 202]: https://nvlpubs.nist.gov/nistpubs/FIPS/NIST.FIPS.202.pdf
+
+const LANES: usize = 25;
+
+/// The largest rate, in bytes, of any Keccak sponge we use.
+pub(crate) const MAX_RATE: usize = 168;
+
+/// A Keccak sponge with a rate of `rate` bytes.
+#[derive(Clone)]
+pub(crate) struct Sponge {
+    state: [u64; LANES],
+    rate: usize,
+
+    /// The number of bytes absorbed into the current block.
+    pos: usize,
+}
+
+impl Sponge {
+    pub(crate) fn new(rate: usize) -> Self {
+        assert!(rate > 0 && rate <= MAX_RATE && rate % 8 == 0);
+        Self {
+            state: [0; LANES],
+            rate,
+            pos: 0,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn rate(&self) -> usize {
+        self.rate
+    }
+
+    pub(crate) fn update(&mut self, input: &[u8]) {
+        for &b in input {
+            self.xor_byte(self.pos, b);
+            self.pos += 1;
+            if self.pos == self.rate {
+                keccak_f1600(&mut self.state);
+                self.pos = 0;
+            }
+        }
+    }
+
+    /// Pads the input absorbed so far with zeros to a multiple of the rate.
+    pub(crate) fn fill_block_with_zeros(&mut self) {
+        if self.pos != 0 {
+            keccak_f1600(&mut self.state);
+            self.pos = 0;
+        }
+    }
+
+    /// Appends the domain separation bits in `suffix` and the `pad10*1`
+    /// padding, then squeezes `out.len()` bytes of output.
+    ///
+    /// `suffix` is the domain separation bits followed by the first bit of
+    /// the padding, in the byte encoding used by FIPS 202; e.g. 0x06 for
+    /// SHA-3, 0x1f for SHAKE, and 0x04 for cSHAKE.
+    pub(crate) fn finish(mut self, suffix: u8, out: &mut [u8]) {
+        self.xor_byte(self.pos, suffix);
+        self.xor_byte(self.rate - 1, 0x80);
+        for chunk in out.chunks_mut(self.rate) {
+            keccak_f1600(&mut self.state);
+            for (i, b) in chunk.iter_mut().enumerate() {
+                *b = self.state[i / 8].to_le_bytes()[i % 8];
+            }
+        }
+    }
+
+    #[inline]
+    fn xor_byte(&mut self, i: usize, b: u8) {
+        self.state[i / 8] ^= u64::from(b) << (8 * (i % 8));
+    }
+}
+
+const ROUND_CONSTANTS: [u64; 24] = [
+    0x0000_0000_0000_0001,
+    0x0000_0000_0000_8082,
+    0x8000_0000_0000_808a,
+    0x8000_0000_8000_8000,
+    0x0000_0000_0000_808b,
+    0x0000_0000_8000_0001,
+    0x8000_0000_8000_8081,
+    0x8000_0000_0000_8009,
+    0x0000_0000_0000_008a,
+    0x0000_0000_0000_0088,
+    0x0000_0000_8000_8009,
+    0x0000_0000_8000_000a,
+    0x0000_0000_8000_808b,
+    0x8000_0000_0000_008b,
+    0x8000_0000_0000_8089,
+    0x8000_0000_0000_8003,
+    0x8000_0000_0000_8002,
+    0x8000_0000_0000_0080,
+    0x0000_0000_0000_800a,
+    0x8000_0000_8000_000a,
+    0x8000_0000_8000_8081,
+    0x8000_0000_0000_8080,
+    0x0000_0000_8000_0001,
+    0x8000_0000_8000_8008,
+];
+
+// The rotation offsets of ρ and the lane order of π, combined so that each
+// lane is moved to its new position in one pass starting from lane 1.
+const RHO: [u32; 24] = [
+    1, 3, 6, 10, 15, 21, 28, 36, 45, 55, 2, 14, 27, 41, 56, 8, 25, 43, 62, 18, 39, 61, 20, 44,
+];
+const PI: [usize; 24] = [
+    10, 7, 11, 17, 18, 3, 5, 16, 8, 21, 24, 4, 15, 23, 19, 13, 12, 2, 20, 14, 22, 9, 6, 1,
+];
+
+fn keccak_f1600(a: &mut [u64; LANES]) {
+    for rc in ROUND_CONSTANTS {
+        // θ
+        let mut c = [0u64; 5];
+        for (x, c) in c.iter_mut().enumerate() {
+            *c = a[x] ^ a[x + 5] ^ a[x + 10] ^ a[x + 15] ^ a[x + 20];
+        }
+        for x in 0..5 {
+            let d = c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+            for y in (0..LANES).step_by(5) {
+                a[y + x] ^= d;
+            }
+        }
+
+        // ρ and π
+        let mut last = a[1];
+        for (&pi, &rho) in PI.iter().zip(RHO.iter()) {
+            let next = a[pi];
+            a[pi] = last.rotate_left(rho);
+            last = next;
+        }
+
+        // χ
+        for row in a.chunks_exact_mut(5) {
+            let [r0, r1, r2, r3, r4] = [row[0], row[1], row[2], row[3], row[4]];
+            row[0] = r0 ^ (!r1 & r2);
+            row[1] = r1 ^ (!r2 & r3);
+            row[2] = r2 ^ (!r3 & r4);
+            row[3] = r3 ^ (!r4 & r0);
+            row[4] = r4 ^ (!r0 & r1);
+        }
+
+        // ι
+        a[0] ^= rc;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    #[test]
+    fn test_keccak() {
+        test::run(test_file!("keccak_tests.txt"), |section, test_case| {
+            let (rate, suffix) = match section {
+                "SHA3-256" => (136, 0x06),
+                "SHAKE128" => (168, 0x1f),
+                _ => unreachable!(),
+            };
+            let input = test_case.consume_bytes("Input");
+            let expected = test_case.consume_bytes("Output");
+
+            let mut sponge = Sponge::new(rate);
+            sponge.update(&input);
+            let mut out = [0u8; 512];
+            let out = &mut out[..expected.len()];
+            sponge.finish(suffix, out);
+            assert_eq!(out, &expected[..]);
+
+            // Absorbing the input one byte at a time gives the same result.
+            let mut sponge = Sponge::new(rate);
+            input.chunks(1).for_each(|b| sponge.update(b));
+            sponge.finish(suffix, out);
+            assert_eq!(out, &expected[..]);
+
+            Ok(())
+        })
+    }
+}
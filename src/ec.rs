@@ -40,6 +40,7 @@ derive_debug_via_id!(Curve);
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum CurveID {
     Curve25519,
+    Curve448,
     P256,
     P384,
 }
@@ -48,7 +49,9 @@ const ELEM_MAX_BITS: usize = 384;
 pub const ELEM_MAX_BYTES: usize = (ELEM_MAX_BITS + 7) / 8;
 
 pub const SCALAR_MAX_BYTES: usize = ELEM_MAX_BYTES;
-const SEED_MAX_BYTES: usize = ELEM_MAX_BYTES;
+// X448 private keys and shared secrets are longer than the elements of any
+// of the NIST curves.
+pub const SEED_MAX_BYTES: usize = 56;
 
 /// The maximum length of a PKCS#8 documents generated by *ring* for ECC keys.
 ///
@@ -61,5 +64,6 @@ const SEED_MAX_BYTES: usize = ELEM_MAX_BYTES;
 pub const PKCS8_DOCUMENT_MAX_LEN: usize = 40 + SCALAR_MAX_BYTES + keys::PUBLIC_KEY_MAX_LEN;
 
 pub mod curve25519;
+pub mod curve448;
 mod keys;
 pub mod suite_b;
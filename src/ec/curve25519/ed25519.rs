@@ -15,7 +15,7 @@
 //! EdDSA Signatures.
 
 use super::ops::ELEM_LEN;
-use crate::digest;
+use crate::{digest, error};
 
 pub mod signing;
 pub mod verification;
@@ -23,10 +23,45 @@ pub mod verification;
 /// The length of an Ed25519 public key.
 pub const ED25519_PUBLIC_KEY_LEN: usize = ELEM_LEN;
 
-pub fn eddsa_digest(signature_r: &[u8], public_key: &[u8], msg: &[u8]) -> digest::Digest {
+pub fn eddsa_digest(dom: Dom, signature_r: &[u8], public_key: &[u8], msg: &[u8]) -> digest::Digest {
     let mut ctx = digest::Context::new(&digest::SHA512);
+    dom.update(&mut ctx);
     ctx.update(signature_r);
     ctx.update(public_key);
     ctx.update(msg);
     ctx.finish()
 }
+
+/// The prefix of every hash computed while signing or verifying.
+///
+/// Plain Ed25519 has no prefix. Ed25519ctx, specified in [RFC 8032 Section
+/// 5.1], prefixes `dom2(0, context)`, so that a signature made with one
+/// context doesn't verify with any other context, nor as a plain Ed25519
+/// signature.
+///
+/// [RFC 8032 Section 5.1]: https://tools.ietf.org/html/rfc8032#section-5.1
+#[derive(Clone, Copy)]
+pub enum Dom<'a> {
+    None,
+    Context(&'a [u8]),
+}
+
+impl<'a> Dom<'a> {
+    /// Fails unless `context` is 1 to 255 bytes long. RFC 8032 says an empty
+    /// context shouldn't be used with Ed25519ctx.
+    pub fn context(context: &'a [u8]) -> Result<Self, error::Unspecified> {
+        if context.is_empty() || context.len() > usize::from(u8::MAX) {
+            return Err(error::Unspecified);
+        }
+        Ok(Self::Context(context))
+    }
+
+    pub fn update(self, ctx: &mut digest::Context) {
+        if let Self::Context(context) = self {
+            ctx.update(b"SigEd25519 no Ed25519 collisions");
+            // `Dom::context` ensures the length fits in a byte.
+            ctx.update(&[0, u8::try_from(context.len()).unwrap()]);
+            ctx.update(context);
+        }
+    }
+}
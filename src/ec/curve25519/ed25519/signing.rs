@@ -14,7 +14,7 @@
 
 //! EdDSA Signatures.
 
-use super::{super::ops::*, eddsa_digest, ED25519_PUBLIC_KEY_LEN};
+use super::{super::ops::*, eddsa_digest, Dom, ED25519_PUBLIC_KEY_LEN};
 use crate::{
     cpu, digest, error,
     io::der,
@@ -89,6 +89,20 @@ impl Ed25519KeyPair {
         )
     }
 
+    /// Like `Ed25519KeyPair::from_pkcs8()`, except the PKCS#8 document is
+    /// PEM-encoded with the label `PRIVATE KEY`.
+    ///
+    /// `openssl genpkey -algorithm ED25519` generates PKCS#8 v1 keys, which
+    /// are rejected; use `Ed25519KeyPair::from_pem_maybe_unchecked()` for
+    /// those.
+    #[cfg(feature = "alloc")]
+    pub fn from_pem(pem: &str) -> Result<Self, error::KeyRejected> {
+        crate::pem::decode_key(pem, |label, der| match label {
+            crate::pem::Label::PrivateKey => Self::from_pkcs8(der),
+            _ => Err(error::KeyRejected::wrong_algorithm()),
+        })
+    }
+
     /// Constructs an Ed25519 key pair by parsing an unencrypted PKCS#8 v1 or v2
     /// Ed25519 private key.
     ///
@@ -123,6 +137,16 @@ impl Ed25519KeyPair {
         }
     }
 
+    /// Like `Ed25519KeyPair::from_pkcs8_maybe_unchecked()`, except the PKCS#8
+    /// document is PEM-encoded with the label `PRIVATE KEY`.
+    #[cfg(feature = "alloc")]
+    pub fn from_pem_maybe_unchecked(pem: &str) -> Result<Self, error::KeyRejected> {
+        crate::pem::decode_key(pem, |label, der| match label {
+            crate::pem::Label::PrivateKey => Self::from_pkcs8_maybe_unchecked(der),
+            _ => Err(error::KeyRejected::wrong_algorithm()),
+        })
+    }
+
     /// Constructs an Ed25519 key pair from the private key seed `seed` and its
     /// public key `public_key`.
     ///
@@ -171,38 +195,74 @@ impl Ed25519KeyPair {
     }
 
     fn from_seed_(seed: &Seed, cpu_features: cpu::Features) -> Self {
-        let h = digest::digest(&digest::SHA512, seed);
-        let (private_scalar, private_prefix) = h.as_ref().split_at(SCALAR_LEN);
-
-        let private_scalar =
-            MaskedScalar::from_bytes_masked(private_scalar.try_into().unwrap()).into();
-
-        let a = ExtPoint::from_scalarmult_base_consttime(&private_scalar, cpu_features);
-
+        let (private_scalar, private_prefix) = expand_seed(seed);
+        let public_key = PublicKey::from_private_scalar(&private_scalar, cpu_features);
         Self {
             private_scalar,
-            private_prefix: private_prefix.try_into().unwrap(),
-            public_key: PublicKey(a.into_encoded_point(cpu_features)),
+            private_prefix,
+            public_key,
         }
     }
 
+    /// Computes the public key for the private key seed `seed`.
+    ///
+    /// This is useful when only the seed is stored and only the public key
+    /// is needed, e.g. when enumerating the public keys of many seeds. It
+    /// gives the same result as
+    /// `Ed25519KeyPair::from_seed_unchecked(seed)?.public_key()` without
+    /// constructing a key pair.
+    pub fn public_key_from_seed(seed: &[u8]) -> Result<PublicKey, error::KeyRejected> {
+        let seed = seed
+            .try_into()
+            .map_err(|_| error::KeyRejected::invalid_encoding())?;
+        let (private_scalar, _) = expand_seed(seed);
+        Ok(PublicKey::from_private_scalar(
+            &private_scalar,
+            cpu::features(),
+        ))
+    }
+
+    // RFC 8032 Section 5.1.5's *s*.
+    pub(crate) fn private_scalar(&self) -> &Scalar {
+        &self.private_scalar
+    }
+
     /// Returns the signature of the message `msg`.
     pub fn sign(&self, msg: &[u8]) -> signature::Signature {
+        self.sign_(Dom::None, msg)
+    }
+
+    /// Returns the Ed25519ctx signature of the message `msg` in the domain
+    /// `domain`.
+    ///
+    /// The signature only verifies with
+    /// [`EdDSAParameters::verify_domain_separated`] and the same `domain`, so
+    /// a signature made for one protocol can't be replayed in another
+    /// protocol that uses the same key with a different domain. It never
+    /// verifies as a plain Ed25519 signature.
+    ///
+    /// This is Ed25519ctx from [RFC 8032 Section 5.1], with `domain` as the
+    /// context. Fails unless `domain` is 1 to 255 bytes long.
+    ///
+    /// [`EdDSAParameters::verify_domain_separated`]:
+    ///     crate::signature::EdDSAParameters::verify_domain_separated
+    /// [RFC 8032 Section 5.1]: https://tools.ietf.org/html/rfc8032#section-5.1
+    pub fn sign_domain_separated(
+        &self,
+        domain: &[u8],
+        msg: &[u8],
+    ) -> Result<signature::Signature, error::Unspecified> {
+        Ok(self.sign_(Dom::context(domain)?, msg))
+    }
+
+    fn sign_(&self, dom: Dom, msg: &[u8]) -> signature::Signature {
         let cpu_features = cpu::features();
         signature::Signature::new(|signature_bytes| {
-            prefixed_extern! {
-                fn x25519_sc_muladd(
-                    s: &mut [u8; SCALAR_LEN],
-                    a: &Scalar,
-                    b: &Scalar,
-                    c: &Scalar,
-                );
-            }
-
             let (signature_bytes, _unused) = signature_bytes.split_at_mut(ELEM_LEN + SCALAR_LEN);
             let (signature_r, signature_s) = signature_bytes.split_at_mut(ELEM_LEN);
             let nonce = {
                 let mut ctx = digest::Context::new(&digest::SHA512);
+                dom.update(&mut ctx);
                 ctx.update(&self.private_prefix);
                 ctx.update(msg);
                 ctx.finish()
@@ -211,16 +271,10 @@ impl Ed25519KeyPair {
 
             let r = ExtPoint::from_scalarmult_base_consttime(&nonce, cpu_features);
             signature_r.copy_from_slice(&r.into_encoded_point(cpu_features));
-            let hram_digest = eddsa_digest(signature_r, self.public_key.as_ref(), msg);
+            let hram_digest = eddsa_digest(dom, signature_r, self.public_key.as_ref(), msg);
             let hram = Scalar::from_sha512_digest_reduced(hram_digest);
-            unsafe {
-                x25519_sc_muladd(
-                    signature_s.try_into().unwrap(),
-                    &hram,
-                    &self.private_scalar,
-                    &nonce,
-                );
-            }
+            let s = Scalar::mul_add(&hram, &self.private_scalar, &nonce);
+            signature_s.copy_from_slice(s.bytes_less_safe());
 
             SIGNATURE_LEN
         })
@@ -244,8 +298,29 @@ impl AsRef<[u8]> for PublicKey {
     }
 }
 
+impl PublicKey {
+    fn from_private_scalar(private_scalar: &Scalar, cpu_features: cpu::Features) -> Self {
+        let a = ExtPoint::from_scalarmult_base_consttime(private_scalar, cpu_features);
+        Self(a.into_encoded_point(cpu_features))
+    }
+
+    /// Returns whether `other_bytes` is this public key. Ed25519 public keys
+    /// have only one encoding.
+    pub fn matches(&self, other_bytes: &[u8]) -> bool {
+        self.as_ref() == other_bytes
+    }
+}
+
 derive_debug_self_as_ref_hex_bytes!(PublicKey);
 
+// RFC 8032 Section 5.1.5, steps 1-3.
+fn expand_seed(seed: &Seed) -> (Scalar, Prefix) {
+    let h = digest::digest(&digest::SHA512, seed);
+    let (private_scalar, private_prefix) = h.as_ref().split_at(SCALAR_LEN);
+    let private_scalar = MaskedScalar::from_bytes_masked(private_scalar.try_into().unwrap());
+    (private_scalar.into(), private_prefix.try_into().unwrap())
+}
+
 fn unwrap_pkcs8(
     version: pkcs8::Version,
     input: untrusted::Input,
@@ -14,8 +14,8 @@
 
 //! EdDSA Signatures.
 
-use super::{super::ops::*, eddsa_digest};
-use crate::{cpu, error, sealed, signature};
+use super::{super::ops::*, eddsa_digest, Dom};
+use crate::{c, cpu, error, rand, sealed, signature};
 
 /// Parameters for EdDSA signing and verification.
 pub struct EdDSAParameters;
@@ -33,6 +33,31 @@ impl core::fmt::Debug for EdDSAParameters {
 /// [Ed25519]: https://ed25519.cr.yp.to/
 pub static ED25519: EdDSAParameters = EdDSAParameters {};
 
+impl EdDSAParameters {
+    /// Verifies that `signature` is a valid signature of `msg` in the domain
+    /// `domain`, made with `Ed25519KeyPair::sign_domain_separated`, using
+    /// the public key `public_key`.
+    ///
+    /// This is Ed25519ctx verification, from [RFC 8032 Section 5.1], with
+    /// `domain` as the context. Fails unless `domain` is 1 to 255 bytes long.
+    ///
+    /// [RFC 8032 Section 5.1]: https://tools.ietf.org/html/rfc8032#section-5.1
+    pub fn verify_domain_separated(
+        &self,
+        public_key: &[u8],
+        domain: &[u8],
+        msg: &[u8],
+        signature: &[u8],
+    ) -> Result<(), error::Unspecified> {
+        verify(
+            Dom::context(domain)?,
+            untrusted::Input::from(public_key),
+            untrusted::Input::from(msg),
+            untrusted::Input::from(signature),
+        )
+    }
+}
+
 impl signature::VerificationAlgorithm for EdDSAParameters {
     fn verify(
         &self,
@@ -40,41 +65,202 @@ impl signature::VerificationAlgorithm for EdDSAParameters {
         msg: untrusted::Input,
         signature: untrusted::Input,
     ) -> Result<(), error::Unspecified> {
-        let cpu_features = cpu::features();
-
-        let public_key: &[u8; ELEM_LEN] = public_key.as_slice_less_safe().try_into()?;
-        let (signature_r, signature_s) = signature.read_all(error::Unspecified, |input| {
-            let signature_r: &[u8; ELEM_LEN] = input
-                .read_bytes(ELEM_LEN)?
-                .as_slice_less_safe()
-                .try_into()?;
-            let signature_s: &[u8; SCALAR_LEN] = input
-                .read_bytes(SCALAR_LEN)?
-                .as_slice_less_safe()
-                .try_into()?;
-            Ok((signature_r, signature_s))
-        })?;
-
-        let signature_s = Scalar::from_bytes_checked(*signature_s)?;
-
-        let mut a = ExtPoint::from_encoded_point_vartime(public_key)?;
-        a.invert_vartime();
-
-        let h_digest = eddsa_digest(signature_r, public_key, msg.as_slice_less_safe());
-        let h = Scalar::from_sha512_digest_reduced(h_digest);
+        verify(Dom::None, public_key, msg, signature)
+    }
+}
+
+fn verify(
+    dom: Dom,
+    public_key: untrusted::Input,
+    msg: untrusted::Input,
+    signature: untrusted::Input,
+) -> Result<(), error::Unspecified> {
+    let cpu_features = cpu::features();
+
+    let public_key: &[u8; ELEM_LEN] = public_key.as_slice_less_safe().try_into()?;
+    let (signature_r, signature_s) = parse_signature(signature)?;
+
+    let mut a = ExtPoint::from_encoded_point_vartime(public_key)?;
+    a.invert_vartime();
+
+    let h_digest = eddsa_digest(dom, signature_r, public_key, msg.as_slice_less_safe());
+    let h = Scalar::from_sha512_digest_reduced(h_digest);
+
+    let mut r = Point::new_at_infinity();
+    unsafe { x25519_ge_double_scalarmult_vartime(&mut r, &h, &a, &signature_s) };
+    let r_check = r.into_encoded_point(cpu_features);
+    if *signature_r != r_check {
+        return Err(error::Unspecified);
+    }
+    Ok(())
+}
+
+fn parse_signature(
+    signature: untrusted::Input<'_>,
+) -> Result<(&'_ EncodedPoint, Scalar), error::Unspecified> {
+    let (signature_r, signature_s) = signature.read_all(error::Unspecified, |input| {
+        let signature_r: &[u8; ELEM_LEN] = input
+            .read_bytes(ELEM_LEN)?
+            .as_slice_less_safe()
+            .try_into()?;
+        let signature_s: &[u8; SCALAR_LEN] = input
+            .read_bytes(SCALAR_LEN)?
+            .as_slice_less_safe()
+            .try_into()?;
+        Ok((signature_r, signature_s))
+    })?;
+    Ok((signature_r, Scalar::from_bytes_checked(*signature_s)?))
+}
+
+/// Verifies a batch of Ed25519 signatures, each given as a tuple of
+/// `(public_key, msg, signature)`, succeeding only if every signature is
+/// valid.
+///
+/// This is faster than verifying each signature with `ED25519` because it
+/// checks one random linear combination of the verification equations,
+/// using one multi-scalar multiplication. The random coefficients are
+/// generated with `rng`. On failure, there's no indication of which
+/// signature was invalid; verify the signatures one at a time to find out.
+/// An empty batch is valid.
+///
+/// The batch equation is checked after multiplying by the cofactor 8, as
+/// [RFC 8032 Section 5.1.7] permits. `ED25519` checks the equation without
+/// the cofactor, so a batch may be accepted even though `ED25519` would
+/// reject one of its signatures, but only if that signature's `R` or public
+/// key has a component of small order, which only a signer that is
+/// deliberately creating such signatures will do. Where every verifier must
+/// reach the same decision, such as in a consensus protocol, all verifiers
+/// must use the same method.
+///
+/// [RFC 8032 Section 5.1.7]: https://tools.ietf.org/html/rfc8032#section-5.1.7
+#[cfg(feature = "alloc")]
+pub fn ed25519_verify_batch(
+    entries: &[(&[u8], &[u8], &[u8])],
+    rng: &dyn rand::SecureRandom,
+) -> Result<(), error::Unspecified> {
+    // Bound the size of the scratch space, and keep it in the cache.
+    const MAX_CHUNK_LEN: usize = 64;
+    entries
+        .chunks(MAX_CHUNK_LEN)
+        .try_for_each(|chunk| verify_batch_chunk(chunk, rng))
+}
 
-        let mut r = Point::new_at_infinity();
-        unsafe { x25519_ge_double_scalarmult_vartime(&mut r, &h, &a, &signature_s) };
-        let r_check = r.into_encoded_point(cpu_features);
-        if *signature_r != r_check {
+#[cfg(feature = "alloc")]
+fn verify_batch_chunk(
+    entries: &[(&[u8], &[u8], &[u8])],
+    rng: &dyn rand::SecureRandom,
+) -> Result<(), error::Unspecified> {
+    use alloc::{vec, vec::Vec};
+
+    let cpu_features = cpu::features();
+
+    // For each signature with random coefficient z, check that
+    //    [z]R + [z*h]A - [z*s]B = 0,
+    // summing the `B` terms.
+    let zero = Scalar::from_bytes_checked([0; SCALAR_LEN])?;
+    let mut points = Vec::with_capacity(2 * entries.len());
+    let mut coeffs = Vec::with_capacity(2 * entries.len());
+    let mut b_coeff = Scalar::from_bytes_checked([0; SCALAR_LEN])?;
+    // 128-bit coefficients are enough for the batch to be accepted with
+    // probability at most 2**-128 when any signature is invalid.
+    let mut z_bytes = vec![0; 16 * entries.len()];
+    rng.fill(&mut z_bytes)?;
+    for (&(public_key, msg, signature), z_bytes) in entries.iter().zip(z_bytes.chunks_exact(16)) {
+        let public_key: &[u8; ELEM_LEN] = public_key.try_into()?;
+        let (signature_r, signature_s) = parse_signature(untrusted::Input::from(signature))?;
+
+        // `verify()` compares `R` with a canonical encoding, so it rejects
+        // every other encoding of `R`.
+        if !is_canonical_encoding(signature_r) {
             return Err(error::Unspecified);
         }
-        Ok(())
+        let r = ExtPoint::from_encoded_point_vartime(signature_r)?;
+        let a = ExtPoint::from_encoded_point_vartime(public_key)?;
+
+        let h_digest = eddsa_digest(Dom::None, signature_r, public_key, msg);
+        let h = Scalar::from_sha512_digest_reduced(h_digest);
+
+        let mut z = [0; SCALAR_LEN];
+        z[..16].copy_from_slice(z_bytes);
+        let z = Scalar::from_bytes_checked(z)?;
+
+        b_coeff = Scalar::mul_add(&z, &signature_s, &b_coeff);
+        coeffs.push(Scalar::mul_add(&z, &h, &zero));
+        points.push(a);
+        coeffs.push(z);
+        points.push(r);
+    }
+    let b_coeff = Scalar::mul_add(&b_coeff, &N_MINUS_ONE, &zero);
+
+    let mut scratch = Vec::with_capacity(8 * points.len());
+    scratch.resize_with(8 * points.len(), CachedPoint::zero);
+    let mut slides = vec![0i8; 256 * points.len()];
+
+    let mut result = Point::new_at_infinity();
+    unsafe {
+        x25519_ge_cofactor_multi_scalarmult_vartime(
+            &mut result,
+            &b_coeff,
+            coeffs.as_ptr(),
+            points.as_ptr(),
+            points.len(),
+            scratch.as_mut_ptr(),
+            slides.as_mut_ptr(),
+        )
+    };
+    if result.into_encoded_point(cpu_features) != NEUTRAL_ELEMENT {
+        return Err(error::Unspecified);
+    }
+    Ok(())
+}
+
+// Whether `encoded` is the canonical encoding of a point, assuming it
+// encodes a point at all.
+#[cfg(feature = "alloc")]
+fn is_canonical_encoding(encoded: &EncodedPoint) -> bool {
+    let (y_low, y_high) = (encoded[0], &encoded[1..]);
+    let x_is_negative = encoded[31] >> 7 == 1;
+    let y_high_is_max = y_high[..30].iter().all(|&b| b == 0xff) && y_high[30] & 0x7f == 0x7f;
+    let y_high_is_zero = y_high[..30].iter().all(|&b| b == 0) && y_high[30] & 0x7f == 0;
+
+    // y >= 2**255 - 19.
+    if y_high_is_max && y_low >= 0xed {
+        return false;
     }
+    // x == 0, when y is 1 or -1, is never negative.
+    let x_is_zero = (y_high_is_zero && y_low == 1) || (y_high_is_max && y_low == 0xec);
+    !(x_is_zero && x_is_negative)
 }
 
+#[cfg(feature = "alloc")]
+const NEUTRAL_ELEMENT: EncodedPoint = {
+    let mut r = [0; ELEM_LEN];
+    r[0] = 1;
+    r
+};
+
+// n - 1, where n is the order of the base point.
+#[cfg(feature = "alloc")]
+static N_MINUS_ONE: Scalar = Scalar::from_bytes_unchecked([
+    0xec, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58, 0xd6, 0x9c, 0xf7, 0xa2, 0xde, 0xf9, 0xde, 0x14,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10,
+]);
+
 impl sealed::Sealed for EdDSAParameters {}
 
+#[cfg(feature = "alloc")]
+prefixed_extern! {
+    fn x25519_ge_cofactor_multi_scalarmult_vartime(
+        r: &mut Point,
+        b_coeff: &Scalar,
+        a_coeffs: *const Scalar,
+        a: *const ExtPoint,
+        n: c::size_t,
+        scratch: *mut CachedPoint,
+        slides: *mut i8,
+    );
+}
+
 prefixed_extern! {
     fn x25519_ge_double_scalarmult_vartime(
         r: &mut Point,
@@ -34,6 +34,8 @@ pub struct Elem<E: Encoding> {
 pub trait Encoding {}
 pub struct T;
 impl Encoding for T {}
+pub struct L;
+impl Encoding for L {}
 
 const ELEM_LIMBS: usize = 5 * 64 / LIMB_BITS;
 
@@ -109,6 +111,37 @@ impl ExtPoint {
         self.x.negate();
         self.t.negate();
     }
+
+    // Returns the u-coordinate of the corresponding Curve25519 point, as
+    // encoded by X25519. The neutral element maps to zero.
+    pub fn into_montgomery_u(self) -> EncodedPoint {
+        prefixed_extern! {
+            fn x25519_ge_p3_to_montgomery_u(out: &mut EncodedPoint, a: &ExtPoint);
+        }
+        let mut u = [0; ELEM_LEN];
+        unsafe { x25519_ge_p3_to_montgomery_u(&mut u, &self) };
+        u
+    }
+}
+
+// Keep this in sync with `ge_cached` in curve25519/internal.h.
+#[repr(C)]
+pub struct CachedPoint {
+    yplusx: Elem<L>,
+    yminusx: Elem<L>,
+    z: Elem<L>,
+    t2d: Elem<L>,
+}
+
+impl CachedPoint {
+    pub fn zero() -> Self {
+        Self {
+            yplusx: Elem::zero(),
+            yminusx: Elem::zero(),
+            z: Elem::zero(),
+            t2d: Elem::zero(),
+        }
+    }
 }
 
 // Keep this in sync with `ge_p2` in curve25519/internal.h.
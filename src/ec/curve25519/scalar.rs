@@ -41,6 +41,31 @@ impl Scalar {
         Ok(Self(bytes))
     }
 
+    pub fn bytes_less_safe(&self) -> &[u8; SCALAR_LEN] {
+        &self.0
+    }
+
+    // Returns `(a * b + c) mod n`.
+    pub fn mul_add(a: &Self, b: &Self, c: &Self) -> Self {
+        prefixed_extern! {
+            fn x25519_sc_muladd(
+                s: &mut [u8; SCALAR_LEN],
+                a: &Scalar,
+                b: &Scalar,
+                c: &Scalar,
+            );
+        }
+        let mut r = [0; SCALAR_LEN];
+        unsafe { x25519_sc_muladd(&mut r, a, b, c) };
+        Self(r)
+    }
+
+    // Constructs a `Scalar` from `bytes`, which must encode a scalar in the
+    // range [0, n).
+    pub const fn from_bytes_unchecked(bytes: [u8; SCALAR_LEN]) -> Self {
+        Self(bytes)
+    }
+
     // Constructs a `Scalar` from `digest` reduced modulo n.
     pub fn from_sha512_digest_reduced(digest: digest::Digest) -> Self {
         prefixed_extern! {
@@ -14,7 +14,11 @@
 
 //! X25519 Key agreement.
 
-use super::{ops, scalar::SCALAR_LEN};
+use super::{
+    ed25519::{signing::Ed25519KeyPair, ED25519_PUBLIC_KEY_LEN},
+    ops,
+    scalar::SCALAR_LEN,
+};
 use crate::{agreement, c, constant_time, cpu, ec, error, rand};
 
 static CURVE25519: ec::Curve = ec::Curve {
@@ -102,45 +106,6 @@ fn x25519_ecdh(
     let my_private_key = ops::MaskedScalar::from_bytes_masked(*my_private_key);
     let peer_public_key: &[u8; PUBLIC_KEY_LEN] = peer_public_key.as_slice_less_safe().try_into()?;
 
-    fn scalar_mult(
-        out: &mut ops::EncodedPoint,
-        scalar: &ops::MaskedScalar,
-        point: &ops::EncodedPoint,
-        #[allow(unused_variables)] cpu_features: cpu::Features,
-    ) {
-        #[cfg(all(target_arch = "arm", any(target_os = "android", target_os = "linux")))]
-        {
-            if cpu::arm::NEON.available(cpu_features) {
-                return x25519_neon(out, scalar, point);
-            }
-        }
-
-        #[cfg(all(target_arch = "x86_64", not(target_os = "windows")))]
-        {
-            if ops::has_fe25519_adx(cpu_features) {
-                prefixed_extern! {
-                    fn x25519_scalar_mult_adx(
-                        out: &mut ops::EncodedPoint,
-                        scalar: &ops::MaskedScalar,
-                        point: &ops::EncodedPoint,
-                    );
-                }
-                return unsafe { x25519_scalar_mult_adx(out, scalar, point) };
-            }
-        }
-
-        prefixed_extern! {
-            fn x25519_scalar_mult_generic_masked(
-                out: &mut ops::EncodedPoint,
-                scalar: &ops::MaskedScalar,
-                point: &ops::EncodedPoint,
-            );
-        }
-        unsafe {
-            x25519_scalar_mult_generic_masked(out, scalar, point);
-        }
-    }
-
     scalar_mult(
         out.try_into()?,
         &my_private_key,
@@ -157,6 +122,45 @@ fn x25519_ecdh(
     Ok(())
 }
 
+fn scalar_mult(
+    out: &mut ops::EncodedPoint,
+    scalar: &ops::MaskedScalar,
+    point: &ops::EncodedPoint,
+    #[allow(unused_variables)] cpu_features: cpu::Features,
+) {
+    #[cfg(all(target_arch = "arm", any(target_os = "android", target_os = "linux")))]
+    {
+        if cpu::arm::NEON.available(cpu_features) {
+            return x25519_neon(out, scalar, point);
+        }
+    }
+
+    #[cfg(all(target_arch = "x86_64", not(target_os = "windows")))]
+    {
+        if ops::has_fe25519_adx(cpu_features) {
+            prefixed_extern! {
+                fn x25519_scalar_mult_adx(
+                    out: &mut ops::EncodedPoint,
+                    scalar: &ops::MaskedScalar,
+                    point: &ops::EncodedPoint,
+                );
+            }
+            return unsafe { x25519_scalar_mult_adx(out, scalar, point) };
+        }
+    }
+
+    prefixed_extern! {
+        fn x25519_scalar_mult_generic_masked(
+            out: &mut ops::EncodedPoint,
+            scalar: &ops::MaskedScalar,
+            point: &ops::EncodedPoint,
+        );
+    }
+    unsafe {
+        x25519_scalar_mult_generic_masked(out, scalar, point);
+    }
+}
+
 // BoringSSL uses `!defined(OPENSSL_APPLE)`.
 #[cfg(all(target_arch = "arm", any(target_os = "android", target_os = "linux")))]
 fn x25519_neon(out: &mut ops::EncodedPoint, scalar: &ops::MaskedScalar, point: &ops::EncodedPoint) {
@@ -170,6 +174,34 @@ fn x25519_neon(out: &mut ops::EncodedPoint, scalar: &ops::MaskedScalar, point: &
     unsafe { x25519_NEON(out, scalar, point) }
 }
 
+// The X25519 function of RFC 7748 Section 5, without the check for an
+// all-zero result.
+pub(crate) fn x25519(
+    scalar: &[u8; SCALAR_LEN],
+    point: &ops::EncodedPoint,
+    cpu_features: cpu::Features,
+) -> SharedSecret {
+    let scalar = ops::MaskedScalar::from_bytes_masked(*scalar);
+    let mut out = [0; SHARED_SECRET_LEN];
+    scalar_mult(&mut out, &scalar, point, cpu_features);
+    out
+}
+
+// The X25519 private key with the same scalar as `key_pair`.
+pub(crate) fn private_key_from_ed25519(key_pair: &Ed25519KeyPair) -> [u8; PRIVATE_KEY_LEN] {
+    // The Ed25519 private scalar is already clamped the way X25519 clamps.
+    *key_pair.private_scalar().bytes_less_safe()
+}
+
+// The X25519 public key for the Ed25519 public key, using the birational map
+// u = (1 + y) / (1 - y) of RFC 7748 Section 4.1.
+pub(crate) fn public_key_from_ed25519(
+    ed25519_public_key: &[u8; ED25519_PUBLIC_KEY_LEN],
+) -> Result<PublicKey, error::Unspecified> {
+    ops::ExtPoint::from_encoded_point_vartime(ed25519_public_key)
+        .map(ops::ExtPoint::into_montgomery_u)
+}
+
 const ELEM_AND_SCALAR_LEN: usize = ops::ELEM_LEN;
 
 type PrivateKey = ops::MaskedScalar;
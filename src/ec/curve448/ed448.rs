@@ -0,0 +1,302 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Ed448 signatures, as specified in [RFC 8032 Section 5.2].
+//!
+//! [RFC 8032 Section 5.2]: https://tools.ietf.org/html/rfc8032#section-5.2
+
+use super::{
+    ops::{EncodedPoint, Point, POINT_LEN},
+    scalar::{Scalar, SCALAR_LEN},
+};
+use crate::{digest::keccak, error, sealed, signature};
+
+/// The length of an Ed448 public key.
+pub const ED448_PUBLIC_KEY_LEN: usize = POINT_LEN;
+
+/// The length of an Ed448 private key seed.
+pub const ED448_SEED_LEN: usize = 57;
+
+const SIGNATURE_LEN: usize = POINT_LEN + SCALAR_LEN;
+
+/// SHAKE256's rate, in bytes.
+const SHAKE256_RATE: usize = 136;
+
+/// SHAKE256's domain separation bits followed by the first padding bit.
+const SHAKE_SUFFIX: u8 = 0x1f;
+
+/// The length of every SHAKE256 output Ed448 uses.
+const DIGEST_LEN: usize = 2 * SCALAR_LEN;
+
+/// An Ed448 key pair, for signing.
+pub struct Ed448KeyPair {
+    // RFC 8032 Section 5.2.6 calls this *s*.
+    private_scalar: Scalar,
+
+    // RFC 8032 Section 5.2.6 calls this *prefix*.
+    private_prefix: [u8; SCALAR_LEN],
+
+    // RFC 8032 Section 5.2.5 calls this *A*.
+    public_key: PublicKey,
+}
+
+derive_debug_via_field!(Ed448KeyPair, stringify!(Ed448KeyPair), public_key);
+
+impl Ed448KeyPair {
+    /// Constructs an Ed448 key pair from the private key seed `seed` and its
+    /// public key `public_key`.
+    ///
+    /// The private and public keys will be verified to be consistent with each
+    /// other. This helps avoid misuse of the key (e.g. accidentally swapping
+    /// the private key and public key, or using the wrong private key for the
+    /// public key). This also detects any corruption of the public or private
+    /// key.
+    pub fn from_seed_and_public_key(
+        seed: &[u8],
+        public_key: &[u8],
+    ) -> Result<Self, error::KeyRejected> {
+        let pair = Self::from_seed_unchecked(seed)?;
+
+        // This implicitly verifies that `public_key` is the right length.
+        if public_key != pair.public_key.as_ref() {
+            let err = if public_key.len() != pair.public_key.as_ref().len() {
+                error::KeyRejected::invalid_encoding()
+            } else {
+                error::KeyRejected::inconsistent_components()
+            };
+            return Err(err);
+        }
+
+        Ok(pair)
+    }
+
+    /// Constructs an Ed448 key pair from the private key seed `seed`.
+    ///
+    /// A new seed is `ED448_SEED_LEN` random bytes, e.g. from
+    /// `rand::generate()`. It is recommended to use
+    /// `Ed448KeyPair::from_seed_and_public_key()` instead when the public key
+    /// is available.
+    ///
+    /// Since the public key is not given, the public key will be computed from
+    /// the private key. It is not possible to detect misuse or corruption of
+    /// the private key since the public key isn't given as input.
+    pub fn from_seed_unchecked(seed: &[u8]) -> Result<Self, error::KeyRejected> {
+        let seed: &[u8; ED448_SEED_LEN] = seed
+            .try_into()
+            .map_err(|_| error::KeyRejected::invalid_encoding())?;
+
+        // RFC 8032 Section 5.2.5, steps 1-3.
+        let h = shake256(&[seed]);
+        let (private_scalar, private_prefix) = h.split_at(SCALAR_LEN);
+        let mut private_scalar: [u8; SCALAR_LEN] = private_scalar.try_into().unwrap();
+        private_scalar[0] &= 0xfc;
+        private_scalar[SCALAR_LEN - 2] |= 0x80;
+        private_scalar[SCALAR_LEN - 1] = 0;
+        // B has order L so reducing *s* doesn't change *A* = [s]B.
+        let private_scalar = Scalar::from_bytes_reduced(&private_scalar);
+
+        let public_key = PublicKey(Point::base().mul(&private_scalar.to_bytes()).to_encoded());
+        Ok(Self {
+            private_scalar,
+            private_prefix: private_prefix.try_into().unwrap(),
+            public_key,
+        })
+    }
+
+    /// Returns the signature of the message `msg`.
+    pub fn sign(&self, msg: &[u8]) -> signature::Signature {
+        self.sign_(Dom4(b""), msg)
+    }
+
+    /// Returns the Ed448 signature of the message `msg` with the context
+    /// `domain`.
+    ///
+    /// The signature only verifies with
+    /// [`Ed448Parameters::verify_domain_separated`] and the same `domain`, so
+    /// a signature made for one protocol can't be replayed in another
+    /// protocol that uses the same key with a different domain. It never
+    /// verifies as a signature made with `sign()`, which uses the empty
+    /// context. Fails unless `domain` is 1 to 255 bytes long.
+    ///
+    /// [`Ed448Parameters::verify_domain_separated`]:
+    ///     crate::signature::Ed448Parameters::verify_domain_separated
+    pub fn sign_domain_separated(
+        &self,
+        domain: &[u8],
+        msg: &[u8],
+    ) -> Result<signature::Signature, error::Unspecified> {
+        Ok(self.sign_(Dom4::context(domain)?, msg))
+    }
+
+    // RFC 8032 Section 5.2.6.
+    fn sign_(&self, dom: Dom4, msg: &[u8]) -> signature::Signature {
+        signature::Signature::new(|signature_bytes| {
+            let (signature_bytes, _unused) = signature_bytes.split_at_mut(SIGNATURE_LEN);
+            let (signature_r, signature_s) = signature_bytes.split_at_mut(POINT_LEN);
+
+            let nonce = dom.digest(&[&self.private_prefix, msg]);
+            let nonce = Scalar::from_bytes_reduced(&nonce);
+
+            let r = Point::base().mul(&nonce.to_bytes());
+            signature_r.copy_from_slice(&r.to_encoded());
+            let hram = dom.digest(&[signature_r, self.public_key.as_ref(), msg]);
+            let hram = Scalar::from_bytes_reduced(&hram);
+            let s = Scalar::mul_add(&hram, &self.private_scalar, &nonce);
+            signature_s.copy_from_slice(&s.to_bytes());
+
+            SIGNATURE_LEN
+        })
+    }
+}
+
+impl signature::KeyPair for Ed448KeyPair {
+    type PublicKey = PublicKey;
+
+    fn public_key(&self) -> &Self::PublicKey {
+        &self.public_key
+    }
+}
+
+/// An Ed448 public key.
+#[derive(Clone, Copy)]
+pub struct PublicKey([u8; ED448_PUBLIC_KEY_LEN]);
+
+impl AsRef<[u8]> for PublicKey {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+derive_debug_self_as_ref_hex_bytes!(PublicKey);
+
+/// Parameters for Ed448 verification.
+pub struct Ed448Parameters;
+
+impl core::fmt::Debug for Ed448Parameters {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        write!(f, "ring::signature::ED448")
+    }
+}
+
+/// Verification of Ed448 signatures, as specified in [RFC 8032 Section
+/// 5.2], with an empty context.
+///
+/// Ed448 uses SHAKE256 as the hash function.
+///
+/// [RFC 8032 Section 5.2]: https://tools.ietf.org/html/rfc8032#section-5.2
+pub static ED448: Ed448Parameters = Ed448Parameters {};
+
+impl Ed448Parameters {
+    /// Verifies that `signature` is a valid signature of `msg` with the
+    /// context `domain`, made with `Ed448KeyPair::sign_domain_separated`,
+    /// using the public key `public_key`.
+    ///
+    /// Fails unless `domain` is 1 to 255 bytes long.
+    pub fn verify_domain_separated(
+        &self,
+        public_key: &[u8],
+        domain: &[u8],
+        msg: &[u8],
+        signature: &[u8],
+    ) -> Result<(), error::Unspecified> {
+        verify(Dom4::context(domain)?, public_key, msg, signature)
+    }
+}
+
+impl signature::VerificationAlgorithm for Ed448Parameters {
+    fn verify(
+        &self,
+        public_key: untrusted::Input,
+        msg: untrusted::Input,
+        signature: untrusted::Input,
+    ) -> Result<(), error::Unspecified> {
+        verify(
+            Dom4(b""),
+            public_key.as_slice_less_safe(),
+            msg.as_slice_less_safe(),
+            signature.as_slice_less_safe(),
+        )
+    }
+}
+
+impl sealed::Sealed for Ed448Parameters {}
+
+// RFC 8032 Section 5.2.7, except that like Ed25519 verification, the
+// non-cofactored equation [S]B = R + [k]A is checked by comparing the
+// encoding of [S]B - [k]A with R.
+fn verify(
+    dom: Dom4,
+    public_key: &[u8],
+    msg: &[u8],
+    signature: &[u8],
+) -> Result<(), error::Unspecified> {
+    let public_key: &EncodedPoint = public_key.try_into()?;
+    if signature.len() != SIGNATURE_LEN {
+        return Err(error::Unspecified);
+    }
+    let (signature_r, signature_s) = signature.split_at(POINT_LEN);
+    let signature_s = Scalar::from_bytes_checked(signature_s.try_into()?)?;
+
+    let a = Point::from_encoded(public_key)?;
+
+    let hram = dom.digest(&[signature_r, public_key, msg]);
+    let hram = Scalar::from_bytes_reduced(&hram);
+
+    let r = Point::base()
+        .mul(&signature_s.to_bytes())
+        .add(&a.neg().mul(&hram.to_bytes()));
+    if signature_r != r.to_encoded() {
+        return Err(error::Unspecified);
+    }
+    Ok(())
+}
+
+/// The `dom4(0, context)` prefix of every hash computed while signing or
+/// verifying, from RFC 8032 Section 5.2. Plain Ed448 uses an empty context.
+#[derive(Clone, Copy)]
+struct Dom4<'a>(&'a [u8]);
+
+impl<'a> Dom4<'a> {
+    /// Fails unless `context` is 1 to 255 bytes long, so that a signature
+    /// with a context never verifies as a plain Ed448 signature.
+    fn context(context: &'a [u8]) -> Result<Self, error::Unspecified> {
+        if context.is_empty() || context.len() > usize::from(u8::MAX) {
+            return Err(error::Unspecified);
+        }
+        Ok(Self(context))
+    }
+
+    fn digest(self, parts: &[&[u8]]) -> [u8; DIGEST_LEN] {
+        let mut sponge = keccak::Sponge::new(SHAKE256_RATE);
+        sponge.update(b"SigEd448");
+        // The context length is checked to fit in a byte when it is
+        // constructed.
+        sponge.update(&[0, u8::try_from(self.0.len()).unwrap()]);
+        sponge.update(self.0);
+        shake256_finish(sponge, parts)
+    }
+}
+
+fn shake256(parts: &[&[u8]]) -> [u8; DIGEST_LEN] {
+    shake256_finish(keccak::Sponge::new(SHAKE256_RATE), parts)
+}
+
+fn shake256_finish(mut sponge: keccak::Sponge, parts: &[&[u8]]) -> [u8; DIGEST_LEN] {
+    for part in parts {
+        sponge.update(part);
+    }
+    let mut out = [0u8; DIGEST_LEN];
+    sponge.finish(SHAKE_SUFFIX, &mut out);
+    out
+}
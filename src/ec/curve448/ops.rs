@@ -0,0 +1,444 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Arithmetic in GF(2^448 - 2^224 - 1) and on the edwards448 curve.
+//!
+//! Everything here is constant-time unless it is documented otherwise.
+
+use super::scalar::SCALAR_LEN;
+use crate::error;
+
+/// The length of an encoded field element.
+pub(super) const ELEM_LEN: usize = 56;
+
+/// The length of an encoded edwards448 point.
+pub(super) const POINT_LEN: usize = ELEM_LEN + 1;
+
+pub(super) type EncodedPoint = [u8; POINT_LEN];
+
+const LIMBS: usize = 8;
+const LIMB_BITS: u32 = 56;
+const LIMB_MASK: u64 = (1 << LIMB_BITS) - 1;
+
+/// p = 2^448 - 2^224 - 1, in the limb representation.
+const P: [u64; LIMBS] = [
+    LIMB_MASK,
+    LIMB_MASK,
+    LIMB_MASK,
+    LIMB_MASK,
+    LIMB_MASK - 1,
+    LIMB_MASK,
+    LIMB_MASK,
+    LIMB_MASK,
+];
+
+/// An element of GF(p), as eight 56-bit limbs, least significant first.
+///
+/// Thanks to p's "golden ratio" form, 2^448 = 2^224 + 1 (mod p), so a carry
+/// out of the top limb is added back into limbs 0 and 4. Limbs may be a few
+/// bits wider than 56 bits between operations, and the value may not be
+/// fully reduced; `to_bytes()` returns the canonical encoding.
+#[derive(Clone, Copy)]
+pub(super) struct Elem([u64; LIMBS]);
+
+impl Elem {
+    pub(super) const ZERO: Self = Self([0; LIMBS]);
+    pub(super) const ONE: Self = Self::from_u32(1);
+
+    #[allow(clippy::cast_lossless)]
+    pub(super) const fn from_u32(value: u32) -> Self {
+        let mut limbs = [0; LIMBS];
+        limbs[0] = value as u64;
+        Self(limbs)
+    }
+
+    /// Decodes the little-endian encoding `bytes`. Values that are not less
+    /// than p are accepted, and reduced, as RFC 7748 requires for X448's
+    /// u-coordinates.
+    pub(super) fn from_bytes(bytes: &[u8; ELEM_LEN]) -> Self {
+        let mut limbs = [0; LIMBS];
+        for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks_exact(7)) {
+            let mut word = [0u8; 8];
+            word[..7].copy_from_slice(chunk);
+            *limb = u64::from_le_bytes(word);
+        }
+        Self(limbs)
+    }
+
+    /// Like `from_bytes()`, but fails unless `bytes` encodes a value less
+    /// than p. Not constant-time.
+    pub(super) fn from_bytes_canonical(bytes: &[u8; ELEM_LEN]) -> Result<Self, error::Unspecified> {
+        let r = Self::from_bytes(bytes);
+        if r.to_bytes() != *bytes {
+            return Err(error::Unspecified);
+        }
+        Ok(r)
+    }
+
+    /// Returns the canonical little-endian encoding of the element.
+    pub(super) fn to_bytes(self) -> [u8; ELEM_LEN] {
+        let mut limbs = self.0;
+        carry(&mut limbs);
+
+        // Now the value is less than 2p. Subtract p, and then add it back if
+        // the subtraction borrowed.
+        let mut borrow: i128 = 0;
+        for (limb, p) in limbs.iter_mut().zip(P) {
+            borrow += i128::from(*limb) - i128::from(p);
+            *limb = low_limb_i128(borrow);
+            borrow >>= LIMB_BITS;
+        }
+        let mask = low_word_i128(borrow); // All ones if it borrowed, or zero.
+        let mut carry: u64 = 0;
+        for (limb, p) in limbs.iter_mut().zip(P) {
+            let sum = *limb + (p & mask) + carry;
+            *limb = sum & LIMB_MASK;
+            carry = sum >> LIMB_BITS;
+        }
+
+        let mut bytes = [0u8; ELEM_LEN];
+        for (chunk, limb) in bytes.chunks_exact_mut(7).zip(limbs) {
+            chunk.copy_from_slice(&limb.to_le_bytes()[..7]);
+        }
+        bytes
+    }
+
+    pub(super) fn add(&self, other: &Self) -> Self {
+        let mut limbs = self.0;
+        for (limb, b) in limbs.iter_mut().zip(other.0) {
+            *limb += b;
+        }
+        carry(&mut limbs);
+        Self(limbs)
+    }
+
+    pub(super) fn sub(&self, other: &Self) -> Self {
+        // Add 2p so that no limb goes negative.
+        let mut limbs = self.0;
+        for ((limb, b), p) in limbs.iter_mut().zip(other.0).zip(P) {
+            *limb = *limb + 2 * p - b;
+        }
+        carry(&mut limbs);
+        Self(limbs)
+    }
+
+    pub(super) fn neg(&self) -> Self {
+        Self::ZERO.sub(self)
+    }
+
+    pub(super) fn mul(&self, other: &Self) -> Self {
+        let mut wide = [0u128; 2 * LIMBS - 1];
+        for (i, a) in self.0.iter().enumerate() {
+            for (w, b) in wide[i..].iter_mut().zip(other.0) {
+                *w += u128::from(*a) * u128::from(b);
+            }
+        }
+
+        // Reduce using 2^448 = 2^224 + 1 (mod p), from the top down so that
+        // the limbs folded into the upper half are folded again.
+        for i in (LIMBS..wide.len()).rev() {
+            let w = wide[i];
+            wide[i - LIMBS / 2] += w;
+            wide[i - LIMBS] += w;
+        }
+
+        let mut limbs = [0u64; LIMBS];
+        let mut carry_in: u128 = 0;
+        for (limb, w) in limbs.iter_mut().zip(wide) {
+            let sum = w + carry_in;
+            *limb = low_limb_u128(sum);
+            carry_in = sum >> LIMB_BITS;
+        }
+        let top = low_word_u128(carry_in);
+        limbs[0] += top;
+        limbs[LIMBS / 2] += top;
+        carry(&mut limbs);
+        Self(limbs)
+    }
+
+    pub(super) fn square(&self) -> Self {
+        self.mul(self)
+    }
+
+    /// Returns `self`<sup>`exponent`</sup>, where `exponent` is given as
+    /// little-endian 64-bit words. Constant-time with respect to `self` but
+    /// not `exponent`.
+    fn pow(&self, exponent: &[u64; 7]) -> Self {
+        let mut r = Self::ONE;
+        for word in exponent.iter().rev() {
+            for bit in (0..64).rev() {
+                r = r.square();
+                if (word >> bit) & 1 == 1 {
+                    r = r.mul(self);
+                }
+            }
+        }
+        r
+    }
+
+    /// Returns the multiplicative inverse, or zero if `self` is zero.
+    pub(super) fn invert(&self) -> Self {
+        // p - 2.
+        const EXPONENT: [u64; 7] = [
+            0xfffffffffffffffd,
+            0xffffffffffffffff,
+            0xffffffffffffffff,
+            0xfffffffeffffffff,
+            0xffffffffffffffff,
+            0xffffffffffffffff,
+            0xffffffffffffffff,
+        ];
+        self.pow(&EXPONENT)
+    }
+
+    /// Returns `self`<sup>(p - 3) / 4</sup>, which is used to compute square
+    /// roots.
+    fn pow_p_minus_3_over_4(&self) -> Self {
+        const EXPONENT: [u64; 7] = [
+            0xffffffffffffffff,
+            0xffffffffffffffff,
+            0xffffffffffffffff,
+            0xffffffffbfffffff,
+            0xffffffffffffffff,
+            0xffffffffffffffff,
+            0x3fffffffffffffff,
+        ];
+        self.pow(&EXPONENT)
+    }
+
+    /// Swaps `a` and `b` if `mask` is all ones, and leaves them alone if it
+    /// is zero.
+    pub(super) fn conditional_swap(a: &mut Self, b: &mut Self, mask: u64) {
+        for (a, b) in a.0.iter_mut().zip(b.0.iter_mut()) {
+            let t = mask & (*a ^ *b);
+            *a ^= t;
+            *b ^= t;
+        }
+    }
+
+    /// Sets `self` to `other` if `mask` is all ones, and leaves it alone if
+    /// it is zero.
+    fn conditional_assign(&mut self, other: &Self, mask: u64) {
+        for (a, b) in self.0.iter_mut().zip(other.0) {
+            *a ^= mask & (*a ^ b);
+        }
+    }
+}
+
+/// Propagates the carries between limbs, folding the carry out of the top
+/// limb back into limbs 0 and 4. Each limb must be less than 2^63.
+fn carry(limbs: &mut [u64; LIMBS]) {
+    for i in 0..(LIMBS - 1) {
+        limbs[i + 1] += limbs[i] >> LIMB_BITS;
+        limbs[i] &= LIMB_MASK;
+    }
+    let top = limbs[LIMBS - 1] >> LIMB_BITS;
+    limbs[LIMBS - 1] &= LIMB_MASK;
+    limbs[0] += top;
+    limbs[LIMBS / 2] += top;
+}
+
+#[allow(clippy::cast_possible_truncation)]
+#[inline(always)]
+fn low_word_u128(value: u128) -> u64 {
+    value as u64
+}
+
+#[inline(always)]
+fn low_limb_u128(value: u128) -> u64 {
+    low_word_u128(value) & LIMB_MASK
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+#[inline(always)]
+fn low_word_i128(value: i128) -> u64 {
+    value as u64
+}
+
+#[inline(always)]
+fn low_limb_i128(value: i128) -> u64 {
+    low_word_i128(value) & LIMB_MASK
+}
+
+/// d = -39081, the edwards448 curve constant.
+const D: Elem = Elem([
+    0x00ffffffffff6756,
+    LIMB_MASK,
+    LIMB_MASK,
+    LIMB_MASK,
+    LIMB_MASK - 1,
+    LIMB_MASK,
+    LIMB_MASK,
+    LIMB_MASK,
+]);
+
+/// A point on edwards448, x^2 + y^2 = 1 + d x^2 y^2, in projective
+/// coordinates (X : Y : Z), where x = X/Z and y = Y/Z.
+#[derive(Clone, Copy)]
+pub(super) struct Point {
+    x: Elem,
+    y: Elem,
+    z: Elem,
+}
+
+impl Point {
+    const IDENTITY: Self = Self {
+        x: Elem::ZERO,
+        y: Elem::ONE,
+        z: Elem::ONE,
+    };
+
+    /// The base point B from RFC 8032 Section 5.2.
+    pub(super) fn base() -> Self {
+        const X: [u8; ELEM_LEN] = [
+            0x5e, 0xc0, 0x0c, 0xc7, 0x2b, 0xa8, 0x26, 0x26, 0x8e, 0x93, 0x00, 0x8b, 0xe1, 0x80,
+            0x3b, 0x43, 0x11, 0x65, 0xb6, 0x2a, 0xf7, 0x1a, 0xae, 0x12, 0x64, 0xa4, 0xd3, 0xa3,
+            0x24, 0xe3, 0x6d, 0xea, 0x67, 0x17, 0x0f, 0x47, 0x70, 0x65, 0x14, 0x9e, 0xda, 0x36,
+            0xbf, 0x22, 0xa6, 0x15, 0x1d, 0x22, 0xed, 0x0d, 0xed, 0x6b, 0xc6, 0x70, 0x19, 0x4f,
+        ];
+        const Y: [u8; ELEM_LEN] = [
+            0x14, 0xfa, 0x30, 0xf2, 0x5b, 0x79, 0x08, 0x98, 0xad, 0xc8, 0xd7, 0x4e, 0x2c, 0x13,
+            0xbd, 0xfd, 0xc4, 0x39, 0x7c, 0xe6, 0x1c, 0xff, 0xd3, 0x3a, 0xd7, 0xc2, 0xa0, 0x05,
+            0x1e, 0x9c, 0x78, 0x87, 0x40, 0x98, 0xa3, 0x6c, 0x73, 0x73, 0xea, 0x4b, 0x62, 0xc7,
+            0xc9, 0x56, 0x37, 0x20, 0x76, 0x88, 0x24, 0xbc, 0xb6, 0x6e, 0x71, 0x46, 0x3f, 0x69,
+        ];
+        Self {
+            x: Elem::from_bytes(&X),
+            y: Elem::from_bytes(&Y),
+            z: Elem::ONE,
+        }
+    }
+
+    /// Decodes a point as in RFC 8032 Section 5.2.3, rejecting non-canonical
+    /// encodings. Not constant-time.
+    pub(super) fn from_encoded(encoded: &EncodedPoint) -> Result<Self, error::Unspecified> {
+        let y: &[u8; ELEM_LEN] = encoded[..ELEM_LEN].try_into()?;
+        let last = encoded[ELEM_LEN];
+        if last & 0x7f != 0 {
+            return Err(error::Unspecified);
+        }
+        let x_0 = last >> 7;
+        let y = Elem::from_bytes_canonical(y)?;
+
+        // x^2 = (y^2 - 1) / (d y^2 - 1) = u / v.
+        let yy = y.square();
+        let u = yy.sub(&Elem::ONE);
+        let v = D.mul(&yy).sub(&Elem::ONE);
+
+        // x = u^3 v (u^5 v^3)^((p-3)/4).
+        let uu = u.square();
+        let u3v = uu.mul(&u).mul(&v);
+        let u5v3 = u3v.mul(&uu).mul(&v.square());
+        let mut x = u3v.mul(&u5v3.pow_p_minus_3_over_4());
+
+        if v.mul(&x.square()).to_bytes() != u.to_bytes() {
+            return Err(error::Unspecified);
+        }
+        let x_bytes = x.to_bytes();
+        if x_bytes == [0; ELEM_LEN] && x_0 == 1 {
+            return Err(error::Unspecified);
+        }
+        if x_bytes[0] & 1 != x_0 {
+            x = x.neg();
+        }
+
+        Ok(Self { x, y, z: Elem::ONE })
+    }
+
+    /// Encodes the point as in RFC 8032 Section 5.2.2.
+    pub(super) fn to_encoded(self) -> EncodedPoint {
+        let z_inv = self.z.invert();
+        let x = self.x.mul(&z_inv).to_bytes();
+        let y = self.y.mul(&z_inv).to_bytes();
+        let mut encoded = [0u8; POINT_LEN];
+        encoded[..ELEM_LEN].copy_from_slice(&y);
+        encoded[ELEM_LEN] = (x[0] & 1) << 7;
+        encoded
+    }
+
+    /// The addition formulas from RFC 8032 Section 5.2.4. They are complete,
+    /// so they also work for doubling and for the identity.
+    pub(super) fn add(&self, other: &Self) -> Self {
+        let a = self.z.mul(&other.z);
+        let b = a.square();
+        let c = self.x.mul(&other.x);
+        let d = self.y.mul(&other.y);
+        let e = D.mul(&c).mul(&d);
+        let f = b.sub(&e);
+        let g = b.add(&e);
+        let h = self.x.add(&self.y).mul(&other.x.add(&other.y));
+        Self {
+            x: a.mul(&f).mul(&h.sub(&c).sub(&d)),
+            y: a.mul(&g).mul(&d.sub(&c)),
+            z: f.mul(&g),
+        }
+    }
+
+    /// The doubling formulas from RFC 8032 Section 5.2.4.
+    fn double(&self) -> Self {
+        let b = self.x.add(&self.y).square();
+        let c = self.x.square();
+        let d = self.y.square();
+        let e = c.add(&d);
+        let h = self.z.square();
+        let j = e.sub(&h.add(&h));
+        Self {
+            x: b.sub(&e).mul(&j),
+            y: e.mul(&c.sub(&d)),
+            z: e.mul(&j),
+        }
+    }
+
+    pub(super) fn neg(&self) -> Self {
+        Self {
+            x: self.x.neg(),
+            y: self.y,
+            z: self.z,
+        }
+    }
+
+    /// Returns [`scalar`]`self`, where `scalar` is little-endian.
+    pub(super) fn mul(&self, scalar: &[u8; SCALAR_LEN]) -> Self {
+        // A fixed window of four bits.
+        let mut table = [Self::IDENTITY; 16];
+        let mut multiple = Self::IDENTITY;
+        for entry in table.iter_mut().skip(1) {
+            multiple = multiple.add(self);
+            *entry = multiple;
+        }
+
+        let mut r = Self::IDENTITY;
+        for byte in scalar.iter().rev() {
+            for window in [byte >> 4, byte & 0xf] {
+                for _ in 0..4 {
+                    r = r.double();
+                }
+                r = r.add(&Self::select(&table, window));
+            }
+        }
+        r
+    }
+
+    fn select(table: &[Self; 16], index: u8) -> Self {
+        let mut r = Self::IDENTITY;
+        for (i, entry) in (0u8..).zip(table) {
+            // All ones if `i == index`, otherwise zero.
+            let mask = (u64::from(i ^ index).wrapping_sub(1) >> 63).wrapping_neg();
+            r.x.conditional_assign(&entry.x, mask);
+            r.y.conditional_assign(&entry.y, mask);
+            r.z.conditional_assign(&entry.z, mask);
+        }
+        r
+    }
+}
@@ -0,0 +1,133 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Arithmetic modulo the order L of the edwards448 base point.
+
+use crate::error;
+
+/// The length of an encoded scalar, as used in Ed448 signatures.
+pub(super) const SCALAR_LEN: usize = 57;
+
+const LIMBS: usize = 7;
+
+/// L = 2^446 - 13818066809895115352007386748515426880336692474882178609894547503885.
+const L: [u64; LIMBS] = [
+    0x2378c292ab5844f3,
+    0x216cc2728dc58f55,
+    0xc44edb49aed63690,
+    0xffffffff7cca23e9,
+    0xffffffffffffffff,
+    0xffffffffffffffff,
+    0x3fffffffffffffff,
+];
+
+/// A scalar, fully reduced modulo L, as little-endian 64-bit limbs.
+#[derive(Clone, Copy)]
+pub(super) struct Scalar([u64; LIMBS]);
+
+impl Scalar {
+    /// Decodes `bytes`, failing unless it encodes a value less than L. Not
+    /// constant-time.
+    pub(super) fn from_bytes_checked(bytes: &[u8; SCALAR_LEN]) -> Result<Self, error::Unspecified> {
+        let r = Self::from_bytes_reduced(bytes);
+        if r.to_bytes() != *bytes {
+            return Err(error::Unspecified);
+        }
+        Ok(r)
+    }
+
+    /// Decodes the little-endian `bytes`, of any length up to 120 bytes,
+    /// reducing the value modulo L.
+    pub(super) fn from_bytes_reduced(bytes: &[u8]) -> Self {
+        let mut words = [0u64; 15];
+        assert!(bytes.len() <= words.len() * 8);
+        for (word, chunk) in words.iter_mut().zip(bytes.chunks(8)) {
+            let mut le = [0u8; 8];
+            le[..chunk.len()].copy_from_slice(chunk);
+            *word = u64::from_le_bytes(le);
+        }
+        Self(reduce(&words))
+    }
+
+    /// Returns `a * b + c` (mod L).
+    pub(super) fn mul_add(a: &Self, b: &Self, c: &Self) -> Self {
+        let mut wide = [0u64; 2 * LIMBS];
+        for (i, a) in a.0.iter().enumerate() {
+            let mut carry: u128 = 0;
+            for (w, b) in wide[i..].iter_mut().zip(b.0) {
+                let t = u128::from(*a) * u128::from(b) + u128::from(*w) + carry;
+                *w = low_word(t);
+                carry = t >> 64;
+            }
+            wide[i + LIMBS] = low_word(carry);
+        }
+
+        // a * b < L^2 < 2^892, so adding c can't carry out of `wide`.
+        let mut carry: u128 = 0;
+        for (i, w) in wide.iter_mut().enumerate() {
+            let t = u128::from(*w) + u128::from(c.0.get(i).copied().unwrap_or(0)) + carry;
+            *w = low_word(t);
+            carry = t >> 64;
+        }
+
+        Self(reduce(&wide))
+    }
+
+    /// Returns the little-endian encoding of the scalar.
+    pub(super) fn to_bytes(self) -> [u8; SCALAR_LEN] {
+        let mut bytes = [0u8; SCALAR_LEN];
+        for (chunk, limb) in bytes.chunks_mut(8).zip(self.0) {
+            chunk.copy_from_slice(&limb.to_le_bytes()[..chunk.len()]);
+        }
+        bytes
+    }
+}
+
+/// Reduces the little-endian `words` modulo L, one bit at a time from the
+/// top, in constant time.
+fn reduce(words: &[u64]) -> [u64; LIMBS] {
+    let mut r = [0u64; LIMBS];
+    for word in words.iter().rev() {
+        for bit in (0..64).rev() {
+            // r = 2r + bit. Since r < L < 2^446, this doesn't overflow.
+            let mut carry = (word >> bit) & 1;
+            for limb in r.iter_mut() {
+                let next_carry = *limb >> 63;
+                *limb = (*limb << 1) | carry;
+                carry = next_carry;
+            }
+
+            // Now r < 2L; subtract L unless that would borrow.
+            let mut difference = [0u64; LIMBS];
+            let mut borrow = 0u64;
+            for ((d, r), l) in difference.iter_mut().zip(r).zip(L) {
+                let (t, b1) = r.overflowing_sub(l);
+                let (t, b2) = t.overflowing_sub(borrow);
+                *d = t;
+                borrow = u64::from(b1 | b2);
+            }
+            let keep_r = borrow.wrapping_neg(); // All ones if r < L.
+            for (r, d) in r.iter_mut().zip(difference) {
+                *r = (*r & keep_r) | (d & !keep_r);
+            }
+        }
+    }
+    r
+}
+
+#[allow(clippy::cast_possible_truncation)]
+#[inline(always)]
+fn low_word(value: u128) -> u64 {
+    value as u64
+}
@@ -0,0 +1,132 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! X448 Key agreement.
+
+use super::ops::{Elem, ELEM_LEN};
+use crate::{agreement, constant_time, cpu, ec, error, rand};
+
+static CURVE448: ec::Curve = ec::Curve {
+    public_key_len: ELEM_LEN,
+    elem_scalar_seed_len: ELEM_LEN,
+    id: ec::CurveID::Curve448,
+    check_private_key_bytes: x448_check_private_key_bytes,
+    generate_private_key: x448_generate_private_key,
+    public_from_private: x448_public_from_private,
+};
+
+/// X448 (ECDH using Curve448) as described in [RFC 7748].
+///
+/// Everything is as described in RFC 7748. Key agreement will fail if the
+/// result of the X448 operation is zero; see the notes on the "all-zero
+/// value" in [RFC 7748 section 6.2].
+///
+/// [RFC 7748]: https://tools.ietf.org/html/rfc7748
+/// [RFC 7748 section 6.2]: https://tools.ietf.org/html/rfc7748#section-6.2
+pub static X448: agreement::Algorithm = agreement::Algorithm {
+    curve: &CURVE448,
+    ecdh: x448_ecdh,
+};
+
+#[allow(clippy::unnecessary_wraps)]
+fn x448_check_private_key_bytes(bytes: &[u8]) -> Result<(), error::Unspecified> {
+    debug_assert_eq!(bytes.len(), ELEM_LEN);
+    Ok(())
+}
+
+fn x448_generate_private_key(
+    rng: &dyn rand::SecureRandom,
+    out: &mut [u8],
+) -> Result<(), error::Unspecified> {
+    rng.fill(out)
+}
+
+fn x448_public_from_private(
+    public_out: &mut [u8],
+    private_key: &ec::Seed,
+    _cpu_features: cpu::Features,
+) -> Result<(), error::Unspecified> {
+    let public_out: &mut [u8; ELEM_LEN] = public_out.try_into()?;
+    let private_key: &[u8; ELEM_LEN] = private_key.bytes_less_safe().try_into()?;
+
+    let mut base_point = [0u8; ELEM_LEN];
+    base_point[0] = 5;
+    *public_out = scalar_mult(private_key, &base_point);
+    Ok(())
+}
+
+fn x448_ecdh(
+    out: &mut [u8],
+    my_private_key: &ec::Seed,
+    peer_public_key: untrusted::Input,
+    _cpu_features: cpu::Features,
+) -> Result<(), error::Unspecified> {
+    let out: &mut [u8; ELEM_LEN] = out.try_into()?;
+    let my_private_key: &[u8; ELEM_LEN] = my_private_key.bytes_less_safe().try_into()?;
+    let peer_public_key: &[u8; ELEM_LEN] = peer_public_key.as_slice_less_safe().try_into()?;
+
+    *out = scalar_mult(my_private_key, peer_public_key);
+
+    let zeros = [0; ELEM_LEN];
+    if constant_time::verify_slices_are_equal(out, &zeros).is_ok() {
+        // All-zero output results when the input is a point of small order.
+        return Err(error::Unspecified);
+    }
+
+    Ok(())
+}
+
+/// The X448 function from RFC 7748 Section 5.
+fn scalar_mult(scalar: &[u8; ELEM_LEN], u: &[u8; ELEM_LEN]) -> [u8; ELEM_LEN] {
+    // decodeScalar448.
+    let mut k = *scalar;
+    k[0] &= 252;
+    k[ELEM_LEN - 1] |= 128;
+
+    // (156326 - 2) / 4.
+    const A24: Elem = Elem::from_u32(39081);
+
+    let x_1 = Elem::from_bytes(u);
+    let mut x_2 = Elem::ONE;
+    let mut z_2 = Elem::ZERO;
+    let mut x_3 = x_1;
+    let mut z_3 = Elem::ONE;
+    let mut swap = 0u64;
+
+    for t in (0..(8 * ELEM_LEN)).rev() {
+        let k_t = u64::from((k[t / 8] >> (t % 8)) & 1);
+        swap ^= k_t;
+        Elem::conditional_swap(&mut x_2, &mut x_3, swap.wrapping_neg());
+        Elem::conditional_swap(&mut z_2, &mut z_3, swap.wrapping_neg());
+        swap = k_t;
+
+        let a = x_2.add(&z_2);
+        let aa = a.square();
+        let b = x_2.sub(&z_2);
+        let bb = b.square();
+        let e = aa.sub(&bb);
+        let c = x_3.add(&z_3);
+        let d = x_3.sub(&z_3);
+        let da = d.mul(&a);
+        let cb = c.mul(&b);
+        x_3 = da.add(&cb).square();
+        z_3 = x_1.mul(&da.sub(&cb).square());
+        x_2 = aa.mul(&bb);
+        z_2 = e.mul(&aa.add(&A24.mul(&e)));
+    }
+    Elem::conditional_swap(&mut x_2, &mut x_3, swap.wrapping_neg());
+    Elem::conditional_swap(&mut z_2, &mut z_3, swap.wrapping_neg());
+
+    x_2.mul(&z_2.invert()).to_bytes()
+}
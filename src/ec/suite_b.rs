@@ -227,6 +227,27 @@ pub(crate) fn key_pair_from_bytes(
     Ok(r)
 }
 
+/// Returns whether `other` is an encoding of the same point as `public_key`,
+/// which must be a valid public key encoded in uncompressed form. `other` may
+/// be in either uncompressed or compressed form; see SEC 1 Section 2.3.3.
+///
+/// Since `public_key` is valid, its X coordinate is fully reduced and the
+/// point it encodes is the only point on the curve with that X coordinate and
+/// the parity of its Y coordinate, so no decompression is needed.
+pub(crate) fn public_key_matches(public_key: &[u8], other: &[u8]) -> bool {
+    let (x, y) = match public_key.split_first() {
+        Some((4, xy)) => xy.split_at(xy.len() / 2),
+        _ => return false,
+    };
+    match other.split_first() {
+        Some((4, _)) => other == public_key,
+        Some((&encoding @ (2 | 3), other_x)) => {
+            other_x == x && y.last().map(|last| last & 1) == Some(encoding & 1)
+        }
+        _ => false,
+    }
+}
+
 pub mod curve;
 
 pub mod ecdh;
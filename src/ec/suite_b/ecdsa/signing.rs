@@ -22,7 +22,7 @@ use crate::{
         self,
         suite_b::{ops::*, private_key},
     },
-    error,
+    error, hmac,
     io::der,
     limb, pkcs8, rand, sealed, signature,
 };
@@ -57,6 +57,55 @@ impl Eq for EcdsaSigningAlgorithm {}
 
 impl sealed::Sealed for EcdsaSigningAlgorithm {}
 
+impl EcdsaSigningAlgorithm {
+    /// Returns the signature of `message` computed by `signer`.
+    ///
+    /// `message` is hashed with this algorithm's digest algorithm, `signer`
+    /// signs the digest, and the signature `signer` returns is re-encoded in
+    /// this algorithm's signature format (fixed-length or ASN.1 DER). This
+    /// allows a private key that is held outside of *ring*, e.g. in a hardware
+    /// security module, to be used with the same signature formats as an
+    /// `EcdsaKeyPair`.
+    ///
+    /// Fails if `signer` fails, or if it returns anything other than a
+    /// fixed-length signature with *r* and *s* in the range [1, n).
+    pub fn sign_with_external_signer(
+        &self,
+        signer: &dyn ExternalSigner,
+        message: &[u8],
+    ) -> Result<signature::Signature, error::Unspecified> {
+        let scalar_ops = self.private_scalar_ops.scalar_ops;
+        let cops = scalar_ops.common;
+
+        let h = digest::digest(self.digest_alg, message);
+        let fixed = signer.sign_digest(h.as_ref())?;
+
+        let scalar_len = scalar_ops.scalar_bytes_len();
+        if fixed.as_ref().len() != 2 * scalar_len {
+            return Err(error::Unspecified);
+        }
+        let (r, s) = fixed.as_ref().split_at(scalar_len);
+        let r = scalar_parse_big_endian_fixed_consttime(cops, untrusted::Input::from(r))?;
+        let s = scalar_parse_big_endian_fixed_consttime(cops, untrusted::Input::from(s))?;
+
+        Ok(signature::Signature::new(|sig_bytes| {
+            (self.format_rs)(scalar_ops, &r, &s, sig_bytes)
+        }))
+    }
+}
+
+/// An ECDSA signer whose private key is held outside of *ring*, e.g. in a
+/// hardware security module accessed through PKCS#11.
+///
+/// See `EcdsaSigningAlgorithm::sign_with_external_signer()`.
+pub trait ExternalSigner {
+    /// Signs the message digest `digest`, returning the signature in the
+    /// fixed-length (PKCS#11 `CKM_ECDSA`) format, *r* || *s*.
+    ///
+    /// Use `Signature::try_from()` to construct the result.
+    fn sign_digest(&self, digest: &[u8]) -> Result<signature::Signature, error::Unspecified>;
+}
+
 /// An ECDSA key pair, used for signing.
 pub struct EcdsaKeyPair {
     d: Scalar<R>,
@@ -117,6 +166,20 @@ impl EcdsaKeyPair {
         Self::new(alg, key_pair, rng)
     }
 
+    /// Like `EcdsaKeyPair::from_pkcs8()`, except the PKCS#8 document is
+    /// PEM-encoded with the label `PRIVATE KEY`.
+    #[cfg(feature = "alloc")]
+    pub fn from_pem(
+        alg: &'static EcdsaSigningAlgorithm,
+        pem: &str,
+        rng: &dyn rand::SecureRandom,
+    ) -> Result<Self, error::KeyRejected> {
+        crate::pem::decode_key(pem, |label, der| match label {
+            crate::pem::Label::PrivateKey => Self::from_pkcs8(alg, der, rng),
+            _ => Err(error::KeyRejected::wrong_algorithm()),
+        })
+    }
+
     /// Constructs an ECDSA key pair from the private key and public key bytes
     ///
     /// The private key must encoded as a big-endian fixed-length integer. For
@@ -167,6 +230,34 @@ impl EcdsaKeyPair {
         })
     }
 
+    /// Serializes the key pair as a PKCS#8 document.
+    ///
+    /// The document is in the same format that `generate_pkcs8()` produces:
+    /// a v1 `OneAsymmetricKey` with the public key included in the
+    /// `ECPrivateKey` structure. `from_pkcs8()` accepts it.
+    pub fn to_pkcs8(&self) -> Result<pkcs8::Document, error::Unspecified> {
+        let scalar_ops = self.alg.private_scalar_ops.scalar_ops;
+        let mut private_key = [0u8; ec::SCALAR_MAX_BYTES];
+        let private_key = &mut private_key[..scalar_ops.scalar_bytes_len()];
+        self.fill_private_key_bytes(private_key, cpu::features());
+
+        Ok(pkcs8::wrap_key(
+            self.alg.pkcs8_template,
+            private_key,
+            self.public_key.as_ref(),
+        ))
+    }
+
+    // Fills `out` with the big-endian encoding of the private key.
+    fn fill_private_key_bytes(&self, out: &mut [u8], cpu: cpu::Features) {
+        let scalar_ops = self.alg.private_scalar_ops.scalar_ops;
+
+        // Convert `d` out of the Montgomery domain.
+        const ONE: Scalar<Unencoded> = Scalar::from_hex("1");
+        let d = scalar_ops.scalar_product(&self.d, &ONE, cpu);
+        limb::big_endian_from_limbs(scalar_ops.leak_limbs(&d), out);
+    }
+
     /// Returns the signature of the `message` using a random nonce generated by `rng`.
     pub fn sign(
         &self,
@@ -190,6 +281,31 @@ impl EcdsaKeyPair {
         self.sign_digest(h, &nonce_rng, cpu)
     }
 
+    /// Returns the signature of the `message` using a nonce derived
+    /// deterministically from the private key and the message, as specified
+    /// in [RFC 6979].
+    ///
+    /// The same key and message always give the same signature, so no
+    /// `SecureRandom` is needed. This is useful for reproducible test vectors
+    /// and where no good source of randomness is available. Otherwise, prefer
+    /// `sign()`: a deterministic nonce makes the signing computation more
+    /// exposed to fault and side-channel attacks that are mitigated by a
+    /// random nonce.
+    ///
+    /// [RFC 6979]: https://tools.ietf.org/html/rfc6979
+    pub fn sign_deterministic(
+        &self,
+        message: &[u8],
+    ) -> Result<signature::Signature, error::Unspecified> {
+        let cpu = cpu::features();
+
+        // Step 4 (out of order).
+        let h = digest::digest(self.alg.digest_alg, message);
+
+        let nonce_rng = Rfc6979NonceRandom::new(self, &h, cpu)?;
+        self.sign_digest(h, &nonce_rng, cpu)
+    }
+
     #[cfg(test)]
     fn sign_with_fixed_nonce_during_test(
         &self,
@@ -339,6 +455,67 @@ impl rand::sealed::SecureRandom for NonceRandom<'_> {
 
 impl<'a> sealed::Sealed for NonceRandom<'a> {}
 
+/// Generates the ECDSA nonces for a message digest as specified in
+/// [RFC 6979 Section 3.2].
+///
+/// RFC 6979's nonce generation is HMAC_DRBG, with the encoded private key as
+/// the entropy input and the encoded, reduced message digest as the nonce.
+/// Each candidate nonce that `sign_digest()` rejects is followed by the next
+/// output of the DRBG, as in step h.3.
+///
+/// This relies on the digest being as long as the scalars, so that each
+/// output of the DRBG is exactly one candidate and `bits2int` needn't shift
+/// it; that's true for every `EcdsaSigningAlgorithm`.
+///
+/// [RFC 6979 Section 3.2]: https://tools.ietf.org/html/rfc6979#section-3.2
+struct Rfc6979NonceRandom(core::cell::RefCell<rand::HmacDrbg>);
+
+impl Rfc6979NonceRandom {
+    fn new(
+        key_pair: &EcdsaKeyPair,
+        h: &digest::Digest,
+        cpu: cpu::Features,
+    ) -> Result<Self, error::Unspecified> {
+        let alg = key_pair.alg;
+        let scalar_ops = alg.private_scalar_ops.scalar_ops;
+        let scalar_len = scalar_ops.scalar_bytes_len();
+        assert_eq!(alg.digest_alg.output_len(), scalar_len);
+
+        // int2octets(x).
+        let mut x = [0u8; ec::SCALAR_MAX_BYTES];
+        let x = &mut x[..scalar_len];
+        key_pair.fill_private_key_bytes(x, cpu);
+
+        // bits2octets(h1).
+        let mut h1 = [0u8; ec::SCALAR_MAX_BYTES];
+        let h1 = &mut h1[..scalar_len];
+        let e = digest_scalar(scalar_ops, *h);
+        limb::big_endian_from_limbs(scalar_ops.leak_limbs(&e), h1);
+
+        let hmac_alg = if alg.digest_alg == &digest::SHA256 {
+            hmac::HMAC_SHA256
+        } else if alg.digest_alg == &digest::SHA384 {
+            hmac::HMAC_SHA384
+        } else {
+            unreachable!()
+        };
+        let drbg = rand::HmacDrbg::new(hmac_alg, x, h1, &[])?;
+        Ok(Self(core::cell::RefCell::new(drbg)))
+    }
+}
+
+impl core::fmt::Debug for Rfc6979NonceRandom {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Rfc6979NonceRandom").finish()
+    }
+}
+
+impl rand::sealed::SecureRandom for Rfc6979NonceRandom {
+    fn fill_impl(&self, dest: &mut [u8]) -> Result<(), error::Unspecified> {
+        self.0.borrow_mut().fill(dest)
+    }
+}
+
 struct NonceRandomKey(digest::Digest);
 
 impl NonceRandomKey {
@@ -382,6 +559,14 @@ impl AsRef<[u8]> for PublicKey {
     }
 }
 
+impl PublicKey {
+    /// Returns whether `other_bytes` is an encoding of this public key, in
+    /// either uncompressed or compressed form.
+    pub fn matches(&self, other_bytes: &[u8]) -> bool {
+        ec::suite_b::public_key_matches(self.as_ref(), other_bytes)
+    }
+}
+
 fn format_rs_fixed(ops: &'static ScalarOps, r: &Scalar, s: &Scalar, out: &mut [u8]) -> usize {
     let scalar_len = ops.scalar_bytes_len();
 
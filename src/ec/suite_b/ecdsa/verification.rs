@@ -66,13 +66,102 @@ impl signature::VerificationAlgorithm for EcdsaVerificationAlgorithm {
             digest_scalar(self.ops.scalar_ops, h)
         };
 
-        self.verify_digest(public_key, e, signature)
+        self.verify_e(public_key, e, signature)
     }
+
+    fn verify_digest(
+        &self,
+        public_key: untrusted::Input,
+        digest: &digest::Digest,
+        signature: untrusted::Input,
+    ) -> Result<(), error::Unspecified> {
+        if digest.algorithm() != self.digest_alg {
+            return Err(error::Unspecified);
+        }
+        let e = digest_scalar(self.ops.scalar_ops, *digest);
+        self.verify_e(public_key, e, signature)
+    }
+}
+
+/// Verifies many signatures of the same message `msg`, each given as a
+/// (public key, signature) pair in `signatures`, using `alg`.
+///
+/// The message is digested only once, instead of once per signature.
+/// Succeeds only if every signature is valid. Fails if `signatures` is empty,
+/// so that an empty batch is never mistaken for a verified one.
+pub fn ecdsa_verify_same_message(
+    alg: &'static EcdsaVerificationAlgorithm,
+    msg: &[u8],
+    signatures: &[(&[u8], &[u8])],
+) -> Result<(), error::Unspecified> {
+    if signatures.is_empty() {
+        return Err(error::Unspecified);
+    }
+
+    let h = digest::digest(alg.digest_alg, msg);
+    let e = digest_scalar(alg.ops.scalar_ops, h);
+
+    signatures.iter().try_for_each(|(public_key, signature)| {
+        alg.verify_e(
+            untrusted::Input::from(public_key),
+            e,
+            untrusted::Input::from(signature),
+        )
+    })
+}
+
+/// Formats the components `r` and `s` of an ECDSA signature as a signature
+/// for `alg`, which must be one of the `ECDSA_*_FIXED` algorithms.
+///
+/// Some implementations strip the leading zeros from *r* and *s*, which
+/// makes their "fixed-length" signatures shorter than the verifiers of
+/// `ECDSA_*_FIXED` accept. When such a signature is received as separate
+/// components, this left-pads each of them with zeros to the length of the
+/// curve's order so the result can be verified with `alg`. Components that
+/// are already padded, or that have extra leading zeros, are accepted too.
+///
+/// A stripped signature that has already been concatenated can't be
+/// reformatted, since it isn't known where *r* ends.
+///
+/// Fails if `alg` isn't an `ECDSA_*_FIXED` algorithm or if either
+/// component is too long for the curve. The values of the components aren't
+/// checked; verification does that.
+pub fn ecdsa_fixed_signature_from_components(
+    alg: &'static EcdsaVerificationAlgorithm,
+    r: &[u8],
+    s: &[u8],
+) -> Result<signature::Signature, error::Unspecified> {
+    if !matches!(
+        alg.id,
+        AlgorithmID::ECDSA_P256_SHA256_FIXED | AlgorithmID::ECDSA_P384_SHA384_FIXED
+    ) {
+        return Err(error::Unspecified);
+    }
+
+    let scalar_len = alg.ops.scalar_ops.scalar_bytes_len();
+    let r = strip_leading_zeros(r);
+    let s = strip_leading_zeros(s);
+    if r.len() > scalar_len || s.len() > scalar_len {
+        return Err(error::Unspecified);
+    }
+
+    Ok(signature::Signature::new(|out| {
+        let (r_out, rest) = out.split_at_mut(scalar_len);
+        let (s_out, _) = rest.split_at_mut(scalar_len);
+        r_out[(scalar_len - r.len())..].copy_from_slice(r);
+        s_out[(scalar_len - s.len())..].copy_from_slice(s);
+        2 * scalar_len
+    }))
+}
+
+fn strip_leading_zeros(value: &[u8]) -> &[u8] {
+    let zeros = value.iter().take_while(|&&b| b == 0).count();
+    &value[zeros..]
 }
 
 impl EcdsaVerificationAlgorithm {
     /// This is intentionally not public.
-    fn verify_digest(
+    fn verify_e(
         &self,
         public_key: untrusted::Input,
         e: Scalar,
@@ -320,7 +409,7 @@ mod tests {
                     alg.ops.scalar_ops,
                     &digest[..],
                 );
-                let actual_result = alg.verify_digest(
+                let actual_result = alg.verify_e(
                     untrusted::Input::from(&public_key[..]),
                     digest,
                     untrusted::Input::from(&sig[..]),
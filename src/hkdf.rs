@@ -18,7 +18,7 @@
 //!
 //! [RFC 5869]: https://tools.ietf.org/html/rfc5869
 
-use crate::{error, hmac};
+use crate::{aead, error, hmac};
 
 /// An HKDF algorithm.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -45,6 +45,12 @@ pub static HKDF_SHA384: Algorithm = Algorithm(hmac::HMAC_SHA384);
 /// HKDF using HMAC-SHA-512.
 pub static HKDF_SHA512: Algorithm = Algorithm(hmac::HMAC_SHA512);
 
+/// HKDF using HMAC-SHA-512/224.
+pub static HKDF_SHA512_224: Algorithm = Algorithm(hmac::HMAC_SHA512_224);
+
+/// HKDF using HMAC-SHA-512/256.
+pub static HKDF_SHA512_256: Algorithm = Algorithm(hmac::HMAC_SHA512_256);
+
 impl KeyType for Algorithm {
     fn len(&self) -> usize {
         self.0.digest_algorithm().output_len()
@@ -65,6 +71,18 @@ impl Salt {
         Self(hmac::Key::new(algorithm.0, value))
     }
 
+    /// Constructs a new `Salt` that uses `key` as the HMAC key for the
+    /// extract step.
+    ///
+    /// `Salt::from_key(hmac::Key::new(algorithm.hmac_algorithm(), value))` is
+    /// equivalent to `Salt::new(algorithm, value)`. This is useful when an
+    /// `hmac::Key` for the salt value has already been constructed, e.g. to
+    /// avoid repeatedly hashing a salt longer than the digest algorithm's
+    /// block length.
+    pub fn from_key(key: hmac::Key) -> Self {
+        Self(key)
+    }
+
     /// The [HKDF-Extract] operation.
     ///
     /// [HKDF-Extract]: https://tools.ietf.org/html/rfc5869#section-2.2
@@ -80,6 +98,18 @@ impl Salt {
         Prk(hmac::Key::new(salt.algorithm(), prk.as_ref()))
     }
 
+    /// Like `extract()`, except it returns the value of the PRK instead of a
+    /// `Prk`.
+    ///
+    /// Usually one can avoid using this; like `Prk::new_less_safe()`, it is
+    /// for applications that intentionally leak the PRK secret, e.g. to store
+    /// it as a master secret or to log it for debugging interoperability.
+    /// Anybody who learns the value can derive every key that is derived
+    /// from it. `Prk::new_less_safe()` constructs the `Prk` from the value.
+    pub fn extract_less_safe(&self, secret: &[u8]) -> hmac::Tag {
+        hmac::sign(&self.0, secret)
+    }
+
     /// The algorithm used to derive this salt.
     #[inline]
     pub fn algorithm(&self) -> Algorithm {
@@ -122,6 +152,10 @@ impl Prk {
     ///
     /// [HKDF-Expand]: https://tools.ietf.org/html/rfc5869#section-2.3
     ///
+    /// The HMAC key's padded inner and outer states are computed once, when
+    /// the `Prk` is constructed, so deriving several outputs from one `Prk`
+    /// doesn't repeat that work.
+    ///
     /// Fails if (and only if) `len` is too large.
     #[inline]
     pub fn expand<'a, L: KeyType>(
@@ -231,3 +265,83 @@ fn fill_okm(
         n = n.checked_add(1).unwrap();
     }
 }
+
+/// Derives an AEAD key for `algorithm` and a nonce base from `prk`.
+///
+/// The key is HKDF-Expand(`prk`, `key_label`, `algorithm.key_len()`) and the
+/// nonce base is HKDF-Expand(`prk`, `nonce_label`, `aead::NONCE_LEN`). The
+/// nonce base is usually combined with a sequence number to form each nonce,
+/// e.g. by XORing the big-endian sequence number into its last bytes as in
+/// [RFC 8446 Section 5.3].
+///
+/// [RFC 8446 Section 5.3]: https://www.rfc-editor.org/rfc/rfc8446#section-5.3
+pub fn derive_key_and_nonce(
+    prk: &Prk,
+    key_label: &[u8],
+    nonce_label: &[u8],
+    algorithm: &'static aead::Algorithm,
+) -> (aead::UnboundKey, [u8; aead::NONCE_LEN]) {
+    // Both lengths are far less than the maximum.
+    let key = prk.expand(&[key_label], algorithm).unwrap().into();
+    let mut nonce = [0u8; aead::NONCE_LEN];
+    prk.expand(&[nonce_label], Len(aead::NONCE_LEN))
+        .unwrap()
+        .fill(&mut nonce)
+        .unwrap();
+    (key, nonce)
+}
+
+struct Len(usize);
+
+impl KeyType for Len {
+    fn len(&self) -> usize {
+        self.0
+    }
+}
+
+/// Derives any number of labeled subkeys from one master secret.
+///
+/// The master secret is extracted into a PRK once, by `new()`, and each
+/// subkey is then only an HKDF-Expand of the PRK with the subkey's label as
+/// the `info`. `KeyDeriver::new(algorithm, salt, master).subkey(label, len)`
+/// is the same as
+/// `Salt::new(algorithm, salt).extract(master).expand(&[label], len)`.
+///
+/// Each subkey should have its own label; subkeys with different labels are
+/// independent, and the same label always gives the same subkey.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+pub struct KeyDeriver {
+    prk: Prk,
+}
+
+#[cfg(feature = "alloc")]
+impl KeyDeriver {
+    /// Constructs a new `KeyDeriver` that derives subkeys from the master
+    /// secret `master`, extracted with `salt`.
+    pub fn new(algorithm: Algorithm, salt: &[u8], master: &[u8]) -> Self {
+        Self::from_prk(Salt::new(algorithm, salt).extract(master))
+    }
+
+    /// Constructs a new `KeyDeriver` that derives subkeys from `prk`.
+    pub fn from_prk(prk: Prk) -> Self {
+        Self { prk }
+    }
+
+    /// Derives the `len`-byte subkey labeled `label`.
+    ///
+    /// Fails if (and only if) `len` is too large, like `Prk::expand()`.
+    pub fn subkey(
+        &self,
+        label: &[u8],
+        len: usize,
+    ) -> Result<alloc::vec::Vec<u8>, error::Unspecified> {
+        let info = [label];
+        let okm = self.prk.expand(&info, Len(len))?;
+        let mut subkey = alloc::vec![0; len];
+        okm.fill(&mut subkey)?;
+        Ok(subkey)
+    }
+}
+
+pub mod quic;
@@ -0,0 +1,86 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! QUIC packet protection key derivation.
+//!
+//! QUIC derives its packet protection keys, IVs, and header protection keys
+//! from a traffic secret using the TLS 1.3 HKDF-Expand-Label function with
+//! "quic " labels, as specified in [RFC 9001 Section 5.1].
+//!
+//! [RFC 9001 Section 5.1]: https://www.rfc-editor.org/rfc/rfc9001#section-5.1
+
+use super::{KeyType, Len, Okm, Prk};
+use crate::aead;
+
+/// Derives the packet protection key for `algorithm` from `secret`, using
+/// the label "quic key".
+pub fn packet_key(secret: &Prk, algorithm: &'static aead::Algorithm) -> aead::UnboundKey {
+    expand_label(secret, b"quic key", algorithm, |okm| okm.into())
+}
+
+/// Derives the packet protection IV from `secret`, using the label
+/// "quic iv".
+///
+/// The nonce for a packet is this IV XORed with the packet number; see
+/// [RFC 9001 Section 5.3].
+///
+/// [RFC 9001 Section 5.3]: https://www.rfc-editor.org/rfc/rfc9001#section-5.3
+pub fn packet_iv(secret: &Prk) -> [u8; aead::NONCE_LEN] {
+    expand_label(secret, b"quic iv", Len(aead::NONCE_LEN), |okm| {
+        let mut iv = [0u8; aead::NONCE_LEN];
+        okm.fill(&mut iv).unwrap();
+        iv
+    })
+}
+
+/// Derives the header protection key for `algorithm` from `secret`, using
+/// the label "quic hp".
+pub fn hp_key(
+    secret: &Prk,
+    algorithm: &'static aead::quic::Algorithm,
+) -> aead::quic::HeaderProtectionKey {
+    expand_label(secret, b"quic hp", algorithm, |okm| okm.into())
+}
+
+/// HKDF-Expand-Label(secret, label, "", len) from
+/// [RFC 8446 Section 7.1].
+///
+/// [RFC 8446 Section 7.1]: https://www.rfc-editor.org/rfc/rfc8446#section-7.1
+fn expand_label<L: KeyType, T>(
+    secret: &Prk,
+    label: &[u8],
+    len: L,
+    f: impl FnOnce(Okm<L>) -> T,
+) -> T {
+    const LABEL_PREFIX: &[u8] = b"tls13 ";
+
+    // struct {
+    //     uint16 length = Length;
+    //     opaque label<7..255> = "tls13 " + Label;
+    //     opaque context<0..255> = Context;
+    // } HkdfLabel;
+    let output_len = u16::try_from(len.len()).unwrap().to_be_bytes();
+    let label_len = [u8::try_from(LABEL_PREFIX.len() + label.len()).unwrap()];
+    let context_len = [0u8];
+    let info = [
+        &output_len[..],
+        &label_len,
+        LABEL_PREFIX,
+        label,
+        &context_len,
+    ];
+
+    // All the lengths used here are far less than the maximum.
+    f(secret.expand(&info, len).unwrap())
+}
@@ -135,6 +135,12 @@ pub static HMAC_SHA384: Algorithm = Algorithm(&digest::SHA384);
 /// HMAC using SHA-512.
 pub static HMAC_SHA512: Algorithm = Algorithm(&digest::SHA512);
 
+/// HMAC using SHA-512/224.
+pub static HMAC_SHA512_224: Algorithm = Algorithm(&digest::SHA512_224);
+
+/// HMAC using SHA-512/256.
+pub static HMAC_SHA512_256: Algorithm = Algorithm(&digest::SHA512_256);
+
 /// An HMAC tag.
 ///
 /// For a given tag `t`, use `t.as_ref()` to get the tag value as a byte slice.
@@ -149,6 +155,11 @@ impl AsRef<[u8]> for Tag {
 }
 
 /// A key to use for HMAC signing.
+///
+/// A key derived with HKDF, such as the TLS 1.3 Finished key, can be
+/// constructed directly from the `hkdf::Okm` using `Key::from()`, so the key
+/// value never has to be copied into a buffer of the caller's:
+/// `hmac::Key::from(prk.expand(&[label], hmac::HMAC_SHA256)?)`.
 #[derive(Clone)]
 pub struct Key {
     inner: digest::BlockContext,
@@ -309,8 +320,8 @@ impl Context {
     /// called.
     ///
     /// It is generally not safe to implement HMAC verification by comparing
-    /// the return value of `sign` to a tag. Use `verify` for verification
-    /// instead.
+    /// the return value of `sign` to a tag. Use `verify_slice` for
+    /// verification instead.
     pub fn sign(self) -> Tag {
         let cpu_features = cpu::features();
 
@@ -321,6 +332,15 @@ impl Context {
         pending[..num_pending].copy_from_slice(self.inner.finish().as_ref());
         Tag(self.outer.finish(pending, num_pending, cpu_features))
     }
+
+    /// Finalizes the HMAC calculation and verifies whether the resultant
+    /// value equals `tag`.
+    ///
+    /// This is the multi-part counterpart of [`verify()`]. The verification
+    /// will be done in constant time to prevent timing attacks.
+    pub fn verify_slice(self, tag: &[u8]) -> Result<(), error::Unspecified> {
+        constant_time::verify_slices_are_equal(self.sign().as_ref(), tag)
+    }
 }
 
 /// Calculates the HMAC of `data` using the key `key` in one step.
@@ -346,6 +366,28 @@ pub fn verify(key: &Key, data: &[u8], tag: &[u8]) -> Result<(), error::Unspecifi
     constant_time::verify_slices_are_equal(sign(key, data).as_ref(), tag)
 }
 
+/// Calculates the HMAC of `data` under each of `keys`, returning the tags in
+/// the same order as `keys`.
+///
+/// The result is the same as calling `sign` for each key, but `data` is only
+/// read once: it is fed to all the keys' contexts a chunk at a time, so each
+/// chunk is still in the cache when it is hashed for the second and later
+/// keys. The inner and outer pads are different for every key, so the
+/// hashing itself can't be shared.
+#[cfg(feature = "alloc")]
+pub fn sign_multi_key(keys: &[&Key], data: &[u8]) -> alloc::vec::Vec<Tag> {
+    // A multiple of every digest algorithm's block length that is small
+    // enough to stay in the L1 cache.
+    const CHUNK_LEN: usize = 16 * digest::MAX_BLOCK_LEN;
+
+    let mut contexts: alloc::vec::Vec<Context> =
+        keys.iter().map(|key| Context::with_key(key)).collect();
+    for chunk in data.chunks(CHUNK_LEN) {
+        contexts.iter_mut().for_each(|ctx| ctx.update(chunk));
+    }
+    contexts.into_iter().map(Context::sign).collect()
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{hmac, rand};
@@ -364,6 +406,8 @@ mod tests {
             hmac::HMAC_SHA256,
             hmac::HMAC_SHA384,
             hmac::HMAC_SHA512,
+            hmac::HMAC_SHA512_224,
+            hmac::HMAC_SHA512_256,
         ] {
             let key = hmac::Key::generate(*algorithm, &rng).unwrap();
             let tag = hmac::sign(&key, HELLO_WORLD_GOOD);
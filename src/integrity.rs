@@ -0,0 +1,214 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Self-tests for validating a build.
+//!
+//! ring can't portably compute a digest of its own machine code, since how
+//! the code is laid out in memory depends on the linker and the loader.
+//! Instead, `verify_code()` runs known-answer tests of every implementation of
+//! the primitives that have assembly language implementations, including
+//! every implementation that the CPU supports, not just the one that ring
+//! would choose. This is a superset of a FIPS 140-style power-on self-test.
+
+use crate::{aead, agreement, constant_time, cpu, digest, error};
+
+/// Runs known-answer tests of the constant-time utilities, the digest
+/// algorithms, the AES and GHASH implementations, AES-128-GCM,
+/// ChaCha20-Poly1305 (unless the `fips` feature is enabled), X25519, and
+/// ECDH on P-256.
+///
+/// A failure means that the build is broken, e.g. by a miscompilation, a
+/// corrupted binary, or a CPU that doesn't correctly implement an
+/// instruction that it claims to implement; such a build should not be used.
+pub fn verify_code() -> Result<(), error::Unspecified> {
+    verify_code_(&Primitives {
+        digest: digest::digest,
+        agree: agree_static,
+    })
+}
+
+// The primitives under test that aren't tested by other modules' self-tests,
+// so that the tests can check that broken implementations are detected.
+struct Primitives {
+    digest: fn(&'static digest::Algorithm, &[u8]) -> digest::Digest,
+
+    // Calls the closure with the shared secret computed from `private_key`
+    // and `peer_public_key`.
+    agree: fn(
+        &'static agreement::Algorithm,
+        &[u8],
+        &[u8],
+        &mut dyn FnMut(&[u8]) -> bool,
+    ) -> Result<bool, error::Unspecified>,
+}
+
+fn verify_code_(primitives: &Primitives) -> Result<(), error::Unspecified> {
+    constant_time::self_test()?;
+
+    // FIPS 180-2 Appendix A.1, B.1, C.1, and D.1.
+    let digests: [(&'static digest::Algorithm, &[u8]); 4] = [
+        (
+            &digest::SHA1_FOR_LEGACY_USE_ONLY,
+            &[
+                0xa9, 0x99, 0x3e, 0x36, 0x47, 0x06, 0x81, 0x6a, 0xba, 0x3e, 0x25, 0x71, 0x78, 0x50,
+                0xc2, 0x6c, 0x9c, 0xd0, 0xd8, 0x9d,
+            ],
+        ),
+        (
+            &digest::SHA256,
+            &[
+                0xba, 0x78, 0x16, 0xbf, 0x8f, 0x01, 0xcf, 0xea, 0x41, 0x41, 0x40, 0xde, 0x5d, 0xae,
+                0x22, 0x23, 0xb0, 0x03, 0x61, 0xa3, 0x96, 0x17, 0x7a, 0x9c, 0xb4, 0x10, 0xff, 0x61,
+                0xf2, 0x00, 0x15, 0xad,
+            ],
+        ),
+        (
+            &digest::SHA384,
+            &[
+                0xcb, 0x00, 0x75, 0x3f, 0x45, 0xa3, 0x5e, 0x8b, 0xb5, 0xa0, 0x3d, 0x69, 0x9a, 0xc6,
+                0x50, 0x07, 0x27, 0x2c, 0x32, 0xab, 0x0e, 0xde, 0xd1, 0x63, 0x1a, 0x8b, 0x60, 0x5a,
+                0x43, 0xff, 0x5b, 0xed, 0x80, 0x86, 0x07, 0x2b, 0xa1, 0xe7, 0xcc, 0x23, 0x58, 0xba,
+                0xec, 0xa1, 0x34, 0xc8, 0x25, 0xa7,
+            ],
+        ),
+        (
+            &digest::SHA512,
+            &[
+                0xdd, 0xaf, 0x35, 0xa1, 0x93, 0x61, 0x7a, 0xba, 0xcc, 0x41, 0x73, 0x49, 0xae, 0x20,
+                0x41, 0x31, 0x12, 0xe6, 0xfa, 0x4e, 0x89, 0xa9, 0x7e, 0xa2, 0x0a, 0x9e, 0xee, 0xe6,
+                0x4b, 0x55, 0xd3, 0x9a, 0x21, 0x92, 0x99, 0x2a, 0x27, 0x4f, 0xc1, 0xa8, 0x36, 0xba,
+                0x3c, 0x23, 0xa3, 0xfe, 0xeb, 0xbd, 0x45, 0x4d, 0x44, 0x23, 0x64, 0x3c, 0xe8, 0x0e,
+                0x2a, 0x9a, 0xc9, 0x4f, 0xa5, 0x4c, 0xa4, 0x9f,
+            ],
+        ),
+    ];
+    for (algorithm, expected) in digests {
+        if (primitives.digest)(algorithm, b"abc").as_ref() != expected {
+            return Err(error::Unspecified);
+        }
+    }
+
+    aead::self_test()?;
+
+    // RFC 7748 Section 5.2.
+    check_agreement(
+        primitives,
+        &agreement::X25519,
+        &[
+            0xa5, 0x46, 0xe3, 0x6b, 0xf0, 0x52, 0x7c, 0x9d, 0x3b, 0x16, 0x15, 0x4b, 0x82, 0x46,
+            0x5e, 0xdd, 0x62, 0x14, 0x4c, 0x0a, 0xc1, 0xfc, 0x5a, 0x18, 0x50, 0x6a, 0x22, 0x44,
+            0xba, 0x44, 0x9a, 0xc4,
+        ],
+        &[
+            0xe6, 0xdb, 0x68, 0x67, 0x58, 0x30, 0x30, 0xdb, 0x35, 0x94, 0xc1, 0xa4, 0x24, 0xb1,
+            0x5f, 0x7c, 0x72, 0x66, 0x24, 0xec, 0x26, 0xb3, 0x35, 0x3b, 0x10, 0xa9, 0x03, 0xa6,
+            0xd0, 0xab, 0x1c, 0x4c,
+        ],
+        &[
+            0xc3, 0xda, 0x55, 0x37, 0x9d, 0xe9, 0xc6, 0x90, 0x8e, 0x94, 0xea, 0x4d, 0xf2, 0x8d,
+            0x08, 0x4f, 0x32, 0xec, 0xcf, 0x03, 0x49, 0x1c, 0x71, 0xf7, 0x54, 0xb4, 0x07, 0x55,
+            0x77, 0xa2, 0x85, 0x52,
+        ],
+    )?;
+
+    check_agreement(
+        primitives,
+        &agreement::ECDH_P256,
+        &[
+            0x64, 0x60, 0xca, 0x4f, 0x1e, 0xdd, 0xee, 0xb4, 0x6b, 0x76, 0x1a, 0x8b, 0x79, 0x2c,
+            0x8b, 0x1e, 0xc7, 0x15, 0xbf, 0x37, 0x8b, 0xc6, 0x65, 0xae, 0x96, 0xdd, 0xed, 0x90,
+            0xa4, 0x5f, 0x7c, 0x1f,
+        ],
+        &[
+            0x04, 0xf0, 0x32, 0x3a, 0x78, 0xc4, 0x64, 0xcd, 0xab, 0xed, 0x75, 0x83, 0xee, 0xab,
+            0x98, 0x83, 0x5d, 0x57, 0xf3, 0x67, 0x78, 0xcc, 0x66, 0x19, 0x5f, 0x1f, 0xf9, 0xc0,
+            0x23, 0xb1, 0xdb, 0x6c, 0xab, 0x95, 0x64, 0x7a, 0xe1, 0xd1, 0xea, 0x62, 0xb0, 0x1a,
+            0x31, 0x13, 0xc8, 0x6d, 0xaf, 0x2a, 0x6c, 0xef, 0x29, 0x86, 0x64, 0x8f, 0x2a, 0x3f,
+            0xd9, 0x37, 0x98, 0x05, 0xe9, 0x42, 0x9a, 0xd2, 0x5d,
+        ],
+        &[
+            0xd2, 0x35, 0x50, 0x9a, 0xbb, 0xc4, 0xaa, 0x75, 0x70, 0x34, 0xde, 0xce, 0x6b, 0xde,
+            0x29, 0x87, 0x18, 0xad, 0x91, 0xfd, 0xe5, 0x7e, 0x03, 0x3a, 0x53, 0xb3, 0x16, 0x9d,
+            0x87, 0x10, 0xd5, 0x04,
+        ],
+    )?;
+
+    Ok(())
+}
+
+fn check_agreement(
+    primitives: &Primitives,
+    algorithm: &'static agreement::Algorithm,
+    private_key: &[u8],
+    peer_public_key: &[u8],
+    expected: &[u8],
+) -> Result<(), error::Unspecified> {
+    let mut check = |shared: &[u8]| shared == expected;
+    if !(primitives.agree)(algorithm, private_key, peer_public_key, &mut check)? {
+        return Err(error::Unspecified);
+    }
+    Ok(())
+}
+
+fn agree_static(
+    algorithm: &'static agreement::Algorithm,
+    private_key: &[u8],
+    peer_public_key: &[u8],
+    f: &mut dyn FnMut(&[u8]) -> bool,
+) -> Result<bool, error::Unspecified> {
+    let private_key =
+        agreement::StaticPrivateKey::from_bytes(algorithm, private_key, cpu::features())?;
+    let peer_public_key = agreement::UnparsedPublicKey::new(algorithm, peer_public_key);
+    agreement::agree_static(&private_key, &peer_public_key, f)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{agree_static, verify_code_, Primitives};
+    use crate::{digest, error};
+
+    #[test]
+    fn test_verify_code_detects_wrong_results() {
+        let correct = Primitives {
+            digest: digest::digest,
+            agree: agree_static,
+        };
+        assert_eq!(verify_code_(&correct), Ok(()));
+
+        // Digests the wrong input.
+        let broken = Primitives {
+            digest: |algorithm, data| digest::digest(algorithm, &data[1..]),
+            ..correct
+        };
+        assert_eq!(verify_code_(&broken), Err(error::Unspecified));
+
+        // Uses the wrong private key.
+        let broken = Primitives {
+            agree: |algorithm, private_key, peer_public_key, f| {
+                let mut private_key = <[u8; 32]>::try_from(private_key).unwrap();
+                private_key[31] ^= 1;
+                agree_static(algorithm, &private_key, peer_public_key, f)
+            },
+            ..correct
+        };
+        assert_eq!(verify_code_(&broken), Err(error::Unspecified));
+
+        // Fails to agree.
+        let broken = Primitives {
+            agree: |_, _, _, _| Err(error::Unspecified),
+            ..correct
+        };
+        assert_eq!(verify_code_(&broken), Err(error::Unspecified));
+    }
+}
@@ -0,0 +1,108 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Key-based key derivation functions, as specified in [NIST SP 800-108r1].
+//!
+//! These derive keying material from a key that is already uniformly random,
+//! e.g. a key from a key exchange that has been run through HKDF-Extract, or
+//! a long-term master key. They are not suitable for deriving keys from
+//! passwords; use `ring::pbkdf2` for that.
+//!
+//! [NIST SP 800-108r1]: https://doi.org/10.6028/NIST.SP.800-108r1-upd1
+
+use crate::{aead::aes_cmac, error, hmac};
+
+/// The KDF in counter mode from [NIST SP 800-108r1 Section 4.1], with
+/// AES-CMAC as the PRF.
+///
+/// `key` is the AES-128 or AES-256 key K_IN, so it must be 16 or 32 bytes
+/// long. `out` is filled with `out.len()` bytes of derived keying material.
+///
+/// The counter is encoded as 32 bits and precedes the fixed input data,
+/// which is `label || 0x00 || context || [L]_32`, where `L` is the length of
+/// `out` in bits. Fails if `key` has the wrong length, or if `out` is longer
+/// than can be represented in `[L]_32`.
+///
+/// [NIST SP 800-108r1 Section 4.1]:
+///     https://doi.org/10.6028/NIST.SP.800-108r1-upd1
+pub fn sp800_108_cmac(
+    key: &[u8],
+    label: &[u8],
+    context: &[u8],
+    out: &mut [u8],
+) -> Result<(), error::Unspecified> {
+    let key = aes_cmac::Key::new(key)?;
+    counter_mode(
+        aes_cmac::TAG_LEN,
+        |parts| key.sign(parts),
+        label,
+        context,
+        out,
+    )
+}
+
+/// The KDF in counter mode from [NIST SP 800-108r1 Section 4.1], with HMAC as
+/// the PRF.
+///
+/// The HMAC algorithm is `key.algorithm()`. Unlike HKDF-Expand, the counter
+/// is 32 bits and precedes the fixed input data, which is
+/// `label || 0x00 || context || [L]_32`, where `L` is the length of `out` in
+/// bits; each block's PRF input doesn't include the previous block. Fails if
+/// `out` is longer than can be represented in `[L]_32`.
+///
+/// [NIST SP 800-108r1 Section 4.1]:
+///     https://doi.org/10.6028/NIST.SP.800-108r1-upd1
+pub fn sp800_108_hmac(
+    key: &hmac::Key,
+    label: &[u8],
+    context: &[u8],
+    out: &mut [u8],
+) -> Result<(), error::Unspecified> {
+    let h = key.algorithm().digest_algorithm().output_len();
+    let prf = |parts: &[&[u8]]| {
+        let mut ctx = hmac::Context::with_key(key);
+        for part in parts {
+            ctx.update(part);
+        }
+        ctx.sign()
+    };
+    counter_mode(h, prf, label, context, out)
+}
+
+/// SP 800-108r1 Section 4.1, for a PRF that computes `prf(parts)` over the
+/// concatenation of `parts`, with an output `h` bytes long.
+fn counter_mode<K: AsRef<[u8]>>(
+    h: usize,
+    prf: impl Fn(&[&[u8]]) -> K,
+    label: &[u8],
+    context: &[u8],
+    out: &mut [u8],
+) -> Result<(), error::Unspecified> {
+    let l = out
+        .len()
+        .checked_mul(8)
+        .and_then(|l| u32::try_from(l).ok())
+        .ok_or(error::Unspecified)?;
+    let l = l.to_be_bytes();
+
+    // Since `[L]_32` bounds the output length, the 32-bit counter can't
+    // overflow.
+    let mut i = 0u32;
+    for chunk in out.chunks_mut(h) {
+        i += 1;
+        let k = prf(&[&i.to_be_bytes(), label, &[0], context, &l]);
+        chunk.copy_from_slice(&k.as_ref()[..chunk.len()]);
+    }
+    Ok(())
+}
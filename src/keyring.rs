@@ -0,0 +1,459 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! A self-describing file format for storing multiple keys.
+//!
+//! A keyring is a set of keys, each with a key ID and a key type, serialized
+//! into a single versioned binary blob. The blob can optionally be protected
+//! with a passphrase, in which case the keys are encrypted with AES-256-GCM
+//! under a key derived from the passphrase with PBKDF2-HMAC-SHA256.
+//!
+//! # Format
+//!
+//! All integers are big-endian.
+//!
+//! ```text
+//! magic:       "RKEY"
+//! version:     u8 (1)
+//! protection:  u8 (0: none, 1: PBKDF2-HMAC-SHA256 + AES-256-GCM)
+//! if protection is 1:
+//!   iterations:  u32
+//!   salt:        [u8; 16]
+//!   nonce:       [u8; 12]
+//! keys:        the encoded keys; if protected, encrypted with the header
+//!              (everything before the keys) as the AAD and followed by the
+//!              AES-256-GCM tag.
+//! ```
+//!
+//! The encoded keys are:
+//!
+//! ```text
+//! count:       u16
+//! count times:
+//!   key type:    u8
+//!   id length:   u8
+//!   id:          [u8; id length]
+//!   key length:  u16
+//!   key:         [u8; key length]
+//! ```
+
+use crate::{aead, constant_time, error, hmac, pbkdf2, rand};
+use alloc::vec::Vec;
+use core::num::NonZeroU32;
+
+/// The type of a key in a keyring, which determines how the key bytes are
+/// used.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum KeyType {
+    /// An `aead::AES_128_GCM` key.
+    Aes128Gcm,
+
+    /// An `aead::AES_256_GCM` key.
+    Aes256Gcm,
+
+    /// An `aead::CHACHA20_POLY1305` key.
+    ChaCha20Poly1305,
+
+    /// An `hmac::HMAC_SHA256` key.
+    HmacSha256,
+
+    /// An `hmac::HMAC_SHA384` key.
+    HmacSha384,
+
+    /// An `hmac::HMAC_SHA512` key.
+    HmacSha512,
+
+    /// A PKCS#8 document containing a signing key pair, e.g. for
+    /// `signature::Ed25519KeyPair::from_pkcs8()`.
+    Pkcs8,
+}
+
+impl KeyType {
+    fn tag(self) -> u8 {
+        match self {
+            Self::Aes128Gcm => 1,
+            Self::Aes256Gcm => 2,
+            Self::ChaCha20Poly1305 => 3,
+            Self::HmacSha256 => 4,
+            Self::HmacSha384 => 5,
+            Self::HmacSha512 => 6,
+            Self::Pkcs8 => 7,
+        }
+    }
+
+    fn from_tag(tag: u8) -> Result<Self, error::Unspecified> {
+        match tag {
+            1 => Ok(Self::Aes128Gcm),
+            2 => Ok(Self::Aes256Gcm),
+            3 => Ok(Self::ChaCha20Poly1305),
+            4 => Ok(Self::HmacSha256),
+            5 => Ok(Self::HmacSha384),
+            6 => Ok(Self::HmacSha512),
+            7 => Ok(Self::Pkcs8),
+            _ => Err(error::Unspecified),
+        }
+    }
+
+    fn aead_algorithm(self) -> Option<&'static aead::Algorithm> {
+        match self {
+            Self::Aes128Gcm => Some(&aead::AES_128_GCM),
+            Self::Aes256Gcm => Some(&aead::AES_256_GCM),
+            #[cfg(not(feature = "fips"))]
+            Self::ChaCha20Poly1305 => Some(&aead::CHACHA20_POLY1305),
+            _ => None,
+        }
+    }
+
+    fn hmac_algorithm(self) -> Option<hmac::Algorithm> {
+        match self {
+            Self::HmacSha256 => Some(hmac::HMAC_SHA256),
+            Self::HmacSha384 => Some(hmac::HMAC_SHA384),
+            Self::HmacSha512 => Some(hmac::HMAC_SHA512),
+            _ => None,
+        }
+    }
+
+    fn is_valid_key_len(self, len: usize) -> bool {
+        match self.aead_algorithm() {
+            Some(algorithm) => len == algorithm.key_len(),
+            None => len > 0,
+        }
+    }
+}
+
+/// A key in a `Keyring`.
+pub struct Entry {
+    id: Vec<u8>,
+    key_type: KeyType,
+    key: Vec<u8>,
+}
+
+impl Entry {
+    /// The key's ID.
+    #[inline]
+    pub fn id(&self) -> &[u8] {
+        &self.id
+    }
+
+    /// The key's type.
+    #[inline]
+    pub fn key_type(&self) -> KeyType {
+        self.key_type
+    }
+
+    /// Constructs an AEAD key from the entry.
+    ///
+    /// Fails if the key type isn't an AEAD key type. When the `fips` feature
+    /// is enabled, `KeyType::ChaCha20Poly1305` isn't an AEAD key type.
+    pub fn aead_key(&self) -> Result<aead::UnboundKey, error::Unspecified> {
+        let algorithm = self.key_type.aead_algorithm().ok_or(error::Unspecified)?;
+        aead::UnboundKey::new(algorithm, &self.key)
+    }
+
+    /// Constructs an HMAC key from the entry.
+    ///
+    /// Fails if the key type isn't an HMAC key type.
+    pub fn hmac_key(&self) -> Result<hmac::Key, error::Unspecified> {
+        let algorithm = self.key_type.hmac_algorithm().ok_or(error::Unspecified)?;
+        Ok(hmac::Key::new(algorithm, &self.key))
+    }
+
+    /// The PKCS#8 document of a `KeyType::Pkcs8` entry.
+    ///
+    /// Fails if the key type isn't `KeyType::Pkcs8`.
+    pub fn pkcs8(&self) -> Result<&[u8], error::Unspecified> {
+        match self.key_type {
+            KeyType::Pkcs8 => Ok(&self.key),
+            _ => Err(error::Unspecified),
+        }
+    }
+}
+
+impl Drop for Entry {
+    fn drop(&mut self) {
+        constant_time::secure_zero(&mut self.key);
+    }
+}
+
+impl core::fmt::Debug for Entry {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("Entry")
+            .field("id", &crate::debug::HexStr(&self.id))
+            .field("key_type", &self.key_type)
+            .finish()
+    }
+}
+
+/// A set of keys, each identified by a unique key ID.
+#[derive(Debug, Default)]
+pub struct Keyring {
+    entries: Vec<Entry>,
+}
+
+impl Keyring {
+    /// Constructs an empty keyring.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a copy of `key` to the keyring with the key ID `id`.
+    ///
+    /// Fails if the keyring already has a key with the ID `id`, if `id` is
+    /// empty or longer than 255 bytes, if `key` isn't a valid length for
+    /// `key_type` or is longer than 65535 bytes, or if the keyring already
+    /// has 65535 keys.
+    pub fn add(
+        &mut self,
+        id: &[u8],
+        key_type: KeyType,
+        key: &[u8],
+    ) -> Result<(), error::Unspecified> {
+        if id.is_empty()
+            || id.len() > usize::from(u8::MAX)
+            || key.len() > usize::from(u16::MAX)
+            || !key_type.is_valid_key_len(key.len())
+            || self.entries.len() >= usize::from(u16::MAX)
+            || self.get(id).is_some()
+        {
+            return Err(error::Unspecified);
+        }
+        self.entries.push(Entry {
+            id: id.to_vec(),
+            key_type,
+            key: key.to_vec(),
+        });
+        Ok(())
+    }
+
+    /// The key with the key ID `id`, if any.
+    pub fn get(&self, id: &[u8]) -> Option<&Entry> {
+        self.entries.iter().find(|entry| entry.id == id)
+    }
+
+    /// The keys, in the order they were added.
+    pub fn entries(&self) -> impl Iterator<Item = &Entry> {
+        self.entries.iter()
+    }
+
+    /// Serializes the keyring without any protection.
+    ///
+    /// The result contains the keys in the clear.
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut out = header(Protection::None);
+        self.encode_keys(&mut out);
+        out
+    }
+
+    /// Serializes the keyring, encrypting the keys with a key derived from
+    /// `passphrase`.
+    ///
+    /// The key is derived with PBKDF2-HMAC-SHA256 using `iterations`
+    /// iterations and a random salt; see `ring::pbkdf2` for guidance on
+    /// choosing `iterations`.
+    pub fn serialize_with_passphrase(
+        &self,
+        passphrase: &[u8],
+        iterations: NonZeroU32,
+        rng: &dyn rand::SecureRandom,
+    ) -> Result<Vec<u8>, error::Unspecified> {
+        let mut salt = [0u8; SALT_LEN];
+        rng.fill(&mut salt)?;
+        let mut nonce = [0u8; aead::NONCE_LEN];
+        rng.fill(&mut nonce)?;
+
+        let mut out = header(Protection::Passphrase {
+            iterations,
+            salt,
+            nonce,
+        });
+        let header_len = out.len();
+        self.encode_keys(&mut out);
+
+        let key = passphrase_key(passphrase, iterations, &salt)?;
+        let (header, in_out) = out.split_at_mut(header_len);
+        let tag = key.seal_in_place_separate_tag(
+            aead::Nonce::assume_unique_for_key(nonce),
+            aead::Aad::from(&*header),
+            in_out,
+        )?;
+        out.extend_from_slice(tag.as_ref());
+        Ok(out)
+    }
+
+    /// Parses a keyring that was serialized with `serialize()`.
+    ///
+    /// Fails if `input` isn't a valid keyring, or if it is protected with a
+    /// passphrase.
+    pub fn parse(input: &[u8]) -> Result<Self, error::Unspecified> {
+        let (protection, header_len) = parse_header(input)?;
+        match protection {
+            Protection::None => decode_keys(&input[header_len..]),
+            Protection::Passphrase { .. } => Err(error::Unspecified),
+        }
+    }
+
+    /// Parses a keyring that was serialized with
+    /// `serialize_with_passphrase()`.
+    ///
+    /// Fails if `input` isn't a valid keyring, if it isn't protected with a
+    /// passphrase, or if `passphrase` is wrong. The PBKDF2 iteration count is
+    /// read from `input`, so parsing an untrusted keyring may be slow.
+    pub fn parse_with_passphrase(
+        input: &[u8],
+        passphrase: &[u8],
+    ) -> Result<Self, error::Unspecified> {
+        let (protection, header_len) = parse_header(input)?;
+        let (iterations, salt, nonce) = match protection {
+            Protection::None => return Err(error::Unspecified),
+            Protection::Passphrase {
+                iterations,
+                salt,
+                nonce,
+            } => (iterations, salt, nonce),
+        };
+
+        let key = passphrase_key(passphrase, iterations, &salt)?;
+        let (header, ciphertext) = input.split_at(header_len);
+        let mut in_out = ciphertext.to_vec();
+        let result = key
+            .open_in_place(
+                aead::Nonce::assume_unique_for_key(nonce),
+                aead::Aad::from(header),
+                &mut in_out,
+            )
+            .and_then(|keys| decode_keys(keys));
+        constant_time::secure_zero(&mut in_out);
+        result
+    }
+
+    fn encode_keys(&self, out: &mut Vec<u8>) {
+        // `add()` ensures all the lengths fit.
+        let count = u16::try_from(self.entries.len()).unwrap();
+        out.extend_from_slice(&count.to_be_bytes());
+        for entry in &self.entries {
+            let id_len = u8::try_from(entry.id.len()).unwrap();
+            let key_len = u16::try_from(entry.key.len()).unwrap();
+            out.push(entry.key_type.tag());
+            out.push(id_len);
+            out.extend_from_slice(&entry.id);
+            out.extend_from_slice(&key_len.to_be_bytes());
+            out.extend_from_slice(&entry.key);
+        }
+    }
+}
+
+const MAGIC: &[u8; 4] = b"RKEY";
+const VERSION: u8 = 1;
+const SALT_LEN: usize = 16;
+
+enum Protection {
+    None,
+    Passphrase {
+        iterations: NonZeroU32,
+        salt: [u8; SALT_LEN],
+        nonce: [u8; aead::NONCE_LEN],
+    },
+}
+
+impl Protection {
+    const NONE: u8 = 0;
+    const PASSPHRASE: u8 = 1;
+}
+
+fn header(protection: Protection) -> Vec<u8> {
+    let mut out = Vec::new();
+    out.extend_from_slice(MAGIC);
+    out.push(VERSION);
+    match protection {
+        Protection::None => out.push(Protection::NONE),
+        Protection::Passphrase {
+            iterations,
+            salt,
+            nonce,
+        } => {
+            out.push(Protection::PASSPHRASE);
+            out.extend_from_slice(&iterations.get().to_be_bytes());
+            out.extend_from_slice(&salt);
+            out.extend_from_slice(&nonce);
+        }
+    }
+    out
+}
+
+/// Returns the protection and the length of the header.
+fn parse_header(input: &[u8]) -> Result<(Protection, usize), error::Unspecified> {
+    let mut reader = untrusted::Reader::new(untrusted::Input::from(input));
+    let magic = reader.read_bytes(MAGIC.len())?;
+    if magic.as_slice_less_safe() != MAGIC || reader.read_byte()? != VERSION {
+        return Err(error::Unspecified);
+    }
+    let protection = match reader.read_byte()? {
+        Protection::NONE => Protection::None,
+        Protection::PASSPHRASE => {
+            let iterations = read_array(&mut reader)?;
+            let iterations =
+                NonZeroU32::new(u32::from_be_bytes(iterations)).ok_or(error::Unspecified)?;
+            Protection::Passphrase {
+                iterations,
+                salt: read_array(&mut reader)?,
+                nonce: read_array(&mut reader)?,
+            }
+        }
+        _ => return Err(error::Unspecified),
+    };
+    let remaining = reader.read_bytes_to_end();
+    Ok((protection, input.len() - remaining.len()))
+}
+
+fn decode_keys(input: &[u8]) -> Result<Keyring, error::Unspecified> {
+    untrusted::Input::from(input).read_all(error::Unspecified, |input| {
+        let count = u16::from_be_bytes(read_array(input)?);
+        let mut keyring = Keyring::new();
+        for _ in 0..count {
+            let key_type = KeyType::from_tag(input.read_byte()?)?;
+            let id_len = input.read_byte()?;
+            let id = input.read_bytes(usize::from(id_len))?;
+            let key_len = u16::from_be_bytes(read_array(input)?);
+            let key = input.read_bytes(usize::from(key_len))?;
+            keyring.add(id.as_slice_less_safe(), key_type, key.as_slice_less_safe())?;
+        }
+        Ok(keyring)
+    })
+}
+
+fn read_array<const N: usize>(
+    input: &mut untrusted::Reader,
+) -> Result<[u8; N], error::Unspecified> {
+    let bytes = input.read_bytes(N)?;
+    Ok(bytes.as_slice_less_safe().try_into()?)
+}
+
+fn passphrase_key(
+    passphrase: &[u8],
+    iterations: NonZeroU32,
+    salt: &[u8; SALT_LEN],
+) -> Result<aead::LessSafeKey, error::Unspecified> {
+    let mut key_bytes = [0u8; 32];
+    pbkdf2::derive(
+        pbkdf2::PBKDF2_HMAC_SHA256,
+        iterations,
+        salt,
+        passphrase,
+        &mut key_bytes,
+    );
+    let key = aead::UnboundKey::new(&aead::AES_256_GCM, &key_bytes);
+    constant_time::secure_zero(&mut key_bytes);
+    Ok(aead::LessSafeKey::new(key?))
+}
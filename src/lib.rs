@@ -22,6 +22,21 @@
 //!     <th>Description
 //! <tr><td><code>alloc (default)</code>
 //!     <td>Enable features that require use of the heap, RSA in particular.
+//! <tr><td><code>debug-internals</code>
+//!     <td>Enable accessors for intermediate state, such as the GHASH state
+//!         of a GMAC computation in progress (see
+//!         <code>aead::gmac::Gmac::current_xi</code>), for use while debugging
+//!         interoperability problems. These accessors aren't covered by
+//!         semver guarantees.
+//! <tr><td><code>fips</code>
+//!     <td>Remove the algorithms that aren't approved for use in FIPS mode
+//!         from <code>ring::aead</code> and <code>ring::signature</code>:
+//!         ChaCha20-Poly1305 in all its forms (including the QUIC header
+//!         protection and Noise ciphers built on it), AES-GCM-SIV, POLYVAL,
+//!         AEGIS-128L, and the <code>*_FOR_LEGACY_USE_ONLY</code> RSA
+//!         verification algorithms. This doesn't make ring a validated module;
+//!         it only prevents the non-approved algorithms from being used
+//!         accidentally.
 //! <tr><td><code>less-safe-getrandom-custom-or-rdrand</code>
 //!     <td>Treat user-provided ("custom") and RDRAND-based <code>getrandom</code>
 //!         implementations as secure random number generators (see
@@ -120,28 +135,54 @@ pub mod aead;
 
 pub mod agreement;
 
+#[cfg(feature = "alloc")]
+pub mod aont;
+
+#[cfg(feature = "alloc")]
+pub mod argon2;
+
 mod bits;
 
 pub(crate) mod c;
+pub mod cipher;
 pub mod constant_time;
 
 pub mod io;
 
-mod cpu;
+pub mod cpu;
 pub mod digest;
 mod ec;
 pub mod error;
 pub mod hkdf;
 pub mod hmac;
+pub mod integrity;
+pub mod kdf;
+
+#[cfg(feature = "alloc")]
+pub mod keyring;
+
 mod limb;
+pub mod mac;
+pub mod noise;
 pub mod pbkdf2;
+
+#[cfg(feature = "alloc")]
+pub mod pem;
+
 pub mod pkcs8;
 pub mod rand;
+pub mod ratchet;
 
 #[cfg(feature = "alloc")]
 pub mod rsa;
 
+#[cfg(feature = "alloc")]
+pub mod shamir;
+
 pub mod signature;
+pub mod signify;
+pub mod siphash;
+pub mod tls12;
 
 #[cfg(test)]
 mod tests;
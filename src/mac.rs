@@ -0,0 +1,257 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! KMAC, the Keccak-based message authentication codes.
+//!
+//! KMAC128 and KMAC256 are specified in [NIST SP 800-185]. Unlike HMAC, KMAC
+//! takes a customization string that separates the MACs computed for
+//! different purposes with the same key, and the length of the output is a
+//! parameter that changes the whole output, not just its truncation.
+//!
+//! ```
+//! use ring::mac;
+//!
+//! let key = mac::Kmac256::new(b"key value", b"My Application", 32)?;
+//! let tag = key.sign(b"hello, world");
+//! key.verify(b"hello, world", tag.as_ref())?;
+//!
+//! # Ok::<(), ring::error::Unspecified>(())
+//! ```
+//!
+//! [NIST SP 800-185]: https://doi.org/10.6028/NIST.SP.800-185
+
+use crate::{constant_time, digest::keccak, error, polyfill::u64_from_usize};
+
+/// The length of the shortest KMAC tag supported.
+///
+/// SP 800-185 Section 8.4.2 recommends against tags shorter than 32 bits.
+pub const MIN_TAG_LEN: usize = 4;
+
+/// The length of the longest KMAC tag supported.
+pub const MAX_TAG_LEN: usize = 64;
+
+/// A KMAC tag.
+///
+/// For a given tag `t`, use `t.as_ref()` to get the tag value as a byte slice.
+#[derive(Clone, Copy, Debug)]
+pub struct Tag {
+    value: [u8; MAX_TAG_LEN],
+    len: usize,
+}
+
+impl AsRef<[u8]> for Tag {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        &self.value[..self.len]
+    }
+}
+
+/// A KMAC128 key.
+#[derive(Clone)]
+pub struct Kmac128(Key);
+
+impl Kmac128 {
+    /// Constructs a KMAC128 key that produces `tag_len`-byte tags, with the
+    /// customization string `customization`.
+    ///
+    /// Fails if `tag_len` is less than `MIN_TAG_LEN` or greater than
+    /// `MAX_TAG_LEN`.
+    pub fn new(
+        key_value: &[u8],
+        customization: &[u8],
+        tag_len: usize,
+    ) -> Result<Self, error::Unspecified> {
+        Key::new(KMAC128_RATE, key_value, customization, tag_len).map(Self)
+    }
+
+    /// Calculates the KMAC128 of `data`.
+    ///
+    /// It is generally not safe to implement KMAC verification by comparing
+    /// the return value of `sign` to a tag. Use `verify` for verification
+    /// instead.
+    pub fn sign(&self, data: &[u8]) -> Tag {
+        self.0.sign(data)
+    }
+
+    /// Calculates the KMAC128 of `data` and verifies in constant time
+    /// whether it equals `tag`.
+    pub fn verify(&self, data: &[u8], tag: &[u8]) -> Result<(), error::Unspecified> {
+        self.0.verify(data, tag)
+    }
+
+    /// The length of the tags.
+    #[inline]
+    pub fn tag_len(&self) -> usize {
+        self.0.tag_len
+    }
+}
+
+impl core::fmt::Debug for Kmac128 {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        self.0.fmt_debug("Kmac128", f)
+    }
+}
+
+/// A KMAC256 key.
+#[derive(Clone)]
+pub struct Kmac256(Key);
+
+impl Kmac256 {
+    /// Constructs a KMAC256 key that produces `tag_len`-byte tags, with the
+    /// customization string `customization`.
+    ///
+    /// Fails if `tag_len` is less than `MIN_TAG_LEN` or greater than
+    /// `MAX_TAG_LEN`.
+    pub fn new(
+        key_value: &[u8],
+        customization: &[u8],
+        tag_len: usize,
+    ) -> Result<Self, error::Unspecified> {
+        Key::new(KMAC256_RATE, key_value, customization, tag_len).map(Self)
+    }
+
+    /// Calculates the KMAC256 of `data`.
+    ///
+    /// It is generally not safe to implement KMAC verification by comparing
+    /// the return value of `sign` to a tag. Use `verify` for verification
+    /// instead.
+    pub fn sign(&self, data: &[u8]) -> Tag {
+        self.0.sign(data)
+    }
+
+    /// Calculates the KMAC256 of `data` and verifies in constant time
+    /// whether it equals `tag`.
+    pub fn verify(&self, data: &[u8], tag: &[u8]) -> Result<(), error::Unspecified> {
+        self.0.verify(data, tag)
+    }
+
+    /// The length of the tags.
+    #[inline]
+    pub fn tag_len(&self) -> usize {
+        self.0.tag_len
+    }
+}
+
+impl core::fmt::Debug for Kmac256 {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        self.0.fmt_debug("Kmac256", f)
+    }
+}
+
+// The rates of cSHAKE128 and cSHAKE256.
+const KMAC128_RATE: usize = 168;
+const KMAC256_RATE: usize = 136;
+
+// cSHAKE's domain separation bits `00` followed by the first padding bit.
+const CSHAKE_SUFFIX: u8 = 0x04;
+
+#[derive(Clone)]
+struct Key {
+    /// The sponge after absorbing the cSHAKE prefix and the padded key,
+    /// which are the same for every message.
+    sponge: keccak::Sponge,
+    tag_len: usize,
+}
+
+impl Key {
+    fn new(
+        rate: usize,
+        key_value: &[u8],
+        customization: &[u8],
+        tag_len: usize,
+    ) -> Result<Self, error::Unspecified> {
+        if !(MIN_TAG_LEN..=MAX_TAG_LEN).contains(&tag_len) {
+            return Err(error::Unspecified);
+        }
+
+        let mut sponge = keccak::Sponge::new(rate);
+        // cSHAKE: bytepad(encode_string(N) || encode_string(S), rate).
+        bytepad(&mut sponge, &[b"KMAC", customization]);
+        // KMAC: bytepad(encode_string(K), rate).
+        bytepad(&mut sponge, &[key_value]);
+        Ok(Self { sponge, tag_len })
+    }
+
+    fn sign(&self, data: &[u8]) -> Tag {
+        let mut sponge = self.sponge.clone();
+        sponge.update(data);
+        sponge.update(right_encode(
+            &mut [0; ENCODE_MAX_LEN],
+            bit_len(self.tag_len),
+        ));
+        let mut value = [0; MAX_TAG_LEN];
+        sponge.finish(CSHAKE_SUFFIX, &mut value[..self.tag_len]);
+        Tag {
+            value,
+            len: self.tag_len,
+        }
+    }
+
+    fn verify(&self, data: &[u8], tag: &[u8]) -> Result<(), error::Unspecified> {
+        constant_time::verify_slices_are_equal(self.sign(data).as_ref(), tag)
+    }
+
+    fn fmt_debug(
+        &self,
+        type_name: &'static str,
+        f: &mut core::fmt::Formatter,
+    ) -> Result<(), core::fmt::Error> {
+        f.debug_struct(type_name)
+            .field("tag_len", &self.tag_len)
+            .finish()
+    }
+}
+
+/// Absorbs `bytepad(encode_string(strings[0]) || ..., rate)`.
+fn bytepad(sponge: &mut keccak::Sponge, strings: &[&[u8]]) {
+    let mut buf = [0; ENCODE_MAX_LEN];
+    let rate = u128::from(u64_from_usize(sponge.rate()));
+    sponge.update(left_encode(&mut buf, rate));
+    for s in strings {
+        sponge.update(left_encode(&mut buf, bit_len(s.len())));
+        sponge.update(s);
+    }
+    sponge.fill_block_with_zeros();
+}
+
+// A `u128` is encoded in at most 16 bytes plus the length byte.
+const ENCODE_MAX_LEN: usize = 1 + 16;
+
+fn bit_len(len: usize) -> u128 {
+    u128::from(u64_from_usize(len)) * 8
+}
+
+/// Encodes `x` as its length in bytes followed by its big-endian bytes.
+fn left_encode(buf: &mut [u8; ENCODE_MAX_LEN], x: u128) -> &[u8] {
+    let x = x.to_be_bytes();
+    let x = trim_leading_zeros(&x);
+    buf[0] = u8::try_from(x.len()).unwrap();
+    buf[1..][..x.len()].copy_from_slice(x);
+    &buf[..=x.len()]
+}
+
+/// Encodes `x` as its big-endian bytes followed by its length in bytes.
+fn right_encode(buf: &mut [u8; ENCODE_MAX_LEN], x: u128) -> &[u8] {
+    let x = x.to_be_bytes();
+    let x = trim_leading_zeros(&x);
+    buf[..x.len()].copy_from_slice(x);
+    buf[x.len()] = u8::try_from(x.len()).unwrap();
+    &buf[..=x.len()]
+}
+
+/// Removes the leading zeros from `bytes`, but leaves at least one byte.
+fn trim_leading_zeros(bytes: &[u8; 16]) -> &[u8] {
+    let zeros = bytes[..15].iter().take_while(|&&b| b == 0).count();
+    &bytes[zeros..]
+}
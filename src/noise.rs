@@ -0,0 +1,227 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! The cipher functions and `CipherState` of the Noise Protocol Framework.
+//!
+//! See [Noise Section 5.1] and [Noise Section 12]. Only the `CipherState`
+//! object is provided; `SymmetricState`, `HandshakeState`, and the choice of
+//! DH and hash functions are left to the Noise implementation built on top.
+//!
+//! [Noise Section 5.1]: https://noiseprotocol.org/noise.html#the-cipherstate-object
+//! [Noise Section 12]: https://noiseprotocol.org/noise.html#cipher-functions
+
+use crate::{aead, error};
+
+/// The length of a Noise cipher key.
+pub const KEY_LEN: usize = 32;
+
+/// A Noise cipher function.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Cipher {
+    aead: &'static aead::Algorithm,
+    nonce_encoding: NonceEncoding,
+}
+
+/// How the 64-bit counter `n` is encoded in the 96-bit AEAD nonce.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum NonceEncoding {
+    BigEndian,
+    #[cfg(not(feature = "fips"))]
+    LittleEndian,
+}
+
+impl Cipher {
+    /// The underlying AEAD algorithm.
+    #[inline]
+    pub fn aead_algorithm(&self) -> &'static aead::Algorithm {
+        self.aead
+    }
+
+    fn nonce(&self, n: u64) -> aead::Nonce {
+        let mut nonce = [0u8; aead::NONCE_LEN];
+        let counter = match self.nonce_encoding {
+            NonceEncoding::BigEndian => n.to_be_bytes(),
+            #[cfg(not(feature = "fips"))]
+            NonceEncoding::LittleEndian => n.to_le_bytes(),
+        };
+        nonce[4..].copy_from_slice(&counter);
+        aead::Nonce::assume_unique_for_key(nonce)
+    }
+}
+
+/// The Noise `AESGCM` cipher: AES-256-GCM with the counter encoded as 32
+/// zero bits followed by the big-endian encoding of `n`.
+pub static AES_256_GCM: Cipher = Cipher {
+    aead: &aead::AES_256_GCM,
+    nonce_encoding: NonceEncoding::BigEndian,
+};
+
+/// The Noise `ChaChaPoly` cipher: ChaCha20-Poly1305 with the counter encoded
+/// as 32 zero bits followed by the little-endian encoding of `n`.
+#[cfg(not(feature = "fips"))]
+pub static CHACHA20_POLY1305: Cipher = Cipher {
+    aead: &aead::CHACHA20_POLY1305,
+    nonce_encoding: NonceEncoding::LittleEndian,
+};
+
+/// A Noise `CipherState`: a cipher key `k`, which may be empty, and a
+/// counter `n` used as the nonce.
+///
+/// Intentionally not `Clone` or `Copy` since cloning would allow a nonce to
+/// be reused.
+pub struct CipherState {
+    cipher: &'static Cipher,
+    key: Option<aead::LessSafeKey>,
+    n: u64,
+}
+
+/// The maximum `n`, which is reserved for `rekey()` and never used to
+/// encrypt a message.
+const MAX_NONCE: u64 = u64::MAX;
+
+impl CipherState {
+    /// Constructs a new `CipherState` with an empty key.
+    pub fn new(cipher: &'static Cipher) -> Self {
+        Self {
+            cipher,
+            key: None,
+            n: 0,
+        }
+    }
+
+    /// Constructs a new `CipherState` with the given key; `n` is zero.
+    pub fn with_key(cipher: &'static Cipher, key: &[u8; KEY_LEN]) -> Self {
+        let mut state = Self::new(cipher);
+        state.initialize_key(key);
+        state
+    }
+
+    /// `InitializeKey(key)`: Sets the key to `key` and sets `n` to zero.
+    pub fn initialize_key(&mut self, key: &[u8; KEY_LEN]) {
+        self.key = Some(new_key(self.cipher, key));
+        self.n = 0;
+    }
+
+    /// `HasKey()`: Returns true if the key is not empty.
+    #[inline]
+    pub fn has_key(&self) -> bool {
+        self.key.is_some()
+    }
+
+    /// `SetNonce(nonce)`: Sets `n` to `nonce`.
+    ///
+    /// This is for out-of-order transport messages; see [Noise Section 11.4].
+    /// The caller is responsible for never reusing a nonce with the same key.
+    ///
+    /// [Noise Section 11.4]: https://noiseprotocol.org/noise.html#out-of-order-transport-messages
+    #[inline]
+    pub fn set_nonce(&mut self, nonce: u64) {
+        self.n = nonce;
+    }
+
+    /// `EncryptWithAd(ad, plaintext)`: Encrypts `in_out` in place with the
+    /// next nonce and appends the tag.
+    ///
+    /// If the key is empty then `in_out` is left unchanged and `n` isn't
+    /// incremented.
+    ///
+    /// Fails, leaving `in_out` unchanged, if the nonces have been exhausted,
+    /// i.e. `n` is 2<sup>64</sup> - 1.
+    pub fn encrypt_with_ad<InOut>(
+        &mut self,
+        ad: &[u8],
+        in_out: &mut InOut,
+    ) -> Result<(), error::Unspecified>
+    where
+        InOut: AsMut<[u8]> + for<'in_out> Extend<&'in_out u8>,
+    {
+        let key = match &self.key {
+            Some(key) => key,
+            None => return Ok(()),
+        };
+        let nonce = self.next_nonce()?;
+        key.seal_in_place_append_tag(nonce, aead::Aad::from(ad), in_out)?;
+        self.n += 1;
+        Ok(())
+    }
+
+    /// `DecryptWithAd(ad, ciphertext)`: Decrypts `in_out` in place with the
+    /// next nonce, returning the plaintext.
+    ///
+    /// If the key is empty then `in_out` is returned unchanged and `n` isn't
+    /// incremented.
+    ///
+    /// Fails if the nonces have been exhausted, i.e. `n` is 2<sup>64</sup> -
+    /// 1, or if authentication fails. `n` isn't incremented on failure, but
+    /// the contents of `in_out` are unspecified.
+    pub fn decrypt_with_ad<'in_out>(
+        &mut self,
+        ad: &[u8],
+        in_out: &'in_out mut [u8],
+    ) -> Result<&'in_out mut [u8], error::Unspecified> {
+        let key = match &self.key {
+            Some(key) => key,
+            None => return Ok(in_out),
+        };
+        let nonce = self.next_nonce()?;
+        let plaintext = key.open_in_place(nonce, aead::Aad::from(ad), in_out)?;
+        self.n += 1;
+        Ok(plaintext)
+    }
+
+    /// `Rekey()`: Replaces the key with the first `KEY_LEN` bytes of the
+    /// encryption of `KEY_LEN` zeros with the maximum nonce and empty
+    /// associated data. `n` is unchanged.
+    ///
+    /// Fails if the key is empty.
+    pub fn rekey(&mut self) -> Result<(), error::Unspecified> {
+        let key = self.key.as_ref().ok_or(error::Unspecified)?;
+        let mut new_key_bytes = [0u8; KEY_LEN];
+        let _: aead::Tag = key.seal_in_place_separate_tag(
+            self.cipher.nonce(MAX_NONCE),
+            aead::Aad::empty(),
+            &mut new_key_bytes,
+        )?;
+        self.key = Some(new_key(self.cipher, &new_key_bytes));
+        Ok(())
+    }
+
+    /// The cipher function.
+    #[inline]
+    pub fn cipher(&self) -> &'static Cipher {
+        self.cipher
+    }
+
+    fn next_nonce(&self) -> Result<aead::Nonce, error::Unspecified> {
+        if self.n == MAX_NONCE {
+            return Err(error::Unspecified);
+        }
+        Ok(self.cipher.nonce(self.n))
+    }
+}
+
+impl core::fmt::Debug for CipherState {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("CipherState")
+            .field("cipher", self.cipher)
+            .field("has_key", &self.has_key())
+            .finish()
+    }
+}
+
+fn new_key(cipher: &'static Cipher, key: &[u8; KEY_LEN]) -> aead::LessSafeKey {
+    // Every Noise cipher uses a 256-bit key.
+    let key = aead::UnboundKey::new(cipher.aead, key).unwrap();
+    aead::LessSafeKey::new(key)
+}
@@ -203,9 +203,10 @@ fn derive_block(secret: &hmac::Key, iterations: NonZeroU32, salt: &[u8], idx: u3
 /// Verifies that a previously-derived (e.g., using `derive`) PBKDF2 value
 /// matches the PBKDF2 value derived from the other inputs.
 ///
-/// The comparison is done in constant time to prevent timing attacks. The
-/// comparison will fail if `previously_derived` is empty (has a length of
-/// zero).
+/// The comparison is done in constant time to prevent timing attacks. Every
+/// block of the derived key is computed and compared even after a mismatch
+/// is found. The comparison will fail if `previously_derived` is empty (has
+/// a length of zero).
 ///
 /// | Parameter                  | RFC 2898 Section 5.2 Term
 /// |----------------------------|--------------------------------------------
@@ -227,45 +228,83 @@ pub fn verify(
     secret: &[u8],
     previously_derived: &[u8],
 ) -> Result<(), error::Unspecified> {
-    let digest_alg = algorithm.0.digest_algorithm();
+    let output_len = algorithm.0.digest_algorithm().output_len();
+    let secret = hmac::Key::new(algorithm.0, secret);
+    verify_blocks(previously_derived, output_len, |idx, out| {
+        derive_block(&secret, iterations, salt, idx, out)
+    })
+}
 
+// Derives every block of the output with `derive_block`, even after a
+// mismatch has been found, and compares all of them in constant time.
+fn verify_blocks(
+    previously_derived: &[u8],
+    output_len: usize,
+    mut derive_block: impl FnMut(u32, &mut [u8]),
+) -> Result<(), error::Unspecified> {
     if previously_derived.is_empty() {
         return Err(error::Unspecified);
     }
 
-    let mut derived_buf = [0u8; digest::MAX_OUTPUT_LEN];
+    // The bitwise OR of the differences between each derived block and
+    // the corresponding block of `previously_derived`.
+    let mut differences = [0u8; digest::MAX_OUTPUT_LEN];
 
-    let output_len = digest_alg.output_len();
-    let secret = hmac::Key::new(algorithm.0, secret);
     let mut idx: u32 = 0;
-
-    let mut matches = 1;
-
     for previously_derived_chunk in previously_derived.chunks(output_len) {
         idx = idx.checked_add(1).expect("derived key too long");
 
+        let mut derived_buf = [0u8; digest::MAX_OUTPUT_LEN];
         let derived_chunk = &mut derived_buf[..previously_derived_chunk.len()];
-        derived_chunk.fill(0);
+        derive_block(idx, derived_chunk);
 
-        derive_block(&secret, iterations, salt, idx, derived_chunk);
+        constant_time::xor_assign_at_start(&mut derived_chunk[..], previously_derived_chunk);
+        differences
+            .iter_mut()
+            .zip(derived_chunk.iter())
+            .for_each(|(d, x)| *d |= *x);
+    }
 
-        // XXX: This isn't fully constant-time-safe. TODO: Fix that.
-        #[allow(clippy::bool_to_int_with_if)]
-        let current_block_matches =
-            if constant_time::verify_slices_are_equal(derived_chunk, previously_derived_chunk)
-                .is_ok()
-            {
-                1
-            } else {
-                0
-            };
+    constant_time::verify_slices_are_equal(&differences, &[0u8; digest::MAX_OUTPUT_LEN])
+}
 
-        matches &= current_block_matches;
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    if matches == 0 {
-        return Err(error::Unspecified);
+    // Returns the result of `verify_blocks`, and how many blocks it derived.
+    fn verify_counting_blocks(
+        previously_derived: &[u8],
+        output_len: usize,
+    ) -> (Result<(), error::Unspecified>, u32) {
+        let mut blocks_derived = 0;
+        let result = verify_blocks(previously_derived, output_len, |idx, out| {
+            blocks_derived += 1;
+            out.fill(u8::try_from(idx).unwrap());
+        });
+        (result, blocks_derived)
     }
 
-    Ok(())
+    #[test]
+    fn test_verify_blocks_derives_every_block() {
+        const OUTPUT_LEN: usize = 4;
+        let expected: [u8; 3 * OUTPUT_LEN - 1] = [1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3];
+        assert_eq!(verify_counting_blocks(&expected, OUTPUT_LEN), (Ok(()), 3));
+
+        // A mismatch in any block, including the first, fails only after
+        // every block has been derived.
+        for i in 0..expected.len() {
+            let mut wrong = expected;
+            wrong[i] ^= 0x80;
+            assert_eq!(
+                verify_counting_blocks(&wrong, OUTPUT_LEN),
+                (Err(error::Unspecified), 3)
+            );
+        }
+
+        assert_eq!(
+            verify_counting_blocks(&[], OUTPUT_LEN),
+            (Err(error::Unspecified), 0)
+        );
+    }
 }
@@ -0,0 +1,104 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Decoding of PEM-encoded keys.
+//!
+//! PEM is the textual encoding described in [RFC 7468]: a DER-encoded value
+//! encoded in base64 between `-----BEGIN <label>-----` and
+//! `-----END <label>-----` lines. The key types' `from_pem()` constructors
+//! use this module to accept PEM input directly.
+//!
+//! [RFC 7468]: https://tools.ietf.org/html/rfc7468
+
+use crate::{constant_time, error};
+use alloc::{vec, vec::Vec};
+
+/// The label of a PEM document, which identifies the type of its contents.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Label {
+    /// `PRIVATE KEY`: An unencrypted PKCS#8 private key.
+    PrivateKey,
+
+    /// `RSA PRIVATE KEY`: An RSA private key encoded as an `RSAPrivateKey`,
+    /// not wrapped in PKCS#8.
+    RsaPrivateKey,
+}
+
+impl Label {
+    fn from_str(label: &str) -> Option<Self> {
+        match label {
+            "PRIVATE KEY" => Some(Self::PrivateKey),
+            "RSA PRIVATE KEY" => Some(Self::RsaPrivateKey),
+            _ => None,
+        }
+    }
+}
+
+/// Decodes the first PEM document in `input`.
+///
+/// Any text before the `-----BEGIN` line and after the matching `-----END`
+/// line is ignored, as is whitespace at the start and end of each line.
+/// Fails if the label isn't one of the labels of `Label`, if the labels of
+/// the `-----BEGIN` and `-----END` lines differ, or if the contents aren't
+/// valid base64. Encrypted PEM documents, which have headers, aren't
+/// supported.
+///
+/// The contents are decoded with `constant_time::decode_base64()`, so this is
+/// suitable for decoding private keys.
+pub fn parse(input: &str) -> Result<(Label, Vec<u8>), error::Unspecified> {
+    const BEGIN: &str = "-----BEGIN ";
+    const END: &str = "-----END ";
+    const DASHES: &str = "-----";
+
+    let mut lines = input.lines().map(str::trim);
+    let label = lines
+        .find_map(|line| line.strip_prefix(BEGIN)?.strip_suffix(DASHES))
+        .ok_or(error::Unspecified)?;
+
+    let mut encoded = Vec::with_capacity(input.len());
+    let result = (|| {
+        loop {
+            let line = lines.next().ok_or(error::Unspecified)?;
+            if let Some(end) = line.strip_prefix(END) {
+                if end.strip_suffix(DASHES) != Some(label) {
+                    return Err(error::Unspecified);
+                }
+                break;
+            }
+            encoded.extend_from_slice(line.as_bytes());
+        }
+        let label = Label::from_str(label).ok_or(error::Unspecified)?;
+
+        let mut decoded = vec![0; encoded.len() / 4 * 3];
+        let len = constant_time::decode_base64(&encoded, &mut decoded)?;
+        decoded.truncate(len);
+        Ok((label, decoded))
+    })();
+    constant_time::secure_zero(&mut encoded);
+    result
+}
+
+/// Decodes `input` with `parse()` and then constructs a key from the
+/// decoded value with `f`, erasing the decoded value afterwards.
+pub(crate) fn decode_key<T>(
+    input: &str,
+    f: impl FnOnce(Label, &[u8]) -> Result<T, error::KeyRejected>,
+) -> Result<T, error::KeyRejected> {
+    let (label, mut der) =
+        parse(input).map_err(|error::Unspecified| error::KeyRejected::invalid_encoding())?;
+    let result = f(label, &der);
+    constant_time::secure_zero(&mut der);
+    result
+}
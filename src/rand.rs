@@ -17,8 +17,21 @@
 //! *ring* functions that generate random bytes take a `&dyn SecureRandom`
 //! parameter to make it clear which functions are non-deterministic.
 
+#[cfg(feature = "std")]
+extern crate std;
+
 use crate::error;
 
+pub use self::hmac_drbg::HmacDrbg;
+
+#[cfg(feature = "alloc")]
+pub use self::prime::random_prime;
+
+mod hmac_drbg;
+
+#[cfg(feature = "alloc")]
+mod prime;
+
 /// A secure random number generator.
 pub trait SecureRandom: sealed::SecureRandom {
     /// Fills `dest` with random bytes.
@@ -163,3 +176,138 @@ impl sealed::SecureRandom for SystemRandom {
         getrandom::getrandom(dest).map_err(|_| error::Unspecified)
     }
 }
+
+/// A secure random number generator that gets its random bytes from a
+/// caller-provided function, e.g. one that reads a hardware TRNG.
+///
+/// *ring* can't check the quality of the bytes; `f` must be a secure source
+/// of randomness. If `f` returns `Err(())` then `fill()` fails.
+pub struct CallbackRandom<F>(F)
+where
+    F: Fn(&mut [u8]) -> Result<(), ()>;
+
+impl<F> CallbackRandom<F>
+where
+    F: Fn(&mut [u8]) -> Result<(), ()>,
+{
+    /// Constructs a new `CallbackRandom` that fills buffers using `f`.
+    #[inline]
+    pub fn new(f: F) -> Self {
+        Self(f)
+    }
+}
+
+impl<F> core::fmt::Debug for CallbackRandom<F>
+where
+    F: Fn(&mut [u8]) -> Result<(), ()>,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("CallbackRandom").finish()
+    }
+}
+
+impl<F> sealed::SecureRandom for CallbackRandom<F>
+where
+    F: Fn(&mut [u8]) -> Result<(), ()>,
+{
+    #[inline]
+    fn fill_impl(&self, dest: &mut [u8]) -> Result<(), error::Unspecified> {
+        (self.0)(dest).map_err(|()| error::Unspecified)
+    }
+}
+
+/// Why `health_check()` failed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum HealthError {
+    /// The random number generator's `fill()` failed.
+    FillFailed,
+
+    /// The repetition count test failed: a byte value was repeated too many
+    /// times in a row.
+    RepetitionCount,
+
+    /// The adaptive proportion test failed: a byte value occurred too often
+    /// within a window.
+    AdaptiveProportion,
+}
+
+impl core::fmt::Display for HealthError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::FillFailed => "FillFailed",
+            Self::RepetitionCount => "RepetitionCount",
+            Self::AdaptiveProportion => "AdaptiveProportion",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for HealthError {}
+
+impl From<HealthError> for error::Unspecified {
+    fn from(_: HealthError) -> Self {
+        Self
+    }
+}
+
+/// The number of bytes that `health_check()` samples.
+pub const HEALTH_CHECK_SAMPLE_LEN: usize = 1024;
+
+/// Runs the start-up health tests of [NIST SP 800-90B Section 4.4] over
+/// `HEALTH_CHECK_SAMPLE_LEN` bytes from `rng`, treating each byte as a
+/// sample.
+///
+/// This detects grossly broken sources, such as one that is stuck on a
+/// constant value, e.g. as a startup diagnostic on embedded systems. Passing
+/// it doesn't mean that `rng` is secure; the output of a deterministic
+/// generator seeded with a constant passes too.
+///
+/// The cutoffs assume a (conservative) min-entropy of 4 bits per byte and a
+/// false positive probability of 2^-20 per sample, so a working generator
+/// essentially never fails. The repetition count test fails if a byte value
+/// is repeated 6 times in a row, and the adaptive proportion test fails if
+/// the first byte of a 512-byte window occurs 62 or more times in it.
+///
+/// [NIST SP 800-90B Section 4.4]: https://doi.org/10.6028/NIST.SP.800-90B
+pub fn health_check(rng: &dyn SecureRandom) -> Result<(), HealthError> {
+    let mut sample = [0u8; HEALTH_CHECK_SAMPLE_LEN];
+    rng.fill(&mut sample)
+        .map_err(|error::Unspecified| HealthError::FillFailed)?;
+    repetition_count_test(&sample)?;
+    adaptive_proportion_test(&sample)
+}
+
+/// SP 800-90B Section 4.4.1: `C = 1 + ceil(20 / H)` for H = 4.
+const REPETITION_COUNT_CUTOFF: usize = 6;
+
+/// SP 800-90B Section 4.4.2, for non-binary samples.
+const ADAPTIVE_PROPORTION_WINDOW: usize = 512;
+
+/// SP 800-90B Section 4.4.2: `C = 1 + CRITBINOM(W, 2^-H, 1 - 2^-20)` for
+/// W = 512 and H = 4.
+const ADAPTIVE_PROPORTION_CUTOFF: usize = 62;
+
+fn repetition_count_test(sample: &[u8]) -> Result<(), HealthError> {
+    let mut run = 0;
+    let mut previous = None;
+    for &b in sample {
+        run = if previous == Some(b) { run + 1 } else { 1 };
+        previous = Some(b);
+        if run >= REPETITION_COUNT_CUTOFF {
+            return Err(HealthError::RepetitionCount);
+        }
+    }
+    Ok(())
+}
+
+fn adaptive_proportion_test(sample: &[u8]) -> Result<(), HealthError> {
+    for window in sample.chunks_exact(ADAPTIVE_PROPORTION_WINDOW) {
+        let first = window[0];
+        let count = window.iter().filter(|&&b| b == first).count();
+        if count >= ADAPTIVE_PROPORTION_CUTOFF {
+            return Err(HealthError::AdaptiveProportion);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,174 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+use crate::{digest, error, hmac};
+
+/// HMAC_DRBG, the deterministic random bit generator specified in
+/// [NIST SP 800-90A Section 10.1.2].
+///
+/// An `HmacDrbg` expands a seed from an entropy source into an arbitrary
+/// amount of output. It isn't a `SecureRandom` because generating output
+/// updates its state, which requires `&mut self`.
+///
+/// `fill_with_additional()` mixes per-call additional input, e.g. a request
+/// ID or fresh bytes from another source, into the state before and after
+/// generating the output. Additional input that is unpredictable to an
+/// attacker keeps the output unpredictable even if the state was
+/// compromised before the call.
+///
+/// Prediction resistance isn't implemented; call `reseed()` with fresh
+/// entropy for that.
+///
+/// [NIST SP 800-90A Section 10.1.2]: https://doi.org/10.6028/NIST.SP.800-90Ar1
+pub struct HmacDrbg {
+    key: hmac::Key,
+    v: [u8; digest::MAX_OUTPUT_LEN],
+    reseed_counter: u64,
+}
+
+impl HmacDrbg {
+    /// The maximum number of bytes that one call to `fill_with_additional()`
+    /// can produce: 2^19 bits, per SP 800-90A Table 2.
+    pub const MAX_REQUEST_LEN: usize = (1 << 19) / 8;
+
+    /// Instantiates an `HmacDrbg` with the given seed material.
+    ///
+    /// `entropy_input` must come from a secure entropy source, and `nonce`
+    /// must not repeat across instantiations with the same entropy input;
+    /// SP 800-90A Section 8.6.7 allows e.g. a timestamp or a counter.
+    /// `personalization_string` is optional and may be empty.
+    ///
+    /// Fails if `entropy_input` is shorter than the security strength of
+    /// `algorithm`, taken here to be its output length, up to 32 bytes.
+    pub fn new(
+        algorithm: hmac::Algorithm,
+        entropy_input: &[u8],
+        nonce: &[u8],
+        personalization_string: &[u8],
+    ) -> Result<Self, error::Unspecified> {
+        let output_len = algorithm.digest_algorithm().output_len();
+        if entropy_input.len() < core::cmp::min(output_len, MAX_SECURITY_STRENGTH_LEN) {
+            return Err(error::Unspecified);
+        }
+
+        let mut drbg = Self {
+            key: hmac::Key::new(algorithm, &[0; digest::MAX_OUTPUT_LEN][..output_len]),
+            v: [1; digest::MAX_OUTPUT_LEN],
+            reseed_counter: 1,
+        };
+        drbg.update(&[entropy_input, nonce, personalization_string]);
+        Ok(drbg)
+    }
+
+    /// Fills `out` with output.
+    ///
+    /// Equivalent to `fill_with_additional(out, &[])`.
+    pub fn fill(&mut self, out: &mut [u8]) -> Result<(), error::Unspecified> {
+        self.fill_with_additional(out, &[])
+    }
+
+    /// Fills `out` with output, mixing `additional_input` into the state
+    /// before and after generating it.
+    ///
+    /// Fails if `out` is longer than `Self::MAX_REQUEST_LEN`, or if the generator
+    /// has produced output 2^48 times since it was last seeded, in which case
+    /// it must be reseeded with `reseed()`.
+    pub fn fill_with_additional(
+        &mut self,
+        out: &mut [u8],
+        additional_input: &[u8],
+    ) -> Result<(), error::Unspecified> {
+        if out.len() > Self::MAX_REQUEST_LEN || self.reseed_counter > RESEED_INTERVAL {
+            return Err(error::Unspecified);
+        }
+
+        if !additional_input.is_empty() {
+            self.update(&[additional_input]);
+        }
+
+        let output_len = self.output_len();
+        for chunk in out.chunks_mut(output_len) {
+            let v = &mut self.v[..output_len];
+            v.copy_from_slice(hmac::sign(&self.key, v).as_ref());
+            chunk.copy_from_slice(&v[..chunk.len()]);
+        }
+
+        self.update(&[additional_input]);
+        self.reseed_counter += 1;
+        Ok(())
+    }
+
+    /// Reseeds the generator with fresh `entropy_input` and optional
+    /// `additional_input`.
+    ///
+    /// Fails if `entropy_input` is too short, like `new()`.
+    pub fn reseed(
+        &mut self,
+        entropy_input: &[u8],
+        additional_input: &[u8],
+    ) -> Result<(), error::Unspecified> {
+        if entropy_input.len() < core::cmp::min(self.output_len(), MAX_SECURITY_STRENGTH_LEN) {
+            return Err(error::Unspecified);
+        }
+        self.update(&[entropy_input, additional_input]);
+        self.reseed_counter = 1;
+        Ok(())
+    }
+
+    /// The HMAC algorithm of the generator.
+    #[inline]
+    pub fn algorithm(&self) -> hmac::Algorithm {
+        self.key.algorithm()
+    }
+
+    fn output_len(&self) -> usize {
+        self.algorithm().digest_algorithm().output_len()
+    }
+
+    /// The HMAC_DRBG_Update function of SP 800-90A Section 10.1.2.2, where
+    /// the provided data is the concatenation of the slices of
+    /// `provided_data`.
+    fn update(&mut self, provided_data: &[&[u8]]) {
+        let algorithm = self.algorithm();
+        let output_len = self.output_len();
+        let has_provided_data = provided_data.iter().any(|data| !data.is_empty());
+
+        for separator in [0u8, 1] {
+            if separator == 1 && !has_provided_data {
+                break;
+            }
+            let v = &mut self.v[..output_len];
+            let mut ctx = hmac::Context::with_key(&self.key);
+            ctx.update(v);
+            ctx.update(&[separator]);
+            provided_data.iter().for_each(|data| ctx.update(data));
+            self.key = hmac::Key::new(algorithm, ctx.sign().as_ref());
+            v.copy_from_slice(hmac::sign(&self.key, v).as_ref());
+        }
+    }
+}
+
+impl core::fmt::Debug for HmacDrbg {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("HmacDrbg")
+            .field("algorithm", self.algorithm().digest_algorithm())
+            .finish()
+    }
+}
+
+/// SP 800-90A Table 2.
+const RESEED_INTERVAL: u64 = 1 << 48;
+
+/// The highest security strength of HMAC_DRBG, 256 bits.
+const MAX_SECURITY_STRENGTH_LEN: usize = 256 / 8;
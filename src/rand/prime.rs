@@ -0,0 +1,295 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Random probable primes.
+
+use super::SecureRandom;
+use crate::{
+    arithmetic::{bigint, montgomery::R},
+    bits::BitLength,
+    cpu, error,
+    polyfill::usize_from_u32,
+};
+use alloc::{vec, vec::Vec};
+
+/// Generates a random probable prime that is exactly `bits` bits long.
+///
+/// The prime is returned as `ceil(bits / 8)` big-endian bytes. Only its
+/// highest bit and lowest bit are fixed; the rest are chosen uniformly at
+/// random from `rng` until the candidate passes trial division by small
+/// primes and then 64 rounds of the Miller-Rabin test with random bases, so
+/// the probability that the result is composite is at most 2**-128.
+///
+/// `bits` must be at least 256 and at most 8192. This is slow for large
+/// lengths, as a uniformly random odd number of `bits` bits is prime with
+/// probability about 2.9 / `bits`.
+///
+/// The primality test isn't constant-time, so this is meant for public
+/// primes, e.g. Diffie-Hellman group parameters, not for secret ones like
+/// the factors of an RSA modulus.
+pub fn random_prime(bits: usize, rng: &dyn SecureRandom) -> Result<Vec<u8>, error::Unspecified> {
+    if !(MIN_BITS..=MAX_BITS).contains(&bits) {
+        return Err(error::Unspecified);
+    }
+    let len = BitLength::from_bits(bits).as_usize_bytes_rounded_up();
+    let top_bit = (bits - 1) % 8;
+
+    let mut candidate = vec![0u8; len];
+    loop {
+        rng.fill(&mut candidate)?;
+        candidate[0] &= 0xff >> (7 - top_bit);
+        candidate[0] |= 1 << top_bit;
+        candidate[len - 1] |= 1;
+
+        if has_small_factor(&candidate) {
+            continue;
+        }
+        if is_probably_prime(&candidate, MILLER_RABIN_ROUNDS, rng)? {
+            return Ok(candidate);
+        }
+    }
+}
+
+const MIN_BITS: usize = 256;
+const MAX_BITS: usize = bigint::MODULUS_MAX_LIMBS * crate::limb::LIMB_BITS;
+
+// Each round passes a composite with probability at most 1/4.
+const MILLER_RABIN_ROUNDS: usize = 64;
+
+/// The Miller-Rabin probabilistic primality test, with `rounds` random bases
+/// from `rng`, for the big-endian odd number `n` of at least 256 bits.
+///
+/// Returns `Ok(false)` if `n` is definitely composite and `Ok(true)` if it
+/// is probably prime. `n` is assumed to be public; the running time depends
+/// on it.
+pub(crate) fn is_probably_prime(
+    n: &[u8],
+    rounds: usize,
+    rng: &dyn SecureRandom,
+) -> Result<bool, error::Unspecified> {
+    let n_owned = bigint::OwnedModulus::<Candidate>::from_be_bytes(untrusted::Input::from(n))?;
+    let m = &n_owned.modulus(cpu::features());
+    let one_rr = bigint::One::newRR(m);
+    let one = bigint::Elem::from_be_bytes_padded(untrusted::Input::from(&[1]), m)?;
+    let is_plus_or_minus_one = |x: &bigint::Elem<Candidate, R>| {
+        let x = bigint::elem_mul(x, one.clone(), m);
+        x.is_one() || x.is_minus_one(m)
+    };
+
+    // n - 1 = 2**s * d, where d is odd. Since n is odd, n - 1 is `n` with
+    // the lowest bit cleared.
+    let mut n_minus_1 = n.to_vec();
+    if let Some(last) = n_minus_1.last_mut() {
+        *last &= !1;
+    }
+    let s = trailing_zeros(&n_minus_1);
+    let d = shr(&n_minus_1, s);
+
+    // Draw each base from the numbers with no more bits than `n`, rejecting
+    // those outside [2, n - 2].
+    let top_byte_mask = 0xff >> n[0].leading_zeros();
+    let mut a_bytes = vec![0u8; n.len()];
+    let mut tmp = m.zero();
+    for _ in 0..rounds {
+        let a = loop {
+            rng.fill(&mut a_bytes)?;
+            a_bytes[0] &= top_byte_mask;
+            if let Ok(a) = bigint::Elem::from_be_bytes_padded(untrusted::Input::from(&a_bytes), m) {
+                if !a.is_zero() && !a.is_one() && !a.is_minus_one(m) {
+                    break a;
+                }
+            }
+        };
+
+        // x = a**d (mod n).
+        let a = bigint::elem_mul(one_rr.as_ref(), a, m);
+        let mut x = elem_exp_vartime(&a, &d, m);
+        if is_plus_or_minus_one(&x) {
+            continue;
+        }
+
+        // Square x up to s - 1 times, looking for -1.
+        let mut witness = true;
+        for _ in 1..s {
+            bigint::elem_mul_into(&mut tmp, &x, &x, m);
+            core::mem::swap(&mut x, &mut tmp);
+            if is_plus_or_minus_one(&x) {
+                // If x is 1 then n is composite, as its square root was
+                // neither 1 nor -1.
+                witness = bigint::elem_mul(&x, one.clone(), m).is_one();
+                break;
+            }
+        }
+        if witness {
+            return Ok(false);
+        }
+    }
+
+    Ok(true)
+}
+
+// Returns `a**e` (mod m) for the nonzero big-endian exponent `e`.
+//
+// The running time depends on `e`. (`bigint::elem_exp_consttime()` only
+// supports moduli whose length is a multiple of 512 bits.)
+fn elem_exp_vartime(
+    a: &bigint::Elem<Candidate, R>,
+    e: &[u8],
+    m: &bigint::Modulus<Candidate>,
+) -> bigint::Elem<Candidate, R> {
+    let mut bits = e
+        .iter()
+        .flat_map(|&b| (0..8).rev().map(move |i| (b >> i) & 1 == 1))
+        .skip_while(|&bit| !bit);
+    let _ = bits.next(); // `acc` starts as `a`, for the highest set bit.
+
+    let mut acc = a.clone();
+    let mut tmp = m.zero();
+    for bit in bits {
+        bigint::elem_mul_into(&mut tmp, &acc, &acc, m);
+        if bit {
+            bigint::elem_mul_into(&mut acc, a, &tmp, m);
+        } else {
+            core::mem::swap(&mut acc, &mut tmp);
+        }
+    }
+    acc
+}
+
+// Type-level representation of a prime candidate. See `bigint`'s
+// module-level documentation.
+enum Candidate {}
+
+// The odd primes less than 256.
+const SMALL_PRIMES: [u8; 53] = [
+    3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+    101, 103, 107, 109, 113, 127, 131, 137, 139, 149, 151, 157, 163, 167, 173, 179, 181, 191, 193,
+    197, 199, 211, 223, 227, 229, 233, 239, 241, 251,
+];
+
+// Whether the big-endian number `n`, which is larger than 256, is divisible
+// by any of `SMALL_PRIMES`.
+fn has_small_factor(n: &[u8]) -> bool {
+    SMALL_PRIMES.iter().any(|&p| {
+        let p = u32::from(p);
+        n.iter().fold(0, |r, &b| ((r << 8) | u32::from(b)) % p) == 0
+    })
+}
+
+// The number of trailing zero bits of the big-endian number `n`.
+fn trailing_zeros(n: &[u8]) -> usize {
+    let zero_bytes = n.iter().rev().take_while(|&&b| b == 0).count();
+    let bits = n
+        .iter()
+        .rev()
+        .nth(zero_bytes)
+        .map_or(0, |b| usize_from_u32(b.trailing_zeros()));
+    (8 * zero_bytes) + bits
+}
+
+// The big-endian number `n` shifted right by `shift` bits, with the same
+// length as `n`.
+fn shr(n: &[u8], shift: usize) -> Vec<u8> {
+    let (bytes, bits) = (shift / 8, shift % 8);
+    let mut r = vec![0u8; n.len()];
+    for i in bytes..n.len() {
+        let lo = n[i - bytes] >> bits;
+        let hi = if bits != 0 && i > bytes {
+            n[i - bytes - 1] << (8 - bits)
+        } else {
+            0
+        };
+        r[i] = lo | hi;
+    }
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{rand, test};
+
+    #[test]
+    fn test_is_probably_prime() {
+        let rng = rand::SystemRandom::new();
+        let check = |hex: &str, expected: bool| {
+            let n = test::from_hex(hex).unwrap();
+            assert_eq!(
+                is_probably_prime(&n, MILLER_RABIN_ROUNDS, &rng),
+                Ok(expected),
+                "{}",
+                hex
+            );
+        };
+
+        // 2**255 - 19.
+        check(
+            "7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed",
+            true,
+        );
+        // The P-256 field prime.
+        check(
+            "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+            true,
+        );
+        // 2**521 - 1.
+        check(
+            "01ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff\
+             ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff\
+             ffff",
+            true,
+        );
+        // A prime p where p - 1 is divisible by 2**64.
+        check(
+            "c8ac56392467375ef880d97d1854e5517f9a82a1ed19f88733bebf7450fc591f\
+             0000000000000001",
+            true,
+        );
+
+        // 2**255 - 17.
+        check(
+            "7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffef",
+            false,
+        );
+        // The product of two 160-bit primes.
+        check(
+            "7b400fba03d5913a69937927ebd6b502395a583bc58ff4f0bd28879f29938999\
+             5cb51b180b767993",
+            false,
+        );
+        // The product of two primes p and q where p - 1 and q - 1 are
+        // divisible by 2**64.
+        check(
+            "ab995403ebe71963e9c6a4841572e8cc985fe143a8e2927f2fd029a72bddc385\
+             0000000000000001",
+            false,
+        );
+        // A Carmichael number (6k + 1)(12k + 1)(18k + 1), which passes the
+        // Fermat test to every base coprime to it.
+        check(
+            "047a2481998ec7b11b7c463c6fd42e5d8636114b90563bb9d7534b73d0beafdb\
+             421dd537d10d39",
+            false,
+        );
+    }
+
+    #[test]
+    fn test_shr_and_trailing_zeros() {
+        let n = [0x12, 0x34, 0x80, 0x00];
+        assert_eq!(trailing_zeros(&n), 15);
+        assert_eq!(shr(&n, 15), [0x00, 0x00, 0x24, 0x69]);
+        assert_eq!(shr(&n, 8), [0x00, 0x12, 0x34, 0x80]);
+        assert_eq!(shr(&n, 0), n);
+    }
+}
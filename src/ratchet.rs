@@ -0,0 +1,77 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! The symmetric-key steps of the [Double Ratchet] algorithm used by the
+//! Signal protocol.
+//!
+//! These are the `KDF_RK` and `KDF_CK` functions of [Section 5.2] of the
+//! specification, using SHA-256 as recommended there.
+//!
+//! [Double Ratchet]: https://signal.org/docs/specifications/doubleratchet/
+//! [Section 5.2]: https://signal.org/docs/specifications/doubleratchet/#recommended-cryptographic-algorithms
+
+use crate::{hkdf, hmac};
+
+/// The length of root keys, chain keys, and message keys.
+pub const KEY_LEN: usize = 32;
+
+/// `KDF_RK(rk, dh_out)`: The root key ratchet step.
+///
+/// Returns `(root_key, chain_key)`, the first and second halves of the
+/// 64-byte output of HKDF-SHA256 with `root_key` as the salt, `dh_out` as the
+/// input keying material, and `info` as the info. The specification leaves
+/// `info` up to the application; e.g. libsignal uses `b"WhisperRatchet"`.
+pub fn kdf_rk(
+    root_key: &[u8; KEY_LEN],
+    dh_out: &[u8],
+    info: &[u8],
+) -> ([u8; KEY_LEN], [u8; KEY_LEN]) {
+    let prk = hkdf::Salt::new(hkdf::HKDF_SHA256, root_key).extract(dh_out);
+    let info = [info];
+    let okm = prk.expand(&info, RootAndChainKeyLen).unwrap();
+    let mut out = [0; 2 * KEY_LEN];
+    okm.fill(&mut out).unwrap();
+
+    let mut root_key = [0; KEY_LEN];
+    let mut chain_key = [0; KEY_LEN];
+    root_key.copy_from_slice(&out[..KEY_LEN]);
+    chain_key.copy_from_slice(&out[KEY_LEN..]);
+    (root_key, chain_key)
+}
+
+/// `KDF_CK(ck)`: The chain key ratchet step.
+///
+/// Returns `(chain_key, message_key)`, where the next chain key is
+/// HMAC-SHA256(`chain_key`, 0x02) and the message key is
+/// HMAC-SHA256(`chain_key`, 0x01).
+pub fn kdf_ck(chain_key: &[u8; KEY_LEN]) -> ([u8; KEY_LEN], [u8; KEY_LEN]) {
+    let key = hmac::Key::new(hmac::HMAC_SHA256, chain_key);
+    let derive = |constant: u8| {
+        let mut out = [0; KEY_LEN];
+        out.copy_from_slice(hmac::sign(&key, &[constant]).as_ref());
+        out
+    };
+    (derive(CHAIN_KEY_CONSTANT), derive(MESSAGE_KEY_CONSTANT))
+}
+
+const MESSAGE_KEY_CONSTANT: u8 = 0x01;
+const CHAIN_KEY_CONSTANT: u8 = 0x02;
+
+struct RootAndChainKeyLen;
+
+impl hkdf::KeyType for RootAndChainKeyLen {
+    fn len(&self) -> usize {
+        2 * KEY_LEN
+    }
+}
@@ -26,7 +26,7 @@ use crate::{
     cpu, digest,
     error::{self, KeyRejected},
     io::der,
-    pkcs8, rand, signature,
+    pem, pkcs8, rand, signature,
 };
 
 /// An RSA key pair, used for signing.
@@ -103,6 +103,17 @@ impl KeyPair {
         Self::from_der(der.as_slice_less_safe())
     }
 
+    /// Parses a PEM-encoded RSA private key.
+    ///
+    /// A key with the label `PRIVATE KEY` is parsed like `from_pkcs8()`, and
+    /// a key with the label `RSA PRIVATE KEY` is parsed like `from_der()`.
+    pub fn from_pem(pem: &str) -> Result<Self, KeyRejected> {
+        pem::decode_key(pem, |label, der| match label {
+            pem::Label::PrivateKey => Self::from_pkcs8(der),
+            pem::Label::RsaPrivateKey => Self::from_der(der),
+        })
+    }
+
     /// Parses an RSA private key that is not inside a PKCS#8 wrapper.
     ///
     /// The private key must be encoded as a binary DER-encoded ASN.1
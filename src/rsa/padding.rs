@@ -17,11 +17,15 @@ use crate::{bits, constant_time, digest, error, rand};
 mod pkcs1;
 mod pss;
 
+pub(super) use self::pss::PSS;
+
+#[cfg(not(feature = "fips"))]
+pub(super) use self::pkcs1::RSA_PKCS1_SHA1_FOR_LEGACY_USE_ONLY;
+
 pub use self::{
     pkcs1::{RSA_PKCS1_SHA256, RSA_PKCS1_SHA384, RSA_PKCS1_SHA512},
     pss::{RSA_PSS_SHA256, RSA_PSS_SHA384, RSA_PSS_SHA512},
 };
-pub(super) use pkcs1::RSA_PKCS1_SHA1_FOR_LEGACY_USE_ONLY;
 
 /// Common features of both RSA padding encoding and RSA padding verification.
 pub trait Padding: 'static + Sync + crate::sealed::Sealed + core::fmt::Debug {
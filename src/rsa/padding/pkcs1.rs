@@ -107,6 +107,7 @@ macro_rules! rsa_pkcs1_padding {
 // minimum, we'd need to create test vectors for signing with it, which we
 // don't currently have. But, it's a bad idea to use SHA-1 anyway, so perhaps
 // we just won't ever expose it.
+#[cfg(not(feature = "fips"))]
 rsa_pkcs1_padding!(
     pub(in super::super) RSA_PKCS1_SHA1_FOR_LEGACY_USE_ONLY,
     &digest::SHA1_FOR_LEGACY_USE_ONLY,
@@ -148,6 +149,7 @@ macro_rules! pkcs1_digestinfo_prefix {
     }
 }
 
+#[cfg(not(feature = "fips"))]
 pkcs1_digestinfo_prefix!(
     SHA1_PKCS1_DIGESTINFO_PREFIX,
     20,
@@ -77,7 +77,7 @@ impl RsaEncoding for PSS {
         };
 
         // Steps 5 and 6.
-        let h = pss_digest(self.digest_alg, m_hash, salt);
+        let h = pss_digest(self.digest_alg, m_hash.as_ref(), salt);
 
         // Step 7.
         db[..separator_pos].fill(0); // ps
@@ -124,70 +124,102 @@ impl Verification for PSS {
                 return Err(error::Unspecified);
             }
         };
-        let em = m;
-
-        // The rest of this function is EMSA-PSS-VERIFY from
-        // https://tools.ietf.org/html/rfc3447#section-9.1.2.
-
-        // Steps 1 and 2 are done by the caller to produce `m_hash`.
-
-        // Step 3 is done by `PSSMetrics::new()` above.
-
-        // Step 5, out of order.
-        let masked_db = em.read_bytes(metrics.db_len)?;
-        let h_hash = em.read_bytes(metrics.h_len)?;
+        emsa_pss_verify(self.digest_alg, m_hash.as_ref(), m, &metrics)
+    }
+}
 
-        // Step 4.
-        if em.read_byte()? != 0xbc {
+impl PSS {
+    // EMSA-PSS-VERIFY on its own; see `crate::rsa::pss::verify_em()`.
+    pub(in crate::rsa) fn verify_em(
+        &self,
+        em_bits: bits::BitLength,
+        em: untrusted::Input,
+        m_hash: &[u8],
+    ) -> Result<(), error::Unspecified> {
+        if m_hash.len() != self.digest_alg.output_len()
+            || em.len() > PUBLIC_KEY_PUBLIC_MODULUS_MAX_LEN
+        {
+            return Err(error::Unspecified);
+        }
+        // `em` must be exactly `ceil(em_bits / 8)` bytes long. Checking the
+        // upper bound first keeps `PSSMetrics::from_em_bits()` from
+        // overflowing for huge `em_bits`.
+        if em_bits.as_bits() > 8 * em.len() || em_bits.as_usize_bytes_rounded_up() != em.len() {
             return Err(error::Unspecified);
         }
+        let metrics = PSSMetrics::from_em_bits(self.digest_alg, em_bits)?;
+        em.read_all(error::Unspecified, |em| {
+            emsa_pss_verify(self.digest_alg, m_hash, em, &metrics)
+        })
+    }
+}
 
-        // Step 7.
-        let mut db = [0u8; PUBLIC_KEY_PUBLIC_MODULUS_MAX_LEN];
-        let db = &mut db[..metrics.db_len];
+// EMSA-PSS-VERIFY from https://tools.ietf.org/html/rfc3447#section-9.1.2,
+// for an `em` of exactly `metrics.em_len` bytes.
+fn emsa_pss_verify(
+    digest_alg: &'static digest::Algorithm,
+    m_hash: &[u8],
+    em: &mut untrusted::Reader,
+    metrics: &PSSMetrics,
+) -> Result<(), error::Unspecified> {
+    // Steps 1 and 2 are done by the caller to produce `m_hash`.
 
-        mgf1(self.digest_alg, h_hash.as_slice_less_safe(), db);
+    // Step 3 is done by `PSSMetrics::new()`.
 
-        masked_db.read_all(error::Unspecified, |masked_bytes| {
-            // Step 6. Check the top bits of first byte are zero.
-            let b = masked_bytes.read_byte()?;
-            if b & !metrics.top_byte_mask != 0 {
-                return Err(error::Unspecified);
-            }
-            db[0] ^= b;
+    // Step 5, out of order.
+    let masked_db = em.read_bytes(metrics.db_len)?;
+    let h_hash = em.read_bytes(metrics.h_len)?;
 
-            // Step 8.
-            let db_rest = &mut db[1..];
-            let masked_bytes = masked_bytes.read_bytes(db_rest.len())?;
-            constant_time::xor_assign_at_start(db_rest, masked_bytes.as_slice_less_safe());
-            Ok(())
-        })?;
+    // Step 4.
+    if em.read_byte()? != 0xbc {
+        return Err(error::Unspecified);
+    }
 
-        // Step 9.
-        db[0] &= metrics.top_byte_mask;
+    // Step 7.
+    let mut db = [0u8; PUBLIC_KEY_PUBLIC_MODULUS_MAX_LEN];
+    let db = &mut db[..metrics.db_len];
 
-        // Step 10.
-        let ps_len = metrics.ps_len;
-        if db[0..ps_len].iter().any(|&db| db != 0) {
-            return Err(error::Unspecified);
-        }
-        if db[metrics.ps_len] != 1 {
+    mgf1(digest_alg, h_hash.as_slice_less_safe(), db);
+
+    masked_db.read_all(error::Unspecified, |masked_bytes| {
+        // Step 6. Check the top bits of first byte are zero.
+        let b = masked_bytes.read_byte()?;
+        if b & !metrics.top_byte_mask != 0 {
             return Err(error::Unspecified);
         }
+        db[0] ^= b;
 
-        // Step 11.
-        let salt = &db[(db.len() - metrics.s_len)..];
+        // Step 8.
+        let db_rest = &mut db[1..];
+        let masked_bytes = masked_bytes.read_bytes(db_rest.len())?;
+        constant_time::xor_assign_at_start(db_rest, masked_bytes.as_slice_less_safe());
+        Ok(())
+    })?;
 
-        // Step 12 and 13.
-        let h_prime = pss_digest(self.digest_alg, m_hash, salt);
+    // Step 9.
+    db[0] &= metrics.top_byte_mask;
 
-        // Step 14.
-        if h_hash.as_slice_less_safe() != h_prime.as_ref() {
-            return Err(error::Unspecified);
-        }
+    // Step 10.
+    let ps_len = metrics.ps_len;
+    if db[0..ps_len].iter().any(|&db| db != 0) {
+        return Err(error::Unspecified);
+    }
+    if db[metrics.ps_len] != 1 {
+        return Err(error::Unspecified);
+    }
 
-        Ok(())
+    // Step 11.
+    let salt = &db[(db.len() - metrics.s_len)..];
+
+    // Step 12 and 13.
+    let h_prime = pss_digest(digest_alg, m_hash, salt);
+
+    // Step 14.
+    if h_hash.as_slice_less_safe() != h_prime.as_ref() {
+        return Err(error::Unspecified);
     }
+
+    Ok(())
 }
 
 struct PSSMetrics {
@@ -205,7 +237,13 @@ impl PSSMetrics {
         digest_alg: &'static digest::Algorithm,
         mod_bits: bits::BitLength,
     ) -> Result<Self, error::Unspecified> {
-        let em_bits = mod_bits.try_sub_1()?;
+        Self::from_em_bits(digest_alg, mod_bits.try_sub_1()?)
+    }
+
+    fn from_em_bits(
+        digest_alg: &'static digest::Algorithm,
+        em_bits: bits::BitLength,
+    ) -> Result<Self, error::Unspecified> {
         let em_len = em_bits.as_usize_bytes_rounded_up();
         let leading_zero_bits = (8 * em_len) - em_bits.as_bits();
         debug_assert!(leading_zero_bits < 8);
@@ -241,7 +279,7 @@ impl PSSMetrics {
 
 fn pss_digest(
     digest_alg: &'static digest::Algorithm,
-    m_hash: digest::Digest,
+    m_hash: &[u8],
     salt: &[u8],
 ) -> digest::Digest {
     // Fixed prefix.
@@ -250,7 +288,7 @@ fn pss_digest(
     // Encoding step 5 and 6, Verification step 12 and 13.
     let mut ctx = digest::Context::new(digest_alg);
     ctx.update(&PREFIX_ZEROS);
-    ctx.update(m_hash.as_ref());
+    ctx.update(m_hash);
     ctx.update(salt);
     ctx.finish()
 }
@@ -0,0 +1,45 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Low-level RSA PSS operations.
+//!
+//! These are for testing and debugging interoperability at the level of the
+//! PSS encoding. To verify RSA PSS signatures, use `signature::RSA_PSS_*`.
+
+use super::padding::PSS;
+use crate::{bits, error};
+
+/// Checks that `em` is a valid PSS encoding of the message digest `m_hash`,
+/// per EMSA-PSS-VERIFY from [RFC 8017 Section 9.1.2].
+///
+/// `em` is the encoded message, i.e. the signature raised to the public
+/// exponent, as `ceil(em_bits / 8)` bytes. `em_bits` is usually one less
+/// than the length of the public modulus in bits. `m_hash` is the digest of
+/// the message using `padding_alg`'s digest algorithm, and the salt must be
+/// as long as that digest, as for signature verification, e.g.
+/// `&signature::RSA_PSS_SHA256`.
+///
+/// [RFC 8017 Section 9.1.2]: https://www.rfc-editor.org/rfc/rfc8017#section-9.1.2
+pub fn verify_em(
+    padding_alg: &'static PSS,
+    em_bits: usize,
+    em: &[u8],
+    m_hash: &[u8],
+) -> Result<(), error::Unspecified> {
+    padding_alg.verify_em(
+        bits::BitLength::from_bits(em_bits),
+        untrusted::Input::from(em),
+        m_hash,
+    )
+}
@@ -14,7 +14,7 @@
 
 use super::{PublicExponent, PublicModulus, N, PUBLIC_KEY_PUBLIC_MODULUS_MAX_LEN};
 use crate::{
-    arithmetic::bigint,
+    arithmetic::{bigint, montgomery::R},
     bits, cpu, error,
     io::{self, der, der_writer},
     limb::LIMB_BYTES,
@@ -146,8 +146,34 @@ impl Inner {
         base: untrusted::Input,
         out_buffer: &'out mut [u8; PUBLIC_KEY_PUBLIC_MODULUS_MAX_LEN],
         cpu_features: cpu::Features,
+    ) -> Result<&'out [u8], error::Unspecified> {
+        let mut scratch = self.new_scratch(cpu_features);
+        self.exponentiate_with_scratch(base, out_buffer, &mut scratch, cpu_features)
+    }
+
+    /// Allocates the storage that `exponentiate_with_scratch` needs for this
+    /// key.
+    pub(super) fn new_scratch(&self, cpu_features: cpu::Features) -> Scratch {
+        let n = &self.n.value(cpu_features);
+        Scratch {
+            s: n.zero(),
+            base_r: n.zero(),
+            acc: n.zero(),
+            m: n.zero(),
+        }
+    }
+
+    /// Like `exponentiate`, but does all the arithmetic in `scratch`, which
+    /// must have been created by `self.new_scratch()`, instead of allocating.
+    pub(super) fn exponentiate_with_scratch<'out>(
+        &self,
+        base: untrusted::Input,
+        out_buffer: &'out mut [u8; PUBLIC_KEY_PUBLIC_MODULUS_MAX_LEN],
+        scratch: &mut Scratch,
+        cpu_features: cpu::Features,
     ) -> Result<&'out [u8], error::Unspecified> {
         let n = &self.n.value(cpu_features);
+        let Scratch { s, base_r, acc, m } = scratch;
 
         // The encoded value of the base must be the same length as the modulus,
         // in bytes.
@@ -158,13 +184,24 @@ impl Inner {
         // RFC 8017 Section 5.2.2: RSAVP1.
 
         // Step 1.
-        let s = bigint::Elem::from_be_bytes_padded(base, n)?;
+        s.set_from_be_bytes_padded(base, n)?;
         if s.is_zero() {
             return Err(error::Unspecified);
         }
 
-        // Step 2.
-        let m = self.exponentiate_elem(&s, cpu_features);
+        // 1 and n - 1 are their own e-th powers for every odd e, so they can
+        // never be valid signatures; reject them without exponentiating.
+        //
+        // `s` is not checked to be coprime to `n`, since finding an `s` that
+        // isn't is as hard as factoring `n`.
+        if s.is_one() || s.is_minus_one(n) {
+            return Err(error::Unspecified);
+        }
+
+        // Step 2. This is `exponentiate_elem`, but in `scratch`.
+        bigint::elem_mul_into(base_r, self.n.oneRR(), s, n);
+        bigint::elem_exp_vartime_into(acc, base_r, self.exponent_without_low_bit(), n);
+        bigint::elem_mul_into(m, s, acc, n);
 
         // Step 3.
         Ok(fill_be_bytes_n(m, self.n.len_bits(), out_buffer))
@@ -178,11 +215,6 @@ impl Inner {
         base: &bigint::Elem<N>,
         cpu_features: cpu::Features,
     ) -> bigint::Elem<N> {
-        // The exponent was already checked to be at least 3.
-        let exponent_without_low_bit = NonZeroU64::try_from(self.e.value().get() & !1).unwrap();
-        // The exponent was already checked to be odd.
-        debug_assert_ne!(exponent_without_low_bit, self.e.value());
-
         let n = &self.n.value(cpu_features);
 
         let base_r = bigint::elem_mul(self.n.oneRR(), base.clone(), n);
@@ -191,11 +223,28 @@ impl Inner {
         // 65537 (0b10000000000000001) or 3 (0b11), both of which have a Hamming
         // weight of 2. The maximum bit length and maximum Hamming weight of the
         // exponent is bounded by the value of `PublicExponent::MAX`.
-        let acc = bigint::elem_exp_vartime(base_r, exponent_without_low_bit, n);
+        let acc = bigint::elem_exp_vartime(base_r, self.exponent_without_low_bit(), n);
 
         // Now do the multiplication for the low bit and convert out of the Montgomery domain.
         bigint::elem_mul(base, acc, n)
     }
+
+    fn exponent_without_low_bit(&self) -> NonZeroU64 {
+        // The exponent was already checked to be at least 3.
+        let exponent_without_low_bit = NonZeroU64::try_from(self.e.value().get() & !1).unwrap();
+        // The exponent was already checked to be odd.
+        debug_assert_ne!(exponent_without_low_bit, self.e.value());
+        exponent_without_low_bit
+    }
+}
+
+/// Storage for the intermediate values of `Inner::exponentiate_with_scratch`,
+/// so that repeated exponentiations with the same key don't allocate.
+pub(super) struct Scratch {
+    s: bigint::Elem<N>,
+    base_r: bigint::Elem<N, R>,
+    acc: bigint::Elem<N, R>,
+    m: bigint::Elem<N>,
 }
 
 // XXX: Refactor `signature::KeyPair` to get rid of this.
@@ -210,11 +259,11 @@ impl AsRef<[u8]> for PublicKey {
 /// the modulus `n`.
 ///
 /// `n_bits` must be the bit length of the public modulus `n`.
-fn fill_be_bytes_n(
-    elem: bigint::Elem<N>,
+fn fill_be_bytes_n<'out>(
+    elem: &bigint::Elem<N>,
     n_bits: bits::BitLength,
-    out: &mut [u8; PUBLIC_KEY_PUBLIC_MODULUS_MAX_LEN],
-) -> &[u8] {
+    out: &'out mut [u8; PUBLIC_KEY_PUBLIC_MODULUS_MAX_LEN],
+) -> &'out [u8] {
     let n_bytes = n_bits.as_usize_bytes_rounded_up();
     let n_bytes_padded = ((n_bytes + (LIMB_BYTES - 1)) / LIMB_BYTES) * LIMB_BYTES;
     let out = &mut out[..n_bytes_padded];
@@ -41,6 +41,25 @@ impl signature::VerificationAlgorithm for RsaParameters {
             cpu::features(),
         )
     }
+
+    fn verify_digest(
+        &self,
+        public_key: untrusted::Input,
+        digest: &digest::Digest,
+        signature: untrusted::Input,
+    ) -> Result<(), error::Unspecified> {
+        let (n, e) = parse_public_key(public_key)?;
+        verify_rsa_digest_(
+            self,
+            (
+                n.big_endian_without_leading_zero_as_input(),
+                e.big_endian_without_leading_zero_as_input(),
+            ),
+            *digest,
+            signature,
+            cpu::features(),
+        )
+    }
 }
 
 impl sealed::Sealed for RsaParameters {}
@@ -58,6 +77,7 @@ macro_rules! rsa_params {
     };
 }
 
+#[cfg(not(feature = "fips"))]
 rsa_params!(
     RSA_PKCS1_1024_8192_SHA1_FOR_LEGACY_USE_ONLY,
     1024,
@@ -66,6 +86,7 @@ rsa_params!(
              PKCS#1.5 padding, and SHA-1.\n\nSee \"`RSA_PKCS1_*` Details\" in
              `ring::signature`'s module-level documentation for more details."
 );
+#[cfg(not(feature = "fips"))]
 rsa_params!(
     RSA_PKCS1_2048_8192_SHA1_FOR_LEGACY_USE_ONLY,
     2048,
@@ -74,6 +95,7 @@ rsa_params!(
              PKCS#1.5 padding, and SHA-1.\n\nSee \"`RSA_PKCS1_*` Details\" in
              `ring::signature`'s module-level documentation for more details."
 );
+#[cfg(not(feature = "fips"))]
 rsa_params!(
     RSA_PKCS1_1024_8192_SHA256_FOR_LEGACY_USE_ONLY,
     1024,
@@ -106,6 +128,7 @@ rsa_params!(
              PKCS#1.5 padding, and SHA-512.\n\nSee \"`RSA_PKCS1_*` Details\" in
              `ring::signature`'s module-level documentation for more details."
 );
+#[cfg(not(feature = "fips"))]
 rsa_params!(
     RSA_PKCS1_1024_8192_SHA512_FOR_LEGACY_USE_ONLY,
     1024,
@@ -200,6 +223,21 @@ pub(crate) fn verify_rsa_(
     signature: untrusted::Input,
     cpu_features: cpu::Features,
 ) -> Result<(), error::Unspecified> {
+    let m_hash = digest::digest(params.padding_alg.digest_alg(), msg.as_slice_less_safe());
+    verify_rsa_digest_(params, (n, e), m_hash, signature, cpu_features)
+}
+
+fn verify_rsa_digest_(
+    params: &RsaParameters,
+    (n, e): (untrusted::Input, untrusted::Input),
+    m_hash: digest::Digest,
+    signature: untrusted::Input,
+    cpu_features: cpu::Features,
+) -> Result<(), error::Unspecified> {
+    if m_hash.algorithm() != params.padding_alg.digest_alg() {
+        return Err(error::Unspecified);
+    }
+
     let max_bits: bits::BitLength =
         bits::BitLength::from_byte_len(PUBLIC_KEY_PUBLIC_MODULUS_MAX_LEN)?;
 
@@ -220,9 +258,98 @@ pub(crate) fn verify_rsa_(
     let mut decoded = [0u8; PUBLIC_KEY_PUBLIC_MODULUS_MAX_LEN];
     let decoded = key.exponentiate(signature, &mut decoded, cpu_features)?;
 
+    verify_padding(params, &key, m_hash, decoded)
+}
+
+fn verify_padding(
+    params: &RsaParameters,
+    key: &public_key::Inner,
+    m_hash: digest::Digest,
+    decoded: &[u8],
+) -> Result<(), error::Unspecified> {
     // Verify the padded message is correct.
-    let m_hash = digest::digest(params.padding_alg.digest_alg(), msg.as_slice_less_safe());
     untrusted::Input::from(decoded).read_all(error::Unspecified, |m| {
         params.padding_alg.verify(m_hash, m, key.n().len_bits())
     })
 }
+
+/// An RSA public key prepared for verifying many signatures.
+///
+/// `signature::UnparsedPublicKey::verify()` parses the public key and
+/// allocates temporary storage for the arithmetic on every call. An
+/// `RsaVerifier` parses the public key once and keeps the temporary storage
+/// between calls, so `verify()` doesn't allocate. The results are the same as
+/// `UnparsedPublicKey::verify()`'s.
+///
+/// `verify()` takes `&mut self` since it uses the storage; use one
+/// `RsaVerifier` per thread to verify signatures concurrently.
+pub struct RsaVerifier {
+    params: &'static RsaParameters,
+    key: public_key::Inner,
+    scratch: public_key::Scratch,
+}
+
+impl RsaVerifier {
+    /// Parses `public_key`, a DER-encoded `RSAPublicKey` as for
+    /// `signature::UnparsedPublicKey`, for verifying signatures using `params`.
+    pub fn new(
+        params: &'static RsaParameters,
+        public_key: &[u8],
+    ) -> Result<Self, error::KeyRejected> {
+        let cpu_features = cpu::features();
+
+        let (n, e) = parse_public_key(untrusted::Input::from(public_key))
+            .map_err(|error::Unspecified| error::KeyRejected::invalid_encoding())?;
+        let max_bits: bits::BitLength =
+            bits::BitLength::from_byte_len(PUBLIC_KEY_PUBLIC_MODULUS_MAX_LEN)
+                .map_err(|error::Unspecified| error::KeyRejected::unexpected_error())?;
+
+        // See `verify_rsa_digest_`.
+        let key = public_key::Inner::from_modulus_and_exponent(
+            n.big_endian_without_leading_zero_as_input(),
+            e.big_endian_without_leading_zero_as_input(),
+            params.min_bits,
+            max_bits,
+            PublicExponent::_3,
+            cpu_features,
+        )?;
+        let scratch = key.new_scratch(cpu_features);
+
+        Ok(Self {
+            params,
+            key,
+            scratch,
+        })
+    }
+
+    /// Verifies that `signature` is a valid signature of `message`.
+    pub fn verify(&mut self, message: &[u8], signature: &[u8]) -> Result<(), error::Unspecified> {
+        let cpu_features = cpu::features();
+        let m_hash = digest::digest(self.params.padding_alg.digest_alg(), message);
+
+        // RFC 8017 Section 5.2.2: RSAVP1.
+        let mut decoded = [0u8; PUBLIC_KEY_PUBLIC_MODULUS_MAX_LEN];
+        let decoded = self.key.exponentiate_with_scratch(
+            untrusted::Input::from(signature),
+            &mut decoded,
+            &mut self.scratch,
+            cpu_features,
+        )?;
+
+        verify_padding(self.params, &self.key, m_hash, decoded)
+    }
+
+    /// The parameters the signatures are verified with.
+    #[inline]
+    pub fn params(&self) -> &'static RsaParameters {
+        self.params
+    }
+}
+
+impl core::fmt::Debug for RsaVerifier {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("RsaVerifier")
+            .field("params", self.params)
+            .finish()
+    }
+}
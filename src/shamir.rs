@@ -0,0 +1,206 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Shamir's secret sharing over GF(2^8).
+//!
+//! `split` divides a secret, such as an AEAD key, into `n` shares so that
+//! any `k` of them (the threshold) reconstruct the secret with `combine`,
+//! while fewer than `k` reveal nothing about it. See [Shamir, "How to Share
+//! a Secret"].
+//!
+//! Each byte of the secret is shared independently: a random polynomial of
+//! degree `k - 1` over GF(2^8), with the AES reduction polynomial
+//! x^8 + x^4 + x^3 + x + 1, is chosen whose constant term is the secret
+//! byte. A share is the index `x` (1 to 255) followed by the value of every
+//! byte's polynomial at `x`, so it is one byte longer than the secret.
+//!
+//! Secret sharing provides no integrity protection. `combine` can't tell
+//! whether it was given enough shares, or whether a share was tampered with;
+//! in either case it returns the wrong secret. Use the reconstructed secret
+//! in a way that detects that, e.g. as an AEAD key.
+//!
+//! [Shamir, "How to Share a Secret"]: https://dl.acm.org/doi/10.1145/359168.359176
+
+use crate::{error, rand};
+use alloc::{vec, vec::Vec};
+
+/// The largest number of shares that `split` can produce.
+pub const MAX_SHARES: usize = 255;
+
+/// A share of a secret.
+#[derive(Clone, Eq, PartialEq)]
+pub struct Share {
+    /// The index followed by the value.
+    bytes: Vec<u8>,
+}
+
+impl Share {
+    /// Parses a share that was serialized with `as_ref()`.
+    ///
+    /// Fails if `bytes` is shorter than two bytes or if its index is zero.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, error::Unspecified> {
+        match bytes {
+            [index, _, ..] if *index != 0 => Ok(Self {
+                bytes: bytes.to_vec(),
+            }),
+            _ => Err(error::Unspecified),
+        }
+    }
+
+    /// The index of the share, from 1 to 255.
+    #[inline]
+    pub fn index(&self) -> u8 {
+        self.bytes[0]
+    }
+
+    fn value(&self) -> &[u8] {
+        &self.bytes[1..]
+    }
+}
+
+/// The serialized share: its index followed by its value.
+impl AsRef<[u8]> for Share {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl core::fmt::Debug for Share {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("Share")
+            .field("index", &self.index())
+            .finish()
+    }
+}
+
+/// Splits `secret` into `shares` shares, any `threshold` of which
+/// reconstruct it.
+///
+/// The shares have the indexes 1 to `shares`. Fails if `secret` is empty,
+/// if `threshold` is less than 2, if `shares` is less than `threshold` or
+/// greater than `MAX_SHARES`, or if `rng` fails.
+pub fn split(
+    rng: &dyn rand::SecureRandom,
+    secret: &[u8],
+    threshold: usize,
+    shares: usize,
+) -> Result<Vec<Share>, error::Unspecified> {
+    if secret.is_empty() || threshold < 2 || shares < threshold || shares > MAX_SHARES {
+        return Err(error::Unspecified);
+    }
+
+    // The coefficients of x^1 to x^(threshold - 1) of each byte's
+    // polynomial.
+    let degree = threshold - 1;
+    let mut coefficients = vec![0u8; secret.len() * degree];
+    rng.fill(&mut coefficients)?;
+
+    let shares = (1..=u8::MAX)
+        .take(shares)
+        .map(|x| {
+            let mut bytes = Vec::with_capacity(1 + secret.len());
+            bytes.push(x);
+            bytes.extend(secret.iter().zip(coefficients.chunks_exact(degree)).map(
+                |(&constant, coefficients)| {
+                    // Horner's method, from the highest-degree term.
+                    let y = coefficients
+                        .iter()
+                        .rev()
+                        .fold(0, |y, &coefficient| gf_mul(y, x) ^ coefficient);
+                    gf_mul(y, x) ^ constant
+                },
+            ));
+            Share { bytes }
+        })
+        .collect();
+    Ok(shares)
+}
+
+/// Reconstructs the secret from `shares`.
+///
+/// Fails if `shares` is empty, if two shares have the same index, or if the
+/// shares aren't all the same length. Given fewer shares than the threshold
+/// the secret was split with, this returns a wrong secret instead of
+/// failing.
+pub fn combine(shares: &[Share]) -> Result<Vec<u8>, error::Unspecified> {
+    let len = shares.first().ok_or(error::Unspecified)?.value().len();
+    for (i, share) in shares.iter().enumerate() {
+        if share.value().len() != len || shares[..i].iter().any(|s| s.index() == share.index()) {
+            return Err(error::Unspecified);
+        }
+    }
+
+    // Lagrange interpolation at x = 0. In GF(2^8), subtraction is XOR, so
+    // the basis polynomial for share i, evaluated at 0, is
+    // product(x_j / (x_j ^ x_i)) over the other shares j.
+    let basis = shares.iter().map(|share| {
+        let (numerator, denominator) = shares
+            .iter()
+            .filter(|other| other.index() != share.index())
+            .fold((1, 1), |(n, d), other| {
+                (
+                    gf_mul(n, other.index()),
+                    gf_mul(d, other.index() ^ share.index()),
+                )
+            });
+        gf_mul(numerator, gf_inv(denominator))
+    });
+
+    let mut secret = vec![0u8; len];
+    for (l, share) in basis.zip(shares) {
+        secret
+            .iter_mut()
+            .zip(share.value())
+            .for_each(|(s, &y)| *s ^= gf_mul(l, y));
+    }
+    Ok(secret)
+}
+
+/// Multiplies in GF(2^8) modulo x^8 + x^4 + x^3 + x + 1, in constant time.
+fn gf_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut r = 0;
+    for _ in 0..8 {
+        r ^= a & 0u8.wrapping_sub(b & 1);
+        let carry = 0u8.wrapping_sub(a >> 7);
+        a = (a << 1) ^ (carry & 0x1b);
+        b >>= 1;
+    }
+    r
+}
+
+/// Inverts a nonzero element of GF(2^8), in constant time, as a^254.
+fn gf_inv(a: u8) -> u8 {
+    // 254 = 0b11111110.
+    let mut r = 1;
+    let mut power = a;
+    for _ in 0..7 {
+        power = gf_mul(power, power);
+        r = gf_mul(r, power);
+    }
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gf_inv() {
+        assert_eq!(gf_mul(0x53, 0xca), 1);
+        for a in 1..=u8::MAX {
+            assert_eq!(gf_mul(a, gf_inv(a)), 1);
+        }
+    }
+}
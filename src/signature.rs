@@ -58,7 +58,14 @@
 //! *r* and *s* are both big-endian-encoded values that are left-padded to the
 //! maximum length. A P-256 signature will be 64 bytes long (two 32-byte
 //! components) and a P-384 signature will be 96 bytes long (two 48-byte
-//! components). This is the form of ECDSA signature used PKCS#11 and DNSSEC.
+//! components). This is the form of ECDSA signature used PKCS#11 and DNSSEC,
+//! and by JWS (the `ES256` and `ES384` algorithms of [RFC 7518 Section 3.4]).
+//! An `EcdsaKeyPair` constructed with `ECDSA_P256_SHA256_FIXED_SIGNING` or
+//! `ECDSA_P384_SHA384_FIXED_SIGNING` produces signatures in this form; there's
+//! no need to convert ASN.1 signatures. `EcdsaKeyPair::sign` always produces
+//! signatures of exactly this length, even when *r* or *s* has leading zero
+//! bytes; use `ecdsa_fixed_signature_from_components` to pad the components of
+//! signatures from implementations that don't.
 //!
 //! The public key is encoding in uncompressed form using the
 //! Octet-String-to-Elliptic-Curve-Point algorithm in
@@ -106,6 +113,7 @@
 //! signature using the secure random number generator passed to `sign()`.
 //!
 //!
+//! [RFC 7518 Section 3.4]: https://tools.ietf.org/html/rfc7518#section-3.4
 //! [SEC 1: Elliptic Curve Cryptography, Version 2.0]:
 //!     http://www.secg.org/sec1-v2.pdf
 //! [NIST Special Publication 800-56A, revision 2]:
@@ -255,7 +263,7 @@
 //! # }
 //! ```
 
-use crate::{cpu, debug, ec, error, sealed};
+use crate::{cpu, debug, digest, ec, error, sealed};
 
 pub use crate::ec::{
     curve25519::ed25519::{
@@ -263,13 +271,15 @@ pub use crate::ec::{
         verification::{EdDSAParameters, ED25519},
         ED25519_PUBLIC_KEY_LEN,
     },
+    curve448::ed448::{Ed448KeyPair, Ed448Parameters, ED448, ED448_PUBLIC_KEY_LEN, ED448_SEED_LEN},
     suite_b::ecdsa::{
         signing::{
-            EcdsaKeyPair, EcdsaSigningAlgorithm, ECDSA_P256_SHA256_ASN1_SIGNING,
+            EcdsaKeyPair, EcdsaSigningAlgorithm, ExternalSigner, ECDSA_P256_SHA256_ASN1_SIGNING,
             ECDSA_P256_SHA256_FIXED_SIGNING, ECDSA_P384_SHA384_ASN1_SIGNING,
             ECDSA_P384_SHA384_FIXED_SIGNING,
         },
         verification::{
+            ecdsa_fixed_signature_from_components, ecdsa_verify_same_message,
             EcdsaVerificationAlgorithm, ECDSA_P256_SHA256_ASN1, ECDSA_P256_SHA256_FIXED,
             ECDSA_P256_SHA384_ASN1, ECDSA_P384_SHA256_ASN1, ECDSA_P384_SHA384_ASN1,
             ECDSA_P384_SHA384_FIXED,
@@ -277,6 +287,9 @@ pub use crate::ec::{
     },
 };
 
+#[cfg(feature = "alloc")]
+pub use crate::ec::curve25519::ed25519::verification::ed25519_verify_batch;
+
 #[cfg(feature = "alloc")]
 pub use crate::rsa::{
     padding::{
@@ -284,16 +297,19 @@ pub use crate::rsa::{
         RSA_PSS_SHA384, RSA_PSS_SHA512,
     },
     verification::{
-        RsaPublicKeyComponents, RSA_PKCS1_1024_8192_SHA1_FOR_LEGACY_USE_ONLY,
-        RSA_PKCS1_1024_8192_SHA256_FOR_LEGACY_USE_ONLY,
-        RSA_PKCS1_1024_8192_SHA512_FOR_LEGACY_USE_ONLY,
-        RSA_PKCS1_2048_8192_SHA1_FOR_LEGACY_USE_ONLY, RSA_PKCS1_2048_8192_SHA256,
+        RsaPublicKeyComponents, RsaVerifier, RSA_PKCS1_2048_8192_SHA256,
         RSA_PKCS1_2048_8192_SHA384, RSA_PKCS1_2048_8192_SHA512, RSA_PKCS1_3072_8192_SHA384,
         RSA_PSS_2048_8192_SHA256, RSA_PSS_2048_8192_SHA384, RSA_PSS_2048_8192_SHA512,
     },
     RsaParameters,
 };
 
+#[cfg(all(feature = "alloc", not(feature = "fips")))]
+pub use crate::rsa::verification::{
+    RSA_PKCS1_1024_8192_SHA1_FOR_LEGACY_USE_ONLY, RSA_PKCS1_1024_8192_SHA256_FOR_LEGACY_USE_ONLY,
+    RSA_PKCS1_1024_8192_SHA512_FOR_LEGACY_USE_ONLY, RSA_PKCS1_2048_8192_SHA1_FOR_LEGACY_USE_ONLY,
+};
+
 /// An RSA key pair, used for signing.
 #[cfg(feature = "alloc")]
 pub type RsaKeyPair = crate::rsa::KeyPair;
@@ -326,6 +342,24 @@ impl AsRef<[u8]> for Signature {
     }
 }
 
+/// Constructs a `Signature` from a signature computed elsewhere, e.g. by an
+/// `ExternalSigner`. The value isn't checked in any way.
+///
+/// Fails if `value` is longer than any signature that *ring* produces.
+impl TryFrom<&[u8]> for Signature {
+    type Error = error::Unspecified;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        if value.len() > MAX_LEN {
+            return Err(error::Unspecified);
+        }
+        Ok(Self::new(|out| {
+            out[..value.len()].copy_from_slice(value);
+            value.len()
+        }))
+    }
+}
+
 /// Key pairs for signing messages (private key and public key).
 pub trait KeyPair: core::fmt::Debug + Send + Sized + Sync {
     /// The type of the public key.
@@ -335,11 +369,39 @@ pub trait KeyPair: core::fmt::Debug + Send + Sized + Sync {
     fn public_key(&self) -> &Self::PublicKey;
 }
 
-/// The longest signature is an ASN.1 P-384 signature where *r* and *s* are of
-/// maximum length with the leading high bit set on each. Then each component
-/// will have a tag, a one-byte length, and a one-byte “I'm not negative”
-/// prefix, and the outer sequence will have a two-byte length.
-pub(crate) const MAX_LEN: usize = 1/*tag:SEQUENCE*/ + 2/*len*/ +
+/// Returns the SHA-256 digest of `spki_der`, a DER-encoded
+/// SubjectPublicKeyInfo.
+///
+/// This is the public key fingerprint used for key pinning, e.g. the
+/// `pin-sha256` value of [RFC 7469] is its base64 encoding. The definition
+/// will never change; a different kind of fingerprint would be a different
+/// function.
+///
+/// `spki_der` isn't parsed or validated in any way, so two different
+/// encodings of the same key have different fingerprints.
+///
+/// [RFC 7469]: https://tools.ietf.org/html/rfc7469#section-2.4
+pub fn public_key_fingerprint(spki_der: &[u8]) -> [u8; digest::SHA256_OUTPUT_LEN] {
+    let mut fingerprint = [0; digest::SHA256_OUTPUT_LEN];
+    fingerprint.copy_from_slice(digest::digest(&digest::SHA256, spki_der).as_ref());
+    fingerprint
+}
+
+/// The longest signature is an Ed448 signature, which is a 57-byte point
+/// followed by a 57-byte scalar.
+pub(crate) const MAX_LEN: usize = if ED448_SIGNATURE_LEN > ECDSA_ASN1_MAX_LEN {
+    ED448_SIGNATURE_LEN
+} else {
+    ECDSA_ASN1_MAX_LEN
+};
+
+const ED448_SIGNATURE_LEN: usize = 2 * ED448_PUBLIC_KEY_LEN;
+
+/// The longest ECDSA signature is an ASN.1 P-384 signature where *r* and *s*
+/// are of maximum length with the leading high bit set on each. Then each
+/// component will have a tag, a one-byte length, and a one-byte “I'm not
+/// negative” prefix, and the outer sequence will have a two-byte length.
+const ECDSA_ASN1_MAX_LEN: usize = 1/*tag:SEQUENCE*/ + 2/*len*/ +
     (2 * (1/*tag:INTEGER*/ + 1/*len*/ + 1/*zero*/ + ec::SCALAR_MAX_BYTES));
 
 /// A signature verification algorithm.
@@ -352,6 +414,22 @@ pub trait VerificationAlgorithm: core::fmt::Debug + Sync + sealed::Sealed {
         msg: untrusted::Input,
         signature: untrusted::Input,
     ) -> Result<(), error::Unspecified>;
+
+    /// Verify the signature `signature` of the message whose digest is
+    /// `digest` with the public key `public_key`.
+    ///
+    /// Fails if `digest` wasn't calculated with the digest algorithm that
+    /// this algorithm uses. The default implementation always fails; it is
+    /// overridden by the ECDSA and RSA algorithms.
+    fn verify_digest(
+        &self,
+        public_key: untrusted::Input,
+        digest: &digest::Digest,
+        signature: untrusted::Input,
+    ) -> Result<(), error::Unspecified> {
+        let _ = (public_key, digest, signature);
+        Err(error::Unspecified)
+    }
 }
 
 /// An unparsed, possibly malformed, public key for signature verification.
@@ -406,4 +484,31 @@ impl<B> UnparsedPublicKey<B> {
             untrusted::Input::from(signature),
         )
     }
+
+    /// Parses the public key and verifies `signature` is a valid signature of
+    /// the message whose digest is `digest` using it.
+    ///
+    /// This is useful when the same message is checked against several
+    /// signatures, e.g. when the to-be-signed part of a certificate is
+    /// checked against the signatures of multiple issuers; the message
+    /// only needs to be digested once.
+    ///
+    /// Only the ECDSA and RSA algorithms support this. Fails if the algorithm
+    /// doesn't support it, or if `digest` wasn't calculated with the digest
+    /// algorithm that the signature algorithm uses.
+    pub fn verify_digest(
+        &self,
+        digest: &digest::Digest,
+        signature: &[u8],
+    ) -> Result<(), error::Unspecified>
+    where
+        B: AsRef<[u8]>,
+    {
+        let _ = cpu::features();
+        self.algorithm.verify_digest(
+            untrusted::Input::from(self.bytes.as_ref()),
+            digest,
+            untrusted::Input::from(signature),
+        )
+    }
 }
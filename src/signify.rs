@@ -0,0 +1,116 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Verification of signify and minisign signatures.
+//!
+//! [signify] is OpenBSD's tool for signing release artifacts with Ed25519.
+//! [minisign] uses the same key and signature formats for its legacy,
+//! non-prehashed, signatures.
+//!
+//! A public key file looks like this:
+//!
+//! ```text
+//! untrusted comment: signify public key
+//! RWQ7fRqQwvReaOBsZimdykXnQGazdASWL9TUzQPFykcWCHmThZ9qTtP+
+//! ```
+//!
+//! and a signature file looks like this:
+//!
+//! ```text
+//! untrusted comment: verify with ring.pub
+//! RWQ7fRqQwvReaMdHGiwkxykvjXwjdnvtnznL8UBzVxP+tjSsuXzhfLFDYkZd5IG4yZ0Z/BE6YJ+0E/6Cpn/fR6RF08Aad4uXTwA=
+//! ```
+//!
+//! The second line of each is the base64 encoding of the algorithm (`Ed`),
+//! an 8-byte key number, and the Ed25519 public key or signature.
+//!
+//! [signify]: https://man.openbsd.org/signify
+//! [minisign]: https://jedisct1.github.io/minisign/
+
+use crate::{constant_time, error, signature};
+
+/// Verifies that `signature` is a valid signature of `message` by
+/// `public_key`.
+///
+/// `public_key` is either the contents of a public key file or just its
+/// base64 line. `signature` is the contents of a signature file, which must
+/// start with its `untrusted comment:` line. The comments aren't
+/// authenticated and are ignored, as is anything after the base64 line of
+/// `signature`; in particular, minisign's trusted comment isn't verified.
+///
+/// Fails if either input is malformed, if the key number of the signature
+/// doesn't match the key number of the public key, or if the signature is
+/// invalid. Minisign's prehashed (`ED`) signatures aren't supported.
+///
+/// ```
+/// use ring::signify;
+///
+/// let public_key = "\
+///     untrusted comment: minisign public key E7620F1842B4E81F\n\
+///     RWQf6LRCGA9i53mlYecO4IzT51TGPpvWucNSCh1CBM0QTaLn73Y7GFO3\n";
+/// let signature = "\
+///     untrusted comment: signature from minisign secret key\n\
+///     RWQf6LRCGA9i59SLOFxz6NxvASXDJeRtuZykwQepbDEGt87ig1BNpWaVWuNrm73YiIiJbq71Wi+dP9eKL8OC351vwIasSSbXxwA=\n\
+///     trusted comment: timestamp:1555779966\tfile:test\n\
+///     QtKMXWyYcwdpZAlPF7tE2ENJkRd1ujvKjlj1m9RtHTBnZPa5WKU5uWRs5GoP5M/VqE81QFuMKI5k/SfNQUaOAA==\n";
+///
+/// signify::verify(public_key, signature, b"test")?;
+/// assert!(signify::verify(public_key, signature, b"Test").is_err());
+/// # Ok::<(), ring::error::Unspecified>(())
+/// ```
+pub fn verify(public_key: &str, signature: &str, message: &[u8]) -> Result<(), error::Unspecified> {
+    let public_key: [u8; PUBLIC_KEY_LEN] = decode(base64_line(public_key, false)?)?;
+    let signature: [u8; SIGNATURE_LEN] = decode(base64_line(signature, true)?)?;
+
+    let (public_key_header, public_key) = public_key.split_at(HEADER_LEN);
+    let (signature_header, signature) = signature.split_at(HEADER_LEN);
+    if public_key_header[..PKALG.len()] != PKALG || signature_header != public_key_header {
+        return Err(error::Unspecified);
+    }
+
+    signature::UnparsedPublicKey::new(&signature::ED25519, public_key).verify(message, signature)
+}
+
+/// The algorithm identifier for Ed25519.
+const PKALG: [u8; 2] = *b"Ed";
+
+const KEYNUM_LEN: usize = 8;
+
+/// The length of the algorithm identifier and the key number.
+const HEADER_LEN: usize = PKALG.len() + KEYNUM_LEN;
+
+const PUBLIC_KEY_LEN: usize = HEADER_LEN + 32;
+const SIGNATURE_LEN: usize = HEADER_LEN + 64;
+
+const COMMENT_PREFIX: &str = "untrusted comment: ";
+
+/// Returns the base64 line of `file`, skipping its comment line.
+fn base64_line(file: &str, comment_required: bool) -> Result<&str, error::Unspecified> {
+    let mut lines = file.lines();
+    let first = lines.next().ok_or(error::Unspecified)?;
+    match first.strip_prefix(COMMENT_PREFIX) {
+        Some(_) => lines.next().ok_or(error::Unspecified),
+        None if !comment_required => Ok(first),
+        None => Err(error::Unspecified),
+    }
+}
+
+fn decode<const N: usize>(line: &str) -> Result<[u8; N], error::Unspecified> {
+    let mut out = [0; N];
+    let len = constant_time::decode_base64(line.as_bytes(), &mut out)?;
+    if len != N {
+        return Err(error::Unspecified);
+    }
+    Ok(out)
+}
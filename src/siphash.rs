@@ -0,0 +1,200 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! SipHash, a keyed hash function for hash tables.
+//!
+//! [SipHash] is a pseudorandom function with a 128-bit key and a 64-bit
+//! output that is fast on short inputs. Keying a hash table's hash function
+//! with a secret random key prevents attackers from choosing keys that all
+//! collide, which would otherwise make the table's operations take linear
+//! time. SipHash's output is too short for it to be used as a MAC; use
+//! `hmac` or `mac` for that.
+//!
+//! `SipHasher13` and `SipHasher24` implement `core::hash::Hasher`:
+//!
+//! ```
+//! use core::hash::{Hash, Hasher};
+//! use ring::siphash;
+//!
+//! let mut hasher = siphash::SipHasher13::new([7; siphash::KEY_LEN]);
+//! "hello, world".hash(&mut hasher);
+//! let _hash: u64 = hasher.finish();
+//! ```
+//!
+//! SipHash-1-3 is the variant used by most hash table implementations,
+//! including Rust's standard library; SipHash-2-4 is the more conservative
+//! variant from the original paper.
+//!
+//! [SipHash]: https://www.aumasson.jp/siphash/siphash.pdf
+
+use core::hash::Hasher;
+
+/// The length of a SipHash key.
+pub const KEY_LEN: usize = 16;
+
+/// A SipHash-1-3 hasher.
+#[derive(Clone)]
+pub struct SipHasher13(State<1, 3>);
+
+impl SipHasher13 {
+    /// Constructs a new hasher with the given key.
+    pub fn new(key: [u8; KEY_LEN]) -> Self {
+        Self(State::new(key))
+    }
+}
+
+impl Hasher for SipHasher13 {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.write(bytes)
+    }
+
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.0.finish()
+    }
+}
+
+impl core::fmt::Debug for SipHasher13 {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("SipHasher13").finish()
+    }
+}
+
+/// A SipHash-2-4 hasher.
+#[derive(Clone)]
+pub struct SipHasher24(State<2, 4>);
+
+impl SipHasher24 {
+    /// Constructs a new hasher with the given key.
+    pub fn new(key: [u8; KEY_LEN]) -> Self {
+        Self(State::new(key))
+    }
+}
+
+impl Hasher for SipHasher24 {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.write(bytes)
+    }
+
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.0.finish()
+    }
+}
+
+impl core::fmt::Debug for SipHasher24 {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("SipHasher24").finish()
+    }
+}
+
+const BLOCK_LEN: usize = 8;
+
+/// The state of SipHash-`C`-`D`, which does `C` rounds per block and `D`
+/// finalization rounds.
+#[derive(Clone)]
+struct State<const C: usize, const D: usize> {
+    v: [u64; 4],
+    pending: [u8; BLOCK_LEN],
+    num_pending: usize,
+    // Only the low 8 bits are used.
+    len: usize,
+}
+
+impl<const C: usize, const D: usize> State<C, D> {
+    fn new(key: [u8; KEY_LEN]) -> Self {
+        let (k0, k1) = key.split_at(KEY_LEN / 2);
+        let k0 = u64::from_le_bytes(k0.try_into().unwrap());
+        let k1 = u64::from_le_bytes(k1.try_into().unwrap());
+        Self {
+            v: [
+                k0 ^ 0x736f6d6570736575,
+                k1 ^ 0x646f72616e646f6d,
+                k0 ^ 0x6c7967656e657261,
+                k1 ^ 0x7465646279746573,
+            ],
+            pending: [0; BLOCK_LEN],
+            num_pending: 0,
+            len: 0,
+        }
+    }
+
+    fn write(&mut self, mut bytes: &[u8]) {
+        self.len = self.len.wrapping_add(bytes.len());
+
+        if self.num_pending > 0 {
+            let to_copy = core::cmp::min(BLOCK_LEN - self.num_pending, bytes.len());
+            let (head, rest) = bytes.split_at(to_copy);
+            self.pending[self.num_pending..][..to_copy].copy_from_slice(head);
+            self.num_pending += to_copy;
+            bytes = rest;
+            if self.num_pending < BLOCK_LEN {
+                return;
+            }
+            self.compress(u64::from_le_bytes(self.pending));
+            self.num_pending = 0;
+        }
+
+        let mut blocks = bytes.chunks_exact(BLOCK_LEN);
+        for block in &mut blocks {
+            self.compress(u64::from_le_bytes(block.try_into().unwrap()));
+        }
+        let remainder = blocks.remainder();
+        self.pending[..remainder.len()].copy_from_slice(remainder);
+        self.num_pending = remainder.len();
+    }
+
+    fn compress(&mut self, m: u64) {
+        self.v[3] ^= m;
+        for _ in 0..C {
+            sip_round(&mut self.v);
+        }
+        self.v[0] ^= m;
+    }
+
+    fn finish(&self) -> u64 {
+        let mut state = self.clone();
+        let mut last = [0; BLOCK_LEN];
+        last[..self.num_pending].copy_from_slice(&self.pending[..self.num_pending]);
+        last[BLOCK_LEN - 1] = self.len.to_le_bytes()[0];
+        state.compress(u64::from_le_bytes(last));
+
+        let mut v = state.v;
+        v[2] ^= 0xff;
+        for _ in 0..D {
+            sip_round(&mut v);
+        }
+        v[0] ^ v[1] ^ v[2] ^ v[3]
+    }
+}
+
+#[inline(always)]
+fn sip_round(v: &mut [u64; 4]) {
+    v[0] = v[0].wrapping_add(v[1]);
+    v[1] = v[1].rotate_left(13);
+    v[1] ^= v[0];
+    v[0] = v[0].rotate_left(32);
+    v[2] = v[2].wrapping_add(v[3]);
+    v[3] = v[3].rotate_left(16);
+    v[3] ^= v[2];
+    v[0] = v[0].wrapping_add(v[3]);
+    v[3] = v[3].rotate_left(21);
+    v[3] ^= v[0];
+    v[2] = v[2].wrapping_add(v[1]);
+    v[1] = v[1].rotate_left(17);
+    v[1] ^= v[2];
+    v[2] = v[2].rotate_left(32);
+}
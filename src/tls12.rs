@@ -0,0 +1,200 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! The TLS 1.2 pseudorandom function (PRF).
+//!
+//! The TLS 1.2 PRF is specified in [RFC 5246 Section 5]. It is not the same
+//! as HKDF, which TLS 1.3 uses instead.
+//!
+//! [RFC 5246 Section 5]: https://tools.ietf.org/html/rfc5246#section-5
+
+use crate::{error, hmac};
+
+/// A TLS 1.2 PRF algorithm.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Algorithm(hmac::Algorithm);
+
+impl Algorithm {
+    /// The underlying HMAC algorithm.
+    #[inline]
+    pub fn hmac_algorithm(&self) -> hmac::Algorithm {
+        self.0
+    }
+}
+
+/// The TLS 1.2 PRF using P_SHA256, used by all the TLS 1.2 cipher suites
+/// defined in RFC 5246.
+pub static PRF_SHA256: Algorithm = Algorithm(hmac::HMAC_SHA256);
+
+/// The TLS 1.2 PRF using P_SHA384, used by the TLS 1.2 cipher suites that
+/// specify it, e.g. the `*_SHA384` AES-256-GCM cipher suites.
+pub static PRF_SHA384: Algorithm = Algorithm(hmac::HMAC_SHA384);
+
+/// Fills `out` with `PRF(secret, label, seed)`.
+///
+/// This is `P_hash(secret, label + seed)`, where `P_hash` is the HMAC-based
+/// expansion described in [RFC 5246 Section 5], truncated to `out.len()`
+/// bytes.
+///
+/// [RFC 5246 Section 5]: https://tools.ietf.org/html/rfc5246#section-5
+pub fn prf(algorithm: Algorithm, secret: &[u8], label: &[u8], seed: &[u8], out: &mut [u8]) {
+    let key = hmac::Key::new(algorithm.0, secret);
+
+    // A(1) = HMAC_hash(secret, label + seed).
+    let mut a = {
+        let mut ctx = hmac::Context::with_key(&key);
+        ctx.update(label);
+        ctx.update(seed);
+        ctx.sign()
+    };
+
+    for chunk in out.chunks_mut(algorithm.0.digest_algorithm().output_len()) {
+        // HMAC_hash(secret, A(i) + label + seed).
+        let mut ctx = hmac::Context::with_key(&key);
+        ctx.update(a.as_ref());
+        ctx.update(label);
+        ctx.update(seed);
+        chunk.copy_from_slice(&ctx.sign().as_ref()[..chunk.len()]);
+
+        // A(i + 1) = HMAC_hash(secret, A(i)).
+        a = hmac::sign(&key, a.as_ref());
+    }
+}
+
+/// The maximum MAC key length supported by `key_block`: the output length of
+/// HMAC-SHA384.
+pub const MAX_MAC_KEY_LEN: usize = 48;
+
+/// The maximum encryption key length supported by `key_block`.
+pub const MAX_KEY_LEN: usize = 32;
+
+/// The maximum IV length supported by `key_block`.
+pub const MAX_IV_LEN: usize = 16;
+
+/// The length of the client and server randoms.
+pub const RANDOM_LEN: usize = 32;
+
+const KEY_BLOCK_MAX_LEN: usize = 2 * (MAX_MAC_KEY_LEN + MAX_KEY_LEN + MAX_IV_LEN);
+
+/// The keys and IVs for both directions of a TLS 1.2 connection, derived by
+/// `key_block`.
+pub struct KeyBlock {
+    bytes: [u8; KEY_BLOCK_MAX_LEN],
+    mac_key_len: usize,
+    key_len: usize,
+    iv_len: usize,
+}
+
+impl KeyBlock {
+    /// The `client_write_MAC_key`. It is empty for AEAD cipher suites.
+    pub fn client_write_mac_key(&self) -> &[u8] {
+        self.part(0, self.mac_key_len)
+    }
+
+    /// The `server_write_MAC_key`. It is empty for AEAD cipher suites.
+    pub fn server_write_mac_key(&self) -> &[u8] {
+        self.part(1, self.mac_key_len)
+    }
+
+    /// The `client_write_key`.
+    pub fn client_write_key(&self) -> &[u8] {
+        self.part(2, self.key_len)
+    }
+
+    /// The `server_write_key`.
+    pub fn server_write_key(&self) -> &[u8] {
+        self.part(3, self.key_len)
+    }
+
+    /// The `client_write_IV`.
+    pub fn client_write_iv(&self) -> &[u8] {
+        self.part(4, self.iv_len)
+    }
+
+    /// The `server_write_IV`.
+    pub fn server_write_iv(&self) -> &[u8] {
+        self.part(5, self.iv_len)
+    }
+
+    // The parts are, in order, two MAC keys, two keys, and two IVs.
+    fn part(&self, index: usize, len: usize) -> &[u8] {
+        let lens = [
+            self.mac_key_len,
+            self.mac_key_len,
+            self.key_len,
+            self.key_len,
+            self.iv_len,
+            self.iv_len,
+        ];
+        let start: usize = lens[..index].iter().sum();
+        &self.bytes[start..][..len]
+    }
+}
+
+impl core::fmt::Debug for KeyBlock {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        f.debug_struct("KeyBlock").finish()
+    }
+}
+
+/// Derives the keys and IVs for a TLS 1.2 connection from its master secret,
+/// as described in [RFC 5246 Section 6.3].
+///
+/// The key block is `PRF(master_secret, "key expansion", server_random +
+/// client_random)`, split into the client and server MAC keys, encryption
+/// keys, and IVs, in that order. For AEAD cipher suites `mac_key_len` is
+/// zero and `iv_len` is the length of the implicit part of the nonce, e.g.
+/// 4 for the AES-GCM cipher suites of [RFC 5288].
+///
+/// Fails if `mac_key_len`, `key_len`, or `iv_len` is larger than
+/// `MAX_MAC_KEY_LEN`, `MAX_KEY_LEN`, or `MAX_IV_LEN`, respectively.
+///
+/// [RFC 5246 Section 6.3]: https://tools.ietf.org/html/rfc5246#section-6.3
+/// [RFC 5288]: https://tools.ietf.org/html/rfc5288
+pub fn key_block(
+    algorithm: Algorithm,
+    master_secret: &[u8],
+    client_random: &[u8; RANDOM_LEN],
+    server_random: &[u8; RANDOM_LEN],
+    mac_key_len: usize,
+    key_len: usize,
+    iv_len: usize,
+) -> Result<KeyBlock, error::Unspecified> {
+    if mac_key_len > MAX_MAC_KEY_LEN || key_len > MAX_KEY_LEN || iv_len > MAX_IV_LEN {
+        return Err(error::Unspecified);
+    }
+
+    let mut seed = [0u8; 2 * RANDOM_LEN];
+    let (server, client) = seed.split_at_mut(RANDOM_LEN);
+    server.copy_from_slice(server_random);
+    client.copy_from_slice(client_random);
+
+    let mut r = KeyBlock {
+        bytes: [0u8; KEY_BLOCK_MAX_LEN],
+        mac_key_len,
+        key_len,
+        iv_len,
+    };
+    let len = 2 * (mac_key_len + key_len + iv_len);
+    prf(
+        algorithm,
+        master_secret,
+        KEY_EXPANSION_LABEL,
+        &seed,
+        &mut r.bytes[..len],
+    );
+    Ok(r)
+}
+
+const KEY_EXPANSION_LABEL: &[u8] = b"key expansion";
@@ -19,7 +19,7 @@ use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
 wasm_bindgen_test_configure!(run_in_browser);
 
 use core::ops::RangeFrom;
-use ring::{aead, error, test, test_file};
+use ring::{aead, digest, error, hkdf, hmac, test, test_file};
 
 /// Generate the known answer test functions for the given algorithm and test
 /// case input file, where each test is implemented by a test in `$test`.
@@ -43,9 +43,10 @@ macro_rules! test_known_answer {
 ///
 /// All of these tests can be run in parallel.
 macro_rules! test_aead {
-    { $( { $alg:ident, $test_file:expr } ),+, } => {
+    { $( $( #[$attr:meta] )* { $alg:ident, $test_file:expr } ),+, } => {
         mod aead_test { // Make `cargo test aead` include these files.
             $(
+                $( #[$attr] )*
                 #[allow(non_snake_case)]
                 mod $alg { // Provide a separate namespace for each algorithm's test.
                     use super::super::*;
@@ -62,6 +63,7 @@ macro_rules! test_aead {
                             less_safe_key_seal_in_place_append_tag,
                             less_safe_key_seal_in_place_separate_tag,
                             opening_key_open_in_place,
+                            opening_key_open_in_place_separate_tag,
                             opening_key_open_within,
                             sealing_key_seal_in_place_append_tag,
                             sealing_key_seal_in_place_separate_tag,
@@ -81,6 +83,11 @@ macro_rules! test_aead {
 test_aead! {
     { AES_128_GCM, "aead_aes_128_gcm_tests.txt" },
     { AES_256_GCM, "aead_aes_256_gcm_tests.txt" },
+    #[cfg(not(feature = "fips"))]
+    { AES_128_GCM_SIV, "aead_aes_128_gcm_siv_tests.txt" },
+    #[cfg(not(feature = "fips"))]
+    { AES_256_GCM_SIV, "aead_aes_256_gcm_siv_tests.txt" },
+    #[cfg(not(feature = "fips"))]
     { CHACHA20_POLY1305, "aead_chacha20_poly1305_tests.txt" },
 }
 
@@ -347,6 +354,22 @@ fn opening_key_open_in_place(
     })
 }
 
+fn opening_key_open_in_place_separate_tag(
+    alg: &'static aead::Algorithm,
+    tc: KnownAnswerTestCase,
+) -> Result<(), error::Unspecified> {
+    let nonce = aead::Nonce::assume_unique_for_key(tc.nonce);
+    let mut key: aead::OpeningKey<_> = make_key(alg, tc.key, nonce);
+    let mut in_out = Vec::from(tc.ciphertext);
+    let tag = tc.tag.try_into().unwrap();
+
+    let actual_plaintext = key.open_in_place_separate_tag(tc.aad, tag, &mut in_out)?;
+
+    assert_eq!(actual_plaintext, tc.plaintext);
+    assert_eq!(&in_out[..], tc.plaintext);
+    Ok(())
+}
+
 fn opening_key_open_within(
     alg: &'static aead::Algorithm,
     tc: KnownAnswerTestCase,
@@ -439,9 +462,21 @@ fn test_aead_nonce_sizes() {
     assert!(aead::Nonce::try_assume_unique_for_key(&[]).is_err());
     assert!(aead::Nonce::try_assume_unique_for_key(&nonce[..1]).is_err());
     assert!(aead::Nonce::try_assume_unique_for_key(&nonce[..16]).is_err()); // 128 bits.
+
+    // Nonces of other lengths.
+    assert!(aead::Nonce::<24>::try_assume_unique_for_key_of_len(&nonce[..24]).is_ok());
+    assert!(aead::Nonce::<24>::try_assume_unique_for_key_of_len(&nonce[..nonce_len]).is_err());
+    assert!(aead::Nonce::<13>::try_assume_unique_for_key_of_len(&nonce[..13]).is_ok());
+    assert!(aead::Nonce::<13>::try_assume_unique_for_key_of_len(&nonce[..nonce_len]).is_err());
+    let nonce: aead::Nonce =
+        aead::Nonce::try_assume_unique_for_key_of_len(&nonce[..nonce_len]).unwrap();
+    assert_eq!(nonce.as_ref(), &[0u8; aead::NONCE_LEN]);
+    let nonce = aead::Nonce::assume_unique_for_key([1u8; 24]);
+    assert_eq!(nonce.as_ref(), &[1u8; 24]);
 }
 
 #[allow(clippy::range_plus_one)]
+#[cfg(not(feature = "fips"))]
 #[test]
 fn aead_chacha20_poly1305_openssh() {
     // TODO: test_aead_key_sizes(...);
@@ -490,6 +525,237 @@ fn aead_chacha20_poly1305_openssh() {
     );
 }
 
+#[cfg(not(feature = "fips"))]
+#[test]
+fn aead_xchacha20_poly1305() {
+    use aead::xchacha20_poly1305::NONCE_LEN;
+
+    test::run(
+        test_file!("aead_xchacha20_poly1305_tests.txt"),
+        |section, test_case| {
+            assert_eq!(section, "");
+            let key = aead::UnboundKey::<NONCE_LEN>::new_of_len(
+                &aead::XCHACHA20_POLY1305,
+                &test_case.consume_bytes("KEY"),
+            )?;
+            let key = aead::LessSafeKey::new(key);
+            let nonce = test_case.consume_bytes("NONCE");
+            let nonce = || aead::Nonce::<NONCE_LEN>::try_assume_unique_for_key_of_len(&nonce);
+            let plaintext = test_case.consume_bytes("IN");
+            let aad = test_case.consume_bytes("AD");
+            let ct = test_case.consume_bytes("CT");
+            let tag = test_case.consume_bytes("TAG");
+
+            let mut in_out = plaintext.clone();
+            let actual_tag =
+                key.seal_in_place_separate_tag(nonce()?, aead::Aad::from(&aad), &mut in_out)?;
+            assert_eq!(in_out, ct);
+            assert_eq!(actual_tag.as_ref(), &tag[..]);
+
+            let mut in_out = [&ct[..], &tag[..]].concat();
+            let opened = key.open_in_place(nonce()?, aead::Aad::from(&aad), &mut in_out)?;
+            assert_eq!(opened, &plaintext[..]);
+
+            // Any change to the tag is detected, and the plaintext isn't
+            // released.
+            let mut in_out = [&ct[..], &tag[..]].concat();
+            *in_out.last_mut().unwrap() ^= 1;
+            assert!(key
+                .open_in_place(nonce()?, aead::Aad::from(&aad), &mut in_out)
+                .is_err());
+            assert!(in_out[..ct.len()].iter().all(|b| *b == 0));
+
+            Ok(())
+        },
+    );
+}
+
+#[cfg(not(feature = "fips"))]
+#[test]
+fn aead_xchacha20_poly1305_random_nonces() {
+    use aead::xchacha20_poly1305::{KEY_LEN, NONCE_LEN};
+    use ring::rand;
+
+    let rng = rand::SystemRandom::new();
+    let key_bytes: [u8; KEY_LEN] = rand::generate(&rng).unwrap().expose();
+    let new_key = |key_bytes: &[u8]| {
+        aead::UnboundKey::<NONCE_LEN>::new_of_len(&aead::XCHACHA20_POLY1305, key_bytes)
+    };
+    let key = aead::LessSafeKey::new(new_key(&key_bytes).unwrap());
+    assert!(new_key(&key_bytes[1..]).is_err());
+
+    // The nonce length is part of the key's type, and must match the
+    // algorithm's.
+    assert!(aead::UnboundKey::new(&aead::XCHACHA20_POLY1305, &key_bytes).is_err());
+    assert!(
+        aead::UnboundKey::<NONCE_LEN>::new_of_len(&aead::CHACHA20_POLY1305, &key_bytes).is_err()
+    );
+
+    let message = b"a message sealed with a random nonce";
+    let mut previous = Vec::new();
+    for _ in 0..16 {
+        let nonce: [u8; NONCE_LEN] = rand::generate(&rng).unwrap().expose();
+
+        let mut in_out = message.to_vec();
+        key.seal_in_place_append_tag(
+            aead::Nonce::assume_unique_for_key(nonce),
+            aead::Aad::from(b"aad"),
+            &mut in_out,
+        )
+        .unwrap();
+        assert_eq!(in_out.len(), message.len() + aead::MAX_TAG_LEN);
+
+        // Sealing the same message with different nonces gives different
+        // ciphertexts.
+        assert!(!previous.contains(&in_out));
+        previous.push(in_out.clone());
+
+        let opened = key
+            .open_in_place(
+                aead::Nonce::assume_unique_for_key(nonce),
+                aead::Aad::from(b"aad"),
+                &mut in_out,
+            )
+            .unwrap();
+        assert_eq!(opened, &message[..]);
+    }
+}
+
+#[cfg(not(feature = "fips"))]
+#[test]
+fn aead_xchacha20_poly1305_bound_keys() {
+    use aead::{
+        xchacha20_poly1305::{PrefixedNonceSequence, KEY_LEN, NONCE_LEN, PREFIX_LEN},
+        BoundKey as _, NonceSequence as _,
+    };
+
+    struct CounterNonceSequence(u32);
+    impl aead::NonceSequence for CounterNonceSequence {
+        fn advance(&mut self) -> Result<aead::Nonce, error::Unspecified> {
+            let mut nonce = [0; aead::NONCE_LEN];
+            nonce[8..].copy_from_slice(&self.0.to_be_bytes());
+            self.0 = self.0.checked_add(1).ok_or(error::Unspecified)?;
+            Ok(aead::Nonce::assume_unique_for_key(nonce))
+        }
+    }
+
+    let new_key = || {
+        aead::UnboundKey::<NONCE_LEN>::new_of_len(&aead::XCHACHA20_POLY1305, &[7; KEY_LEN]).unwrap()
+    };
+    let new_nonce_sequence =
+        || PrefixedNonceSequence::new([9; PREFIX_LEN], CounterNonceSequence(0));
+    let mut sealing_key = aead::SealingKey::new(new_key(), new_nonce_sequence());
+    let mut opening_key = aead::OpeningKey::new(new_key(), new_nonce_sequence());
+
+    // Each nonce is the prefix followed by the inner sequence's nonce.
+    let less_safe_key = aead::LessSafeKey::new(new_key());
+    let mut nonces = new_nonce_sequence();
+
+    for message in [&b"first"[..], b"second", b""] {
+        let mut in_out = message.to_vec();
+        sealing_key
+            .seal_in_place_append_tag(aead::Aad::empty(), &mut in_out)
+            .unwrap();
+
+        let mut expected = message.to_vec();
+        less_safe_key
+            .seal_in_place_append_tag(nonces.advance().unwrap(), aead::Aad::empty(), &mut expected)
+            .unwrap();
+        assert_eq!(in_out, expected);
+
+        let opened = opening_key
+            .open_in_place(aead::Aad::empty(), &mut in_out)
+            .unwrap();
+        assert_eq!(opened, message);
+    }
+}
+
+#[cfg(not(feature = "fips"))]
+#[test]
+fn aead_aegis128l() {
+    use aead::aegis128l::NONCE_LEN;
+
+    test::run(
+        test_file!("../src/aead/aegis128l_tests.txt"),
+        |section, test_case| {
+            assert_eq!(section, "");
+            let key = aead::UnboundKey::<NONCE_LEN>::new_of_len(
+                &aead::AEGIS_128L,
+                &test_case.consume_bytes("KEY"),
+            )?;
+            let key = aead::LessSafeKey::new(key);
+            let nonce = test_case.consume_bytes("NONCE");
+            let nonce = || aead::Nonce::<NONCE_LEN>::try_assume_unique_for_key_of_len(&nonce);
+            let aad = test_case.consume_bytes("AD");
+            let plaintext = test_case.consume_bytes("IN");
+            let ct = test_case.consume_bytes("CT");
+            let tag = test_case.consume_bytes("TAG");
+
+            let mut in_out = plaintext.clone();
+            let actual_tag =
+                key.seal_in_place_separate_tag(nonce()?, aead::Aad::from(&aad), &mut in_out)?;
+            assert_eq!(in_out, ct);
+            assert_eq!(actual_tag.as_ref(), &tag[..]);
+
+            let mut in_out = [&ct[..], &tag[..]].concat();
+            let opened = key.open_in_place(nonce()?, aead::Aad::from(&aad), &mut in_out)?;
+            assert_eq!(opened, &plaintext[..]);
+
+            // Any change to the tag is detected, and the plaintext isn't
+            // released.
+            let mut in_out = [&ct[..], &tag[..]].concat();
+            *in_out.last_mut().unwrap() ^= 1;
+            assert!(key
+                .open_in_place(nonce()?, aead::Aad::from(&aad), &mut in_out)
+                .is_err());
+            assert!(in_out[..ct.len()].iter().all(|b| *b == 0));
+
+            Ok(())
+        },
+    );
+}
+
+#[cfg(not(feature = "fips"))]
+#[test]
+fn aead_aegis128l_bound_keys() {
+    use aead::{
+        aegis128l::{KEY_LEN, NONCE_LEN},
+        BoundKey as _,
+    };
+
+    struct CounterNonceSequence(u64);
+    impl aead::NonceSequence<NONCE_LEN> for CounterNonceSequence {
+        fn advance(&mut self) -> Result<aead::Nonce<NONCE_LEN>, error::Unspecified> {
+            let mut nonce = [0; NONCE_LEN];
+            nonce[8..].copy_from_slice(&self.0.to_be_bytes());
+            self.0 = self.0.checked_add(1).ok_or(error::Unspecified)?;
+            Ok(aead::Nonce::assume_unique_for_key(nonce))
+        }
+    }
+
+    let new_key =
+        || aead::UnboundKey::<NONCE_LEN>::new_of_len(&aead::AEGIS_128L, &[7; KEY_LEN]).unwrap();
+    let mut sealing_key = aead::SealingKey::new(new_key(), CounterNonceSequence(0));
+    let mut opening_key = aead::OpeningKey::new(new_key(), CounterNonceSequence(0));
+
+    for message in [&b"first"[..], &[0x5a; 100], b""] {
+        let mut in_out = message.to_vec();
+        sealing_key
+            .seal_in_place_append_tag(aead::Aad::from(b"aad"), &mut in_out)
+            .unwrap();
+        assert_eq!(in_out.len(), message.len() + aead::AEGIS_128L.tag_len());
+
+        let opened = opening_key
+            .open_in_place(aead::Aad::from(b"aad"), &mut in_out)
+            .unwrap();
+        assert_eq!(opened, message);
+    }
+
+    // The nonce length is part of the key's type, and must match the
+    // algorithm's.
+    assert!(aead::UnboundKey::new(&aead::AEGIS_128L, &[7; KEY_LEN]).is_err());
+}
+
 #[test]
 fn aead_test_aad_traits() {
     test::compile_time_assert_send::<aead::Aad<&'_ [u8]>>();
@@ -612,11 +878,1316 @@ fn test_aead_lesssafekey_clone_aes_256_gcm() {
     test_aead_lesssafekey_clone_for_algorithm(&aead::AES_256_GCM);
 }
 
+#[cfg(not(feature = "fips"))]
 #[test]
 fn test_aead_lesssafekey_clone_chacha20_poly1305() {
     test_aead_lesssafekey_clone_for_algorithm(&aead::CHACHA20_POLY1305);
 }
 
+#[test]
+fn test_aead_ratcheting_key() {
+    for algorithm in [
+        &aead::AES_128_GCM,
+        &aead::AES_256_GCM,
+        #[cfg(not(feature = "fips"))]
+        &aead::CHACHA20_POLY1305,
+    ] {
+        let key_bytes = vec![7u8; algorithm.key_len()];
+        let mut sealing_key = aead::RatchetingSealingKey::new(algorithm, &key_bytes).unwrap();
+
+        let records: Vec<Vec<u8>> = (0..4u8)
+            .map(|i| {
+                let mut in_out = vec![i; 20];
+                sealing_key
+                    .seal_in_place_append_tag(aead::Aad::empty(), &mut in_out)
+                    .unwrap();
+                in_out
+            })
+            .collect();
+
+        // Sequential records open in order.
+        let mut opening_key = aead::RatchetingOpeningKey::new(algorithm, &key_bytes).unwrap();
+        for (i, record) in records.iter().enumerate() {
+            let mut in_out = record.clone();
+            let plaintext = opening_key
+                .open_in_place(aead::Aad::empty(), &mut in_out)
+                .unwrap();
+            assert_eq!(plaintext, &vec![i as u8; 20][..]);
+        }
+
+        // Skipping a record breaks the chain: neither the record after the
+        // skipped one nor any later record can be opened.
+        let mut opening_key = aead::RatchetingOpeningKey::new(algorithm, &key_bytes).unwrap();
+        let mut in_out = records[0].clone();
+        assert!(opening_key
+            .open_in_place(aead::Aad::empty(), &mut in_out)
+            .is_ok());
+        for record in &records[2..] {
+            let mut in_out = record.clone();
+            assert!(opening_key
+                .open_in_place(aead::Aad::empty(), &mut in_out)
+                .is_err());
+        }
+    }
+}
+
+#[test]
+fn test_aead_compute_commitment() {
+    let nonce = || aead::Nonce::assume_unique_for_key([1; aead::NONCE_LEN]);
+
+    let key_a = [0u8; 32];
+    let mut key_b = key_a;
+    key_b[31] = 1;
+
+    let commitment_a = aead::compute_commitment(&key_a, &nonce());
+    assert_eq!(commitment_a, aead::compute_commitment(&key_a, &nonce()));
+    assert_ne!(commitment_a, aead::compute_commitment(&key_b, &nonce()));
+
+    // A short key isn't confused with the same key padded with zeros.
+    assert_ne!(
+        aead::compute_commitment(&key_a[..16], &nonce()),
+        commitment_a
+    );
+
+    // The commitment is also bound to the nonce.
+    let other_nonce = aead::Nonce::assume_unique_for_key([2; aead::NONCE_LEN]);
+    assert_ne!(commitment_a, aead::compute_commitment(&key_a, &other_nonce));
+}
+
+#[test]
+fn test_aead_with_protocol_id() {
+    for algorithm in [
+        &aead::AES_128_GCM,
+        &aead::AES_256_GCM,
+        #[cfg(not(feature = "fips"))]
+        &aead::CHACHA20_POLY1305,
+    ] {
+        let key_bytes = vec![3u8; algorithm.key_len()];
+        let nonce = || aead::Nonce::assume_unique_for_key([0; aead::NONCE_LEN]);
+        let bound =
+            |id| aead::with_protocol_id(aead::UnboundKey::new(algorithm, &key_bytes).unwrap(), id);
+
+        let mut in_out = b"hello".to_vec();
+        bound(1)
+            .seal_in_place_append_tag(nonce(), aead::Aad::from(b"header"), &mut in_out)
+            .unwrap();
+        let sealed = in_out;
+
+        // Opens under the same protocol ID.
+        let mut in_out = sealed.clone();
+        assert_eq!(
+            bound(1)
+                .open_in_place(nonce(), aead::Aad::from(b"header"), &mut in_out)
+                .unwrap(),
+            b"hello"
+        );
+
+        // Fails under a different protocol ID.
+        let mut in_out = sealed.clone();
+        assert!(bound(2)
+            .open_in_place(nonce(), aead::Aad::from(b"header"), &mut in_out)
+            .is_err());
+
+        // It is equivalent to prepending the big-endian ID to the AAD.
+        let mut in_out = sealed.clone();
+        assert_eq!(
+            make_less_safe_key(algorithm, &key_bytes)
+                .open_in_place(nonce(), aead::Aad::from(b"\x00\x01header"), &mut in_out)
+                .unwrap(),
+            b"hello"
+        );
+    }
+}
+
+#[cfg(not(feature = "fips"))]
+#[test]
+fn test_aead_transcript() {
+    let key = make_less_safe_key(&aead::CHACHA20_POLY1305, &[4u8; 32]);
+    let nonce = |i| {
+        let mut nonce = [0u8; aead::NONCE_LEN];
+        nonce[aead::NONCE_LEN - 1] = i;
+        aead::Nonce::assume_unique_for_key(nonce)
+    };
+
+    let mut sender = aead::Transcript::new();
+    let sealed: Vec<Vec<u8>> = (0u8..3)
+        .map(|i| {
+            let mut in_out = vec![i; 10];
+            key.seal_in_place_append_tag(nonce(i), sender.aad(), &mut in_out)
+                .unwrap();
+            sender.update(&in_out);
+            in_out
+        })
+        .collect();
+
+    // Opens the messages `order` in that order, returning whether each one
+    // opened successfully.
+    let open = |order: [usize; 3]| {
+        let mut recipient = aead::Transcript::new();
+        order.map(|i| {
+            let mut in_out = sealed[i].clone();
+            let result = key
+                .open_in_place(nonce(i as u8), recipient.aad(), &mut in_out)
+                .map(|plaintext| assert_eq!(plaintext, &[i as u8; 10]));
+            recipient.update(&sealed[i]);
+            result.is_ok()
+        })
+    };
+
+    assert_eq!(open([0, 1, 2]), [true, true, true]);
+
+    // Reordering the first two messages breaks verification of every message,
+    // including the third.
+    assert_eq!(open([1, 0, 2]), [false, false, false]);
+
+    // A dropped message breaks verification of every later message.
+    let mut recipient = aead::Transcript::new();
+    recipient.update(&sealed[0]);
+    let mut in_out = sealed[2].clone();
+    assert!(key
+        .open_in_place(nonce(2), recipient.aad(), &mut in_out)
+        .is_err());
+}
+
+#[test]
+fn test_aead_seal_with_total_length() {
+    let nonce = |i| aead::Nonce::assume_unique_for_key([i; aead::NONCE_LEN]);
+
+    // Known answer, computed with an independent implementation.
+    let key = make_less_safe_key(&aead::AES_128_GCM, &[5; 16]);
+    let mut in_out = b"the last record!!!".to_vec();
+    let nonce_bytes: [u8; aead::NONCE_LEN] = core::array::from_fn(|i| i as u8);
+    key.seal_in_place_append_tag_with_total_length(
+        aead::Nonce::assume_unique_for_key(nonce_bytes),
+        aead::Aad::from(b"final"),
+        &mut in_out,
+        1000,
+    )
+    .unwrap();
+    assert_eq!(
+        in_out,
+        test::from_hex(
+            "e66110a1c59d4b430838476ff8d1724e06e6\
+             8aefe3eeecd4cee707f4e33610ca4920"
+        )
+        .unwrap()
+    );
+
+    for algorithm in [&aead::AES_128_GCM, &aead::AES_256_GCM] {
+        let key = make_less_safe_key(algorithm, &vec![7; algorithm.key_len()]);
+
+        // A stream of three records; only the last one binds the total length.
+        let records: [&[u8]; 3] = [b"first", b"second record", b"third"];
+        let total_len = records.iter().map(|r| r.len() as u64).sum::<u64>();
+        let sealed: Vec<Vec<u8>> = records
+            .iter()
+            .enumerate()
+            .map(|(i, record)| {
+                let mut in_out = record.to_vec();
+                let nonce = nonce(i as u8);
+                if i == records.len() - 1 {
+                    key.seal_in_place_append_tag_with_total_length(
+                        nonce,
+                        aead::Aad::empty(),
+                        &mut in_out,
+                        total_len,
+                    )
+                } else {
+                    key.seal_in_place_append_tag(nonce, aead::Aad::empty(), &mut in_out)
+                }
+                .unwrap();
+                in_out
+            })
+            .collect();
+
+        // The whole stream opens.
+        let mut received = 0;
+        for (i, record) in sealed.iter().enumerate() {
+            let mut in_out = record.clone();
+            let plaintext = if i == sealed.len() - 1 {
+                key.open_in_place_with_total_length(
+                    nonce(i as u8),
+                    aead::Aad::empty(),
+                    &mut in_out,
+                    received + (record.len() - aead::MAX_TAG_LEN) as u64,
+                )
+            } else {
+                key.open_in_place(nonce(i as u8), aead::Aad::empty(), &mut in_out)
+            }
+            .unwrap();
+            assert_eq!(plaintext, records[i]);
+            received += plaintext.len() as u64;
+        }
+        assert_eq!(received, total_len);
+
+        // If the final record is dropped, then the record the recipient
+        // treats as final doesn't open.
+        let mut in_out = sealed[1].clone();
+        assert!(key
+            .open_in_place_with_total_length(
+                nonce(1),
+                aead::Aad::empty(),
+                &mut in_out,
+                (records[0].len() + records[1].len()) as u64,
+            )
+            .is_err());
+        assert!(in_out[..records[1].len()].iter().all(|&b| b == 0));
+
+        // The final record doesn't open with a different total length, or
+        // as a normal record.
+        let mut in_out = sealed[2].clone();
+        assert!(key
+            .open_in_place_with_total_length(
+                nonce(2),
+                aead::Aad::empty(),
+                &mut in_out,
+                total_len - 1
+            )
+            .is_err());
+        let mut in_out = sealed[2].clone();
+        assert!(key
+            .open_in_place(nonce(2), aead::Aad::empty(), &mut in_out)
+            .is_err());
+    }
+
+    // Only AES-GCM is supported.
+    #[cfg(not(feature = "fips"))]
+    {
+        let key = make_less_safe_key(&aead::CHACHA20_POLY1305, &[7; 32]);
+        let mut in_out = b"record".to_vec();
+        assert!(key
+            .seal_in_place_append_tag_with_total_length(
+                nonce(0),
+                aead::Aad::empty(),
+                &mut in_out,
+                6
+            )
+            .is_err());
+        assert_eq!(in_out, b"record");
+    }
+}
+
+#[test]
+fn test_aead_analyze_ciphertext() {
+    for algorithm in [
+        &aead::AES_128_GCM,
+        &aead::AES_256_GCM,
+        #[cfg(not(feature = "fips"))]
+        &aead::CHACHA20_POLY1305,
+    ] {
+        let tag_len = algorithm.tag_len();
+
+        // Too short to contain a tag, so the tag must be detached.
+        for len in [0, 1, tag_len - 1] {
+            let shape = aead::analyze_ciphertext(algorithm, &vec![0; len]);
+            assert!(!shape.is_long_enough_for_tag());
+            assert!(shape.is_consistent_with_detached_tag());
+            assert_eq!(shape.tagged_plaintext_len(), None);
+        }
+
+        // Long enough to contain a tag, or not.
+        for len in [tag_len, tag_len + 1, 1000] {
+            let shape = aead::analyze_ciphertext(algorithm, &vec![0; len]);
+            assert!(shape.is_long_enough_for_tag());
+            assert!(shape.is_consistent_with_detached_tag());
+            assert_eq!(shape.tagged_plaintext_len(), Some(len - tag_len));
+        }
+
+        // A sealed message with an appended tag.
+        let key = make_less_safe_key(algorithm, &vec![1; algorithm.key_len()]);
+        let mut in_out = b"hello".to_vec();
+        key.seal_in_place_append_tag(
+            aead::Nonce::assume_unique_for_key([0; aead::NONCE_LEN]),
+            aead::Aad::empty(),
+            &mut in_out,
+        )
+        .unwrap();
+        let shape = aead::analyze_ciphertext(algorithm, &in_out);
+        assert!(shape.is_long_enough_for_tag());
+        assert_eq!(shape.tagged_plaintext_len(), Some(5));
+
+        // The same message with its tag detached.
+        let shape = aead::analyze_ciphertext(algorithm, &in_out[..5]);
+        assert!(shape.is_consistent_with_detached_tag());
+        assert!(!shape.is_long_enough_for_tag());
+    }
+}
+
+#[test]
+fn test_aead_unbound_key_from_derived() {
+    struct Len(usize);
+
+    impl hkdf::KeyType for Len {
+        fn len(&self) -> usize {
+            self.0
+        }
+    }
+
+    let prk = hkdf::Salt::new(hkdf::HKDF_SHA256, b"salt").extract(b"secret");
+    const INFO: &[&[u8]] = &[b"key"];
+
+    for algorithm in [
+        &aead::AES_128_GCM,
+        &aead::AES_256_GCM,
+        #[cfg(not(feature = "fips"))]
+        &aead::CHACHA20_POLY1305,
+    ] {
+        let mut key_bytes = vec![0u8; algorithm.key_len()];
+        prk.expand(INFO, algorithm)
+            .unwrap()
+            .fill(&mut key_bytes)
+            .unwrap();
+
+        let seal = |key: aead::UnboundKey| {
+            let mut in_out = b"hello".to_vec();
+            aead::LessSafeKey::new(key)
+                .seal_in_place_append_tag(
+                    aead::Nonce::assume_unique_for_key([0; aead::NONCE_LEN]),
+                    aead::Aad::empty(),
+                    &mut in_out,
+                )
+                .unwrap();
+            in_out
+        };
+        let expected = seal(aead::UnboundKey::new(algorithm, &key_bytes).unwrap());
+
+        let okm = prk.expand(INFO, algorithm).unwrap();
+        let key = aead::UnboundKey::from_derived(algorithm, okm).unwrap();
+        assert_eq!(key.algorithm(), algorithm);
+        assert_eq!(seal(key), expected);
+
+        let okm = prk.expand(INFO, Len(algorithm.key_len())).unwrap();
+        let key = aead::UnboundKey::from_derived(algorithm, okm).unwrap();
+        assert_eq!(seal(key), expected);
+
+        // `From<hkdf::Okm>` is equivalent.
+        let key = aead::UnboundKey::from(prk.expand(INFO, algorithm).unwrap());
+        assert_eq!(seal(key), expected);
+
+        // The length of the OKM must be the key length.
+        for len in [algorithm.key_len() - 1, algorithm.key_len() + 1] {
+            let okm = prk.expand(INFO, Len(len)).unwrap();
+            assert!(aead::UnboundKey::from_derived(algorithm, okm).is_err());
+        }
+    }
+}
+
+#[test]
+fn test_aead_seal_in_place_into() {
+    use aead::BoundKey as _;
+    use std::collections::VecDeque;
+
+    struct CounterNonceSequence(u32);
+    impl aead::NonceSequence for CounterNonceSequence {
+        fn advance(&mut self) -> Result<aead::Nonce, error::Unspecified> {
+            let mut nonce = [0u8; aead::NONCE_LEN];
+            nonce[8..].copy_from_slice(&self.0.to_be_bytes());
+            self.0 += 1;
+            Ok(aead::Nonce::assume_unique_for_key(nonce))
+        }
+    }
+
+    const RECORD_LEN: usize = 37;
+    const RING_CAPACITY: usize = 64;
+
+    for algorithm in [
+        &aead::AES_128_GCM,
+        &aead::AES_256_GCM,
+        #[cfg(not(feature = "fips"))]
+        &aead::CHACHA20_POLY1305,
+    ] {
+        let key_bytes = vec![3u8; algorithm.key_len()];
+        let mut sealing_key = aead::SealingKey::new(
+            aead::UnboundKey::new(algorithm, &key_bytes).unwrap(),
+            CounterNonceSequence(0),
+        );
+        let record_len = RECORD_LEN + algorithm.tag_len();
+
+        // A bounded transmit ring buffer that is drained to the "network"
+        // whenever the next record wouldn't fit.
+        let mut ring = VecDeque::with_capacity(RING_CAPACITY);
+        let mut transmitted = Vec::new();
+        for i in 0..4u8 {
+            if ring.len() + record_len > RING_CAPACITY {
+                transmitted.extend(ring.drain(..));
+            }
+            let mut in_out = [i; RECORD_LEN];
+            sealing_key
+                .seal_in_place_into(aead::Aad::from([i]), &mut in_out, &mut ring)
+                .unwrap();
+            assert!(ring.len() <= RING_CAPACITY);
+        }
+        transmitted.extend(ring.drain(..));
+        assert_eq!(transmitted.len(), 4 * record_len);
+
+        let mut opening_key = aead::OpeningKey::new(
+            aead::UnboundKey::new(algorithm, &key_bytes).unwrap(),
+            CounterNonceSequence(0),
+        );
+        for (i, record) in transmitted.chunks_mut(record_len).enumerate() {
+            let i = i as u8;
+            let plaintext = opening_key
+                .open_in_place(aead::Aad::from([i]), record)
+                .unwrap();
+            assert_eq!(plaintext, &[i; RECORD_LEN][..]);
+        }
+    }
+}
+
+#[test]
+fn test_aead_sealing_key_reject_trivial_nonces() {
+    let zero_nonce = || aead::Nonce::assume_unique_for_key([0; aead::NONCE_LEN]);
+    let nonzero_nonce = || {
+        let mut nonce = [0; aead::NONCE_LEN];
+        nonce[aead::NONCE_LEN - 1] = 1;
+        aead::Nonce::assume_unique_for_key(nonce)
+    };
+
+    for algorithm in [
+        &aead::AES_128_GCM,
+        &aead::AES_256_GCM,
+        #[cfg(not(feature = "fips"))]
+        &aead::CHACHA20_POLY1305,
+    ] {
+        let key_bytes = vec![5u8; algorithm.key_len()];
+
+        // Accepted by default.
+        let mut key: aead::SealingKey<_> = make_key(algorithm, &key_bytes, zero_nonce());
+        let mut in_out = [0u8; 8];
+        assert!(key
+            .seal_in_place_separate_tag(aead::Aad::empty(), &mut in_out)
+            .is_ok());
+
+        // Rejected when opted in.
+        let key: aead::SealingKey<_> = make_key(algorithm, &key_bytes, zero_nonce());
+        let mut key = key.reject_trivial_nonces();
+        let mut in_out = vec![0u8; 8];
+        assert!(key
+            .seal_in_place_append_tag(aead::Aad::empty(), &mut in_out)
+            .is_err());
+        assert_eq!(in_out, [0u8; 8]);
+
+        // Other nonces are still accepted when opted in.
+        let key: aead::SealingKey<_> = make_key(algorithm, &key_bytes, nonzero_nonce());
+        let mut key = key.reject_trivial_nonces();
+        let mut in_out = [0u8; 8];
+        assert!(key
+            .seal_in_place_separate_tag(aead::Aad::empty(), &mut in_out)
+            .is_ok());
+    }
+}
+
+#[test]
+fn test_aead_seal_in_place_append_tag_reserved() {
+    for algorithm in [
+        &aead::AES_128_GCM,
+        &aead::AES_256_GCM,
+        #[cfg(not(feature = "fips"))]
+        &aead::CHACHA20_POLY1305,
+    ] {
+        let key = make_less_safe_key(algorithm, &vec![0x42; algorithm.key_len()]);
+        let nonce = || aead::Nonce::assume_unique_for_key([7; aead::NONCE_LEN]);
+        let plaintext = [0x5a; 100];
+
+        // With space reserved for the tag, the tag is appended without
+        // reallocating.
+        let mut reserved = Vec::with_capacity(plaintext.len() + algorithm.tag_len());
+        reserved.extend_from_slice(&plaintext);
+        let (ptr, capacity) = (reserved.as_ptr(), reserved.capacity());
+        key.seal_in_place_append_tag(nonce(), aead::Aad::empty(), &mut reserved)
+            .unwrap();
+        assert_eq!(reserved.len(), plaintext.len() + algorithm.tag_len());
+        assert_eq!(reserved.as_ptr(), ptr);
+        assert_eq!(reserved.capacity(), capacity);
+
+        // Without it, sealing still works and gives the same result.
+        let mut unreserved = plaintext.to_vec();
+        unreserved.shrink_to_fit();
+        key.seal_in_place_append_tag(nonce(), aead::Aad::empty(), &mut unreserved)
+            .unwrap();
+        assert_eq!(unreserved, reserved);
+
+        let opened = key
+            .open_in_place(nonce(), aead::Aad::empty(), &mut reserved)
+            .unwrap();
+        assert_eq!(opened, &plaintext[..]);
+    }
+}
+
+#[test]
+fn test_aead_ciphertext_overhead() {
+    for algorithm in [
+        &aead::AES_128_GCM,
+        &aead::AES_256_GCM,
+        #[cfg(not(feature = "fips"))]
+        &aead::CHACHA20_POLY1305,
+    ] {
+        assert_eq!(algorithm.ciphertext_overhead(), 16);
+
+        let key = make_less_safe_key(algorithm, &vec![0; algorithm.key_len()]);
+        let nonce = aead::Nonce::assume_unique_for_key([0; aead::NONCE_LEN]);
+        let mut in_out = vec![0u8; 100];
+        key.seal_in_place_append_tag(nonce, aead::Aad::empty(), &mut in_out)
+            .unwrap();
+        assert_eq!(in_out.len(), 100 + algorithm.ciphertext_overhead());
+    }
+}
+
+#[test]
+fn test_aead_algorithm_params() {
+    let algorithms = [
+        &aead::AES_128_GCM,
+        &aead::AES_256_GCM,
+        #[cfg(not(feature = "fips"))]
+        &aead::AES_128_GCM_SIV,
+        #[cfg(not(feature = "fips"))]
+        &aead::AES_256_GCM_SIV,
+        #[cfg(not(feature = "fips"))]
+        &aead::CHACHA20_POLY1305,
+        #[cfg(not(feature = "fips"))]
+        &aead::XCHACHA20_POLY1305,
+        #[cfg(not(feature = "fips"))]
+        &aead::AEGIS_128L,
+    ];
+    for (i, algorithm) in algorithms.iter().enumerate() {
+        let params = algorithm.params();
+        assert_eq!(params.key_len(), algorithm.key_len());
+        assert_eq!(params.nonce_len(), algorithm.nonce_len());
+        assert_eq!(params.tag_len(), algorithm.tag_len());
+
+        // Every algorithm has a unique ID.
+        for other in &algorithms[..i] {
+            assert_ne!(params.id(), other.params().id());
+        }
+
+        assert_eq!(aead::algorithm_by_id(params.id()), Some(*algorithm));
+    }
+
+    // The IDs are from the IANA "AEAD Algorithms" registry, so they must
+    // never change.
+    assert_eq!(aead::AES_128_GCM.params().id(), 1);
+    assert_eq!(aead::AES_256_GCM.params().id(), 2);
+    #[cfg(not(feature = "fips"))]
+    {
+        assert_eq!(aead::AES_128_GCM_SIV.params().id(), 30);
+        assert_eq!(aead::AES_256_GCM_SIV.params().id(), 31);
+        assert_eq!(aead::CHACHA20_POLY1305.params().id(), 29);
+
+        // XChaCha20-Poly1305 isn't registered, so it has an ID from the
+        // Private Use range.
+        assert_eq!(aead::XCHACHA20_POLY1305.params().id(), 32768);
+        assert_eq!(
+            aead::XCHACHA20_POLY1305.nonce_len(),
+            aead::xchacha20_poly1305::NONCE_LEN
+        );
+
+        assert_eq!(aead::AEGIS_128L.params().id(), 32);
+        assert_eq!(aead::AEGIS_128L.nonce_len(), aead::aegis128l::NONCE_LEN);
+    }
+
+    assert_eq!(aead::algorithm_by_id(0), None);
+    assert_eq!(aead::algorithm_by_id(3), None);
+}
+
+#[cfg(not(feature = "fips"))]
+#[test]
+fn test_aead_aes_gcm_siv_nonce_reuse() {
+    for (algorithm, key_bytes) in [
+        (&aead::AES_128_GCM_SIV, &[1; 16][..]),
+        (&aead::AES_256_GCM_SIV, &[1; 32][..]),
+    ] {
+        let key = make_less_safe_key(algorithm, key_bytes);
+        let seal = |plaintext: &[u8]| {
+            let mut in_out = plaintext.to_vec();
+            let nonce = aead::Nonce::assume_unique_for_key([2; aead::NONCE_LEN]);
+            key.seal_in_place_append_tag(nonce, aead::Aad::from(b"aad"), &mut in_out)
+                .unwrap();
+            in_out
+        };
+
+        // Plaintexts that share a long prefix, sealed with the same nonce,
+        // don't produce ciphertexts that share any block, unlike AES-GCM.
+        let mut a = [0x55u8; 64];
+        let b = a;
+        a[63] ^= 1;
+        let (sealed_a, sealed_b) = (seal(&a), seal(&b));
+        for (block_a, block_b) in sealed_a.chunks(16).zip(sealed_b.chunks(16)) {
+            assert_ne!(block_a, block_b);
+        }
+
+        // Only sealing the same plaintext twice is detectable.
+        assert_eq!(seal(&a), sealed_a);
+
+        // The keystream depends on the tag, so opening without it fails.
+        let mut in_out = sealed_a[..64].to_vec();
+        let nonce = aead::Nonce::assume_unique_for_key([2; aead::NONCE_LEN]);
+        assert!(unsafe {
+            key.open_in_place_unchecked(nonce, aead::Aad::from(b"aad"), &mut in_out, 0..)
+        }
+        .is_err());
+    }
+}
+
+#[test]
+fn test_aead_streaming_key() {
+    const SEGMENT_LEN: usize = 16;
+
+    fn seal(algorithm: &'static aead::Algorithm, key: &[u8], message: &[u8]) -> Vec<Vec<u8>> {
+        let nonce = aead::Nonce::assume_unique_for_key(BASE_NONCE);
+        let mut sealer =
+            aead::StreamingSealingKey::new(algorithm, key, nonce, SEGMENT_LEN).unwrap();
+        let mut chunks = message.chunks(SEGMENT_LEN).peekable();
+        let mut sealed = Vec::new();
+        // A message that is a multiple of the segment length, including
+        // the empty message, ends with an empty last segment.
+        while let Some(chunk) = chunks.next() {
+            let mut in_out = chunk.to_vec();
+            if chunks.peek().is_none() && chunk.len() < SEGMENT_LEN {
+                sealer.seal_last_chunk(&mut in_out).unwrap();
+                sealed.push(in_out);
+                return sealed;
+            }
+            sealer.seal_chunk(&mut in_out).unwrap();
+            sealed.push(in_out);
+        }
+        let mut in_out = Vec::new();
+        sealer.seal_last_chunk(&mut in_out).unwrap();
+        sealed.push(in_out);
+        sealed
+    }
+
+    fn open(
+        algorithm: &'static aead::Algorithm,
+        key: &[u8],
+        sealed: &[Vec<u8>],
+    ) -> Result<Vec<u8>, error::Unspecified> {
+        let nonce = aead::Nonce::assume_unique_for_key(BASE_NONCE);
+        let mut opener = aead::StreamingOpeningKey::new(algorithm, key, nonce, SEGMENT_LEN)?;
+        let (last, rest) = sealed.split_last().unwrap();
+        let mut message = Vec::new();
+        for segment in rest {
+            let mut in_out = segment.clone();
+            message.extend_from_slice(opener.open_chunk(&mut in_out)?);
+        }
+        let mut in_out = last.clone();
+        message.extend_from_slice(opener.open_last_chunk(&mut in_out)?);
+        Ok(message)
+    }
+
+    const BASE_NONCE: [u8; aead::NONCE_LEN] = [
+        0xa0, 0xa1, 0xa2, 0xa3, 0xa4, 0xa5, 0xa6, 0xa7, 0xa8, 0xa9, 0xaa, 0xab,
+    ];
+
+    // The framing is as documented, checked with an independent
+    // implementation.
+    let key: Vec<u8> = (0..16).collect();
+    let message: Vec<u8> = (0..40).collect();
+    let expected = test::from_hex(
+        "aa873ab87a8c350d8271bf0b4a1fbe6f7e94d7a999f8a782b235a4e92f3970ab\
+         2feb7489beff9af81fb20c96127b5f6b2f2b11ad156e625b6c51a41c7509c0e9\
+         c3ef7ef0af6ce7e103fe1942040221f2520ea1467cf48612",
+    )
+    .unwrap();
+    assert_eq!(seal(&aead::AES_128_GCM, &key, &message).concat(), expected);
+
+    for algorithm in [
+        &aead::AES_128_GCM,
+        &aead::AES_256_GCM,
+        #[cfg(not(feature = "fips"))]
+        &aead::CHACHA20_POLY1305,
+    ] {
+        let key = vec![7; algorithm.key_len()];
+        let message: Vec<u8> = (0..100).collect();
+        for len in [0, 1, SEGMENT_LEN - 1, SEGMENT_LEN, 3 * SEGMENT_LEN, 100] {
+            let message = &message[..len];
+            let sealed = seal(algorithm, &key, message);
+            assert_eq!(open(algorithm, &key, &sealed).unwrap(), message);
+
+            // Truncating the stream at a segment boundary is detected.
+            if sealed.len() > 1 {
+                assert!(open(algorithm, &key, &sealed[..(sealed.len() - 1)]).is_err());
+            }
+
+            // Reordering segments is detected.
+            if sealed.len() > 2 {
+                let mut reordered = sealed.clone();
+                reordered.swap(0, 1);
+                assert!(open(algorithm, &key, &reordered).is_err());
+            }
+        }
+    }
+
+    // Only the last segment may be shorter than the segment length.
+    let nonce = aead::Nonce::assume_unique_for_key(BASE_NONCE);
+    let mut sealer =
+        aead::StreamingSealingKey::new(&aead::AES_128_GCM, &key, nonce, SEGMENT_LEN).unwrap();
+    assert!(sealer.seal_chunk(&mut vec![0; SEGMENT_LEN - 1]).is_err());
+    assert!(sealer.seal_chunk(&mut vec![0; SEGMENT_LEN + 1]).is_err());
+    assert!(sealer
+        .seal_last_chunk(&mut vec![0; SEGMENT_LEN + 1])
+        .is_err());
+
+    let nonce = aead::Nonce::assume_unique_for_key(BASE_NONCE);
+    assert!(aead::StreamingSealingKey::new(&aead::AES_128_GCM, &key, nonce, 0).is_err());
+}
+
+#[test]
+fn test_aead_rekeyable_key() {
+    fn seal(key: aead::RekeyableKey) -> Vec<u8> {
+        seal_unbound(key.unbound_key())
+    }
+
+    fn seal_unbound(key: aead::UnboundKey) -> Vec<u8> {
+        let key = aead::LessSafeKey::new(key);
+        let nonce = aead::Nonce::assume_unique_for_key([0; aead::NONCE_LEN]);
+        let mut in_out = vec![0u8; 32];
+        key.seal_in_place_append_tag(nonce, aead::Aad::empty(), &mut in_out)
+            .unwrap();
+        in_out
+    }
+
+    for algorithm in [
+        &aead::AES_128_GCM,
+        &aead::AES_256_GCM,
+        #[cfg(not(feature = "fips"))]
+        &aead::CHACHA20_POLY1305,
+    ] {
+        let key_bytes = vec![9u8; algorithm.key_len()];
+        let key = || aead::RekeyableKey::new(algorithm, &key_bytes).unwrap();
+
+        let rekeyed = key().rekey(b"epoch 1");
+        assert_eq!(rekeyed.algorithm(), algorithm);
+        let rekeyed = seal(rekeyed);
+
+        // Rekeying is deterministic.
+        assert_eq!(seal(key().rekey(b"epoch 1")), rekeyed);
+
+        // Before rekeying, the key is the same as an `UnboundKey`.
+        let unbound = aead::UnboundKey::new(algorithm, &key_bytes).unwrap();
+        assert_eq!(seal(key()), seal_unbound(unbound));
+
+        // The new key differs from the old key, and depends on the context.
+        assert_ne!(seal(key()), rekeyed);
+        assert_ne!(seal(key().rekey(b"epoch 2")), rekeyed);
+        assert_ne!(seal(key().rekey(b"")), rekeyed);
+
+        // Rekeying can be chained, and each step gives a different key.
+        let twice = || key().rekey(b"epoch 1").rekey(b"epoch 1");
+        assert_eq!(seal(twice()), seal(twice()));
+        assert_ne!(seal(twice()), rekeyed);
+
+        // The key length is checked.
+        assert!(aead::RekeyableKey::new(algorithm, &key_bytes[1..]).is_err());
+    }
+}
+
+#[test]
+fn test_aead_open_in_place_bounded() {
+    const PLAINTEXT_LEN: usize = 100;
+
+    for algorithm in [
+        &aead::AES_128_GCM,
+        &aead::AES_256_GCM,
+        #[cfg(not(feature = "fips"))]
+        &aead::CHACHA20_POLY1305,
+    ] {
+        let key_bytes = vec![4u8; algorithm.key_len()];
+        let key = make_less_safe_key(algorithm, &key_bytes);
+        let nonce = || aead::Nonce::assume_unique_for_key([2; aead::NONCE_LEN]);
+
+        let mut sealed = vec![1u8; PLAINTEXT_LEN];
+        key.seal_in_place_append_tag(nonce(), aead::Aad::empty(), &mut sealed)
+            .unwrap();
+
+        // A plaintext that is exactly the maximum length is accepted.
+        let mut in_out = sealed.clone();
+        let plaintext = key
+            .open_in_place_bounded(nonce(), aead::Aad::empty(), &mut in_out, PLAINTEXT_LEN)
+            .unwrap();
+        assert_eq!(plaintext, &[1u8; PLAINTEXT_LEN][..]);
+
+        // A longer one is rejected before anything is decrypted, even though
+        // it is authentic.
+        let mut in_out = sealed.clone();
+        assert!(key
+            .open_in_place_bounded(nonce(), aead::Aad::empty(), &mut in_out, PLAINTEXT_LEN - 1)
+            .is_err());
+        assert_eq!(in_out, sealed);
+
+        // Inputs shorter than a tag are still rejected.
+        assert!(key
+            .open_in_place_bounded(nonce(), aead::Aad::empty(), &mut [0u8; 3], 0)
+            .is_err());
+
+        let mut opening_key: aead::OpeningKey<_> = make_key(algorithm, &key_bytes, nonce());
+        let mut in_out = sealed.clone();
+        assert!(opening_key
+            .open_in_place_bounded(aead::Aad::empty(), &mut in_out, 0)
+            .is_err());
+        assert_eq!(in_out, sealed);
+    }
+}
+
+#[cfg(all(feature = "alloc", not(feature = "fips")))]
+#[test]
+fn test_aead_seal_multi_recipient() {
+    const PLAINTEXT: &[u8] = b"broadcast message for every recipient";
+    let nonce = || aead::Nonce::assume_unique_for_key([6; aead::NONCE_LEN]);
+    let aad = || aead::Aad::from(b"header");
+
+    let content_key = make_less_safe_key(&aead::CHACHA20_POLY1305, &[1; 32]);
+    let recipient_keys = [
+        make_less_safe_key(&aead::AES_128_GCM, &[2; 16]),
+        make_less_safe_key(&aead::AES_256_GCM, &[3; 32]),
+        make_less_safe_key(&aead::AES_128_GCM, &[4; 16]),
+    ];
+    let recipient_key_refs = recipient_keys.iter().collect::<Vec<_>>();
+
+    let mut ciphertext = PLAINTEXT.to_vec();
+    let (content_tag, recipient_tags) = aead::seal_multi_recipient(
+        &content_key,
+        &recipient_key_refs,
+        nonce(),
+        aad(),
+        &mut ciphertext,
+    )
+    .unwrap();
+    assert_eq!(recipient_tags.len(), recipient_keys.len());
+    assert_ne!(&ciphertext[..], PLAINTEXT);
+
+    for (i, key) in recipient_keys.iter().enumerate() {
+        for (j, tag) in recipient_tags.iter().enumerate() {
+            let result = aead::verify_recipient_tag(key, nonce(), aad(), &ciphertext, tag);
+            assert_eq!(result.is_ok(), i == j);
+        }
+
+        // The tag covers the AAD and the ciphertext.
+        let tag = &recipient_tags[i];
+        assert!(
+            aead::verify_recipient_tag(key, nonce(), aead::Aad::empty(), &ciphertext, tag).is_err()
+        );
+        let mut tampered = ciphertext.clone();
+        tampered[0] ^= 1;
+        assert!(aead::verify_recipient_tag(key, nonce(), aad(), &tampered, tag).is_err());
+    }
+
+    // A recipient then opens the ciphertext with the content key.
+    let mut in_out = ciphertext.clone();
+    let plaintext = content_key
+        .open_in_place_separate_tag(nonce(), aad(), content_tag, &mut in_out, 0..)
+        .unwrap();
+    assert_eq!(plaintext, PLAINTEXT);
+
+    // Recipient keys must be AES-GCM keys.
+    let chacha_recipient = make_less_safe_key(&aead::CHACHA20_POLY1305, &[5; 32]);
+    let mut in_out = PLAINTEXT.to_vec();
+    assert!(aead::seal_multi_recipient(
+        &content_key,
+        &[&recipient_keys[0], &chacha_recipient],
+        nonce(),
+        aad(),
+        &mut in_out,
+    )
+    .is_err());
+    assert_eq!(in_out, PLAINTEXT);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_aead_open_oblivious() {
+    const PLAINTEXT: &[u8] = b"message for exactly one of the keys";
+    let nonce = || aead::Nonce::assume_unique_for_key([8; aead::NONCE_LEN]);
+    let aad = || aead::Aad::from(b"header");
+
+    let keys = [
+        make_less_safe_key(&aead::AES_128_GCM, &[1; 16]),
+        make_less_safe_key(&aead::AES_256_GCM, &[2; 32]),
+        #[cfg(not(feature = "fips"))]
+        make_less_safe_key(&aead::CHACHA20_POLY1305, &[3; 32]),
+        #[cfg(not(feature = "fips"))]
+        make_less_safe_key(&aead::AES_128_GCM_SIV, &[4; 16]),
+    ];
+    let key_refs = keys.iter().collect::<Vec<_>>();
+
+    for (sealer, sealing_key) in keys.iter().enumerate() {
+        let mut sealed = PLAINTEXT.to_vec();
+        sealing_key
+            .seal_in_place_append_tag(nonce(), aad(), &mut sealed)
+            .unwrap();
+
+        for selector in 0..keys.len() {
+            let mut in_out = sealed.clone();
+            let result = aead::open_oblivious(&key_refs, selector, nonce(), aad(), &mut in_out);
+            if selector == sealer {
+                assert_eq!(result.unwrap(), PLAINTEXT);
+            } else {
+                assert!(result.is_err());
+                assert!(in_out[..PLAINTEXT.len()].iter().all(|&b| b == 0));
+            }
+        }
+
+        // The same key in a different position is still selected correctly.
+        let mut reversed = key_refs.clone();
+        reversed.reverse();
+        let mut in_out = sealed.clone();
+        let plaintext = aead::open_oblivious(
+            &reversed,
+            keys.len() - 1 - sealer,
+            nonce(),
+            aad(),
+            &mut in_out,
+        )
+        .unwrap();
+        assert_eq!(plaintext, PLAINTEXT);
+
+        // A tampered ciphertext, a wrong AAD, or an out-of-range selector
+        // fail.
+        let mut tampered = sealed.clone();
+        tampered[0] ^= 1;
+        assert!(aead::open_oblivious(&key_refs, sealer, nonce(), aad(), &mut tampered).is_err());
+        let mut in_out = sealed.clone();
+        assert!(
+            aead::open_oblivious(&key_refs, sealer, nonce(), aead::Aad::empty(), &mut in_out)
+                .is_err()
+        );
+        let mut in_out = sealed.clone();
+        assert!(aead::open_oblivious(&key_refs, keys.len(), nonce(), aad(), &mut in_out).is_err());
+    }
+
+    // Too short to hold a tag.
+    let mut in_out = [0u8; aead::MAX_TAG_LEN - 1];
+    assert!(aead::open_oblivious(&key_refs, 0, nonce(), aad(), &mut in_out).is_err());
+}
+
+#[test]
+fn test_aead_seal_blocks() {
+    let nonce = || aead::Nonce::assume_unique_for_key([7; aead::NONCE_LEN]);
+    let aad = || aead::Aad::from(b"header");
+
+    for (alg, key_bytes) in [
+        (&aead::AES_128_GCM, &[8; 16][..]),
+        (&aead::AES_256_GCM, &[9; 32][..]),
+    ] {
+        let key = make_less_safe_key(alg, key_bytes);
+
+        for len in [0, 1, 15, 16, 17, 32, 100, 3 * 1024 + 5] {
+            let plaintext = (0..len).map(|i| i as u8).collect::<Vec<u8>>();
+
+            let mut expected = plaintext.clone();
+            let expected_tag = key
+                .seal_in_place_separate_tag(nonce(), aad(), &mut expected)
+                .unwrap();
+
+            // Generate the blocks on the fly instead of slicing `plaintext`.
+            let whole_len = len - (len % 16);
+            let blocks = (0..whole_len / 16).map(|i| {
+                let mut block = [0u8; 16];
+                for (j, b) in block.iter_mut().enumerate() {
+                    *b = (i * 16 + j) as u8;
+                }
+                block
+            });
+            let mut remainder = plaintext[whole_len..].to_vec();
+            let mut ciphertext = Vec::new();
+            let tag = key
+                .seal_blocks(nonce(), aad(), blocks, &mut remainder, |block| {
+                    ciphertext.extend_from_slice(&block)
+                })
+                .unwrap();
+            ciphertext.extend_from_slice(&remainder);
+
+            assert_eq!(ciphertext, expected);
+            assert_eq!(tag.as_ref(), expected_tag.as_ref());
+        }
+
+        // The final block must be partial.
+        let blocks = core::iter::empty();
+        assert!(key
+            .seal_blocks(nonce(), aad(), blocks, &mut [0; 16], |_| unreachable!())
+            .is_err());
+    }
+
+    // Only AES-GCM is supported.
+    #[cfg(not(feature = "fips"))]
+    {
+        let key = make_less_safe_key(&aead::CHACHA20_POLY1305, &[10; 32]);
+        let blocks = core::iter::once([0; 16]);
+        assert!(key
+            .seal_blocks(nonce(), aad(), blocks, &mut [], |_| unreachable!())
+            .is_err());
+    }
+}
+
+#[test]
+fn test_aead_seal_builder_order() {
+    let nonce = || aead::Nonce::assume_unique_for_key([3; aead::NONCE_LEN]);
+    let aad = b"header";
+    let plaintext = b"the plaintext, which is longer than one block";
+
+    for (alg, key_bytes) in [
+        (&aead::AES_128_GCM, &[1; 16][..]),
+        (&aead::AES_256_GCM, &[2; 32][..]),
+        #[cfg(not(feature = "fips"))]
+        (&aead::CHACHA20_POLY1305, &[3; 32][..]),
+    ] {
+        let key = make_less_safe_key(alg, key_bytes);
+
+        let mut expected = *plaintext;
+        let expected_tag = key
+            .seal_in_place_separate_tag(nonce(), aead::Aad::from(aad), &mut expected)
+            .unwrap();
+
+        let mut in_out = *plaintext;
+        let tag = aead::SealBuilder::new(&key, nonce())
+            .plaintext(&mut in_out)
+            .aad(aad)
+            .seal()
+            .unwrap();
+        assert_eq!(in_out, expected);
+        assert_eq!(tag.as_ref(), expected_tag.as_ref());
+
+        let mut in_out = *plaintext;
+        let tag = aead::SealBuilder::new(&key, nonce())
+            .aad(aad)
+            .plaintext(&mut in_out)
+            .seal()
+            .unwrap();
+        assert_eq!(in_out, expected);
+        assert_eq!(tag.as_ref(), expected_tag.as_ref());
+
+        // Omitted inputs are empty.
+        let expected_tag = key
+            .seal_in_place_separate_tag(nonce(), aead::Aad::empty(), &mut [])
+            .unwrap();
+        let tag = aead::SealBuilder::new(&key, nonce()).seal().unwrap();
+        assert_eq!(tag.as_ref(), expected_tag.as_ref());
+    }
+}
+
+#[test]
+fn test_aead_deterministic_nonce() {
+    let key = hmac::Key::new(hmac::HMAC_SHA256, b"nonce derivation key");
+
+    // The nonce is the truncated HMAC of the counter and the message.
+    let counter = 7u64;
+    let message = b"message";
+    let expected = hmac::sign(&key, &[&counter.to_be_bytes()[..], message].concat());
+    assert_eq!(
+        aead::deterministic_nonce(&key, message, counter).as_ref(),
+        &expected.as_ref()[..aead::NONCE_LEN]
+    );
+
+    // The same inputs always give the same nonce.
+    assert_eq!(
+        aead::deterministic_nonce(&key, message, counter).as_ref(),
+        aead::deterministic_nonce(&key, message, counter).as_ref()
+    );
+
+    // Distinct messages with the same counter, and the same message with
+    // distinct counters, give distinct nonces.
+    let mut nonces = Vec::new();
+    for counter in 0..4u64 {
+        for message in [&b""[..], b"a", b"b", b"ab"] {
+            nonces.push(*aead::deterministic_nonce(&key, message, counter).as_ref());
+        }
+    }
+    let len = nonces.len();
+    nonces.sort_unstable();
+    nonces.dedup();
+    assert_eq!(nonces.len(), len);
+
+    // A different key gives a different nonce.
+    let other_key = hmac::Key::new(hmac::HMAC_SHA256, b"another key");
+    assert_ne!(
+        aead::deterministic_nonce(&key, message, counter).as_ref(),
+        aead::deterministic_nonce(&other_key, message, counter).as_ref()
+    );
+}
+
+#[test]
+fn test_aead_open_in_place_with_digest() {
+    let nonce = || aead::Nonce::assume_unique_for_key([7; aead::NONCE_LEN]);
+    let plaintext = b"content to be verified after decryption";
+
+    for (alg, key_bytes) in [
+        (&aead::AES_128_GCM, &[1; 16][..]),
+        (&aead::AES_256_GCM, &[2; 32][..]),
+        #[cfg(not(feature = "fips"))]
+        (&aead::CHACHA20_POLY1305, &[3; 32][..]),
+    ] {
+        let key = make_less_safe_key(alg, key_bytes);
+        let mut in_out = plaintext.to_vec();
+        key.seal_in_place_append_tag(nonce(), aead::Aad::from(b"aad"), &mut in_out)
+            .unwrap();
+
+        for digest_alg in [&digest::SHA256, &digest::SHA384, &digest::SHA512] {
+            let mut ciphertext = in_out.clone();
+            let (opened, d) = key
+                .open_in_place_with_digest(
+                    nonce(),
+                    aead::Aad::from(b"aad"),
+                    &mut ciphertext,
+                    digest_alg,
+                )
+                .unwrap();
+            assert_eq!(opened, &plaintext[..]);
+            assert_eq!(d.algorithm(), digest_alg);
+            assert_eq!(d.as_ref(), digest::digest(digest_alg, opened).as_ref());
+        }
+
+        let mut ciphertext = in_out.clone();
+        assert!(key
+            .open_in_place_with_digest(
+                nonce(),
+                aead::Aad::from(b"wrong"),
+                &mut ciphertext,
+                &digest::SHA256,
+            )
+            .is_err());
+    }
+}
+
+#[cfg(all(feature = "alloc", not(feature = "fips")))]
+#[test]
+fn test_aead_replay_guard() {
+    let key = make_less_safe_key(&aead::CHACHA20_POLY1305, &[5; 32]);
+    let seal = |n: u8| {
+        let mut in_out = b"message".to_vec();
+        key.seal_in_place_append_tag(
+            aead::Nonce::assume_unique_for_key([n; aead::NONCE_LEN]),
+            aead::Aad::empty(),
+            &mut in_out,
+        )
+        .unwrap();
+        in_out
+    };
+    let open = |guard: &mut aead::ReplayGuard, n: u8, ciphertext: &[u8]| {
+        let mut in_out = ciphertext.to_vec();
+        guard
+            .open_in_place(
+                &key,
+                aead::Nonce::assume_unique_for_key([n; aead::NONCE_LEN]),
+                aead::Aad::empty(),
+                &mut in_out,
+            )
+            .map(|plaintext| plaintext.to_vec())
+    };
+
+    assert!(aead::ReplayGuard::new(0).is_err());
+    let mut guard = aead::ReplayGuard::new(2).unwrap();
+    assert_eq!(guard.capacity(), 2);
+
+    let c1 = seal(1);
+    let c2 = seal(2);
+    let c3 = seal(3);
+
+    // Fresh ciphertexts are accepted; replays are rejected.
+    assert_eq!(open(&mut guard, 1, &c1).unwrap(), b"message");
+    assert!(open(&mut guard, 1, &c1).is_err());
+    assert_eq!(open(&mut guard, 2, &c2).unwrap(), b"message");
+    assert!(open(&mut guard, 2, &c2).is_err());
+
+    // Forgeries aren't recorded, so they don't evict anything.
+    let mut forged = c3.clone();
+    forged[0] ^= 1;
+    assert!(open(&mut guard, 3, &forged).is_err());
+    assert!(open(&mut guard, 1, &c1).is_err());
+
+    // The least recently used entry (`c2`) is evicted once the cache is full.
+    assert_eq!(open(&mut guard, 3, &c3).unwrap(), b"message");
+    assert!(open(&mut guard, 1, &c1).is_err());
+    assert!(open(&mut guard, 3, &c3).is_err());
+    assert_eq!(open(&mut guard, 2, &c2).unwrap(), b"message");
+
+    // Inputs shorter than a tag are rejected.
+    assert!(open(&mut guard, 4, &[0; aead::MAX_TAG_LEN - 1]).is_err());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_aead_benchmark_select() {
+    ring::cpu::benchmark_select();
+    ring::cpu::benchmark_select(); // Only the first call does anything.
+
+    for (alg, test_file) in [
+        (&aead::AES_128_GCM, test_file!("aead_aes_128_gcm_tests.txt")),
+        (&aead::AES_256_GCM, test_file!("aead_aes_256_gcm_tests.txt")),
+    ] {
+        test_aead(alg, less_safe_key_seal_in_place_separate_tag, test_file);
+    }
+    test_aead(
+        &aead::AES_128_GCM,
+        less_safe_key_open_in_place,
+        test_file!("aead_aes_128_gcm_tests.txt"),
+    );
+}
+
+#[cfg(all(feature = "alloc", not(feature = "fips")))]
+#[test]
+fn test_aead_chacha20poly1305_from_password() {
+    use ring::argon2;
+
+    // Keep the test fast; real uses need much more memory.
+    let params = argon2::Params::new(64, 1, 1).unwrap();
+    let salt = [1; 16];
+    let password = b"correct horse battery staple";
+
+    let seal = |key: aead::UnboundKey| {
+        assert_eq!(key.algorithm(), &aead::CHACHA20_POLY1305);
+        let mut in_out = b"message".to_vec();
+        aead::LessSafeKey::new(key)
+            .seal_in_place_append_tag(
+                aead::Nonce::assume_unique_for_key([0; aead::NONCE_LEN]),
+                aead::Aad::empty(),
+                &mut in_out,
+            )
+            .unwrap();
+        in_out
+    };
+    let derive = |salt: &[u8], password: &[u8]| {
+        seal(aead::chacha20poly1305_from_password(&params, salt, password).unwrap())
+    };
+
+    // The same password and salt give the same key, which is the Argon2id
+    // output.
+    let sealed = derive(&salt, password);
+    assert_eq!(derive(&salt, password), sealed);
+    let mut key_bytes = [0; 32];
+    argon2::derive(&params, &salt, password, &mut key_bytes).unwrap();
+    let expected = seal(aead::UnboundKey::new(&aead::CHACHA20_POLY1305, &key_bytes).unwrap());
+    assert_eq!(sealed, expected);
+
+    // A different salt or password gives a different key.
+    assert_ne!(derive(&[2; 16], password), sealed);
+    assert_ne!(derive(&salt, b"wrong password"), sealed);
+
+    // The salt must be at least `argon2::MIN_SALT_LEN` bytes long.
+    assert!(aead::chacha20poly1305_from_password(
+        &params,
+        &salt[..argon2::MIN_SALT_LEN - 1],
+        password
+    )
+    .is_err());
+}
+
+#[cfg(feature = "debug-internals")]
+#[test]
+fn test_aead_gmac_current_xi() {
+    // The key and AAD of test case 4 of "The Galois/Counter Mode of Operation
+    // (GCM)", with the intermediate values X_1 and X_2.
+    let key = test::from_hex("feffe9928665731c6d6a8f9467308308").unwrap();
+    let aad = test::from_hex("feedfacedeadbeeffeedfacedeadbeefabaddad2").unwrap();
+    let x_1 = test::from_hex("ed56aaf8a72d67049fdb9228edba1322").unwrap();
+    let x_2 = test::from_hex("cd47221ccef0554ee4bb044c88150352").unwrap();
+
+    let mut gmac = aead::gmac::Gmac::new(key.as_slice().try_into().unwrap()).unwrap();
+    assert_eq!(gmac.current_xi(), [0; 16]);
+    gmac.update(&aad[..16]);
+    assert_eq!(&gmac.current_xi()[..], &x_1[..]);
+    gmac.update(&aad[16..]);
+    assert_eq!(&gmac.current_xi()[..], &x_2[..]);
+}
+
 fn make_key<K: aead::BoundKey<OneNonceSequence>>(
     algorithm: &'static aead::Algorithm,
     key: &[u8],
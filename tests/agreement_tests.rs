@@ -20,7 +20,8 @@ wasm_bindgen_test_configure!(run_in_browser);
 
 extern crate alloc;
 
-use ring::{agreement, error, rand, test, test_file};
+use core::num::NonZeroU32;
+use ring::{agreement, error, pbkdf2, rand, signature, test, test_file};
 
 #[test]
 fn agreement_traits() {
@@ -81,9 +82,11 @@ fn agreement_agree_ephemeral() {
 
         match test_case.consume_optional_string("Error") {
             None => {
-                let my_private = test_case.consume_bytes("D");
+                let my_private_bytes = test_case.consume_bytes("D");
                 let my_private = {
-                    let rng = test::rand::FixedSliceRandom { bytes: &my_private };
+                    let rng = test::rand::FixedSliceRandom {
+                        bytes: &my_private_bytes,
+                    };
                     agreement::EphemeralPrivateKey::generate(alg, &rng)?
                 };
                 let my_public = test_case.consume_bytes("MyQ");
@@ -96,6 +99,18 @@ fn agreement_agree_ephemeral() {
 
                 assert_eq!(my_private.algorithm(), alg);
 
+                let my_static =
+                    agreement::StaticPrivateKey::from_private_key_bytes(alg, &my_private_bytes)?;
+                assert_eq!(
+                    my_static.compute_public_key().unwrap().as_ref(),
+                    &my_public[..]
+                );
+                let static_output =
+                    agreement::agree_static(&my_static, &peer_public, |key_material| {
+                        Vec::from(key_material)
+                    });
+                assert_eq!(static_output, Ok(output.clone()));
+
                 let result = agreement::agree_ephemeral(my_private, &peer_public, |key_material| {
                     assert_eq!(key_material, &output[..]);
                 });
@@ -125,24 +140,336 @@ fn agreement_agree_ephemeral() {
     });
 }
 
+#[test]
+fn agreement_agree_static_two_peers() {
+    let rng = rand::SystemRandom::new();
+
+    for (alg, private_key_bytes) in [
+        (
+            &agreement::X25519,
+            h("77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a"),
+        ),
+        (
+            &agreement::ECDH_P256,
+            h("c88f01f510d9ac3f70a292daa2316de544e9aab8afe84049c62a9c57862d1433"),
+        ),
+    ] {
+        let my_private =
+            agreement::StaticPrivateKey::from_private_key_bytes(alg, &private_key_bytes).unwrap();
+        let my_public = my_private.compute_public_key().unwrap();
+        let my_public = agreement::UnparsedPublicKey::new(alg, my_public.as_ref());
+
+        let mut shared_secrets = Vec::new();
+        for _ in 0..2 {
+            let peer_private = agreement::EphemeralPrivateKey::generate(alg, &rng).unwrap();
+            let peer_public = peer_private.compute_public_key().unwrap();
+            let peer_public = agreement::UnparsedPublicKey::new(alg, peer_public.as_ref());
+
+            // The static key is borrowed, so it's still usable afterwards.
+            let ours = agreement::agree_static(&my_private, &peer_public, |key_material| {
+                Vec::from(key_material)
+            })
+            .unwrap();
+            let theirs = agreement::agree_ephemeral(peer_private, &my_public, |key_material| {
+                Vec::from(key_material)
+            })
+            .unwrap();
+            assert_eq!(ours, theirs);
+            shared_secrets.push(ours);
+        }
+        assert_ne!(shared_secrets[0], shared_secrets[1]);
+    }
+
+    // Private keys of the wrong length, and P-256 scalars that are zero or not
+    // less than the group order, are rejected.
+    for (alg, bytes) in [
+        (&agreement::X25519, &[0u8; 31][..]),
+        (&agreement::ECDH_P256, &[0u8; 32][..]),
+        (&agreement::ECDH_P256, &[0xff; 32][..]),
+        (&agreement::ECDH_P256, &[1u8; 33][..]),
+    ] {
+        assert!(agreement::StaticPrivateKey::from_private_key_bytes(alg, bytes).is_err());
+    }
+}
+
+#[test]
+fn agreement_public_key_matches() {
+    let rng = rand::SystemRandom::new();
+
+    for alg in [&agreement::ECDH_P256, &agreement::ECDH_P384] {
+        let private_key = agreement::EphemeralPrivateKey::generate(alg, &rng).unwrap();
+        let public_key = private_key.compute_public_key().unwrap();
+        let uncompressed = public_key.as_ref();
+
+        // SEC 1 compressed form: 0x02 or 0x03 depending on the parity of Y,
+        // followed by X.
+        let x = &uncompressed[1..][..(uncompressed.len() - 1) / 2];
+        let y_is_odd = uncompressed[uncompressed.len() - 1] & 1;
+        let compressed = [&[2 | y_is_odd][..], x].concat();
+        let wrong_parity = [&[3 ^ y_is_odd][..], x].concat();
+
+        assert!(public_key.matches(uncompressed));
+        assert!(public_key.matches(&compressed));
+        assert!(!public_key.matches(&wrong_parity));
+        assert!(!public_key.matches(&compressed[..compressed.len() - 1]));
+        assert!(!public_key.matches(&[]));
+
+        let other = agreement::EphemeralPrivateKey::generate(alg, &rng)
+            .unwrap()
+            .compute_public_key()
+            .unwrap();
+        assert!(!public_key.matches(other.as_ref()));
+    }
+
+    let private_key = agreement::EphemeralPrivateKey::generate(&agreement::X25519, &rng).unwrap();
+    let public_key = private_key.compute_public_key().unwrap();
+    assert!(public_key.matches(public_key.as_ref()));
+    assert!(!public_key.matches(&public_key.as_ref()[1..]));
+}
+
+#[test]
+fn test_agreement_x25519_from_passphrase() {
+    const PASSPHRASE: &[u8] = b"correct horse battery staple";
+    const SALT: &[u8] = b"ring x25519 test salt";
+    let iterations = NonZeroU32::new(1000).unwrap();
+
+    let derive = |salt: &[u8], passphrase: &[u8]| {
+        agreement::x25519::from_passphrase(pbkdf2::PBKDF2_HMAC_SHA256, iterations, salt, passphrase)
+            .unwrap()
+    };
+
+    let (private_key, public_key) = derive(SALT, PASSPHRASE);
+    assert_eq!(private_key.algorithm(), &agreement::X25519);
+    assert_eq!(
+        public_key.as_ref(),
+        &h("d084ba46b70681a5936b24ff13d58893dd49f47e90e87025a9bf1086b9cd8f47")[..]
+    );
+    assert_eq!(
+        format!("{:?}", &private_key),
+        "StaticPrivateKey { algorithm: Algorithm { curve: Curve25519 } }"
+    );
+
+    // The same inputs give the same key pair.
+    let (private_key_2, public_key_2) = derive(SALT, PASSPHRASE);
+    assert_eq!(public_key.as_ref(), public_key_2.as_ref());
+    assert_eq!(
+        private_key.compute_public_key().unwrap().as_ref(),
+        public_key_2.as_ref()
+    );
+
+    // Different inputs give different key pairs.
+    let (_, other_public_key) = derive(b"another salt", PASSPHRASE);
+    assert_ne!(public_key.as_ref(), other_public_key.as_ref());
+    let (other_private_key, other_public_key) = derive(SALT, b"another passphrase");
+    assert_ne!(public_key.as_ref(), other_public_key.as_ref());
+
+    // A static private key can be used for multiple key agreements, and both
+    // parties agree on the same value.
+    let agree = |private_key: &agreement::StaticPrivateKey, peer: &agreement::PublicKey| {
+        let peer = agreement::UnparsedPublicKey::new(&agreement::X25519, peer.as_ref());
+        agreement::agree_static(private_key, &peer, |key_material| Vec::from(key_material)).unwrap()
+    };
+    let shared = agree(&private_key, &other_public_key);
+    assert_eq!(shared, agree(&private_key_2, &other_public_key));
+    assert_eq!(shared, agree(&other_private_key, &public_key));
+
+    // The peer's algorithm must match.
+    let rng = rand::SystemRandom::new();
+    let p256_public_key = agreement::EphemeralPrivateKey::generate(&agreement::ECDH_P256, &rng)
+        .unwrap()
+        .compute_public_key()
+        .unwrap();
+    let p256_public_key =
+        agreement::UnparsedPublicKey::new(&agreement::ECDH_P256, p256_public_key.as_ref());
+    assert!(agreement::agree_static(&private_key, &p256_public_key, |_| ()).is_err());
+}
+
+#[test]
+fn test_agreement_x25519_from_ed25519() {
+    test::run(
+        test_file!("agreement_x25519_from_ed25519_tests.txt"),
+        |section, test_case| {
+            assert_eq!(section, "");
+            let seed = test_case.consume_bytes("Seed");
+            let ed25519_public_key = test_case.consume_bytes("Ed25519");
+            let x25519_public_key = test_case.consume_bytes("X25519");
+            let peer = test_case.consume_bytes("Peer");
+            let shared = test_case.consume_bytes("Shared");
+
+            let key_pair =
+                signature::Ed25519KeyPair::from_seed_and_public_key(&seed, &ed25519_public_key)
+                    .unwrap();
+            let (private_key, public_key) =
+                agreement::x25519::from_ed25519_key_pair(&key_pair).unwrap();
+            assert_eq!(public_key.as_ref(), &x25519_public_key[..]);
+
+            let converted =
+                agreement::x25519::public_key_from_ed25519(&ed25519_public_key).unwrap();
+            assert_eq!(converted.algorithm(), &agreement::X25519);
+            assert_eq!(&converted.bytes()[..], &x25519_public_key[..]);
+
+            let peer = agreement::UnparsedPublicKey::new(&agreement::X25519, &peer);
+            let agreed = agreement::agree_static(&private_key, &peer, |key_material| {
+                Vec::from(key_material)
+            })
+            .unwrap();
+            assert_eq!(agreed, shared);
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn test_agreement_x25519_public_key_from_ed25519_invalid() {
+    // Wrong length.
+    assert!(agreement::x25519::public_key_from_ed25519(&[0; 31]).is_err());
+    assert!(agreement::x25519::public_key_from_ed25519(&[0; 33]).is_err());
+
+    // y = 2 isn't the y-coordinate of any point on the curve.
+    let mut not_on_curve = [0u8; 32];
+    not_on_curve[0] = 2;
+    assert!(agreement::x25519::public_key_from_ed25519(&not_on_curve).is_err());
+
+    // The neutral element converts to zero, with which key agreement fails.
+    let mut neutral = [0u8; 32];
+    neutral[0] = 1;
+    let converted = agreement::x25519::public_key_from_ed25519(&neutral).unwrap();
+    assert_eq!(converted.bytes(), &[0; 32]);
+    let rng = rand::SystemRandom::new();
+    let private_key = agreement::EphemeralPrivateKey::generate(&agreement::X25519, &rng).unwrap();
+    assert!(agreement::agree_ephemeral(private_key, &converted, |_| ()).is_err());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_agreement_x25519_generate_ephemeral_batch() {
+    // RFC 7748 Section 6.1. All of the randomness is drawn in one call.
+    let alice_private_key = h("77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a");
+    let bob_private_key = h("5dab087e624a8a4b79e17f8b83800ee66f3bb1292618b6fd1c2f8b27ff88e0eb");
+    let rng = test::rand::FixedSliceRandom {
+        bytes: &[alice_private_key, bob_private_key].concat(),
+    };
+    let mut batch = agreement::x25519::generate_ephemeral_batch(&rng, 2).unwrap();
+    assert_eq!(batch.len(), 2);
+    let (bob_private_key, bob_public_key) = batch.pop().unwrap();
+    let (alice_private_key, alice_public_key) = batch.pop().unwrap();
+    assert_eq!(
+        alice_public_key.as_ref(),
+        &h("8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a")[..]
+    );
+    assert_eq!(
+        bob_public_key.as_ref(),
+        &h("de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e146f882b4f")[..]
+    );
+    let shared = h("4a5d9d5ba4ce2de1728e3bf480350f25e07e21c947d19e3376f09b3c1e161742");
+    for (private_key, peer_public_key) in [
+        (alice_private_key, &bob_public_key),
+        (bob_private_key, &alice_public_key),
+    ] {
+        let peer_public_key =
+            agreement::UnparsedPublicKey::new(&agreement::X25519, peer_public_key.as_ref());
+        let agreed = agreement::agree_ephemeral(private_key, &peer_public_key, |key_material| {
+            Vec::from(key_material)
+        })
+        .unwrap();
+        assert_eq!(agreed, shared);
+    }
+
+    // Every key in a batch agrees with a peer.
+    let rng = rand::SystemRandom::new();
+    let batch = agreement::x25519::generate_ephemeral_batch(&rng, 16).unwrap();
+    assert_eq!(batch.len(), 16);
+    for (i, (private_key, public_key)) in batch.into_iter().enumerate() {
+        assert_eq!(private_key.algorithm(), &agreement::X25519);
+        assert_eq!(
+            private_key.compute_public_key().unwrap().as_ref(),
+            public_key.as_ref()
+        );
+
+        let peer_private_key =
+            agreement::EphemeralPrivateKey::generate(&agreement::X25519, &rng).unwrap();
+        let peer_public_key = peer_private_key.compute_public_key().unwrap();
+        let peer_public_key =
+            agreement::UnparsedPublicKey::new(&agreement::X25519, peer_public_key.as_ref());
+        let public_key = agreement::UnparsedPublicKey::new(&agreement::X25519, public_key.as_ref());
+        let agreed = agreement::agree_ephemeral(private_key, &peer_public_key, |key_material| {
+            Vec::from(key_material)
+        })
+        .unwrap();
+        let peer_agreed =
+            agreement::agree_ephemeral(peer_private_key, &public_key, |key_material| {
+                Vec::from(key_material)
+            })
+            .unwrap();
+        assert_eq!(agreed, peer_agreed, "key {}", i);
+    }
+
+    assert!(agreement::x25519::generate_ephemeral_batch(&rng, 0)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn test_agreement_x25519_scalar_mult() {
+    // RFC 7748 Section 5.2. In the second test vector, the most significant
+    // bit of the u-coordinate is set, and must be ignored.
+    for (scalar, u, expected) in [
+        (
+            "a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4",
+            "e6db6867583030db3594c1a424b15f7c726624ec26b3353b10a903a6d0ab1c4c",
+            "c3da55379de9c6908e94ea4df28d084f32eccf03491c71f754b4075577a28552",
+        ),
+        (
+            "4b66e9d4d1b4673c5ad22691957d6af5c11b6421e0ea01d42ca4169e7918ba0d",
+            "e5210f12786811d3f4b7959d0538ae2c31dbe7106fc03c3efc4cd549c715a493",
+            "95cbde9476e8907d7aade45cb4b873f88b595a68799fa152e6f8f7647aac7957",
+        ),
+    ] {
+        let scalar: [u8; 32] = h(scalar).try_into().unwrap();
+        let u: [u8; 32] = h(u).try_into().unwrap();
+        assert_eq!(
+            &agreement::x25519::scalar_mult(&scalar, &u)[..],
+            &h(expected)[..]
+        );
+    }
+
+    // Unlike key agreement, a point of small order gives an all-zero result
+    // instead of an error.
+    let scalar = [0x42; 32];
+    let mut one = [0; 32];
+    one[0] = 1;
+    for u in [[0; 32], one] {
+        assert_eq!(agreement::x25519::scalar_mult(&scalar, &u), [0; 32]);
+        assert!(x25519_(&scalar, &u).is_err());
+    }
+}
+
 #[test]
 fn test_agreement_ecdh_x25519_rfc_iterated() {
+    test_x25519_rfc_iterated(x25519);
+    test_x25519_rfc_iterated(|k, u| {
+        let k: &[u8; 32] = k.try_into().unwrap();
+        let u: &[u8; 32] = u.try_into().unwrap();
+        agreement::x25519::scalar_mult(k, u).to_vec()
+    });
+}
+
+fn test_x25519_rfc_iterated(x25519: fn(&[u8], &[u8]) -> Vec<u8>) {
     let mut k = h("0900000000000000000000000000000000000000000000000000000000000000");
     let mut u = k.clone();
 
-    fn expect_iterated_x25519(
-        expected_result: &str,
-        range: core::ops::Range<usize>,
-        k: &mut Vec<u8>,
-        u: &mut Vec<u8>,
-    ) {
+    let expect_iterated_x25519 = |expected_result: &str,
+                                  range: core::ops::Range<usize>,
+                                  k: &mut Vec<u8>,
+                                  u: &mut Vec<u8>| {
         for _ in range {
             let new_k = x25519(k, u);
             u.clone_from(k);
             *k = new_k;
         }
         assert_eq!(&h(expected_result), k);
-    }
+    };
 
     expect_iterated_x25519(
         "422c8e7a6227d7bca1350b3e2bb7279f7897b87bb6854b783c60e80311ae3079",
@@ -177,6 +504,53 @@ fn test_agreement_ecdh_x25519_rfc_iterated() {
     }
 }
 
+#[test]
+fn test_agreement_ecdh_x448_rfc_iterated() {
+    let mut k = h(
+        "0500000000000000000000000000000000000000000000000000000000000000\
+                   000000000000000000000000000000000000000000000000",
+    );
+    let mut u = k.clone();
+
+    let x448 = |private_key: &[u8], public_key: &[u8]| {
+        let rng = test::rand::FixedSliceRandom { bytes: private_key };
+        let private_key = agreement::EphemeralPrivateKey::generate(&agreement::X448, &rng).unwrap();
+        let public_key = agreement::UnparsedPublicKey::new(&agreement::X448, public_key);
+        agreement::agree_ephemeral(private_key, &public_key, |agreed_value| {
+            Vec::from(agreed_value)
+        })
+        .unwrap()
+    };
+
+    let mut expect_iterated_x448 = |expected_result: &str, iterations: usize| {
+        for _ in 0..iterations {
+            let new_k = x448(&k, &u);
+            u = core::mem::replace(&mut k, new_k);
+        }
+        assert_eq!(k, h(expected_result));
+    };
+
+    // RFC 7748 Section 5.2.
+    expect_iterated_x448(
+        "3f482c8a9f19b01e6c46ee9711d9dc14fd4bf67af30765c2ae2b846a4d23a8cd\
+         0db897086239492caf350b51f833868b9bc2b3bca9cf4113",
+        1,
+    );
+    expect_iterated_x448(
+        "aa3b4749d55b9daf1e5b00288826c467274ce3ebbdd5c17b975e09d4af6c67cf\
+         10d087202db88286e2b79fceea3ec353ef54faa26e219f38",
+        999,
+    );
+
+    if cfg!(feature = "slow_tests") {
+        expect_iterated_x448(
+            "077f453681caca3693198420bbe515cae0002472519b3e67661a7e89cab94695\
+             c8f4bcd66e61b9b9c946da8d524de3d69bd9d9d66b997e37",
+            999_000,
+        );
+    }
+}
+
 fn x25519(private_key: &[u8], public_key: &[u8]) -> Vec<u8> {
     x25519_(private_key, public_key).unwrap()
 }
@@ -206,6 +580,8 @@ fn alg_from_curve_name(curve_name: &str) -> &'static agreement::Algorithm {
         &agreement::ECDH_P384
     } else if curve_name == "X25519" {
         &agreement::X25519
+    } else if curve_name == "X448" {
+        &agreement::X448
     } else {
         panic!("Unsupported curve: {}", curve_name);
     }
@@ -0,0 +1,71 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+#![cfg(feature = "alloc")]
+
+use ring::{aont, error, rand, test, test_file};
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+fn aont_known_answer_test() {
+    test::run(test_file!("aont_tests.txt"), |section, test_case| {
+        assert_eq!(section, "");
+        let random_key = test_case.consume_bytes("RandomKey");
+        let message = test_case.consume_bytes("Message");
+        let expected = test_case.consume_bytes("Package");
+
+        let rng = test::rand::FixedSliceRandom { bytes: &random_key };
+        let package = aont::transform(&rng, &message)?;
+        assert_eq!(package, expected);
+        assert_eq!(aont::inverse_transform(&package)?, message);
+
+        Ok(())
+    });
+}
+
+#[test]
+fn aont_round_trip_test() {
+    let rng = rand::SystemRandom::new();
+    let message: Vec<u8> = (0..100).collect();
+    for len in 0..=message.len() {
+        let message = &message[..len];
+        let package = aont::transform(&rng, message).unwrap();
+        assert_eq!(package.len(), len + aont::OVERHEAD_LEN);
+        assert_eq!(aont::inverse_transform(&package).unwrap(), message);
+
+        // Changing any byte of the package changes the recovered random key,
+        // and so garbles every block of the message.
+        if len >= 32 {
+            let mut package = package;
+            package[0] ^= 1;
+            let garbled = aont::inverse_transform(&package).unwrap();
+            assert_ne!(&garbled[16..32], &message[16..32]);
+        }
+    }
+}
+
+#[test]
+fn aont_inverse_transform_too_short_test() {
+    for len in 0..aont::OVERHEAD_LEN {
+        assert_eq!(
+            aont::inverse_transform(&vec![0u8; len]),
+            Err(error::Unspecified)
+        );
+    }
+}
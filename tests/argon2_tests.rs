@@ -0,0 +1,105 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+#![cfg(feature = "alloc")]
+
+use ring::{argon2, error, test, test_file};
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+fn argon2_tests() {
+    test::run(test_file!("argon2_tests.txt"), |section, test_case| {
+        assert_eq!(section, "");
+        let m = test_case.consume_usize("M").try_into().unwrap();
+        let t = test_case.consume_usize("T").try_into().unwrap();
+        let p = test_case.consume_usize("P").try_into().unwrap();
+        let password = test_case.consume_bytes("Password");
+        let salt = test_case.consume_bytes("Salt");
+        let secret = test_case.consume_bytes("Secret");
+        let ad = test_case.consume_bytes("AD");
+        let tag = test_case.consume_bytes("Tag");
+
+        let params = argon2::Params::new(m, t, p).unwrap();
+
+        let mut out = vec![0u8; tag.len()];
+        argon2::derive_with_secret(&params, &salt, &password, &secret, &ad, &mut out).unwrap();
+        assert_eq!(out, tag);
+        assert_eq!(
+            argon2::verify_with_secret(&params, &salt, &password, &secret, &ad, &tag),
+            Ok(())
+        );
+
+        if secret.is_empty() && ad.is_empty() {
+            let mut out = vec![0u8; tag.len()];
+            argon2::derive(&params, &salt, &password, &mut out).unwrap();
+            assert_eq!(out, tag);
+            assert_eq!(argon2::verify(&params, &salt, &password, &tag), Ok(()));
+        }
+
+        let mut wrong_password = password.clone();
+        wrong_password.push(0);
+        assert_eq!(
+            argon2::verify_with_secret(&params, &salt, &wrong_password, &secret, &ad, &tag),
+            Err(error::Unspecified)
+        );
+
+        let mut wrong_tag = tag.clone();
+        wrong_tag[0] ^= 1;
+        assert_eq!(
+            argon2::verify_with_secret(&params, &salt, &password, &secret, &ad, &wrong_tag),
+            Err(error::Unspecified)
+        );
+
+        Ok(())
+    });
+}
+
+#[test]
+fn argon2_params_test() {
+    assert!(argon2::Params::new(8, 1, 1).is_ok());
+    assert!(argon2::Params::new(7, 1, 1).is_err());
+    assert!(argon2::Params::new(8, 0, 1).is_err());
+    assert!(argon2::Params::new(8, 1, 0).is_err());
+    assert!(argon2::Params::new(16, 1, 2).is_ok());
+    assert!(argon2::Params::new(15, 1, 2).is_err());
+    assert!(argon2::Params::new(u32::MAX, 1, 1 << 24).is_err());
+}
+
+#[test]
+fn argon2_input_lengths_test() {
+    let params = argon2::Params::new(8, 1, 1).unwrap();
+    let salt = [0u8; argon2::MIN_SALT_LEN];
+
+    let mut out = [0u8; argon2::MIN_OUTPUT_LEN];
+    assert!(argon2::derive(&params, &salt, b"password", &mut out).is_ok());
+    assert_eq!(
+        argon2::derive(&params, &salt[1..], b"password", &mut out),
+        Err(error::Unspecified)
+    );
+
+    let mut out = [0u8; argon2::MIN_OUTPUT_LEN - 1];
+    assert_eq!(
+        argon2::derive(&params, &salt, b"password", &mut out),
+        Err(error::Unspecified)
+    );
+    assert_eq!(
+        argon2::verify(&params, &salt, b"password", &out),
+        Err(error::Unspecified)
+    );
+}
@@ -0,0 +1,268 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+use ring::{cipher, test, test_file};
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+fn hchacha20_test() {
+    // draft-irtf-cfrg-xchacha-03 Section 2.2.1.
+    let key: [u8; cipher::HCHACHA20_KEY_LEN] =
+        test::from_hex("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f")
+            .unwrap()
+            .try_into()
+            .unwrap();
+    let nonce: [u8; cipher::HCHACHA20_NONCE_LEN] =
+        test::from_hex("000000090000004a0000000031415927")
+            .unwrap()
+            .try_into()
+            .unwrap();
+    let expected =
+        test::from_hex("82413b4227b27bfed30e42508a877d73a0f9e4d58a74a853c12ec41326d3ecdc").unwrap();
+
+    assert_eq!(&cipher::hchacha20(key, nonce)[..], &expected[..]);
+}
+
+#[test]
+fn aes_ctr_big_endian_test() {
+    // NIST SP 800-38A Appendix F.5.1 (CTR-AES128.Encrypt), truncated to end
+    // with a partial block.
+    let key = test::from_hex("2b7e151628aed2a6abf7158809cf4f3c").unwrap();
+    let initial_counter_block = test::from_hex("f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff")
+        .unwrap()
+        .try_into()
+        .unwrap();
+    let plaintext = test::from_hex(
+        "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e5130c81c46a35ce411",
+    )
+    .unwrap();
+    let ciphertext = test::from_hex(
+        "874d6191b620e3261bef6864990db6ce9806f66b7970fdff8617187bb9fffdff5ae4df3edbd5d35e",
+    )
+    .unwrap();
+
+    for counter in [
+        cipher::CounterConfig::BIG_ENDIAN_128,
+        cipher::CounterConfig::BIG_ENDIAN_32,
+    ] {
+        let key = cipher::CtrKey::new(&key, counter).unwrap();
+        let mut in_out = plaintext.clone();
+        key.apply_keystream(initial_counter_block, &mut in_out)
+            .unwrap();
+        assert_eq!(in_out, ciphertext);
+        key.apply_keystream(initial_counter_block, &mut in_out)
+            .unwrap();
+        assert_eq!(in_out, plaintext);
+    }
+}
+
+#[test]
+fn aes_ctr_little_endian_test() {
+    // A 64-bit little-endian counter in the last 8 bytes, starting just
+    // before a carry out of its lowest 32 bits. Computed with an independent
+    // implementation.
+    let key = test::from_hex("2b7e151628aed2a6abf7158809cf4f3c").unwrap();
+    let initial_counter_block = test::from_hex("f0f1f2f3f4f5f6f7feffffff00000000")
+        .unwrap()
+        .try_into()
+        .unwrap();
+    let plaintext = test::from_hex(
+        "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e5130c81c46a35ce411",
+    )
+    .unwrap();
+    let ciphertext = test::from_hex(
+        "65cde4e68b9644b505da421ed79ddd2f25164c409373bef3bff5864755d1d72678bf0cbd7a665510",
+    )
+    .unwrap();
+
+    let counter = cipher::CounterConfig::new(8, 8, cipher::ByteOrder::LittleEndian).unwrap();
+    let key = cipher::CtrKey::new(&key, counter).unwrap();
+    let mut in_out = plaintext.clone();
+    key.apply_keystream(initial_counter_block, &mut in_out)
+        .unwrap();
+    assert_eq!(in_out, ciphertext);
+}
+
+#[test]
+fn aes_ctr_stream_test() {
+    // NIST SP 800-38A Appendix F.5.1 (CTR-AES128.Encrypt), truncated to end
+    // with a partial block. Its counter never carries out of the last 32
+    // bits.
+    let key = test::from_hex("2b7e151628aed2a6abf7158809cf4f3c").unwrap();
+    let nonce = test::from_hex("f0f1f2f3f4f5f6f7f8f9fafb")
+        .unwrap()
+        .try_into()
+        .unwrap();
+    let plaintext = test::from_hex(
+        "6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e5130c81c46a35ce411",
+    )
+    .unwrap();
+    let ciphertext = test::from_hex(
+        "874d6191b620e3261bef6864990db6ce9806f66b7970fdff8617187bb9fffdff5ae4df3edbd5d35e",
+    )
+    .unwrap();
+
+    // Splitting the input into three pieces anywhere gives the same result.
+    for i in 0..=plaintext.len() {
+        for j in i..=plaintext.len() {
+            let mut ctr = cipher::Ctr::with_initial_counter(&key, nonce, 0xfcfdfeff).unwrap();
+            let mut in_out = plaintext.clone();
+            let (a, rest) = in_out.split_at_mut(i);
+            let (b, c) = rest.split_at_mut(j - i);
+            ctr.apply_keystream(a).unwrap();
+            ctr.apply_keystream(b).unwrap();
+            ctr.apply_keystream(c).unwrap();
+            assert_eq!(in_out, ciphertext);
+        }
+    }
+}
+
+#[test]
+fn aes_ctr_stream_counter_wrap_test() {
+    // The SP 800-38A F.5.1 key and nonce, with the keystream blocks for the
+    // block counters 2^32 - 3 and 2^32 - 2, computed independently with
+    // AES-ECB.
+    let key = test::from_hex("2b7e151628aed2a6abf7158809cf4f3c").unwrap();
+    let nonce: [u8; cipher::CTR_NONCE_LEN] = test::from_hex("f0f1f2f3f4f5f6f7f8f9fafb")
+        .unwrap()
+        .try_into()
+        .unwrap();
+    let second_to_last = test::from_hex("628158d5ed1abb642f4774272e045ae8").unwrap();
+    let last = test::from_hex("2f5dcd912d142c3d47192b41d1f72345").unwrap();
+
+    // The keystream ends before the block counter reaches 2^32 - 1.
+    let mut ctr = cipher::Ctr::with_initial_counter(&key, nonce, u32::MAX).unwrap();
+    assert!(ctr.apply_keystream(&mut [0]).is_err());
+    assert!(ctr.apply_keystream(&mut []).is_ok());
+
+    // Using the whole last block at once.
+    let mut ctr = cipher::Ctr::with_initial_counter(&key, nonce, u32::MAX - 1).unwrap();
+    let mut in_out = [0u8; cipher::AES_CTR_BLOCK_LEN + 1];
+    assert!(ctr.apply_keystream(&mut in_out).is_err());
+    assert_eq!(in_out, [0; cipher::AES_CTR_BLOCK_LEN + 1]);
+    ctr.apply_keystream(&mut in_out[..cipher::AES_CTR_BLOCK_LEN])
+        .unwrap();
+    assert_eq!(&in_out[..cipher::AES_CTR_BLOCK_LEN], &last[..]);
+    assert!(ctr.apply_keystream(&mut [0]).is_err());
+    assert!(ctr.apply_keystream(&mut []).is_ok());
+
+    // Using the last block across calls.
+    let mut ctr = cipher::Ctr::with_initial_counter(&key, nonce, u32::MAX - 1).unwrap();
+    let mut in_out = [0u8; cipher::AES_CTR_BLOCK_LEN];
+    let (a, b) = in_out.split_at_mut(5);
+    ctr.apply_keystream(a).unwrap();
+    let mut too_long = [0u8; cipher::AES_CTR_BLOCK_LEN];
+    assert!(ctr.apply_keystream(&mut too_long).is_err());
+    assert_eq!(too_long, [0; cipher::AES_CTR_BLOCK_LEN]);
+    ctr.apply_keystream(b).unwrap();
+    assert_eq!(&in_out[..], &last[..]);
+    assert!(ctr.apply_keystream(&mut [0]).is_err());
+
+    // Two blocks starting at 2^32 - 3 is fine, but not one byte more.
+    let mut ctr = cipher::Ctr::with_initial_counter(&key, nonce, u32::MAX - 2).unwrap();
+    let mut in_out = [0u8; 2 * cipher::AES_CTR_BLOCK_LEN + 1];
+    assert!(ctr.apply_keystream(&mut in_out).is_err());
+    assert_eq!(in_out, [0; 2 * cipher::AES_CTR_BLOCK_LEN + 1]);
+    let in_out = &mut in_out[..2 * cipher::AES_CTR_BLOCK_LEN];
+    ctr.apply_keystream(in_out).unwrap();
+    assert_eq!(&in_out[..cipher::AES_CTR_BLOCK_LEN], &second_to_last[..]);
+    assert_eq!(&in_out[cipher::AES_CTR_BLOCK_LEN..], &last[..]);
+    assert!(ctr.apply_keystream(&mut [0]).is_err());
+
+    assert!(cipher::Ctr::new(&[0; 24], nonce).is_err());
+}
+
+#[test]
+fn aes_ctr_counter_wrap_test() {
+    let key = cipher::CtrKey::new(
+        &[0; 16],
+        cipher::CounterConfig::new(0, 1, cipher::ByteOrder::LittleEndian).unwrap(),
+    )
+    .unwrap();
+    let mut initial_counter_block = [0u8; cipher::AES_CTR_BLOCK_LEN];
+    initial_counter_block[0] = 0xfe;
+
+    // Counter values 0xfe and 0xff are OK.
+    let mut in_out = [0u8; 2 * cipher::AES_CTR_BLOCK_LEN];
+    assert!(key
+        .apply_keystream(initial_counter_block, &mut in_out)
+        .is_ok());
+
+    // A third block would wrap the counter; nothing is encrypted.
+    let mut in_out = [0u8; 2 * cipher::AES_CTR_BLOCK_LEN + 1];
+    assert!(key
+        .apply_keystream(initial_counter_block, &mut in_out)
+        .is_err());
+    assert!(in_out.iter().all(|&b| b == 0));
+
+    // The counter must fit in the block.
+    assert!(cipher::CounterConfig::new(0, 0, cipher::ByteOrder::BigEndian).is_err());
+    assert!(cipher::CounterConfig::new(12, 5, cipher::ByteOrder::BigEndian).is_err());
+    assert!(cipher::CounterConfig::new(usize::MAX, 2, cipher::ByteOrder::BigEndian).is_err());
+    assert!(cipher::CtrKey::new(&[0; 24], cipher::CounterConfig::BIG_ENDIAN_32).is_err());
+}
+
+#[test]
+fn aes_xts_test() {
+    test::run(test_file!("aes_xts_tests.txt"), |section, test_case| {
+        assert_eq!(section, "");
+        let key1 = test_case.consume_bytes("Key1");
+        let key2 = test_case.consume_bytes("Key2");
+        let sector_index = test_case
+            .consume_bytes("DataUnit")
+            .iter()
+            .fold(0u128, |acc, &b| (acc << 8) | u128::from(b));
+        let plaintext = test_case.consume_bytes("PTX");
+        let ciphertext = test_case.consume_bytes("CTX");
+
+        let key = cipher::XtsKey::new(&key1, &key2).unwrap();
+        let mut in_out = plaintext.clone();
+        key.encrypt_sector(sector_index, &mut in_out).unwrap();
+        assert_eq!(in_out, ciphertext);
+        key.decrypt_sector(sector_index, &mut in_out).unwrap();
+        assert_eq!(in_out, plaintext);
+
+        Ok(())
+    });
+}
+
+#[test]
+fn aes_xts_bad_lengths_test() {
+    // Keys must both be AES-128 or both be AES-256 keys.
+    assert!(cipher::XtsKey::new(&[1; 16], &[2; 32]).is_err());
+    assert!(cipher::XtsKey::new(&[1; 24], &[2; 24]).is_err());
+
+    // The two keys must differ, e.g. IEEE 1619-2007 Vector 1 is rejected.
+    assert!(cipher::XtsKey::new(&[0; 16], &[0; 16]).is_err());
+    assert!(cipher::XtsKey::new(&[2; 32], &[2; 32]).is_err());
+
+    // Sectors must be at least one block long; nothing is modified.
+    let key = cipher::XtsKey::new(&[1; 32], &[2; 32]).unwrap();
+    let mut in_out = [0u8; cipher::XTS_BLOCK_LEN - 1];
+    assert!(key.encrypt_sector(0, &mut in_out).is_err());
+    assert!(key.decrypt_sector(0, &mut in_out).is_err());
+    assert_eq!(in_out, [0u8; cipher::XTS_BLOCK_LEN - 1]);
+
+    // A different sector index gives a different ciphertext.
+    let mut a = [0u8; cipher::XTS_BLOCK_LEN];
+    let mut b = [0u8; cipher::XTS_BLOCK_LEN];
+    key.encrypt_sector(0, &mut a).unwrap();
+    key.encrypt_sector(1, &mut b).unwrap();
+    assert_ne!(a, b);
+}
@@ -78,3 +78,159 @@ fn test_verify_slices_are_equal() {
         }
     }
 }
+
+#[test]
+fn test_self_test() {
+    assert_eq!(constant_time::self_test(), Ok(()));
+}
+
+#[test]
+fn test_secure_zero() {
+    for len in [0, 1, 15, 16, 17, 1000] {
+        let mut bytes = vec![0xa5u8; len];
+        constant_time::secure_zero(&mut bytes);
+        // Read the bytes back through volatile reads so the check itself
+        // can't be optimized based on what was written.
+        assert!((0..len).all(|i| unsafe { core::ptr::read_volatile(bytes.as_ptr().add(i)) } == 0));
+    }
+
+    // Only the given bytes are zeroed.
+    let mut bytes = [1u8; 8];
+    constant_time::secure_zero(&mut bytes[2..5]);
+    assert_eq!(bytes, [1, 1, 0, 0, 0, 1, 1, 1]);
+}
+
+#[test]
+fn test_decode_base64url() {
+    // RFC 4648 Section 10, which are also valid base64url.
+    const TEST_VECTORS: &[(&str, &[u8])] = &[
+        ("", b""),
+        ("Zg", b"f"),
+        ("Zg==", b"f"),
+        ("Zm8", b"fo"),
+        ("Zm8=", b"fo"),
+        ("Zm9v", b"foo"),
+        ("Zm9vYg", b"foob"),
+        ("Zm9vYg==", b"foob"),
+        ("Zm9vYmE", b"fooba"),
+        ("Zm9vYmE=", b"fooba"),
+        ("Zm9vYmFy", b"foobar"),
+        // Characters that differ between base64 and base64url.
+        ("-_-_", &[0xfb, 0xff, 0xbf]),
+    ];
+    for &(input, expected) in TEST_VECTORS {
+        let mut out = [0u8; 6];
+        let len = constant_time::decode_base64url(input.as_bytes(), &mut out).unwrap();
+        assert_eq!(&out[..len], expected, "{}", input);
+    }
+
+    const INVALID: &[&str] = &[
+        "Z",          // Impossible length.
+        "Zm9vY",      // Impossible length.
+        "Zg=",        // Padding without a whole group.
+        "Zg===",      // Too much padding.
+        "Z===",       // Too much padding.
+        "====",       // Only padding.
+        "Zm9v====",   // Padding a whole group.
+        "Zm9v=",      // Padding a whole group.
+        "Zm=v",       // Padding in the middle.
+        "Zh",         // Non-zero unused bits.
+        "Zm9",        // Non-zero unused bits.
+        "Zm9+",       // base64, not base64url.
+        "Zm9/",       // base64, not base64url.
+        "Zm9 ",       // Whitespace.
+        "Zm9v\nYmFy", // Whitespace.
+    ];
+    for input in INVALID {
+        let mut out = [0u8; 6];
+        assert_eq!(
+            constant_time::decode_base64url(input.as_bytes(), &mut out),
+            Err(error::Unspecified),
+            "{}",
+            input
+        );
+    }
+
+    // The output buffer must be big enough.
+    let mut out = [0u8; 5];
+    assert!(constant_time::decode_base64url(b"Zm9vYmFy", &mut out).is_err());
+    assert!(constant_time::decode_base64url(b"Zm9vYmE", &mut out).is_ok());
+}
+
+#[test]
+fn test_decode_base64url_against_reference() {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+    fn reference_encode(input: &[u8]) -> Vec<u8> {
+        let mut out = Vec::new();
+        for chunk in input.chunks(3) {
+            let mut group = [0u8; 3];
+            group[..chunk.len()].copy_from_slice(chunk);
+            let bits =
+                (u32::from(group[0]) << 16) | (u32::from(group[1]) << 8) | u32::from(group[2]);
+            for i in 0..=chunk.len() {
+                out.push(ALPHABET[((bits >> (18 - 6 * i)) & 0x3f) as usize]);
+            }
+        }
+        out
+    }
+
+    // Every single character is either in the alphabet, and decodes to its
+    // index, or is rejected.
+    for c in 0..=255u8 {
+        let input = [c, b'A', b'A', b'A'];
+        let mut out = [0u8; 3];
+        let result = constant_time::decode_base64url(&input, &mut out);
+        match ALPHABET.iter().position(|&a| a == c) {
+            Some(index) => {
+                assert_eq!(result, Ok(3));
+                assert_eq!(out[0] >> 2, index as u8);
+            }
+            None => assert_eq!(result, Err(error::Unspecified)),
+        }
+    }
+
+    let rng = rand::SystemRandom::new();
+    for len in 0..64 {
+        let bytes: [u8; 64] = rand::generate(&rng).unwrap().expose();
+        let bytes = &bytes[..len];
+        let encoded = reference_encode(bytes);
+        let mut out = [0u8; 64];
+        let decoded_len = constant_time::decode_base64url(&encoded, &mut out).unwrap();
+        assert_eq!(&out[..decoded_len], bytes);
+    }
+}
+
+#[test]
+fn test_decode_base64() {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    const TEST_VECTORS: &[(&str, &[u8])] = &[
+        ("", b""),
+        ("Zg==", b"f"),
+        ("Zm8=", b"fo"),
+        ("Zm9vYmFy", b"foobar"),
+        // Characters that differ between base64 and base64url.
+        ("+/+/", &[0xfb, 0xff, 0xbf]),
+    ];
+    for &(input, expected) in TEST_VECTORS {
+        let mut out = [0u8; 6];
+        let len = constant_time::decode_base64(input.as_bytes(), &mut out).unwrap();
+        assert_eq!(&out[..len], expected, "{}", input);
+    }
+
+    // Every single character is either in the alphabet, and decodes to its
+    // index, or is rejected.
+    for c in 0..=255u8 {
+        let input = [c, b'A', b'A', b'A'];
+        let mut out = [0u8; 3];
+        let result = constant_time::decode_base64(&input, &mut out);
+        match ALPHABET.iter().position(|&a| a == c) {
+            Some(index) => {
+                assert_eq!(result, Ok(3));
+                assert_eq!(out[0] >> 2, index as u8);
+            }
+            None => assert_eq!(result, Err(error::Unspecified)),
+        }
+    }
+}
@@ -88,6 +88,8 @@ test_i_u_f!(digest_test_i_u_f_sha1, digest::SHA1_FOR_LEGACY_USE_ONLY);
 test_i_u_f!(digest_test_i_u_f_sha256, digest::SHA256);
 test_i_u_f!(digest_test_i_u_f_sha384, digest::SHA384);
 test_i_u_f!(digest_test_i_u_f_sha512, digest::SHA512);
+test_i_u_f!(digest_test_i_u_f_sha512_224, digest::SHA512_224);
+test_i_u_f!(digest_test_i_u_f_sha512_256, digest::SHA512_256);
 
 /// See https://bugzilla.mozilla.org/show_bug.cgi?id=610162. This tests the
 /// calculation of 8GB of the byte 123.
@@ -183,12 +185,134 @@ test_large_digest!(
 // TODO: test_large_digest!(digest_test_large_digest_sha512_256,
 //                            digest::SHA512_256, 256 / 8, [ ... ]);
 
+#[test]
+fn digest_ct_eq() {
+    for alg in [
+        &digest::SHA1_FOR_LEGACY_USE_ONLY,
+        &digest::SHA256,
+        &digest::SHA384,
+        &digest::SHA512,
+        &digest::SHA512_224,
+        &digest::SHA512_256,
+    ] {
+        let a = digest::digest(alg, b"a");
+        let a2 = digest::digest(alg, b"a");
+        let b = digest::digest(alg, b"b");
+        assert!(a.ct_eq(&a2));
+        assert_eq!(a, a2);
+        assert!(!a.ct_eq(&b));
+        assert_ne!(a, b);
+    }
+
+    // Digests of different algorithms are never equal.
+    assert_ne!(
+        digest::digest(&digest::SHA512, b""),
+        digest::digest(&digest::SHA512_256, b"")
+    );
+    assert_ne!(
+        digest::digest(&digest::SHA512_224, b""),
+        digest::digest(&digest::SHA512_256, b"")
+    );
+
+    // Find an input whose digest has the same first two bytes as, but is
+    // otherwise different from, the digest of `[0; 4]`, to check that the
+    // comparison doesn't stop after a matching prefix.
+    let target = digest::digest(&digest::SHA256, &[0; 4]);
+    let same_prefix = (1u32..)
+        .map(|i| digest::digest(&digest::SHA256, &i.to_be_bytes()))
+        .find(|d| d.as_ref()[..2] == target.as_ref()[..2])
+        .unwrap();
+    assert!(!target.ct_eq(&same_prefix));
+}
+
+#[test]
+fn digest_verify() {
+    for alg in [
+        &digest::SHA1_FOR_LEGACY_USE_ONLY,
+        &digest::SHA256,
+        &digest::SHA384,
+        &digest::SHA512,
+        &digest::SHA512_224,
+        &digest::SHA512_256,
+    ] {
+        let expected = digest::digest(alg, b"data");
+        let expected = expected.as_ref();
+        assert!(digest::verify(alg, b"data", expected).is_ok());
+        assert!(digest::verify(alg, b"datA", expected).is_err());
+
+        let mut wrong = expected.to_vec();
+        wrong[expected.len() - 1] ^= 1;
+        assert!(digest::verify(alg, b"data", &wrong).is_err());
+
+        // A prefix or an extension of the correct digest, or an empty
+        // value, must never verify; a comparison that stopped at the end of
+        // the shorter input would accept them.
+        let mut extended = expected.to_vec();
+        extended.push(0);
+        for wrong in [
+            &[][..],
+            &expected[..1],
+            &expected[..expected.len() - 1],
+            &extended[..],
+        ] {
+            assert!(digest::verify(alg, b"data", wrong).is_err());
+        }
+    }
+
+    // The digest of a different algorithm doesn't verify, even when it is
+    // a truncation of the same computation.
+    let sha512 = digest::digest(&digest::SHA512, b"data");
+    assert!(digest::verify(&digest::SHA512_256, b"data", &sha512.as_ref()[..32]).is_err());
+}
+
+#[test]
+fn digest_context_clone() {
+    let data: Vec<u8> = (0..=255).cycle().take(3 * digest::MAX_BLOCK_LEN).collect();
+    for alg in [
+        &digest::SHA1_FOR_LEGACY_USE_ONLY,
+        &digest::SHA256,
+        &digest::SHA384,
+        &digest::SHA512,
+        &digest::SHA512_224,
+        &digest::SHA512_256,
+    ] {
+        // Fork after prefixes that end mid-block, at a block boundary, and
+        // after a whole block plus a partial block.
+        for prefix_len in [
+            0,
+            1,
+            alg.block_len() - 1,
+            alg.block_len(),
+            alg.block_len() + 3,
+        ] {
+            let (prefix, rest) = data.split_at(prefix_len);
+            let mut ctx = digest::Context::new(alg);
+            ctx.update(prefix);
+
+            for suffix_len in [0, 1, rest.len()] {
+                let suffix = &rest[..suffix_len];
+                let mut fork = ctx.clone();
+                fork.update(suffix);
+                assert_eq!(
+                    fork.finish(),
+                    digest::digest(alg, &data[..(prefix_len + suffix_len)])
+                );
+            }
+
+            // Finishing the forks doesn't affect the original.
+            ctx.update(b"x");
+            assert_eq!(ctx.finish(), digest::digest(alg, &[prefix, b"x"].concat()));
+        }
+    }
+}
+
 #[test]
 fn test_fmt_algorithm() {
     assert_eq!("SHA1", &format!("{:?}", digest::SHA1_FOR_LEGACY_USE_ONLY));
     assert_eq!("SHA256", &format!("{:?}", digest::SHA256));
     assert_eq!("SHA384", &format!("{:?}", digest::SHA384));
     assert_eq!("SHA512", &format!("{:?}", digest::SHA512));
+    assert_eq!("SHA512_224", &format!("{:?}", digest::SHA512_224));
     assert_eq!("SHA512_256", &format!("{:?}", digest::SHA512_256));
 }
 
@@ -219,6 +343,11 @@ fn digest_test_fmt() {
         &format!("{:?}", digest::digest(&digest::SHA512, b"hello, world"))
     );
 
+    assert_eq!(
+        "SHA512_224:7cbabb02ab4083f5b270bdd94705137aa3\
+         aaa6260a8e041b0f8f3046",
+        &format!("{:?}", digest::digest(&digest::SHA512_224, b"hello, world"))
+    );
     assert_eq!(
         "SHA512_256:11f2c88c04f0a9c3d0970894ad2472505e\
          0bc6e8c7ec46b5211cd1fa3e253e62",
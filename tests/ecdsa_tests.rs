@@ -13,7 +13,7 @@
 // CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
 
 use ring::{
-    rand,
+    digest, error, rand,
     signature::{self, KeyPair},
     test, test_file,
 };
@@ -110,6 +110,123 @@ fn ecdsa_generate_pkcs8_test() {
     }
 }
 
+#[cfg(feature = "alloc")]
+#[test]
+fn ecdsa_to_pkcs8_round_trip() {
+    let rng = rand::SystemRandom::new();
+
+    for alg in &[
+        &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+        &signature::ECDSA_P256_SHA256_FIXED_SIGNING,
+        &signature::ECDSA_P384_SHA384_ASN1_SIGNING,
+        &signature::ECDSA_P384_SHA384_FIXED_SIGNING,
+    ] {
+        let pkcs8 = signature::EcdsaKeyPair::generate_pkcs8(alg, &rng).unwrap();
+        let key_pair = signature::EcdsaKeyPair::from_pkcs8(alg, pkcs8.as_ref(), &rng).unwrap();
+
+        // Serializing gives back exactly the document the key came from.
+        let serialized = key_pair.to_pkcs8().unwrap();
+        assert_eq!(serialized.as_ref(), pkcs8.as_ref());
+
+        // The re-parsed key is the same key.
+        let reparsed = signature::EcdsaKeyPair::from_pkcs8(alg, serialized.as_ref(), &rng).unwrap();
+        assert_eq!(
+            reparsed.public_key().as_ref(),
+            key_pair.public_key().as_ref()
+        );
+        let verification_alg = if alg == &&signature::ECDSA_P256_SHA256_ASN1_SIGNING {
+            &signature::ECDSA_P256_SHA256_ASN1
+        } else if alg == &&signature::ECDSA_P256_SHA256_FIXED_SIGNING {
+            &signature::ECDSA_P256_SHA256_FIXED
+        } else if alg == &&signature::ECDSA_P384_SHA384_ASN1_SIGNING {
+            &signature::ECDSA_P384_SHA384_ASN1
+        } else {
+            &signature::ECDSA_P384_SHA384_FIXED
+        };
+        let sig = reparsed.sign(&rng, b"message").unwrap();
+        signature::UnparsedPublicKey::new(verification_alg, key_pair.public_key())
+            .verify(b"message", sig.as_ref())
+            .unwrap();
+    }
+
+    // A key that wasn't generated by *ring*.
+    let alg = &signature::ECDSA_P256_SHA256_ASN1_SIGNING;
+    let pkcs8 = include_bytes!("ecdsa_test_private_key_p256.p8");
+    let key_pair = signature::EcdsaKeyPair::from_pkcs8(alg, pkcs8, &rng).unwrap();
+    let serialized = key_pair.to_pkcs8().unwrap();
+    let reparsed = signature::EcdsaKeyPair::from_pkcs8(alg, serialized.as_ref(), &rng).unwrap();
+    assert_eq!(
+        reparsed.public_key().as_ref(),
+        key_pair.public_key().as_ref()
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn ecdsa_public_key_matches() {
+    let rng = rand::SystemRandom::new();
+    let alg = &signature::ECDSA_P256_SHA256_ASN1_SIGNING;
+    let pkcs8 = signature::EcdsaKeyPair::generate_pkcs8(alg, &rng).unwrap();
+    let key_pair = signature::EcdsaKeyPair::from_pkcs8(alg, pkcs8.as_ref(), &rng).unwrap();
+    let public_key = key_pair.public_key();
+    let uncompressed = public_key.as_ref();
+
+    let y_is_odd = uncompressed[64] & 1;
+    let compressed = [&[2 | y_is_odd][..], &uncompressed[1..33]].concat();
+    let wrong_parity = [&[3 ^ y_is_odd][..], &uncompressed[1..33]].concat();
+
+    assert!(public_key.matches(uncompressed));
+    assert!(public_key.matches(&compressed));
+    assert!(!public_key.matches(&wrong_parity));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn ecdsa_verify_same_message_test() {
+    let rng = rand::SystemRandom::new();
+    let msg = b"the shared message";
+
+    for (signing_alg, verification_alg) in [
+        (
+            &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+            &signature::ECDSA_P256_SHA256_ASN1,
+        ),
+        (
+            &signature::ECDSA_P384_SHA384_FIXED_SIGNING,
+            &signature::ECDSA_P384_SHA384_FIXED,
+        ),
+    ] {
+        let signers = (0..3)
+            .map(|_| {
+                let pkcs8 = signature::EcdsaKeyPair::generate_pkcs8(signing_alg, &rng).unwrap();
+                let key_pair =
+                    signature::EcdsaKeyPair::from_pkcs8(signing_alg, pkcs8.as_ref(), &rng).unwrap();
+                let sig = key_pair.sign(&rng, msg).unwrap();
+                (
+                    key_pair.public_key().as_ref().to_vec(),
+                    sig.as_ref().to_vec(),
+                )
+            })
+            .collect::<Vec<_>>();
+        let batch = signers
+            .iter()
+            .map(|(public_key, sig)| (&public_key[..], &sig[..]))
+            .collect::<Vec<_>>();
+
+        assert!(signature::ecdsa_verify_same_message(verification_alg, msg, &batch).is_ok());
+        assert!(
+            signature::ecdsa_verify_same_message(verification_alg, b"another message", &batch)
+                .is_err()
+        );
+        assert!(signature::ecdsa_verify_same_message(verification_alg, msg, &[]).is_err());
+
+        // One signature from the wrong signer fails the whole batch.
+        let mut mixed = batch.clone();
+        mixed[1].1 = batch[2].1;
+        assert!(signature::ecdsa_verify_same_message(verification_alg, msg, &mixed).is_err());
+    }
+}
+
 #[test]
 fn signature_ecdsa_verify_asn1_test() {
     test::run(
@@ -124,18 +241,22 @@ fn signature_ecdsa_verify_asn1_test() {
             let sig = test_case.consume_bytes("Sig");
             let is_valid = test_case.consume_string("Result") == "P (0 )";
 
-            let alg = match (curve_name.as_str(), digest_name.as_str()) {
-                ("P-256", "SHA256") => &signature::ECDSA_P256_SHA256_ASN1,
-                ("P-256", "SHA384") => &signature::ECDSA_P256_SHA384_ASN1,
-                ("P-384", "SHA256") => &signature::ECDSA_P384_SHA256_ASN1,
-                ("P-384", "SHA384") => &signature::ECDSA_P384_SHA384_ASN1,
+            let (alg, digest_alg) = match (curve_name.as_str(), digest_name.as_str()) {
+                ("P-256", "SHA256") => (&signature::ECDSA_P256_SHA256_ASN1, &digest::SHA256),
+                ("P-256", "SHA384") => (&signature::ECDSA_P256_SHA384_ASN1, &digest::SHA384),
+                ("P-384", "SHA256") => (&signature::ECDSA_P384_SHA256_ASN1, &digest::SHA256),
+                ("P-384", "SHA384") => (&signature::ECDSA_P384_SHA384_ASN1, &digest::SHA384),
                 _ => {
                     panic!("Unsupported curve+digest: {}+{}", curve_name, digest_name);
                 }
             };
 
-            let actual_result =
-                signature::UnparsedPublicKey::new(alg, &public_key).verify(&msg, &sig);
+            let key = signature::UnparsedPublicKey::new(alg, &public_key);
+            let actual_result = key.verify(&msg, &sig);
+            assert_eq!(actual_result.is_ok(), is_valid);
+
+            // Verifying a precomputed digest gives the same result.
+            let actual_result = key.verify_digest(&digest::digest(digest_alg, &msg), &sig);
             assert_eq!(actual_result.is_ok(), is_valid);
 
             Ok(())
@@ -143,6 +264,34 @@ fn signature_ecdsa_verify_asn1_test() {
     );
 }
 
+#[test]
+fn signature_verify_digest_rejects_wrong_digest_algorithm() {
+    let rng = rand::SystemRandom::new();
+    let alg = &signature::ECDSA_P256_SHA256_ASN1_SIGNING;
+    let pkcs8 = signature::EcdsaKeyPair::generate_pkcs8(alg, &rng).unwrap();
+    let key_pair = signature::EcdsaKeyPair::from_pkcs8(alg, pkcs8.as_ref(), &rng).unwrap();
+
+    const MSG: &[u8] = b"to be signed";
+    let sig = key_pair.sign(&rng, MSG).unwrap();
+    let public_key = signature::UnparsedPublicKey::new(
+        &signature::ECDSA_P256_SHA256_ASN1,
+        key_pair.public_key().as_ref(),
+    );
+
+    assert!(public_key
+        .verify_digest(&digest::digest(&digest::SHA256, MSG), sig.as_ref())
+        .is_ok());
+    assert!(public_key
+        .verify_digest(&digest::digest(&digest::SHA384, MSG), sig.as_ref())
+        .is_err());
+
+    // Ed25519 signs the message itself, not a digest of it.
+    let ed25519_key = signature::UnparsedPublicKey::new(&signature::ED25519, [0; 32]);
+    assert!(ed25519_key
+        .verify_digest(&digest::digest(&digest::SHA512, MSG), &[0; 64])
+        .is_err());
+}
+
 #[test]
 fn signature_ecdsa_verify_fixed_test() {
     test::run(
@@ -209,6 +358,72 @@ fn ecdsa_test_public_key_coverage() {
     );
 }
 
+#[test]
+fn signature_ecdsa_fixed_leading_zeros_test() {
+    let rng = rand::SystemRandom::new();
+    let msg = b"message";
+
+    for (signing_alg, verification_alg, scalar_len) in [
+        (
+            &signature::ECDSA_P256_SHA256_FIXED_SIGNING,
+            &signature::ECDSA_P256_SHA256_FIXED,
+            32,
+        ),
+        (
+            &signature::ECDSA_P384_SHA384_FIXED_SIGNING,
+            &signature::ECDSA_P384_SHA384_FIXED,
+            48,
+        ),
+    ] {
+        let pkcs8 = signature::EcdsaKeyPair::generate_pkcs8(signing_alg, &rng).unwrap();
+        let key_pair =
+            signature::EcdsaKeyPair::from_pkcs8(signing_alg, pkcs8.as_ref(), &rng).unwrap();
+        let public_key =
+            signature::UnparsedPublicKey::new(verification_alg, key_pair.public_key().as_ref());
+
+        // About one in 256 signatures has an `r` with a leading zero byte.
+        let sig = (0..10_000)
+            .map(|_| key_pair.sign(&rng, msg).unwrap())
+            .find(|sig| sig.as_ref()[0] == 0)
+            .unwrap();
+        assert_eq!(sig.as_ref().len(), 2 * scalar_len);
+        assert_eq!(public_key.verify(msg, sig.as_ref()), Ok(()));
+
+        let (r, s) = sig.as_ref().split_at(scalar_len);
+        let stripped_r = &r[r.iter().take_while(|&&b| b == 0).count()..];
+        assert!(stripped_r.len() < scalar_len);
+
+        // The stripped signature isn't a valid fixed-length signature...
+        let stripped = [stripped_r, s].concat();
+        assert!(public_key.verify(msg, &stripped).is_err());
+
+        // ...but its components can be reformatted into one.
+        for r in [r, stripped_r, &[&[0, 0][..], r].concat()[..]] {
+            let reformatted =
+                signature::ecdsa_fixed_signature_from_components(verification_alg, r, s).unwrap();
+            assert_eq!(reformatted.as_ref(), sig.as_ref());
+            assert_eq!(public_key.verify(msg, reformatted.as_ref()), Ok(()));
+        }
+
+        // Components that are too long, and non-fixed algorithms, are rejected.
+        let too_long = vec![1u8; scalar_len + 1];
+        assert!(
+            signature::ecdsa_fixed_signature_from_components(verification_alg, &too_long, s)
+                .is_err()
+        );
+        assert!(
+            signature::ecdsa_fixed_signature_from_components(verification_alg, r, &too_long)
+                .is_err()
+        );
+    }
+    assert!(signature::ecdsa_fixed_signature_from_components(
+        &signature::ECDSA_P256_SHA256_ASN1,
+        &[1],
+        &[1]
+    )
+    .is_err());
+}
+
 // This test is not a known-answer test, though it re-uses the known-answer
 // test vectors. Because the nonce is randomized, the signature will be
 // different each time. Because of that, here we simply verify that the
@@ -316,3 +531,224 @@ fn signature_ecdsa_sign_asn1_test() {
         },
     );
 }
+
+#[test]
+fn signature_ecdsa_sign_fixed_round_trip_test() {
+    let rng = rand::SystemRandom::new();
+
+    for (signing_alg, verification_alg, asn1_verification_alg, sig_len) in [
+        (
+            &signature::ECDSA_P256_SHA256_FIXED_SIGNING,
+            &signature::ECDSA_P256_SHA256_FIXED,
+            &signature::ECDSA_P256_SHA256_ASN1,
+            64,
+        ),
+        (
+            &signature::ECDSA_P384_SHA384_FIXED_SIGNING,
+            &signature::ECDSA_P384_SHA384_FIXED,
+            &signature::ECDSA_P384_SHA384_ASN1,
+            96,
+        ),
+    ] {
+        let pkcs8 = signature::EcdsaKeyPair::generate_pkcs8(signing_alg, &rng).unwrap();
+        let key_pair =
+            signature::EcdsaKeyPair::from_pkcs8(signing_alg, pkcs8.as_ref(), &rng).unwrap();
+        let public_key_bytes = key_pair.public_key().as_ref();
+
+        for msg in [&b""[..], b"hello, world", &[0xab; 1000]] {
+            for sig in [
+                key_pair.sign(&rng, msg).unwrap(),
+                key_pair.sign_deterministic(msg).unwrap(),
+            ] {
+                assert_eq!(sig.as_ref().len(), sig_len);
+
+                let public_key =
+                    signature::UnparsedPublicKey::new(verification_alg, public_key_bytes);
+                assert_eq!(public_key.verify(msg, sig.as_ref()), Ok(()));
+
+                let asn1_public_key =
+                    signature::UnparsedPublicKey::new(asn1_verification_alg, public_key_bytes);
+                assert!(asn1_public_key.verify(msg, sig.as_ref()).is_err());
+            }
+        }
+    }
+}
+
+#[test]
+fn signature_ecdsa_sign_deterministic_test() {
+    let rng = rand::SystemRandom::new();
+
+    test::run(
+        test_file!("ecdsa_sign_rfc6979_tests.txt"),
+        |section, test_case| {
+            assert_eq!(section, "");
+
+            let curve_name = test_case.consume_string("Curve");
+            let digest_name = test_case.consume_string("Digest");
+
+            let msg = test_case.consume_bytes("Msg");
+            let d = test_case.consume_bytes("d");
+            let q = test_case.consume_bytes("Q");
+            let expected_sig = test_case.consume_bytes("Sig");
+
+            let algs = match (curve_name.as_str(), digest_name.as_str()) {
+                ("P-256", "SHA256") => [
+                    (
+                        &signature::ECDSA_P256_SHA256_FIXED_SIGNING,
+                        &signature::ECDSA_P256_SHA256_FIXED,
+                    ),
+                    (
+                        &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+                        &signature::ECDSA_P256_SHA256_ASN1,
+                    ),
+                ],
+                ("P-384", "SHA384") => [
+                    (
+                        &signature::ECDSA_P384_SHA384_FIXED_SIGNING,
+                        &signature::ECDSA_P384_SHA384_FIXED,
+                    ),
+                    (
+                        &signature::ECDSA_P384_SHA384_ASN1_SIGNING,
+                        &signature::ECDSA_P384_SHA384_ASN1,
+                    ),
+                ],
+                _ => {
+                    panic!("Unsupported curve+digest: {}+{}", curve_name, digest_name);
+                }
+            };
+
+            for (signing_alg, verification_alg) in algs {
+                let key_pair = signature::EcdsaKeyPair::from_private_key_and_public_key(
+                    signing_alg,
+                    &d,
+                    &q,
+                    &rng,
+                )
+                .unwrap();
+
+                let sig = key_pair.sign_deterministic(&msg).unwrap();
+                if signing_alg == &signature::ECDSA_P256_SHA256_FIXED_SIGNING
+                    || signing_alg == &signature::ECDSA_P384_SHA384_FIXED_SIGNING
+                {
+                    assert_eq!(sig.as_ref(), &expected_sig[..]);
+                }
+                assert_eq!(
+                    key_pair.sign_deterministic(&msg).unwrap().as_ref(),
+                    sig.as_ref()
+                );
+
+                let public_key = signature::UnparsedPublicKey::new(verification_alg, &q);
+                assert_eq!(public_key.verify(&msg, sig.as_ref()), Ok(()));
+                assert!(public_key.verify(b"other message", sig.as_ref()).is_err());
+            }
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn signature_ecdsa_sign_with_external_signer_test() {
+    // A stand-in for a hardware security module. A real one would sign the
+    // digest it is given; this one can only sign `message` with an in-memory
+    // key, so it checks that it was given the digest of `message`.
+    struct MockHsm<'a> {
+        key_pair: signature::EcdsaKeyPair,
+        digest_alg: &'static digest::Algorithm,
+        message: &'a [u8],
+        rng: rand::SystemRandom,
+    }
+
+    impl signature::ExternalSigner for MockHsm<'_> {
+        fn sign_digest(&self, digest: &[u8]) -> Result<signature::Signature, error::Unspecified> {
+            assert_eq!(
+                digest,
+                digest::digest(self.digest_alg, self.message).as_ref()
+            );
+            self.key_pair.sign(&self.rng, self.message)
+        }
+    }
+
+    // Returns a fixed value instead of signing.
+    struct BrokenHsm(Vec<u8>);
+
+    impl signature::ExternalSigner for BrokenHsm {
+        fn sign_digest(&self, _: &[u8]) -> Result<signature::Signature, error::Unspecified> {
+            signature::Signature::try_from(&self.0[..])
+        }
+    }
+
+    const MESSAGE: &[u8] = b"hello, world";
+    let rng = rand::SystemRandom::new();
+
+    for (hsm_alg, signing_alg, verification_alg, digest_alg) in [
+        (
+            &signature::ECDSA_P256_SHA256_FIXED_SIGNING,
+            &signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+            &signature::ECDSA_P256_SHA256_ASN1,
+            &digest::SHA256,
+        ),
+        (
+            &signature::ECDSA_P256_SHA256_FIXED_SIGNING,
+            &signature::ECDSA_P256_SHA256_FIXED_SIGNING,
+            &signature::ECDSA_P256_SHA256_FIXED,
+            &digest::SHA256,
+        ),
+        (
+            &signature::ECDSA_P384_SHA384_FIXED_SIGNING,
+            &signature::ECDSA_P384_SHA384_ASN1_SIGNING,
+            &signature::ECDSA_P384_SHA384_ASN1,
+            &digest::SHA384,
+        ),
+    ] {
+        let pkcs8 = signature::EcdsaKeyPair::generate_pkcs8(hsm_alg, &rng).unwrap();
+        let key_pair = signature::EcdsaKeyPair::from_pkcs8(hsm_alg, pkcs8.as_ref(), &rng).unwrap();
+        let public_key = signature::UnparsedPublicKey::new(
+            verification_alg,
+            key_pair.public_key().as_ref().to_vec(),
+        );
+        let hsm = MockHsm {
+            key_pair,
+            digest_alg,
+            message: MESSAGE,
+            rng: rand::SystemRandom::new(),
+        };
+
+        let signature = signing_alg
+            .sign_with_external_signer(&hsm, MESSAGE)
+            .unwrap();
+        assert_eq!(public_key.verify(MESSAGE, signature.as_ref()), Ok(()));
+        assert!(public_key
+            .verify(b"another message", signature.as_ref())
+            .is_err());
+    }
+
+    // Malformed signatures from the signer are rejected.
+    let alg = &signature::ECDSA_P256_SHA256_ASN1_SIGNING;
+    let n =
+        test::from_hex("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551").unwrap();
+    let one = {
+        let mut one = vec![0; 32];
+        one[31] = 1;
+        one
+    };
+    for bad in [
+        vec![],
+        vec![1; 63],
+        vec![1; 65],
+        [&[0; 32][..], &one].concat(),
+        [&one[..], &[0; 32]].concat(),
+        [&n[..], &one].concat(),
+        [&one[..], &n].concat(),
+    ] {
+        assert!(alg
+            .sign_with_external_signer(&BrokenHsm(bad), MESSAGE)
+            .is_err());
+    }
+    assert!(alg
+        .sign_with_external_signer(&BrokenHsm([&one[..], &one].concat()), MESSAGE)
+        .is_ok());
+
+    // Signatures that are too long for `Signature`.
+    assert!(signature::Signature::try_from(&[0; 1000][..]).is_err());
+}
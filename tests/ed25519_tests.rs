@@ -45,6 +45,13 @@ fn test_signature_ed25519() {
             assert_eq!(&expected_sig[..], actual_sig.as_ref());
         }
 
+        assert_eq!(
+            Ed25519KeyPair::public_key_from_seed(&seed)
+                .unwrap()
+                .as_ref(),
+            &public_key[..]
+        );
+
         // Test PKCS#8 generation, parsing, and private-to-public calculations.
         let rng = test::rand::FixedSliceRandom { bytes: &seed };
         let pkcs8 = Ed25519KeyPair::generate_pkcs8(&rng).unwrap();
@@ -89,6 +96,85 @@ fn test_signature_ed25519_verify() {
     );
 }
 
+#[cfg(feature = "alloc")]
+#[test]
+fn test_signature_ed25519_verify_batch() {
+    let rng = rand::SystemRandom::new();
+
+    let mut valid = Vec::new();
+    test::run(test_file!("ed25519_tests.txt"), |section, test_case| {
+        assert_eq!(section, "");
+        let _ = test_case.consume_bytes("SEED");
+        let public_key = test_case.consume_bytes("PUB");
+        let msg = test_case.consume_bytes("MESSAGE");
+        let sig = test_case.consume_bytes("SIG");
+        valid.push((public_key, msg, sig));
+        Ok(())
+    });
+    let verify_batch = |batch: &[(Vec<u8>, Vec<u8>, Vec<u8>)]| {
+        signature::ed25519_verify_batch(&as_entries(batch), &rng)
+    };
+
+    assert_eq!(verify_batch(&[]), Ok(()));
+    assert_eq!(verify_batch(&valid[..1]), Ok(()));
+    assert_eq!(verify_batch(&valid), Ok(()));
+
+    // One bad signature, anywhere in the batch, fails the whole batch.
+    for i in [0, 1, 63, 64, valid.len() - 1] {
+        let mut batch = valid.clone();
+        batch[i].2[0] ^= 1;
+        assert_eq!(verify_batch(&batch), Err(error::Unspecified));
+
+        let mut batch = valid.clone();
+        batch[i].2[ED25519_SIGNATURE_S_OFFSET] ^= 1;
+        assert_eq!(verify_batch(&batch), Err(error::Unspecified));
+
+        let mut batch = valid.clone();
+        batch[i].1.push(0);
+        assert_eq!(verify_batch(&batch), Err(error::Unspecified));
+
+        let mut batch = valid.clone();
+        batch[i].0 = valid[(i + 1) % valid.len()].0.clone();
+        assert_eq!(verify_batch(&batch), Err(error::Unspecified));
+
+        let mut batch = valid.clone();
+        let _ = batch[i].2.pop();
+        assert_eq!(verify_batch(&batch), Err(error::Unspecified));
+    }
+
+    // Batch verification agrees with `ED25519` on the edge cases.
+    test::run(
+        test_file!("ed25519_verify_tests.txt"),
+        |section, test_case| {
+            assert_eq!(section, "");
+            let public_key = test_case.consume_bytes("PUB");
+            let msg = test_case.consume_bytes("MESSAGE");
+            let sig = test_case.consume_bytes("SIG");
+            let expected_result = match test_case.consume_string("Result").as_str() {
+                "P" => Ok(()),
+                "F" => Err(error::Unspecified),
+                s => panic!("{:?} is not a valid result", s),
+            };
+            let mut batch = valid[..3].to_vec();
+            batch.insert(1, (public_key, msg, sig));
+            assert_eq!(verify_batch(&batch[1..2]), expected_result);
+            assert_eq!(verify_batch(&batch), expected_result);
+            Ok(())
+        },
+    );
+}
+
+#[cfg(feature = "alloc")]
+fn as_entries(batch: &[(Vec<u8>, Vec<u8>, Vec<u8>)]) -> Vec<(&[u8], &[u8], &[u8])> {
+    batch
+        .iter()
+        .map(|(public_key, msg, sig)| (&public_key[..], &msg[..], &sig[..]))
+        .collect()
+}
+
+#[cfg(feature = "alloc")]
+const ED25519_SIGNATURE_S_OFFSET: usize = 32;
+
 fn test_signature_verification(
     public_key: &[u8],
     msg: &[u8],
@@ -101,6 +187,58 @@ fn test_signature_verification(
     );
 }
 
+#[test]
+fn test_signature_ed25519_domain_separated() {
+    // RFC 8032 Section 7.2 (Ed25519ctx).
+    let seed =
+        test::from_hex("0305334e381af78f141cb666f6199f57bc3495335a256a95bd2a55bf546663f6").unwrap();
+    let public_key =
+        test::from_hex("dfc9425e4f968f7f0c29f0259cf5f9aed6851c2bb4ad8bfb860cfee0ab248292").unwrap();
+    let msg = test::from_hex("f726936d19c800494e3fdaff20b276a8").unwrap();
+    let key_pair = Ed25519KeyPair::from_seed_and_public_key(&seed, &public_key).unwrap();
+
+    for (domain, expected) in [
+        (
+            &b"foo"[..],
+            "55a4cc2f70a54e04288c5f4cd1e45a7bb520b36292911876cada7323198dd87a\
+             8b36950b95130022907a7fb7c4e9b2d5f6cca685a587b4b21f4b888e4e7edb0d",
+        ),
+        (
+            &b"bar"[..],
+            "fc60d5872fc46b3aa69f8b5b4351d5808f92bcc044606db097abab6dbcb1aee3\
+             216c48e8b3b66431b5b186d1d28f8ee15a5ca2df6668346291c2043d4eb3e90d",
+        ),
+    ] {
+        let expected = test::from_hex(expected).unwrap();
+        let sig = key_pair.sign_domain_separated(domain, &msg).unwrap();
+        assert_eq!(sig.as_ref(), &expected[..]);
+        assert!(signature::ED25519
+            .verify_domain_separated(&public_key, domain, &msg, sig.as_ref())
+            .is_ok());
+    }
+
+    // A signature doesn't verify in any other domain, nor as a plain Ed25519
+    // signature, nor vice versa.
+    let sig = key_pair.sign_domain_separated(b"foo", &msg).unwrap();
+    for other_domain in [&b"bar"[..], b"fo", b"foo\0", &[b'x'; 255]] {
+        assert!(signature::ED25519
+            .verify_domain_separated(&public_key, other_domain, &msg, sig.as_ref())
+            .is_err());
+    }
+    test_signature_verification(&public_key, &msg, sig.as_ref(), Err(error::Unspecified));
+    let plain_sig = key_pair.sign(&msg);
+    assert!(signature::ED25519
+        .verify_domain_separated(&public_key, b"foo", &msg, plain_sig.as_ref())
+        .is_err());
+
+    // The domain must be 1 to 255 bytes long.
+    assert!(key_pair.sign_domain_separated(b"", &msg).is_err());
+    assert!(key_pair.sign_domain_separated(&[0; 256], &msg).is_err());
+    assert!(signature::ED25519
+        .verify_domain_separated(&public_key, b"", &msg, plain_sig.as_ref())
+        .is_err());
+}
+
 #[test]
 fn test_ed25519_from_seed_and_public_key_misuse() {
     const PRIVATE_KEY: &[u8] = include_bytes!("ed25519_test_private_key.bin");
@@ -110,6 +248,7 @@ fn test_ed25519_from_seed_and_public_key_misuse() {
 
     // Truncated private key.
     assert!(Ed25519KeyPair::from_seed_and_public_key(&PRIVATE_KEY[..31], PUBLIC_KEY).is_err());
+    assert!(Ed25519KeyPair::public_key_from_seed(&PRIVATE_KEY[..31]).is_err());
 
     // Truncated public key.
     assert!(Ed25519KeyPair::from_seed_and_public_key(PRIVATE_KEY, &PUBLIC_KEY[..31]).is_err());
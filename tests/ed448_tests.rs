@@ -0,0 +1,174 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+use ring::{
+    error, rand,
+    signature::{self, Ed448KeyPair, KeyPair},
+    test, test_file,
+};
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+fn test_signature_ed448() {
+    test::run(test_file!("ed448_tests.txt"), |section, test_case| {
+        assert_eq!(section, "");
+        let seed = test_case.consume_bytes("SEED");
+        assert_eq!(signature::ED448_SEED_LEN, seed.len());
+
+        let public_key = test_case.consume_bytes("PUB");
+        assert_eq!(signature::ED448_PUBLIC_KEY_LEN, public_key.len());
+
+        let msg = test_case.consume_bytes("MESSAGE");
+
+        let expected_sig = test_case.consume_bytes("SIG");
+
+        let key_pair = Ed448KeyPair::from_seed_and_public_key(&seed, &public_key).unwrap();
+        let actual_sig = key_pair.sign(&msg);
+        assert_eq!(&expected_sig[..], actual_sig.as_ref());
+
+        let key_pair = Ed448KeyPair::from_seed_unchecked(&seed).unwrap();
+        assert_eq!(public_key, key_pair.public_key().as_ref());
+
+        test_signature_verification(&public_key, &msg, &expected_sig, Ok(()));
+
+        let mut tampered_sig = expected_sig;
+        tampered_sig[0] ^= 1;
+        test_signature_verification(&public_key, &msg, &tampered_sig, Err(error::Unspecified));
+
+        Ok(())
+    });
+}
+
+#[test]
+fn test_signature_ed448_verify() {
+    test::run(
+        test_file!("ed448_verify_tests.txt"),
+        |section, test_case| {
+            assert_eq!(section, "");
+
+            let public_key = test_case.consume_bytes("PUB");
+            let msg = test_case.consume_bytes("MESSAGE");
+            let sig = test_case.consume_bytes("SIG");
+            let expected_result = match test_case.consume_string("Result").as_str() {
+                "P" => Ok(()),
+                "F" => Err(error::Unspecified),
+                s => panic!("{:?} is not a valid result", s),
+            };
+            test_signature_verification(&public_key, &msg, &sig, expected_result);
+            Ok(())
+        },
+    );
+}
+
+fn test_signature_verification(
+    public_key: &[u8],
+    msg: &[u8],
+    sig: &[u8],
+    expected_result: Result<(), error::Unspecified>,
+) {
+    assert_eq!(
+        expected_result,
+        signature::UnparsedPublicKey::new(&signature::ED448, public_key).verify(msg, sig)
+    );
+}
+
+#[test]
+fn test_signature_ed448_domain_separated() {
+    // RFC 8032 Section 7.4, "1 octet (with context)".
+    let seed = test::from_hex(
+        "c4eab05d357007c632f3dbb48489924d552b08fe0c353a0d4a1f00acda2c463a\
+         fbea67c5e8d2877c5e3bc397a659949ef8021e954e0a12274e",
+    )
+    .unwrap();
+    let public_key = test::from_hex(
+        "43ba28f430cdff456ae531545f7ecd0ac834a55d9358c0372bfa0c6c6798c086\
+         6aea01eb00742802b8438ea4cb82169c235160627b4c3a9480",
+    )
+    .unwrap();
+    let msg = [0x03];
+    let expected = test::from_hex(
+        "d4f8f6131770dd46f40867d6fd5d5055de43541f8c5e35abbcd001b32a89f7d2\
+         151f7647f11d8ca2ae279fb842d607217fce6e042f6815ea000c85741de5c8da\
+         1144a6a1aba7f96de42505d7a7298524fda538fccbbb754f578c1cad10d54d0d\
+         5428407e85dcbc98a49155c13764e66c3c00",
+    )
+    .unwrap();
+    let key_pair = Ed448KeyPair::from_seed_and_public_key(&seed, &public_key).unwrap();
+
+    let sig = key_pair.sign_domain_separated(b"foo", &msg).unwrap();
+    assert_eq!(sig.as_ref(), &expected[..]);
+    assert!(signature::ED448
+        .verify_domain_separated(&public_key, b"foo", &msg, sig.as_ref())
+        .is_ok());
+
+    // A signature doesn't verify in any other domain, nor as a plain Ed448
+    // signature, nor vice versa.
+    for other_domain in [&b"bar"[..], b"fo", b"foo\0", &[b'x'; 255]] {
+        assert!(signature::ED448
+            .verify_domain_separated(&public_key, other_domain, &msg, sig.as_ref())
+            .is_err());
+    }
+    test_signature_verification(&public_key, &msg, sig.as_ref(), Err(error::Unspecified));
+    let plain_sig = key_pair.sign(&msg);
+    assert!(signature::ED448
+        .verify_domain_separated(&public_key, b"foo", &msg, plain_sig.as_ref())
+        .is_err());
+
+    // The domain must be 1 to 255 bytes long.
+    assert!(key_pair.sign_domain_separated(b"", &msg).is_err());
+    assert!(key_pair.sign_domain_separated(&[0; 256], &msg).is_err());
+    assert!(signature::ED448
+        .verify_domain_separated(&public_key, b"", &msg, plain_sig.as_ref())
+        .is_err());
+}
+
+#[test]
+fn test_signature_ed448_sign_verify_random() {
+    let rng = rand::SystemRandom::new();
+    let seed: [u8; signature::ED448_SEED_LEN] = rand::generate(&rng).unwrap().expose();
+    let key_pair = Ed448KeyPair::from_seed_unchecked(&seed).unwrap();
+    for msg in [&b""[..], b"hello, world", &[0xa5; 1000]] {
+        let sig = key_pair.sign(msg);
+        assert_eq!(sig.as_ref().len(), 2 * signature::ED448_PUBLIC_KEY_LEN);
+        test_signature_verification(key_pair.public_key().as_ref(), msg, sig.as_ref(), Ok(()));
+    }
+}
+
+#[test]
+fn test_ed448_from_seed_and_public_key_misuse() {
+    let seed = [0x42; signature::ED448_SEED_LEN];
+    let key_pair = Ed448KeyPair::from_seed_unchecked(&seed).unwrap();
+    let public_key = key_pair.public_key().as_ref();
+
+    assert!(Ed448KeyPair::from_seed_and_public_key(&seed, public_key).is_ok());
+
+    // Truncated private key.
+    assert!(Ed448KeyPair::from_seed_and_public_key(&seed[..56], public_key).is_err());
+    assert!(Ed448KeyPair::from_seed_unchecked(&seed[..56]).is_err());
+
+    // Truncated public key.
+    assert!(Ed448KeyPair::from_seed_and_public_key(&seed, &public_key[..56]).is_err());
+
+    // The wrong public key.
+    let other = Ed448KeyPair::from_seed_unchecked(&[0x43; signature::ED448_SEED_LEN]).unwrap();
+    assert!(Ed448KeyPair::from_seed_and_public_key(&seed, other.public_key().as_ref()).is_err());
+
+    assert_eq!(format!("{:?}", signature::ED448), "ring::signature::ED448");
+    assert!(format!("{:?}", key_pair).starts_with("Ed448KeyPair { public_key: PublicKey(\""));
+}
@@ -0,0 +1,46 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+//! Tests that only the approved algorithms are reachable when the `fips`
+//! feature is enabled. Most of the non-approved algorithms are removed from
+//! the API entirely, so their absence is checked by the build itself; these
+//! tests cover the ways of finding an algorithm at runtime.
+
+#![cfg(feature = "fips")]
+
+use ring::{aead, integrity};
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+fn fips_aead_algorithm_by_id() {
+    // ChaCha20-Poly1305, AES-128-GCM-SIV, and AES-256-GCM-SIV.
+    for id in [29, 30, 31] {
+        assert_eq!(aead::algorithm_by_id(id), None);
+    }
+
+    let approved = (0..=u16::MAX)
+        .filter_map(aead::algorithm_by_id)
+        .collect::<Vec<_>>();
+    assert_eq!(approved, [&aead::AES_128_GCM, &aead::AES_256_GCM]);
+}
+
+#[test]
+fn fips_verify_code() {
+    assert_eq!(integrity::verify_code(), Ok(()));
+}
@@ -12,7 +12,7 @@
 // OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
 // CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
 
-use ring::{digest, error, hkdf, test, test_file};
+use ring::{aead, digest, error, hkdf, hmac, test, test_file};
 
 #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
 use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
@@ -30,6 +30,10 @@ fn hkdf_tests() {
                 .ok_or(error::Unspecified)?;
             if digest_alg == &digest::SHA256 {
                 hkdf::HKDF_SHA256
+            } else if digest_alg == &digest::SHA512_224 {
+                hkdf::HKDF_SHA512_224
+            } else if digest_alg == &digest::SHA512_256 {
+                hkdf::HKDF_SHA512_256
             } else {
                 // TODO: add test vectors for other algorithms
                 panic!("unsupported algorithm: {:?}", digest_alg);
@@ -38,10 +42,11 @@ fn hkdf_tests() {
         let secret = test_case.consume_bytes("IKM");
         let salt = test_case.consume_bytes("salt");
         let info = test_case.consume_bytes("info");
-        let _ = test_case.consume_bytes("PRK");
+        let expected_prk = test_case.consume_bytes("PRK");
         let expected_out = test_case.consume_bytes("OKM");
 
         let salt = hkdf::Salt::new(alg, &salt);
+        assert_eq!(salt.extract_less_safe(&secret).as_ref(), &expected_prk[..]);
 
         // TODO: test multi-part info, especially with empty parts.
         let My(out) = salt
@@ -57,7 +62,13 @@ fn hkdf_tests() {
 
 #[test]
 fn hkdf_output_len_tests() {
-    for &alg in &[hkdf::HKDF_SHA256, hkdf::HKDF_SHA384, hkdf::HKDF_SHA512] {
+    for &alg in &[
+        hkdf::HKDF_SHA256,
+        hkdf::HKDF_SHA384,
+        hkdf::HKDF_SHA512,
+        hkdf::HKDF_SHA512_224,
+        hkdf::HKDF_SHA512_256,
+    ] {
         const MAX_BLOCKS: usize = 255;
 
         let salt = hkdf::Salt::new(alg, &[]);
@@ -107,6 +118,234 @@ fn hkdf_output_len_tests() {
     }
 }
 
+#[cfg(feature = "alloc")]
+#[test]
+fn hkdf_key_deriver_tests() {
+    for &alg in &[hkdf::HKDF_SHA256, hkdf::HKDF_SHA384, hkdf::HKDF_SHA512] {
+        let deriver = hkdf::KeyDeriver::new(alg, b"salt", b"master secret");
+
+        let labels: [&[u8]; 3] = [b"encryption", b"authentication", b""];
+        for label in labels {
+            for len in [0, 16, 32, 100] {
+                let subkey = deriver.subkey(label, len).unwrap();
+                assert_eq!(subkey.len(), len);
+
+                // The same as extracting and expanding manually.
+                let My(expected): My<Vec<u8>> = hkdf::Salt::new(alg, b"salt")
+                    .extract(b"master secret")
+                    .expand(&[label], My(len))
+                    .unwrap()
+                    .into();
+                assert_eq!(subkey, expected);
+
+                // The same label always gives the same subkey.
+                assert_eq!(deriver.subkey(label, len).unwrap(), subkey);
+            }
+        }
+
+        // Different labels give different subkeys.
+        for (i, a) in labels.iter().enumerate() {
+            for b in &labels[..i] {
+                assert_ne!(
+                    deriver.subkey(a, 32).unwrap(),
+                    deriver.subkey(b, 32).unwrap()
+                );
+            }
+        }
+
+        // Different master secrets give different subkeys.
+        let other = hkdf::KeyDeriver::new(alg, b"salt", b"other master secret");
+        assert_ne!(
+            deriver.subkey(labels[0], 32).unwrap(),
+            other.subkey(labels[0], 32).unwrap()
+        );
+
+        let prk = hkdf::Salt::new(alg, b"salt").extract(b"master secret");
+        assert_eq!(
+            hkdf::KeyDeriver::from_prk(prk)
+                .subkey(labels[0], 32)
+                .unwrap(),
+            deriver.subkey(labels[0], 32).unwrap()
+        );
+
+        let max_len = 255 * alg.hmac_algorithm().digest_algorithm().output_len();
+        assert!(deriver.subkey(labels[0], max_len).is_ok());
+        assert!(deriver.subkey(labels[0], max_len + 1).is_err());
+        // The length is checked before the subkey is allocated.
+        assert!(deriver.subkey(labels[0], usize::MAX).is_err());
+    }
+}
+
+#[test]
+fn hkdf_derive_key_and_nonce_tests() {
+    for &alg in &[hkdf::HKDF_SHA256, hkdf::HKDF_SHA384, hkdf::HKDF_SHA512] {
+        let prk = hkdf::Salt::new(alg, b"salt").extract(b"secret");
+        for aead_alg in [
+            &aead::AES_128_GCM,
+            &aead::AES_256_GCM,
+            #[cfg(not(feature = "fips"))]
+            &aead::CHACHA20_POLY1305,
+        ] {
+            let (key, nonce) = hkdf::derive_key_and_nonce(&prk, b"key", b"iv", aead_alg);
+            assert_eq!(key.algorithm(), aead_alg);
+
+            // The same as expanding each output individually.
+            let My(expected_nonce): My<Vec<u8>> =
+                prk.expand(&[b"iv"], My(aead::NONCE_LEN)).unwrap().into();
+            assert_eq!(&nonce[..], &expected_nonce[..]);
+
+            let My(expected_key): My<Vec<u8>> = prk
+                .expand(&[b"key"], My(aead_alg.key_len()))
+                .unwrap()
+                .into();
+            let expected_key = aead::UnboundKey::new(aead_alg, &expected_key).unwrap();
+
+            // Compare the keys by what they compute since their values aren't
+            // exposed.
+            let seal = |key: aead::UnboundKey| {
+                let key = aead::LessSafeKey::new(key);
+                let nonce = aead::Nonce::assume_unique_for_key(nonce);
+                let mut in_out = b"record".to_vec();
+                key.seal_in_place_append_tag(nonce, aead::Aad::empty(), &mut in_out)
+                    .unwrap();
+                in_out
+            };
+            assert_eq!(seal(key), seal(expected_key));
+        }
+    }
+}
+
+#[test]
+fn hkdf_extract_less_safe_tests() {
+    for &alg in &[
+        hkdf::HKDF_SHA256,
+        hkdf::HKDF_SHA384,
+        hkdf::HKDF_SHA512,
+        hkdf::HKDF_SHA512_224,
+        hkdf::HKDF_SHA512_256,
+    ] {
+        let digest_alg = alg.hmac_algorithm().digest_algorithm();
+        let salt = hkdf::Salt::new(alg, b"salt");
+        let value = salt.extract_less_safe(b"secret");
+        assert_eq!(value.as_ref().len(), digest_alg.output_len());
+
+        // Constructing a `Prk` from the value gives the `Prk` that
+        // `extract()` returns.
+        let expand = |prk: &hkdf::Prk| -> Vec<u8> {
+            let My(out) = prk.expand(&[b"info"], My(42)).unwrap().into();
+            out
+        };
+        assert_eq!(
+            expand(&hkdf::Prk::new_less_safe(alg, value.as_ref())),
+            expand(&salt.extract(b"secret"))
+        );
+    }
+}
+
+#[test]
+fn hkdf_salt_from_key_tests() {
+    for &alg in &[
+        hkdf::HKDF_SHA256,
+        hkdf::HKDF_SHA384,
+        hkdf::HKDF_SHA512,
+        hkdf::HKDF_SHA512_224,
+        hkdf::HKDF_SHA512_256,
+    ] {
+        let block_len = alg.hmac_algorithm().digest_algorithm().block_len();
+
+        // Salts that are shorter, the same length as, and longer than the
+        // block length, where the last is hashed by HMAC.
+        for salt_len in [0, 13, block_len, block_len + 1, 3 * block_len] {
+            let salt_value = vec![0x5a; salt_len];
+
+            let expected = hkdf::Salt::new(alg, &salt_value);
+            let actual = hkdf::Salt::from_key(hmac::Key::new(alg.hmac_algorithm(), &salt_value));
+            assert_eq!(actual.algorithm(), alg);
+
+            let expand = |salt: &hkdf::Salt| -> My<Vec<u8>> {
+                salt.extract(b"secret")
+                    .expand(&[b"info"], My(42))
+                    .unwrap()
+                    .into()
+            };
+            assert_eq!(expand(&actual), expand(&expected));
+        }
+    }
+}
+
+#[test]
+fn hkdf_hmac_key_from_okm_tests() {
+    for &(alg, hmac_alg) in &[
+        (hkdf::HKDF_SHA256, hmac::HMAC_SHA256),
+        (hkdf::HKDF_SHA384, hmac::HMAC_SHA384),
+        (hkdf::HKDF_SHA512, hmac::HMAC_SHA512),
+    ] {
+        let prk = hkdf::Salt::new(alg, b"salt").extract(b"secret");
+        let info: &[&[u8]] = &[b"tls13 ", b"finished"];
+
+        let key = hmac::Key::from(prk.expand(info, hmac_alg).unwrap());
+        assert_eq!(key.algorithm(), hmac_alg);
+
+        let My(key_value) = prk
+            .expand(info, My(hmac_alg.digest_algorithm().output_len()))
+            .unwrap()
+            .into();
+        let expected = hmac::Key::new(hmac_alg, &key_value);
+
+        let msg = b"transcript hash";
+        assert_eq!(
+            hmac::sign(&key, msg).as_ref(),
+            hmac::sign(&expected, msg).as_ref()
+        );
+        assert!(hmac::verify(&key, msg, hmac::sign(&expected, msg).as_ref()).is_ok());
+    }
+}
+
+#[test]
+fn hkdf_quic_tests() {
+    test::run(test_file!("hkdf_quic_tests.txt"), |section, test_case| {
+        assert_eq!(section, "");
+        let secret = test_case.consume_bytes("SECRET");
+        let expected_key = test_case.consume_bytes("KEY");
+        let expected_iv = test_case.consume_bytes("IV");
+        let expected_hp = test_case.consume_bytes("HP");
+        let (aead_alg, hp_alg) = match test_case.consume_string("AEAD").as_str() {
+            "AES_128_GCM" => (&aead::AES_128_GCM, &aead::quic::AES_128),
+            #[cfg(not(feature = "fips"))]
+            "CHACHA20_POLY1305" => (&aead::CHACHA20_POLY1305, &aead::quic::CHACHA20),
+            #[cfg(feature = "fips")]
+            "CHACHA20_POLY1305" => return Ok(()),
+            s => panic!("unsupported algorithm: {}", s),
+        };
+
+        let secret = hkdf::Prk::new_less_safe(hkdf::HKDF_SHA256, &secret);
+
+        assert_eq!(&hkdf::quic::packet_iv(&secret)[..], &expected_iv[..]);
+
+        // Compare the keys by what they compute since their values aren't
+        // exposed.
+        let seal = |key: aead::UnboundKey| {
+            let key = aead::LessSafeKey::new(key);
+            let nonce = aead::Nonce::assume_unique_for_key([0; aead::NONCE_LEN]);
+            let mut in_out = b"packet".to_vec();
+            key.seal_in_place_append_tag(nonce, aead::Aad::from(b"header"), &mut in_out)
+                .unwrap();
+            in_out
+        };
+        let expected = aead::UnboundKey::new(aead_alg, &expected_key).unwrap();
+        let actual = hkdf::quic::packet_key(&secret, aead_alg);
+        assert_eq!(seal(actual), seal(expected));
+
+        let sample = [0x5a; 16];
+        let expected = aead::quic::HeaderProtectionKey::new(hp_alg, &expected_hp).unwrap();
+        let actual = hkdf::quic::hp_key(&secret, hp_alg);
+        assert_eq!(actual.algorithm(), hp_alg);
+        assert_eq!(actual.new_mask(&sample), expected.new_mask(&sample));
+
+        Ok(())
+    });
+}
+
 /// Generic newtype wrapper that lets us implement traits for externally-defined
 /// types.
 #[derive(Debug, PartialEq)]
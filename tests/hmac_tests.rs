@@ -44,6 +44,10 @@ fn hmac_tests() {
                 hmac::HMAC_SHA384
             } else if digest_alg == &digest::SHA512 {
                 hmac::HMAC_SHA512
+            } else if digest_alg == &digest::SHA512_224 {
+                hmac::HMAC_SHA512_224
+            } else if digest_alg == &digest::SHA512_256 {
+                hmac::HMAC_SHA512_256
             } else {
                 unreachable!()
             }
@@ -94,11 +98,62 @@ fn hmac_test_case_inner(
         for b in input {
             ctx.update(&[*b]);
         }
-        let signature = ctx.sign();
+        let signature = ctx.clone().sign();
         assert_eq!(is_ok, signature.as_ref() == output);
+        assert_eq!(is_ok, ctx.verify_slice(output).is_ok());
     }
 }
 
+#[test]
+fn hmac_verify_wrong_length() {
+    let key = hmac::Key::new(hmac::HMAC_SHA256, b"key");
+    let tag = hmac::sign(&key, b"data");
+    let tag = tag.as_ref();
+
+    // A prefix or an extension of the correct tag, or an empty tag, must
+    // never verify; a comparison that stopped at the end of the shorter
+    // input would accept them.
+    let mut extended = tag.to_vec();
+    extended.push(0);
+    for wrong in [&[][..], &tag[..1], &tag[..tag.len() - 1], &extended[..]] {
+        assert!(hmac::verify(&key, b"data", wrong).is_err());
+        let mut ctx = hmac::Context::with_key(&key);
+        ctx.update(b"data");
+        assert!(ctx.verify_slice(wrong).is_err());
+    }
+
+    let mut ctx = hmac::Context::with_key(&key);
+    ctx.update(b"da");
+    ctx.update(b"ta");
+    assert!(ctx.verify_slice(tag).is_ok());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn hmac_sign_multi_key() {
+    let keys = [
+        hmac::Key::new(hmac::HMAC_SHA256, b"key 1"),
+        hmac::Key::new(hmac::HMAC_SHA256, b"key 2"),
+        hmac::Key::new(hmac::HMAC_SHA384, b"key 1"),
+        hmac::Key::new(hmac::HMAC_SHA512, &[3; 200]),
+        hmac::Key::new(hmac::HMAC_SHA1_FOR_LEGACY_USE_ONLY, b""),
+    ];
+    let keys: Vec<&hmac::Key> = keys.iter().collect();
+
+    let data: Vec<u8> = (0..10_000).map(|i| i as u8).collect();
+    for len in [0, 1, 63, 64, 127, 128, 2047, 2048, 2049, 10_000] {
+        let data = &data[..len];
+        let tags = hmac::sign_multi_key(&keys, data);
+        assert_eq!(tags.len(), keys.len());
+        for (key, tag) in keys.iter().zip(tags.iter()) {
+            assert_eq!(tag.as_ref(), hmac::sign(key, data).as_ref());
+            assert!(hmac::verify(key, data, tag.as_ref()).is_ok());
+        }
+    }
+
+    assert!(hmac::sign_multi_key(&[], &data).is_empty());
+}
+
 #[test]
 fn hmac_debug() {
     let key = hmac::Key::new(hmac::HMAC_SHA256, &[0; 32]);
@@ -0,0 +1,62 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+use ring::{error, hmac, kdf, test, test_file};
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+fn sp800_108_tests() {
+    test::run(test_file!("kdf_tests.txt"), |section, test_case| {
+        let key = test_case.consume_bytes("KI");
+        let label = test_case.consume_bytes("Label");
+        let context = test_case.consume_bytes("Context");
+        let expected = test_case.consume_bytes("KO");
+
+        let mut out = vec![0u8; expected.len()];
+        let hmac_alg = match section {
+            "AES-CMAC" => None,
+            "HMAC-SHA1" => Some(hmac::HMAC_SHA1_FOR_LEGACY_USE_ONLY),
+            "HMAC-SHA256" => Some(hmac::HMAC_SHA256),
+            "HMAC-SHA384" => Some(hmac::HMAC_SHA384),
+            "HMAC-SHA512" => Some(hmac::HMAC_SHA512),
+            _ => unreachable!(),
+        };
+        match hmac_alg {
+            None => kdf::sp800_108_cmac(&key, &label, &context, &mut out)?,
+            Some(alg) => {
+                let key = hmac::Key::new(alg, &key);
+                kdf::sp800_108_hmac(&key, &label, &context, &mut out)?
+            }
+        }
+        assert_eq!(out, expected);
+
+        Ok(())
+    });
+}
+
+#[test]
+fn sp800_108_cmac_bad_key_len_test() {
+    let mut out = [0u8; 16];
+    for len in [0, 15, 17, 24, 31, 33] {
+        assert_eq!(
+            kdf::sp800_108_cmac(&vec![0u8; len], b"label", b"context", &mut out),
+            Err(error::Unspecified)
+        );
+    }
+}
@@ -0,0 +1,186 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+#![cfg(feature = "alloc")]
+
+use core::num::NonZeroU32;
+use ring::{
+    aead, hmac,
+    keyring::{KeyType, Keyring},
+    rand,
+    signature::{self, KeyPair as _},
+};
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+wasm_bindgen_test_configure!(run_in_browser);
+
+// Keep the tests fast; real uses need many more iterations.
+const ITERATIONS: NonZeroU32 = match NonZeroU32::new(10) {
+    Some(iterations) => iterations,
+    None => unreachable!(),
+};
+
+fn mixed_keyring(rng: &dyn rand::SecureRandom) -> Keyring {
+    let ed25519 = signature::Ed25519KeyPair::generate_pkcs8(rng).unwrap();
+
+    let mut keyring = Keyring::new();
+    keyring
+        .add(b"aes128", KeyType::Aes128Gcm, &[1; 16])
+        .unwrap();
+    keyring
+        .add(b"chacha", KeyType::ChaCha20Poly1305, &[2; 32])
+        .unwrap();
+    keyring
+        .add(b"mac", KeyType::HmacSha256, b"an hmac key")
+        .unwrap();
+    keyring
+        .add(b"signing", KeyType::Pkcs8, ed25519.as_ref())
+        .unwrap();
+    keyring
+}
+
+fn check_mixed_keyring(original: &Keyring, parsed: &Keyring) {
+    let ids = |keyring: &Keyring| {
+        keyring
+            .entries()
+            .map(|entry| (entry.id().to_vec(), entry.key_type()))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(ids(parsed), ids(original));
+
+    // The keys themselves round-trip.
+    for id in [&b"aes128"[..], b"chacha"] {
+        if cfg!(feature = "fips") && id == b"chacha" {
+            assert!(parsed.get(id).unwrap().aead_key().is_err());
+            continue;
+        }
+        let sealing = aead::LessSafeKey::new(original.get(id).unwrap().aead_key().unwrap());
+        let opening = aead::LessSafeKey::new(parsed.get(id).unwrap().aead_key().unwrap());
+        let mut in_out = b"message".to_vec();
+        let nonce = || aead::Nonce::assume_unique_for_key([0; aead::NONCE_LEN]);
+        sealing
+            .seal_in_place_append_tag(nonce(), aead::Aad::empty(), &mut in_out)
+            .unwrap();
+        let plaintext = opening
+            .open_in_place(nonce(), aead::Aad::empty(), &mut in_out)
+            .unwrap();
+        assert_eq!(plaintext, b"message");
+    }
+
+    let tag = hmac::sign(&original.get(b"mac").unwrap().hmac_key().unwrap(), b"msg");
+    hmac::verify(
+        &parsed.get(b"mac").unwrap().hmac_key().unwrap(),
+        b"msg",
+        tag.as_ref(),
+    )
+    .unwrap();
+
+    let original_pair =
+        signature::Ed25519KeyPair::from_pkcs8(original.get(b"signing").unwrap().pkcs8().unwrap())
+            .unwrap();
+    let parsed_pair =
+        signature::Ed25519KeyPair::from_pkcs8(parsed.get(b"signing").unwrap().pkcs8().unwrap())
+            .unwrap();
+    assert_eq!(
+        original_pair.public_key().as_ref(),
+        parsed_pair.public_key().as_ref()
+    );
+}
+
+#[test]
+fn keyring_round_trip_test() {
+    let rng = rand::SystemRandom::new();
+    let keyring = mixed_keyring(&rng);
+
+    let serialized = keyring.serialize();
+    let parsed = Keyring::parse(&serialized).unwrap();
+    check_mixed_keyring(&keyring, &parsed);
+
+    // An unprotected keyring can't be parsed as a protected one.
+    assert!(Keyring::parse_with_passphrase(&serialized, b"passphrase").is_err());
+
+    // Truncation and trailing data are detected.
+    assert!(Keyring::parse(&serialized[..serialized.len() - 1]).is_err());
+    let mut extended = serialized.clone();
+    extended.push(0);
+    assert!(Keyring::parse(&extended).is_err());
+
+    // Other versions are rejected.
+    let mut other_version = serialized.clone();
+    other_version[4] = 2;
+    assert!(Keyring::parse(&other_version).is_err());
+}
+
+#[test]
+fn keyring_passphrase_round_trip_test() {
+    let rng = rand::SystemRandom::new();
+    let keyring = mixed_keyring(&rng);
+
+    let serialized = keyring
+        .serialize_with_passphrase(b"correct horse", ITERATIONS, &rng)
+        .unwrap();
+    let parsed = Keyring::parse_with_passphrase(&serialized, b"correct horse").unwrap();
+    check_mixed_keyring(&keyring, &parsed);
+
+    // A protected keyring can't be parsed without the passphrase.
+    assert!(Keyring::parse(&serialized).is_err());
+}
+
+#[test]
+fn keyring_wrong_passphrase_test() {
+    let rng = rand::SystemRandom::new();
+    let keyring = mixed_keyring(&rng);
+    let serialized = keyring
+        .serialize_with_passphrase(b"correct horse", ITERATIONS, &rng)
+        .unwrap();
+
+    assert!(Keyring::parse_with_passphrase(&serialized, b"battery staple").is_err());
+    assert!(Keyring::parse_with_passphrase(&serialized, b"").is_err());
+
+    // The header is authenticated too, e.g. the iteration count can't be
+    // changed.
+    let mut tampered = serialized.clone();
+    tampered[9] ^= 1;
+    assert!(Keyring::parse_with_passphrase(&tampered, b"correct horse").is_err());
+}
+
+#[test]
+fn keyring_add_test() {
+    let mut keyring = Keyring::new();
+    keyring.add(b"a", KeyType::Aes256Gcm, &[0; 32]).unwrap();
+
+    // Key IDs are unique.
+    assert!(keyring.add(b"a", KeyType::HmacSha512, &[0; 64]).is_err());
+
+    // AEAD keys must have the right length.
+    assert!(keyring.add(b"b", KeyType::Aes256Gcm, &[0; 16]).is_err());
+
+    // Key IDs must be 1 to 255 bytes.
+    assert!(keyring.add(b"", KeyType::HmacSha384, &[0; 48]).is_err());
+    assert!(keyring
+        .add(&[b'c'; 256], KeyType::HmacSha384, &[0; 48])
+        .is_err());
+    keyring
+        .add(&[b'c'; 255], KeyType::HmacSha384, &[0; 48])
+        .unwrap();
+
+    // A key of a non-AEAD type can't be used as an AEAD key, and vice versa.
+    assert!(keyring.get(&[b'c'; 255]).unwrap().aead_key().is_err());
+    assert!(keyring.get(b"a").unwrap().hmac_key().is_err());
+    assert!(keyring.get(b"a").unwrap().pkcs8().is_err());
+    assert!(keyring.get(b"missing").is_none());
+}
@@ -0,0 +1,85 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+use ring::{error, mac, test, test_file};
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+fn kmac_tests() {
+    test::run(test_file!("mac_tests.txt"), |section, test_case| {
+        let key = test_case.consume_bytes("Key");
+        let data = test_case.consume_bytes("Data");
+        let customization = test_case.consume_bytes("S");
+        let expected = test_case.consume_bytes("Tag");
+
+        let (tag, verified) = match section {
+            "KMAC128" => {
+                let key = mac::Kmac128::new(&key, &customization, expected.len())?;
+                assert_eq!(key.tag_len(), expected.len());
+                (key.sign(&data), key.verify(&data, &expected))
+            }
+            "KMAC256" => {
+                let key = mac::Kmac256::new(&key, &customization, expected.len())?;
+                assert_eq!(key.tag_len(), expected.len());
+                (key.sign(&data), key.verify(&data, &expected))
+            }
+            _ => unreachable!(),
+        };
+        assert_eq!(tag.as_ref(), &expected[..]);
+        assert_eq!(verified, Ok(()));
+
+        Ok(())
+    });
+}
+
+#[test]
+fn kmac_verify_test() {
+    let key = mac::Kmac256::new(b"key", b"customization", 32).unwrap();
+    let tag = key.sign(b"data");
+    assert_eq!(key.verify(b"data", tag.as_ref()), Ok(()));
+    assert_eq!(key.verify(b"datb", tag.as_ref()), Err(error::Unspecified));
+    assert_eq!(
+        key.verify(b"data", &tag.as_ref()[..31]),
+        Err(error::Unspecified)
+    );
+
+    // The customization string and the tag length change the whole tag.
+    let other = mac::Kmac256::new(b"key", b"customizatioN", 32).unwrap();
+    assert_eq!(other.verify(b"data", tag.as_ref()), Err(error::Unspecified));
+    let longer = mac::Kmac256::new(b"key", b"customization", 33).unwrap();
+    assert_ne!(&longer.sign(b"data").as_ref()[..32], tag.as_ref());
+}
+
+#[test]
+fn kmac_tag_len_test() {
+    for tag_len in [0, mac::MIN_TAG_LEN - 1, mac::MAX_TAG_LEN + 1] {
+        assert!(mac::Kmac128::new(b"key", b"", tag_len).is_err());
+        assert!(mac::Kmac256::new(b"key", b"", tag_len).is_err());
+    }
+    for tag_len in [mac::MIN_TAG_LEN, mac::MAX_TAG_LEN] {
+        assert!(mac::Kmac128::new(b"key", b"", tag_len).is_ok());
+        assert!(mac::Kmac256::new(b"key", b"", tag_len).is_ok());
+    }
+}
+
+#[test]
+fn kmac_key_debug() {
+    let key = mac::Kmac128::new(b"key", b"", 16).unwrap();
+    assert_eq!("Kmac128 { tag_len: 16 }", format!("{:?}", key));
+}
@@ -0,0 +1,151 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+use ring::{error, noise, test, test_file};
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+wasm_bindgen_test_configure!(run_in_browser);
+
+const CIPHERS: &[&noise::Cipher] = &[
+    &noise::AES_256_GCM,
+    #[cfg(not(feature = "fips"))]
+    &noise::CHACHA20_POLY1305,
+];
+
+#[test]
+fn noise_cipher_state_tests() {
+    test::run(test_file!("noise_tests.txt"), |section, test_case| {
+        let key = test_case.consume_bytes("Key");
+        let rekeys = test_case.consume_usize("Rekeys");
+        let nonce: u64 = test_case
+            .consume_string("Nonce")
+            .parse()
+            .map_err(|_| error::Unspecified)?;
+        let ad = test_case.consume_bytes("AD");
+        let plaintext = test_case.consume_bytes("Plaintext");
+        let ciphertext = test_case.consume_bytes("Ciphertext");
+        let key: &[u8; noise::KEY_LEN] = key.as_slice().try_into()?;
+        let cipher = match section {
+            "AESGCM" => &noise::AES_256_GCM,
+            #[cfg(not(feature = "fips"))]
+            "ChaChaPoly" => &noise::CHACHA20_POLY1305,
+            #[cfg(feature = "fips")]
+            "ChaChaPoly" => return Ok(()),
+            _ => unreachable!(),
+        };
+
+        let new_state = || {
+            let mut state = noise::CipherState::with_key(cipher, key);
+            for _ in 0..rekeys {
+                state.rekey().unwrap();
+            }
+            state.set_nonce(nonce);
+            state
+        };
+
+        let mut in_out = plaintext.clone();
+        new_state().encrypt_with_ad(&ad, &mut in_out)?;
+        assert_eq!(in_out, ciphertext);
+
+        let mut in_out = ciphertext.clone();
+        let mut state = new_state();
+        assert_eq!(state.decrypt_with_ad(&ad, &mut in_out)?, &plaintext[..]);
+
+        Ok(())
+    });
+}
+
+#[test]
+fn noise_cipher_state_empty_key() {
+    for cipher in CIPHERS {
+        let mut state = noise::CipherState::new(cipher);
+        assert!(!state.has_key());
+
+        let mut in_out = b"plaintext".to_vec();
+        state.encrypt_with_ad(b"ad", &mut in_out).unwrap();
+        assert_eq!(in_out, b"plaintext");
+        assert_eq!(
+            state.decrypt_with_ad(b"ad", &mut in_out).unwrap(),
+            b"plaintext"
+        );
+        assert!(state.rekey().is_err());
+    }
+}
+
+#[test]
+fn noise_cipher_state_round_trip() {
+    for cipher in CIPHERS {
+        let key = [0x42; noise::KEY_LEN];
+        let mut sender = noise::CipherState::with_key(cipher, &key);
+        let mut receiver = noise::CipherState::with_key(cipher, &key);
+        assert!(sender.has_key());
+
+        for i in 0..3u8 {
+            let mut in_out = vec![i; 10];
+            sender.encrypt_with_ad(&[i], &mut in_out).unwrap();
+
+            // Decrypting with the wrong AD fails and doesn't consume a nonce.
+            let mut wrong = in_out.clone();
+            assert!(receiver.decrypt_with_ad(&[i + 1], &mut wrong).is_err());
+
+            assert_eq!(
+                receiver.decrypt_with_ad(&[i], &mut in_out).unwrap(),
+                &[i; 10][..]
+            );
+        }
+
+        // Both sides rekey; `n` continues from where it was.
+        sender.rekey().unwrap();
+        receiver.rekey().unwrap();
+        let mut in_out = b"after rekey".to_vec();
+        sender.encrypt_with_ad(b"", &mut in_out).unwrap();
+        assert_eq!(
+            receiver.decrypt_with_ad(b"", &mut in_out).unwrap(),
+            b"after rekey"
+        );
+    }
+}
+
+#[test]
+fn noise_cipher_state_nonce_exhaustion() {
+    for cipher in CIPHERS {
+        let key = [0x42; noise::KEY_LEN];
+        let mut sender = noise::CipherState::with_key(cipher, &key);
+        let mut receiver = noise::CipherState::with_key(cipher, &key);
+        sender.set_nonce(u64::MAX - 1);
+        receiver.set_nonce(u64::MAX - 1);
+
+        // The last usable nonce is 2^64 - 2.
+        let mut in_out = b"last".to_vec();
+        sender.encrypt_with_ad(b"", &mut in_out).unwrap();
+        assert_eq!(receiver.decrypt_with_ad(b"", &mut in_out).unwrap(), b"last");
+
+        // 2^64 - 1 is reserved, so every further call fails.
+        let mut in_out = b"too many".to_vec();
+        assert!(sender.encrypt_with_ad(b"", &mut in_out).is_err());
+        assert_eq!(in_out, b"too many");
+        assert!(sender.encrypt_with_ad(b"", &mut in_out).is_err());
+        assert!(receiver.decrypt_with_ad(b"", &mut in_out).is_err());
+
+        // Rekeying still works since it uses the reserved nonce.
+        sender.rekey().unwrap();
+
+        // Re-initializing the key resets `n`.
+        sender.initialize_key(&key);
+        sender.encrypt_with_ad(b"", &mut in_out).unwrap();
+    }
+}
@@ -13,6 +13,7 @@
 // CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
 
 use ring::{
+    digest, hmac,
     rand::{self, SecureRandom as _},
     test,
 };
@@ -76,3 +77,323 @@ fn test_system_random_traits() {
         format!("{:?}", rand::SystemRandom::new())
     );
 }
+
+#[test]
+fn test_callback_random() {
+    let counter = core::cell::Cell::new(0u8);
+    let rng = rand::CallbackRandom::new(|dest: &mut [u8]| {
+        for b in dest {
+            *b = counter.get();
+            counter.set(counter.get().wrapping_add(1));
+        }
+        Ok(())
+    });
+    let mut buf = [0u8; 4];
+    assert!(rng.fill(&mut buf).is_ok());
+    assert_eq!(buf, [0, 1, 2, 3]);
+    assert!(rng.fill(&mut buf).is_ok());
+    assert_eq!(buf, [4, 5, 6, 7]);
+
+    let a: [u8; 2] = rand::generate(&rng).unwrap().expose();
+    assert_eq!(a, [8, 9]);
+
+    assert_eq!("CallbackRandom", format!("{:?}", rng));
+}
+
+#[test]
+fn test_callback_random_propagates_errors() {
+    let rng = rand::CallbackRandom::new(|_: &mut [u8]| Err(()));
+    assert!(rng.fill(&mut [0u8; 4]).is_err());
+    assert!(rand::generate::<[u8; 4]>(&rng).is_err());
+
+    // Key generation fails when the entropy source fails.
+    assert!(ring::signature::EcdsaKeyPair::generate_pkcs8(
+        &ring::signature::ECDSA_P256_SHA256_FIXED_SIGNING,
+        &rng
+    )
+    .is_err());
+}
+
+#[test]
+fn test_health_check() {
+    assert_eq!(rand::health_check(&rand::SystemRandom::new()), Ok(()));
+
+    // A source that is stuck on a constant value.
+    let stuck = rand::CallbackRandom::new(|dest: &mut [u8]| {
+        dest.fill(0x5a);
+        Ok(())
+    });
+    assert_eq!(
+        rand::health_check(&stuck),
+        Err(rand::HealthError::RepetitionCount)
+    );
+
+    // A source that alternates between two values never repeats a value
+    // twice in a row, but it is caught by the adaptive proportion test.
+    let counter = core::cell::Cell::new(0u8);
+    let alternating = rand::CallbackRandom::new(|dest: &mut [u8]| {
+        for b in dest {
+            *b = counter.get() & 1;
+            counter.set(counter.get().wrapping_add(1));
+        }
+        Ok(())
+    });
+    assert_eq!(
+        rand::health_check(&alternating),
+        Err(rand::HealthError::AdaptiveProportion)
+    );
+
+    // A counter passes both tests, which is why passing them doesn't mean
+    // that a source is secure.
+    let counter = core::cell::Cell::new(0u8);
+    let counting = rand::CallbackRandom::new(|dest: &mut [u8]| {
+        for b in dest {
+            *b = counter.get();
+            counter.set(counter.get().wrapping_add(1));
+        }
+        Ok(())
+    });
+    assert_eq!(rand::health_check(&counting), Ok(()));
+
+    let failing = rand::CallbackRandom::new(|_: &mut [u8]| Err(()));
+    assert_eq!(
+        rand::health_check(&failing),
+        Err(rand::HealthError::FillFailed)
+    );
+}
+
+#[test]
+fn test_hmac_drbg_rfc6979() {
+    // RFC 6979 generates the ECDSA nonce `k` with HMAC_DRBG instantiated
+    // with the private key as the entropy input and the message hash as the
+    // nonce. These are the P-256 cases of Appendix A.2.5, where the first
+    // output is the `k` that is used.
+    let x =
+        test::from_hex("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721").unwrap();
+    let q =
+        test::from_hex("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551").unwrap();
+    for (algorithm, message, expected_k) in [
+        (
+            hmac::HMAC_SHA256,
+            &b"sample"[..],
+            "a6e3c57dd01abe90086538398355dd4c3b17aa873382b0f24d6129493d8aad60",
+        ),
+        (
+            hmac::HMAC_SHA256,
+            b"test",
+            "d16b6ae827f17175e040871a1c7ec3500192c4c92677336ec2537acaee0008e0",
+        ),
+        (
+            hmac::HMAC_SHA512,
+            b"sample",
+            "5fa81c63109badb88c1f367b47da606da28cad69aa22c4fe6ad7df73a7173aa5",
+        ),
+    ] {
+        // bits2octets(h1) is the leftmost 256 bits of the hash, reduced mod
+        // q; these are all already less than q.
+        let h = digest::digest(algorithm.digest_algorithm(), message);
+        let h1 = &h.as_ref()[..32];
+        assert!(h1 < &q[..]);
+
+        let mut drbg = rand::HmacDrbg::new(algorithm, &x, h1, &[]).unwrap();
+        let mut k = [0u8; 32];
+        drbg.fill(&mut k).unwrap();
+        assert_eq!(&k[..], &test::from_hex(expected_k).unwrap()[..]);
+    }
+}
+
+#[test]
+fn test_hmac_drbg_additional_input() {
+    let entropy: Vec<u8> = (0..32).collect();
+    let nonce: Vec<u8> = (0x20..0x30).collect();
+    let new = || rand::HmacDrbg::new(hmac::HMAC_SHA256, &entropy, &nonce, b"ring test").unwrap();
+
+    // Different additional inputs produce different output from the same
+    // state, and the same additional input produces the same output.
+    let fill = |additional_input: &[u8]| {
+        let mut drbg = new();
+        let mut out = [0u8; 40];
+        drbg.fill_with_additional(&mut out, additional_input)
+            .unwrap();
+        out
+    };
+    assert_eq!(fill(b"request 1"), fill(b"request 1"));
+    assert_ne!(fill(b"request 1"), fill(b"request 2"));
+    assert_ne!(fill(b"request 1"), fill(b""));
+    let mut out = [0u8; 40];
+    new().fill(&mut out).unwrap();
+    assert_eq!(fill(b""), out);
+
+    // The additional input also changes the state afterwards.
+    let mut a = new();
+    let mut b = new();
+    let (mut out_a, mut out_b) = ([0u8; 40], [0u8; 40]);
+    a.fill_with_additional(&mut out_a, b"request 1").unwrap();
+    b.fill_with_additional(&mut out_b, b"request 2").unwrap();
+    a.fill(&mut out_a).unwrap();
+    b.fill(&mut out_b).unwrap();
+    assert_ne!(out_a, out_b);
+
+    // Known answers for a sequence of calls with a reseed, from an
+    // independent implementation of SP 800-90A.
+    let mut drbg = new();
+    let mut out = [0u8; 40];
+    for (expected, additional_input) in [
+        (
+            "28c75b1e7b51423cca8cce542d7e96908e44d85ed4069ef587b71ff3491301daf404465ac0e38ef6",
+            &b"request 1"[..],
+        ),
+        (
+            "79b35534b789c7687772776f597562ac95eaf8f125319966adcb8489e7fe04afcfd03c40295bf15d",
+            b"",
+        ),
+    ] {
+        drbg.fill_with_additional(&mut out, additional_input)
+            .unwrap();
+        assert_eq!(&out[..], &test::from_hex(expected).unwrap()[..]);
+    }
+    let reseed_entropy: Vec<u8> = (0x40..0x60).collect();
+    drbg.reseed(&reseed_entropy, b"reseed").unwrap();
+    drbg.fill_with_additional(&mut out, b"request 2").unwrap();
+    assert_eq!(
+        &out[..],
+        &test::from_hex(
+            "7fc7e8c883b33cb59949c3176ca0c0b5cc38030245d06b3a97b1739f0aec8d0e8b274b892093edb2"
+        )
+        .unwrap()[..]
+    );
+}
+
+#[test]
+fn test_hmac_drbg_limits() {
+    let entropy = [7; 32];
+
+    // The entropy input must be at least as long as the security strength.
+    assert!(rand::HmacDrbg::new(hmac::HMAC_SHA256, &entropy[..31], &[], &[]).is_err());
+    assert!(rand::HmacDrbg::new(hmac::HMAC_SHA512, &entropy[..31], &[], &[]).is_err());
+    assert!(rand::HmacDrbg::new(hmac::HMAC_SHA512, &entropy, &[], &[]).is_ok());
+    assert!(rand::HmacDrbg::new(
+        hmac::HMAC_SHA1_FOR_LEGACY_USE_ONLY,
+        &entropy[..20],
+        &[],
+        &[]
+    )
+    .is_ok());
+
+    let mut drbg = rand::HmacDrbg::new(hmac::HMAC_SHA256, &entropy, &[], &[]).unwrap();
+    assert!(drbg.reseed(&entropy[..31], &[]).is_err());
+    assert!(drbg.reseed(&entropy, &[]).is_ok());
+
+    let mut out = vec![0u8; rand::HmacDrbg::MAX_REQUEST_LEN + 1];
+    assert!(drbg.fill(&mut out).is_err());
+    assert!(out.iter().all(|&b| b == 0));
+    assert!(drbg
+        .fill(&mut out[..rand::HmacDrbg::MAX_REQUEST_LEN])
+        .is_ok());
+    assert!(drbg.fill(&mut []).is_ok());
+
+    assert_eq!("HmacDrbg { algorithm: SHA256 }", format!("{:?}", drbg));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_random_prime() {
+    let rng = rand::SystemRandom::new();
+    for &bits in &[256, 257, 263, 384, 521] {
+        let p = rand::random_prime(bits, &rng).unwrap();
+        assert_eq!(p.len(), (bits + 7) / 8);
+        let leading_zeros = usize::try_from(p[0].leading_zeros()).unwrap();
+        assert_eq!((8 * p.len()) - leading_zeros, bits);
+        assert!(is_probable_prime_fermat(&p), "{:?}", p);
+    }
+
+    assert!(rand::random_prime(255, &rng).is_err());
+    assert!(rand::random_prime(8193, &rng).is_err());
+}
+
+// Trial division and the Fermat test to bases 2 and 3, using simple
+// arithmetic that is independent of *ring*'s, for the big-endian `n`.
+#[cfg(feature = "alloc")]
+fn is_probable_prime_fermat(n: &[u8]) -> bool {
+    let n = limbs_from_be_bytes(n);
+    let small_factor = (3..1000u64).step_by(2).any(|p| {
+        n.iter().rev().fold(0, |r, &limb| {
+            ((u128::from(r) << 64 | u128::from(limb)) % u128::from(p)) as u64
+        }) == 0
+    });
+    if n[0] & 1 == 0 || small_factor {
+        return false;
+    }
+
+    let mut n_minus_1 = n.clone();
+    n_minus_1[0] -= 1;
+    let mut one = vec![0; n.len()];
+    one[0] = 1;
+    [2, 3].iter().all(|&base| {
+        let mut a = vec![0; n.len()];
+        a[0] = base;
+        pow_mod(&a, &n_minus_1, &n) == one
+    })
+}
+
+// Little-endian 64-bit limbs.
+#[cfg(feature = "alloc")]
+fn limbs_from_be_bytes(bytes: &[u8]) -> Vec<u64> {
+    bytes
+        .rchunks(8)
+        .map(|chunk| chunk.iter().fold(0, |r, &b| (r << 8) | u64::from(b)))
+        .collect()
+}
+
+// a**e (mod n) by square-and-multiply, where a < n.
+#[cfg(feature = "alloc")]
+fn pow_mod(a: &[u64], e: &[u64], n: &[u64]) -> Vec<u64> {
+    let mut r = vec![0; n.len()];
+    r[0] = 1;
+    for i in (0..(64 * e.len())).rev() {
+        r = mul_mod(&r, &r, n);
+        if (e[i / 64] >> (i % 64)) & 1 == 1 {
+            r = mul_mod(&r, a, n);
+        }
+    }
+    r
+}
+
+// a * b (mod n) by double-and-add, where a, b < n.
+#[cfg(feature = "alloc")]
+fn mul_mod(a: &[u64], b: &[u64], n: &[u64]) -> Vec<u64> {
+    let mut r = vec![0; n.len()];
+    for i in (0..(64 * b.len())).rev() {
+        r = add_mod(&r, &r, n);
+        if (b[i / 64] >> (i % 64)) & 1 == 1 {
+            r = add_mod(&r, a, n);
+        }
+    }
+    r
+}
+
+// a + b (mod n), where a, b < n.
+#[cfg(feature = "alloc")]
+fn add_mod(a: &[u64], b: &[u64], n: &[u64]) -> Vec<u64> {
+    let mut carry = false;
+    let mut r: Vec<u64> = a
+        .iter()
+        .zip(b)
+        .map(|(&a, &b)| {
+            let (s, c1) = a.overflowing_add(b);
+            let (s, c2) = s.overflowing_add(u64::from(carry));
+            carry = c1 || c2;
+            s
+        })
+        .collect();
+    if carry || r.iter().rev().cmp(n.iter().rev()) != core::cmp::Ordering::Less {
+        let mut borrow = false;
+        for (r, &n) in r.iter_mut().zip(n) {
+            let (d, b1) = r.overflowing_sub(n);
+            let (d, b2) = d.overflowing_sub(u64::from(borrow));
+            borrow = b1 || b2;
+            *r = d;
+        }
+    }
+    r
+}
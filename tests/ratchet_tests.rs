@@ -0,0 +1,52 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+use ring::{ratchet, test, test_file};
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+fn ratchet_tests() {
+    test::run(test_file!("ratchet_tests.txt"), |section, test_case| {
+        match section {
+            "KDF_RK" => {
+                let root_key = test_case.consume_bytes("RootKey");
+                let dh_out = test_case.consume_bytes("DHOut");
+                let info = test_case.consume_bytes("Info");
+                let expected_root_key = test_case.consume_bytes("NextRootKey");
+                let expected_chain_key = test_case.consume_bytes("ChainKey");
+
+                let (root_key, chain_key) =
+                    ratchet::kdf_rk(root_key.as_slice().try_into()?, &dh_out, &info);
+                assert_eq!(&root_key[..], &expected_root_key[..]);
+                assert_eq!(&chain_key[..], &expected_chain_key[..]);
+            }
+            "KDF_CK" => {
+                let chain_key = test_case.consume_bytes("ChainKey");
+                let expected_chain_key = test_case.consume_bytes("NextChainKey");
+                let expected_message_key = test_case.consume_bytes("MessageKey");
+
+                let (chain_key, message_key) = ratchet::kdf_ck(chain_key.as_slice().try_into()?);
+                assert_eq!(&chain_key[..], &expected_chain_key[..]);
+                assert_eq!(&message_key[..], &expected_message_key[..]);
+            }
+            _ => unreachable!(),
+        }
+        Ok(())
+    });
+}
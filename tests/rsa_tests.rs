@@ -15,7 +15,7 @@
 #![cfg(feature = "alloc")]
 
 use ring::{
-    error,
+    digest, error,
     io::der,
     rand, rsa,
     signature::{self, KeyPair},
@@ -160,16 +160,19 @@ fn test_signature_rsa_pkcs1_sign_output_buffer_len() {
 #[test]
 fn test_signature_rsa_pkcs1_verify() {
     let sha1_params = &[
+        #[cfg(not(feature = "fips"))]
         (
             &signature::RSA_PKCS1_1024_8192_SHA1_FOR_LEGACY_USE_ONLY,
             1024,
         ),
+        #[cfg(not(feature = "fips"))]
         (
             &signature::RSA_PKCS1_2048_8192_SHA1_FOR_LEGACY_USE_ONLY,
             2048,
         ),
     ];
     let sha256_params = &[
+        #[cfg(not(feature = "fips"))]
         (
             &signature::RSA_PKCS1_1024_8192_SHA256_FOR_LEGACY_USE_ONLY,
             1024,
@@ -181,6 +184,7 @@ fn test_signature_rsa_pkcs1_verify() {
         (&signature::RSA_PKCS1_3072_8192_SHA384, 3072),
     ];
     let sha512_params = &[
+        #[cfg(not(feature = "fips"))]
         (
             &signature::RSA_PKCS1_1024_8192_SHA512_FOR_LEGACY_USE_ONLY,
             1024,
@@ -193,11 +197,11 @@ fn test_signature_rsa_pkcs1_verify() {
             assert_eq!(section, "");
 
             let digest_name = test_case.consume_string("Digest");
-            let params: &[_] = match digest_name.as_ref() {
-                "SHA1" => sha1_params,
-                "SHA256" => sha256_params,
-                "SHA384" => sha384_params,
-                "SHA512" => sha512_params,
+            let (params, digest_alg): (&[_], _) = match digest_name.as_ref() {
+                "SHA1" => (sha1_params, &digest::SHA1_FOR_LEGACY_USE_ONLY),
+                "SHA256" => (sha256_params, &digest::SHA256),
+                "SHA384" => (sha384_params, &digest::SHA384),
+                "SHA512" => (sha512_params, &digest::SHA512),
                 _ => panic!("Unsupported digest: {}", digest_name),
             };
 
@@ -225,10 +229,15 @@ fn test_signature_rsa_pkcs1_verify() {
             let msg = test_case.consume_bytes("Msg");
             let sig = test_case.consume_bytes("Sig");
             let is_valid = test_case.consume_string("Result") == "P";
+            let msg_digest = digest::digest(digest_alg, &msg);
             for &(alg, min_bits) in params {
                 let width_ok = key_bits >= min_bits;
-                let actual_result =
-                    signature::UnparsedPublicKey::new(alg, &public_key).verify(&msg, &sig);
+                let key = signature::UnparsedPublicKey::new(alg, &public_key);
+                let actual_result = key.verify(&msg, &sig);
+                assert_eq!(actual_result.is_ok(), is_valid && width_ok);
+                let actual_result = key.verify_digest(&msg_digest, &sig);
+                assert_eq!(actual_result.is_ok(), is_valid && width_ok);
+                let actual_result = verify_with_rsa_verifier(alg, &public_key, &msg, &sig);
                 assert_eq!(actual_result.is_ok(), is_valid && width_ok);
             }
 
@@ -278,12 +287,94 @@ fn test_signature_rsa_pss_verify() {
             let actual_result =
                 signature::UnparsedPublicKey::new(alg, &public_key).verify(&msg, &sig);
             assert_eq!(actual_result.is_ok(), is_valid);
+            let actual_result = verify_with_rsa_verifier(alg, &public_key, &msg, &sig);
+            assert_eq!(actual_result.is_ok(), is_valid);
 
             Ok(())
         },
     );
 }
 
+#[test]
+fn test_rsa_pss_verify_em() {
+    test::run(test_file!("rsa_pss_em_tests.txt"), |section, test_case| {
+        assert_eq!(section, "");
+
+        let digest_name = test_case.consume_string("Digest");
+        let alg = match digest_name.as_ref() {
+            "SHA256" => &signature::RSA_PSS_SHA256,
+            "SHA384" => &signature::RSA_PSS_SHA384,
+            "SHA512" => &signature::RSA_PSS_SHA512,
+            _ => panic!("Unsupported digest: {}", digest_name),
+        };
+
+        let m_hash = test_case.consume_bytes("MHash");
+        // The salt and H are recomputed in verification.
+        let _ = test_case.consume_bytes("Salt");
+        let _ = test_case.consume_bytes("H");
+        let em_bits = test_case.consume_usize("EmBits");
+        let em = test_case.consume_bytes("EM");
+        let is_valid = test_case.consume_string("Result") == "P";
+
+        let actual_result = rsa::pss::verify_em(alg, em_bits, &em, &m_hash);
+        assert_eq!(actual_result.is_ok(), is_valid);
+
+        // `em` must be exactly `ceil(em_bits / 8)` bytes long.
+        if is_valid {
+            for em_bits in [8 * em.len() + 1, 8 * (em.len() - 1), usize::MAX] {
+                assert!(rsa::pss::verify_em(alg, em_bits, &em, &m_hash).is_err());
+            }
+        }
+
+        Ok(())
+    });
+
+    // Encodings longer than any supported public modulus are rejected.
+    let em = [0; 1024 + 1];
+    assert!(rsa::pss::verify_em(&signature::RSA_PSS_SHA256, 8 * em.len(), &em, &[0; 32]).is_err());
+
+    // Huge `em_bits` are rejected without overflowing.
+    let em = [0; 256];
+    assert!(rsa::pss::verify_em(&signature::RSA_PSS_SHA256, usize::MAX, &em, &[0; 32]).is_err());
+}
+
+// Signatures of 0, 1, n - 1, and values that aren't less than n are rejected,
+// for both PKCS#1 1.5 and PSS padding.
+#[cfg(feature = "alloc")]
+#[test]
+fn test_signature_rsa_verify_rejects_degenerate_signatures() {
+    const N: &[u8] = include_bytes!("rsa_test_public_modulus.bin");
+    const MSG: &[u8] = b"hello, world";
+
+    let public_key = signature::RsaPublicKeyComponents {
+        n: N,
+        e: &[0x01, 0x00, 0x01][..],
+    };
+
+    let mut n_minus_one = N.to_vec();
+    *n_minus_one.last_mut().unwrap() -= 1; // `n` is odd.
+    let mut one = vec![0u8; N.len()];
+    *one.last_mut().unwrap() = 1;
+    let mut n_plus_one = N.to_vec();
+    *n_plus_one.last_mut().unwrap() += 1;
+
+    for s in [
+        N.to_vec(),
+        vec![0u8; N.len()],
+        n_minus_one,
+        one,
+        n_plus_one,
+        vec![0xff; N.len()],
+    ] {
+        for params in [
+            &signature::RSA_PKCS1_2048_8192_SHA256,
+            &signature::RSA_PSS_2048_8192_SHA256,
+        ] {
+            assert_eq!(public_key.verify(params, MSG, &s), Err(error::Unspecified));
+        }
+    }
+}
+
 // Test for `primitive::verify()`. Read public key parts from a file
 // and use them to verify a signature.
 #[cfg(feature = "alloc")]
@@ -343,3 +434,16 @@ fn test_public_key_coverage(key: &rsa::PublicKey) {
     const _65537: &[u8] = &[0x01, 0x00, 0x01];
     assert_eq!(_65537, &components.e);
 }
+
+fn verify_with_rsa_verifier(
+    params: &'static signature::RsaParameters,
+    public_key: &[u8],
+    msg: &[u8],
+    sig: &[u8],
+) -> Result<(), error::Unspecified> {
+    let mut verifier =
+        signature::RsaVerifier::new(params, public_key).map_err(|_| error::Unspecified)?;
+    verifier.verify(msg, sig)?;
+    // Verifying again with the same storage gives the same result.
+    verifier.verify(msg, sig)
+}
@@ -0,0 +1,104 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+#![cfg(feature = "alloc")]
+
+//! Allocation counting for `signature::RsaVerifier`. This is separate from
+//! rsa_tests.rs because it replaces the global allocator.
+
+use ring::{rand, signature};
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+wasm_bindgen_test_configure!(run_in_browser);
+
+struct CountingAllocator;
+
+thread_local! {
+    // Per-thread so that tests running concurrently don't interfere.
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|n| n.set(n.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn allocations_during(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    f();
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+#[test]
+fn rsa_verifier_does_not_allocate() {
+    const PRIVATE_KEY: &[u8] = include_bytes!("rsa_test_private_key_2048.p8");
+    const PUBLIC_KEY: &[u8] = include_bytes!("rsa_test_public_key_2048.der");
+    const MESSAGE: &[u8] = b"hello, world";
+
+    let rng = rand::SystemRandom::new();
+    let key_pair = signature::RsaKeyPair::from_pkcs8(PRIVATE_KEY).unwrap();
+
+    for (encoding, params) in [
+        (
+            &signature::RSA_PKCS1_SHA256 as &dyn signature::RsaEncoding,
+            &signature::RSA_PKCS1_2048_8192_SHA256,
+        ),
+        (
+            &signature::RSA_PSS_SHA256,
+            &signature::RSA_PSS_2048_8192_SHA256,
+        ),
+    ] {
+        let mut sig = vec![0; key_pair.public().modulus_len()];
+        key_pair.sign(encoding, &rng, MESSAGE, &mut sig).unwrap();
+
+        let unparsed = signature::UnparsedPublicKey::new(params, PUBLIC_KEY);
+        let one_shot = allocations_during(|| unparsed.verify(MESSAGE, &sig).unwrap());
+
+        let mut verifier = signature::RsaVerifier::new(params, PUBLIC_KEY).unwrap();
+        let reused = allocations_during(|| verifier.verify(MESSAGE, &sig).unwrap());
+
+        // The one-shot path allocates its temporaries; the verifier reuses
+        // its scratch buffers.
+        assert!(one_shot > 0);
+        assert_eq!(reused, 0);
+
+        // Failures don't allocate either, and don't break later calls.
+        let mut bad_sig = sig.clone();
+        bad_sig[0] ^= 1;
+        assert_eq!(
+            allocations_during(|| assert!(verifier.verify(MESSAGE, &bad_sig).is_err())),
+            0
+        );
+        assert_eq!(
+            allocations_during(|| verifier.verify(MESSAGE, &sig).unwrap()),
+            0
+        );
+    }
+}
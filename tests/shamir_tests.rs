@@ -0,0 +1,145 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+#![cfg(feature = "alloc")]
+
+use ring::{error, rand, shamir, test, test_file};
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+fn shamir_known_answer_test() {
+    test::run(test_file!("shamir_tests.txt"), |section, test_case| {
+        assert_eq!(section, "");
+        let secret = test_case.consume_bytes("Secret");
+        let threshold = test_case.consume_usize("Threshold");
+        let count = test_case.consume_usize("Count");
+        let random = test_case.consume_bytes("Random");
+        let expected = test_case.consume_bytes("Shares");
+
+        let rng = test::rand::FixedSliceRandom { bytes: &random };
+        let shares = shamir::split(&rng, &secret, threshold, count)?;
+        assert_eq!(serialize(&shares), expected);
+
+        // Every subset of `threshold` shares reconstructs the secret.
+        for subset in subsets(&shares, threshold) {
+            assert_eq!(shamir::combine(&subset)?, secret);
+        }
+
+        // Parsing the serialized shares gives the same shares.
+        let parsed = expected
+            .chunks(1 + secret.len())
+            .map(shamir::Share::from_bytes)
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(parsed, shares);
+
+        Ok(())
+    });
+}
+
+#[test]
+fn shamir_round_trip_test() {
+    let rng = rand::SystemRandom::new();
+    let secret: [u8; 32] = rand::generate(&rng).unwrap().expose();
+
+    for (threshold, count) in [(2, 2), (2, 5), (3, 5), (5, 7)] {
+        let shares = shamir::split(&rng, &secret, threshold, count).unwrap();
+        assert_eq!(shares.len(), count);
+        for (i, share) in shares.iter().enumerate() {
+            assert_eq!(usize::from(share.index()), i + 1);
+            assert_eq!(share.as_ref().len(), 1 + secret.len());
+        }
+
+        // Any `threshold` shares, or more, reconstruct the secret.
+        for len in threshold..=count {
+            for subset in subsets(&shares, len) {
+                assert_eq!(shamir::combine(&subset).unwrap(), secret);
+            }
+        }
+
+        // Fewer shares don't.
+        for len in 1..threshold {
+            for subset in subsets(&shares, len) {
+                assert_ne!(shamir::combine(&subset).unwrap(), secret);
+            }
+        }
+    }
+
+    // The largest number of shares.
+    let shares = shamir::split(&rng, &secret, 3, shamir::MAX_SHARES).unwrap();
+    assert_eq!(shares.last().unwrap().index(), 255);
+    assert_eq!(shamir::combine(&shares[252..]).unwrap(), secret);
+}
+
+#[test]
+fn shamir_split_errors_test() {
+    let rng = rand::SystemRandom::new();
+    let secret = [1u8; 16];
+    for (secret, threshold, count) in [
+        (&[][..], 2, 3),
+        (&secret[..], 0, 3),
+        (&secret[..], 1, 3),
+        (&secret[..], 4, 3),
+        (&secret[..], 2, shamir::MAX_SHARES + 1),
+    ] {
+        assert_eq!(
+            shamir::split(&rng, secret, threshold, count).unwrap_err(),
+            error::Unspecified
+        );
+    }
+}
+
+#[test]
+fn shamir_combine_errors_test() {
+    let rng = rand::SystemRandom::new();
+    let shares = shamir::split(&rng, &[1u8; 16], 2, 3).unwrap();
+
+    assert!(shamir::combine(&[]).is_err());
+    assert!(shamir::combine(&[shares[0].clone(), shares[0].clone()]).is_err());
+
+    let short = shamir::Share::from_bytes(&shares[1].as_ref()[..16]).unwrap();
+    assert!(shamir::combine(&[shares[0].clone(), short]).is_err());
+
+    assert!(shamir::Share::from_bytes(&[]).is_err());
+    assert!(shamir::Share::from_bytes(&[1]).is_err());
+    assert!(shamir::Share::from_bytes(&[0, 1]).is_err());
+}
+
+fn serialize(shares: &[shamir::Share]) -> Vec<u8> {
+    shares
+        .iter()
+        .flat_map(|share| share.as_ref().iter().copied())
+        .collect()
+}
+
+/// Returns every subset of `len` elements of `items`, in order.
+fn subsets<T: Clone>(items: &[T], len: usize) -> Vec<Vec<T>> {
+    if len == 0 {
+        return vec![vec![]];
+    }
+    if items.len() < len {
+        return vec![];
+    }
+    let (first, rest) = items.split_first().unwrap();
+    let mut with_first = subsets(rest, len - 1);
+    with_first
+        .iter_mut()
+        .for_each(|subset| subset.insert(0, first.clone()));
+    with_first.extend(subsets(rest, len));
+    with_first
+}
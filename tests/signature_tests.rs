@@ -24,3 +24,17 @@ fn signature_impl_test() {
     // Test `AsRef<[u8]>`
     assert_eq!(unparsed_public_key.as_ref(), &[0x01, 0x02, 0x03]);
 }
+
+#[test]
+fn signature_public_key_fingerprint_test() {
+    // The Ed25519 public key of tests/ed25519_test_private_key.pem, and its
+    // pin computed with
+    // `openssl pkey -pubout -outform der | openssl dgst -sha256 -binary`.
+    let spki = test::from_hex(
+        "302a300506032b65700321005809e9fef6dcec58f0f2e3b0d67e9880a11957e083ace85835c3b6c8fbaf6b7d",
+    )
+    .unwrap();
+    let expected =
+        test::from_hex("0837d59f302d53c7be3b8050b76fa94c02fe7f9bb36475bab7ecb2d21e34a2d0").unwrap();
+    assert_eq!(&signature::public_key_fingerprint(&spki)[..], &expected[..]);
+}
@@ -0,0 +1,108 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+use ring::{error, signify, test, test_file};
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+fn signify_verify_tests() {
+    test::run(test_file!("signify_tests.txt"), |section, test_case| {
+        assert_eq!(section, "");
+        let public_key = test_case.consume_string("PublicKey");
+        let signature = test_case.consume_string("Signature");
+        let message = test_case.consume_bytes("Message");
+        let expected_result = match test_case.consume_string("Result").as_str() {
+            "P" => Ok(()),
+            "F" => Err(error::Unspecified),
+            s => panic!("{:?} is not a valid result", s),
+        };
+
+        let public_key_file = format!("untrusted comment: signify public key\n{public_key}\n");
+        let signature_file = format!("untrusted comment: verify with test.pub\n{signature}\n");
+
+        // The public key may be given as a whole file or as just its base64
+        // line.
+        assert_eq!(
+            signify::verify(&public_key_file, &signature_file, &message),
+            expected_result
+        );
+        assert_eq!(
+            signify::verify(&public_key, &signature_file, &message),
+            expected_result
+        );
+
+        // CRLF line endings and trailing lines, e.g. minisign's trusted
+        // comment, are accepted.
+        let signature_file_crlf = signature_file.replace('\n', "\r\n");
+        assert_eq!(
+            signify::verify(&public_key_file, &signature_file_crlf, &message),
+            expected_result
+        );
+        let minisign_file = format!("{signature_file}trusted comment: timestamp:0\nAAAA\n");
+        assert_eq!(
+            signify::verify(&public_key_file, &minisign_file, &message),
+            expected_result
+        );
+
+        // The signature's comment line is required.
+        assert!(signify::verify(&public_key_file, &signature, &message).is_err());
+
+        Ok(())
+    });
+}
+
+#[test]
+fn signify_verify_minisign_output() {
+    // The complete files written by `minisign -G` and `minisign -S -l`, from
+    // the minisign-verify test suite.
+    const PUBLIC_KEY: &str = "untrusted comment: minisign public key E7620F1842B4E81F\n\
+        RWQf6LRCGA9i53mlYecO4IzT51TGPpvWucNSCh1CBM0QTaLn73Y7GFO3\n";
+    const SIGNATURE: &str = "untrusted comment: signature from minisign secret key\n\
+        RWQf6LRCGA9i59SLOFxz6NxvASXDJeRtuZykwQepbDEGt87ig1BNpWaVWuNrm73YiIiJbq71Wi+dP9eKL8OC351vwIasSSbXxwA=\n\
+        trusted comment: timestamp:1555779966\tfile:test\n\
+        QtKMXWyYcwdpZAlPF7tE2ENJkRd1ujvKjlj1m9RtHTBnZPa5WKU5uWRs5GoP5M/VqE81QFuMKI5k/SfNQUaOAA==\n";
+
+    assert!(signify::verify(PUBLIC_KEY, SIGNATURE, b"test").is_ok());
+    assert!(signify::verify(PUBLIC_KEY, SIGNATURE, b"Test").is_err());
+}
+
+#[test]
+fn signify_verify_malformed() {
+    const PUBLIC_KEY: &str = "RWQ7fRqQwvReaOBsZimdykXnQGazdASWL9TUzQPFykcWCHmThZ9qTtP+";
+    const SIGNATURE: &str = "untrusted comment: verify with ring.pub\n\
+        RWQ7fRqQwvReaMdHGiwkxykvjXwjdnvtnznL8UBzVxP+tjSsuXzhfLFDYkZd5IG4yZ0Z/BE6YJ+0E/6Cpn/fR6RF08Aad4uXTwA=\n";
+    const MESSAGE: &[u8] = b"ring-0.17.8.tar.gz\n";
+
+    assert!(signify::verify(PUBLIC_KEY, SIGNATURE, MESSAGE).is_ok());
+
+    for (public_key, signature) in [
+        ("", SIGNATURE),
+        (PUBLIC_KEY, ""),
+        ("untrusted comment: signify public key\n", SIGNATURE),
+        (PUBLIC_KEY, "untrusted comment: verify with ring.pub\n"),
+        // Whitespace isn't allowed within the base64 line.
+        (&format!(" {PUBLIC_KEY}"), SIGNATURE),
+        // The base64 line must be valid base64 of the right length.
+        (&PUBLIC_KEY[..PUBLIC_KEY.len() - 4], SIGNATURE),
+        (&format!("{PUBLIC_KEY}AAAA"), SIGNATURE),
+        (&PUBLIC_KEY.replace('+', "-"), SIGNATURE),
+    ] {
+        assert!(signify::verify(public_key, signature, MESSAGE).is_err());
+    }
+}
@@ -0,0 +1,102 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+use core::hash::{Hash, Hasher};
+use ring::{siphash, test, test_file};
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+fn siphash_tests() {
+    test::run(test_file!("siphash_tests.txt"), |section, test_case| {
+        let key: [u8; siphash::KEY_LEN] = test_case.consume_bytes("Key").try_into().unwrap();
+        let input = test_case.consume_bytes("Input");
+        let expected = test_case.consume_bytes("Output");
+        let expected = u64::from_le_bytes(expected.try_into().unwrap());
+
+        match section {
+            "SipHash-1-3" => check(|| siphash::SipHasher13::new(key), &input, expected),
+            "SipHash-2-4" => check(|| siphash::SipHasher24::new(key), &input, expected),
+            _ => unreachable!(),
+        }
+
+        Ok(())
+    });
+}
+
+fn check<H: Hasher>(new: impl Fn() -> H, input: &[u8], expected: u64) {
+    // One call to `write`.
+    let mut hasher = new();
+    hasher.write(input);
+    assert_eq!(hasher.finish(), expected);
+
+    // `finish` doesn't reset or consume the state.
+    assert_eq!(hasher.finish(), expected);
+
+    // Every split of the input into two calls to `write`.
+    for i in 0..=input.len() {
+        let (a, b) = input.split_at(i);
+        let mut hasher = new();
+        hasher.write(a);
+        hasher.write(b);
+        assert_eq!(hasher.finish(), expected);
+    }
+
+    // One byte at a time.
+    let mut hasher = new();
+    input.iter().for_each(|b| hasher.write_u8(*b));
+    assert_eq!(hasher.finish(), expected);
+}
+
+#[test]
+#[allow(deprecated)]
+fn siphash_24_matches_core() {
+    // `core::hash::SipHasher` is SipHash-2-4.
+    let key: [u8; siphash::KEY_LEN] = core::array::from_fn(|i| (i * 3 + 1) as u8);
+    let k0 = u64::from_le_bytes(key[..8].try_into().unwrap());
+    let k1 = u64::from_le_bytes(key[8..].try_into().unwrap());
+
+    for value in [&b""[..], b"a", b"hello, world", &[0xab; 1000]] {
+        let mut ours = siphash::SipHasher24::new(key);
+        let mut core = core::hash::SipHasher::new_with_keys(k0, k1);
+        value.hash(&mut ours);
+        value.hash(&mut core);
+        (1234u64, "str", [1u16, 2, 3]).hash(&mut ours);
+        (1234u64, "str", [1u16, 2, 3]).hash(&mut core);
+        assert_eq!(ours.finish(), core.finish());
+    }
+}
+
+#[test]
+fn siphash_keyed() {
+    let hash = |key: [u8; siphash::KEY_LEN]| {
+        let mut hasher = siphash::SipHasher13::new(key);
+        "hello, world".hash(&mut hasher);
+        hasher.finish()
+    };
+    assert_eq!(hash([1; siphash::KEY_LEN]), hash([1; siphash::KEY_LEN]));
+    assert_ne!(hash([1; siphash::KEY_LEN]), hash([2; siphash::KEY_LEN]));
+}
+
+#[test]
+fn siphash_debug() {
+    let hasher = siphash::SipHasher13::new([0; siphash::KEY_LEN]);
+    assert_eq!("SipHasher13", format!("{:?}", hasher));
+    let hasher = siphash::SipHasher24::new([0; siphash::KEY_LEN]);
+    assert_eq!("SipHasher24", format!("{:?}", hasher));
+}
@@ -0,0 +1,115 @@
+// Copyright 2026 Brian Smith.
+//
+// Permission to use, copy, modify, and/or distribute this software for any
+// purpose with or without fee is hereby granted, provided that the above
+// copyright notice and this permission notice appear in all copies.
+//
+// THE SOFTWARE IS PROVIDED "AS IS" AND THE AUTHORS DISCLAIM ALL WARRANTIES
+// WITH REGARD TO THIS SOFTWARE INCLUDING ALL IMPLIED WARRANTIES OF
+// MERCHANTABILITY AND FITNESS. IN NO EVENT SHALL THE AUTHORS BE LIABLE FOR ANY
+// SPECIAL, DIRECT, INDIRECT, OR CONSEQUENTIAL DAMAGES OR ANY DAMAGES
+// WHATSOEVER RESULTING FROM LOSS OF USE, DATA OR PROFITS, WHETHER IN AN ACTION
+// OF CONTRACT, NEGLIGENCE OR OTHER TORTIOUS ACTION, ARISING OUT OF OR IN
+// CONNECTION WITH THE USE OR PERFORMANCE OF THIS SOFTWARE.
+
+use ring::{digest, error, test, test_file, tls12};
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+use wasm_bindgen_test::{wasm_bindgen_test as test, wasm_bindgen_test_configure};
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[test]
+fn tls12_prf_tests() {
+    test::run(test_file!("tls12_tests.txt"), |section, test_case| {
+        assert_eq!(section, "");
+        let alg = prf_alg(test_case.consume_digest_alg("Hash"))?;
+        let secret = test_case.consume_bytes("Secret");
+        let label = test_case.consume_bytes("Label");
+        let seed = test_case.consume_bytes("Seed");
+        let expected = test_case.consume_bytes("Output");
+
+        let mut out = vec![0u8; expected.len()];
+        tls12::prf(alg, &secret, &label, &seed, &mut out);
+        assert_eq!(out, expected);
+
+        Ok(())
+    });
+}
+
+#[test]
+fn tls12_key_block_tests() {
+    test::run(
+        test_file!("tls12_key_block_tests.txt"),
+        |section, test_case| {
+            assert_eq!(section, "");
+            let alg = prf_alg(test_case.consume_digest_alg("Hash"))?;
+            let master_secret = test_case.consume_bytes("MasterSecret");
+            let client_random = test_case.consume_bytes("ClientRandom");
+            let server_random = test_case.consume_bytes("ServerRandom");
+            let client_write_mac_key = test_case.consume_bytes("ClientWriteMacKey");
+            let server_write_mac_key = test_case.consume_bytes("ServerWriteMacKey");
+            let client_write_key = test_case.consume_bytes("ClientWriteKey");
+            let server_write_key = test_case.consume_bytes("ServerWriteKey");
+            let client_write_iv = test_case.consume_bytes("ClientWriteIV");
+            let server_write_iv = test_case.consume_bytes("ServerWriteIV");
+
+            let key_block = tls12::key_block(
+                alg,
+                &master_secret,
+                client_random.as_slice().try_into()?,
+                server_random.as_slice().try_into()?,
+                client_write_mac_key.len(),
+                client_write_key.len(),
+                client_write_iv.len(),
+            )?;
+            assert_eq!(key_block.client_write_mac_key(), &client_write_mac_key[..]);
+            assert_eq!(key_block.server_write_mac_key(), &server_write_mac_key[..]);
+            assert_eq!(key_block.client_write_key(), &client_write_key[..]);
+            assert_eq!(key_block.server_write_key(), &server_write_key[..]);
+            assert_eq!(key_block.client_write_iv(), &client_write_iv[..]);
+            assert_eq!(key_block.server_write_iv(), &server_write_iv[..]);
+
+            Ok(())
+        },
+    );
+}
+
+#[test]
+fn tls12_key_block_lengths() {
+    let random = [0u8; tls12::RANDOM_LEN];
+    let key_block = |mac_key_len, key_len, iv_len| {
+        tls12::key_block(
+            tls12::PRF_SHA256,
+            &[0; 48],
+            &random,
+            &random,
+            mac_key_len,
+            key_len,
+            iv_len,
+        )
+    };
+
+    assert!(key_block(
+        tls12::MAX_MAC_KEY_LEN,
+        tls12::MAX_KEY_LEN,
+        tls12::MAX_IV_LEN
+    )
+    .is_ok());
+    assert!(key_block(tls12::MAX_MAC_KEY_LEN + 1, 16, 4).is_err());
+    assert!(key_block(0, tls12::MAX_KEY_LEN + 1, 4).is_err());
+    assert!(key_block(0, 16, tls12::MAX_IV_LEN + 1).is_err());
+
+    assert_eq!(format!("{:?}", key_block(0, 16, 4).unwrap()), "KeyBlock");
+}
+
+fn prf_alg(
+    digest_alg: Option<&'static digest::Algorithm>,
+) -> Result<tls12::Algorithm, error::Unspecified> {
+    match digest_alg {
+        Some(alg) if alg == &digest::SHA256 => Ok(tls12::PRF_SHA256),
+        Some(alg) if alg == &digest::SHA384 => Ok(tls12::PRF_SHA384),
+        _ => Err(error::Unspecified),
+    }
+}